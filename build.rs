@@ -0,0 +1,8 @@
+//! Build script: records the build date shown on the help screen
+
+fn main() {
+    println!(
+        "cargo:rustc-env=WARPSCAN_BUILD_DATE={}",
+        chrono::Utc::now().format("%Y-%m-%d")
+    );
+}
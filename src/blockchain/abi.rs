@@ -0,0 +1,76 @@
+//! Method selector decoding for transaction input data
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Canonical signatures of commonly called functions, as a JSON array
+const FUNCTION_SIGNATURES: &str = include_str!("function_signatures.json");
+
+/// Process-wide decoder built from the bundled signatures
+static DECODER: OnceLock<MethodDecoder> = OnceLock::new();
+
+/// Lookup table from 4-byte method selectors to function signatures
+#[derive(Debug, Clone, Default)]
+pub struct MethodDecoder {
+    selectors: HashMap<[u8; 4], String>,
+}
+
+impl MethodDecoder {
+    /// Build a decoder from canonical signatures such as `transfer(address,uint256)`
+    pub fn from_signatures<'a>(signatures: impl IntoIterator<Item = &'a str>) -> Self {
+        let selectors = signatures
+            .into_iter()
+            .map(|signature| {
+                let hash = ethers::utils::id(signature);
+                ([hash[0], hash[1], hash[2], hash[3]], signature.to_string())
+            })
+            .collect();
+        Self { selectors }
+    }
+
+    /// Get the shared decoder of the bundled signatures
+    pub fn bundled() -> &'static MethodDecoder {
+        DECODER.get_or_init(|| {
+            let signatures: Vec<String> = serde_json::from_str(FUNCTION_SIGNATURES)
+                .expect("Bundled function signatures are valid JSON");
+            Self::from_signatures(signatures.iter().map(String::as_str))
+        })
+    }
+
+    /// Signature for a selector, if known
+    pub fn lookup(&self, selector: [u8; 4]) -> Option<&str> {
+        self.selectors.get(&selector).map(String::as_str)
+    }
+
+    /// Signature of the function called by `input`, from its first 4 bytes
+    ///
+    /// Returns `None` for plain transfers and unknown selectors.
+    pub fn decode_selector(input: &[u8]) -> Option<String> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        Self::bundled().lookup(selector).map(str::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_selector() {
+        // transfer(address,uint256) followed by its arguments
+        let input = hex::decode(
+            "a9059cbb000000000000000000000000d8da6bf26964af9d7eed9e03e53415d37aa96045\
+             0000000000000000000000000000000000000000000000000de0b6b3a7640000",
+        )
+        .unwrap();
+        assert_eq!(
+            MethodDecoder::decode_selector(&input).as_deref(),
+            Some("transfer(address,uint256)")
+        );
+        assert_eq!(
+            MethodDecoder::decode_selector(&[0xde, 0xad, 0xbe, 0xef]),
+            None
+        );
+        assert_eq!(MethodDecoder::decode_selector(&[]), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Local address analysis helpers (no network calls)
+
+use crate::error::{Error, Result};
+use ethers::types::Address;
+use ethers::utils::{get_create2_address_from_hash, keccak256, to_checksum};
+use std::str::FromStr;
+
+/// EIP-55 checksum status of an address as it was entered
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChecksumStatus {
+    /// Mixed case matching the EIP-55 checksum
+    Valid,
+    /// All lowercase or all uppercase, so no checksum to verify
+    Missing,
+    /// Mixed case that does not match the EIP-55 checksum
+    Invalid,
+}
+
+/// Hex nibbles of an address without the `0x` prefix, lowercased
+fn nibbles(address: &str) -> String {
+    address.strip_prefix("0x").unwrap_or(address).to_lowercase()
+}
+
+/// Shannon entropy (bits per nibble) of the address hex characters
+///
+/// A random address is close to the maximum of 4.0.
+pub fn compute_address_entropy(address: &str) -> f64 {
+    let hex = nibbles(address);
+    if hex.is_empty() {
+        return 0.0;
+    }
+
+    let mut counts = [0usize; 16];
+    for c in hex.chars() {
+        if let Some(d) = c.to_digit(16) {
+            counts[d as usize] += 1;
+        }
+    }
+
+    let total = hex.len() as f64;
+    counts
+        .iter()
+        .filter(|&&n| n > 0)
+        .map(|&n| {
+            let p = n as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Number of leading zero nibbles in the address
+pub fn count_leading_zeros(address: &str) -> u8 {
+    nibbles(address).chars().take_while(|&c| c == '0').count() as u8
+}
+
+/// Longest run of the same hex character in the address
+fn longest_repeat(address: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut previous = None;
+    for c in nibbles(address).chars() {
+        if Some(c) == previous {
+            current += 1;
+        } else {
+            current = 1;
+            previous = Some(c);
+        }
+        longest = longest.max(current);
+    }
+    longest
+}
+
+/// Whether the address looks like a generated vanity address
+///
+/// True when it has more than 4 leading zeros or a run of more than 5 repeated characters.
+pub fn is_vanity_address(address: &str) -> bool {
+    count_leading_zeros(address) > 4 || longest_repeat(address) > 5
+}
+
+/// EIP-55 checksummed form of an address
+pub fn to_checksum_address(address: &str) -> Option<String> {
+    Address::from_str(address)
+        .ok()
+        .map(|addr| to_checksum(&addr, None))
+}
+
+/// Check the EIP-55 checksum of an address as entered
+pub fn checksum_status(address: &str) -> Option<ChecksumStatus> {
+    let checksummed = to_checksum_address(address)?;
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+
+    if hex == hex.to_lowercase() || hex == hex.to_uppercase() {
+        Some(ChecksumStatus::Missing)
+    } else if address == checksummed {
+        Some(ChecksumStatus::Valid)
+    } else {
+        Some(ChecksumStatus::Invalid)
+    }
+}
+
+/// Decode a hex string with or without the `0x` prefix
+fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>> {
+    let hex = value.trim().strip_prefix("0x").unwrap_or(value.trim());
+    hex::decode(hex).map_err(|e| Error::validation(format!("Invalid {} hex: {}", field, e)))
+}
+
+/// Predict a CREATE2 deployment address (EIP-1014)
+///
+/// `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12:]`. The salt is
+/// left-padded to 32 bytes. `init_code_hash` should be the 32-byte hash; any other
+/// length is treated as raw init code and hashed first.
+pub fn calculate_create2_address(
+    deployer: &str,
+    salt: &str,
+    init_code_hash: &str,
+) -> Result<String> {
+    let deployer = Address::from_str(deployer.trim())
+        .map_err(|e| Error::validation(format!("Invalid deployer address: {}", e)))?;
+
+    let salt = decode_hex("salt", salt)?;
+    if salt.len() > 32 {
+        return Err(Error::validation("Salt must be at most 32 bytes"));
+    }
+    let mut salt_bytes = [0u8; 32];
+    salt_bytes[32 - salt.len()..].copy_from_slice(&salt);
+
+    let init_code = decode_hex("init code hash", init_code_hash)?;
+    let hash = if init_code.len() == 32 {
+        init_code
+    } else {
+        keccak256(&init_code).to_vec()
+    };
+
+    let address = get_create2_address_from_hash(deployer, salt_bytes, hash);
+    Ok(to_checksum(&address, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vanity_and_checksum() {
+        let vanity = "0x0000000000c2d145a2526bd8c716263bfebe1a72";
+        assert_eq!(count_leading_zeros(vanity), 10);
+        assert!(is_vanity_address(vanity));
+        assert!(!is_vanity_address(
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"
+        ));
+        assert!(compute_address_entropy("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed") > 3.0);
+
+        assert_eq!(
+            checksum_status("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Some(ChecksumStatus::Valid)
+        );
+        assert_eq!(
+            checksum_status("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            Some(ChecksumStatus::Missing)
+        );
+        assert_eq!(
+            checksum_status("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+            Some(ChecksumStatus::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_create2_address() {
+        // EIP-1014 example 0: zero deployer and salt, init code 0x00
+        let predicted =
+            calculate_create2_address("0x0000000000000000000000000000000000000000", "0x00", "0x00")
+                .unwrap();
+        assert_eq!(predicted, "0x4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38");
+    }
+}
@@ -0,0 +1,202 @@
+//! beaconcha.in API client for consensus layer validator data
+
+use crate::error::{Error, Result};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Base URL of the beaconcha.in v1 API (Ethereum mainnet)
+const BEACONCHAIN_API_URL: &str = "https://beaconcha.in/api/v1";
+
+/// Beacon chain deposit contract on Ethereum mainnet
+pub const DEPOSIT_CONTRACT_ADDRESS: &str = "0x00000000219ab540356cBB839Cbe05303d7705Fa";
+
+/// The free tier allows one request per second
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Lifecycle status of a validator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ValidatorStatus {
+    Pending,
+    Active,
+    Exiting,
+    Exited,
+    Slashed,
+    Unknown,
+}
+
+impl ValidatorStatus {
+    /// Map a beaconcha.in status string (e.g. "active_online") to a status
+    pub fn from_api(status: &str) -> Self {
+        let status = status.to_lowercase();
+        if status.contains("slash") {
+            Self::Slashed
+        } else if status.starts_with("pending") || status == "deposited" {
+            Self::Pending
+        } else if status.starts_with("active") {
+            Self::Active
+        } else if status.starts_with("exiting") {
+            Self::Exiting
+        } else if status.starts_with("exited") || status.starts_with("withdraw") {
+            Self::Exited
+        } else {
+            Self::Unknown
+        }
+    }
+
+    /// Human readable label
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Pending => "Pending",
+            Self::Active => "Active",
+            Self::Exiting => "Exiting",
+            Self::Exited => "Exited",
+            Self::Slashed => "Slashed",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+/// Consensus layer state of a single validator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorInfo {
+    pub pubkey: String,
+    pub index: u64,
+    pub balance_gwei: u64,
+    pub effective_balance_gwei: u64,
+    pub status: ValidatorStatus,
+    pub activation_epoch: u64,
+    pub slashed: bool,
+}
+
+/// Rate-limited beaconcha.in client
+#[derive(Clone)]
+pub struct BeaconClient {
+    client: Client,
+    last_request: Arc<Mutex<Option<Instant>>>,
+}
+
+impl BeaconClient {
+    /// Create a new client
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .expect("Failed to build HTTP client");
+        Self {
+            client,
+            last_request: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Send a GET request, waiting out the rate limit first, and return `data`
+    async fn get_data(&self, path: &str) -> Result<serde_json::Value> {
+        {
+            let mut last_request = self.last_request.lock().await;
+            if let Some(last) = *last_request {
+                let elapsed = last.elapsed();
+                if elapsed < MIN_REQUEST_INTERVAL {
+                    tokio::time::sleep(MIN_REQUEST_INTERVAL - elapsed).await;
+                }
+            }
+            *last_request = Some(Instant::now());
+        }
+
+        let url = format!("{}/{}", BEACONCHAIN_API_URL, path);
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("beaconcha.in request failed: {}", e)))?;
+
+        if resp.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(Error::network("beaconcha.in rate limit exceeded"));
+        }
+        if !resp.status().is_success() {
+            return Err(Error::network(format!(
+                "beaconcha.in HTTP error: {}",
+                resp.status()
+            )));
+        }
+
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| Error::parse(format!("Invalid beaconcha.in response: {}", e)))?;
+        if json.get("status").and_then(|s| s.as_str()) != Some("OK") {
+            return Err(Error::network(format!(
+                "beaconcha.in error: {}",
+                json.get("status")
+                    .and_then(|s| s.as_str())
+                    .unwrap_or("unknown")
+            )));
+        }
+        Ok(json.get("data").cloned().unwrap_or(serde_json::Value::Null))
+    }
+
+    /// Look up a validator by public key or index
+    pub async fn get_validator(&self, pubkey_or_index: &str) -> Result<Option<ValidatorInfo>> {
+        let data = self
+            .get_data(&format!("validator/{}", pubkey_or_index))
+            .await?;
+
+        // A single match is returned as an object, several as an array
+        let entry = match data {
+            serde_json::Value::Array(entries) => entries.into_iter().next(),
+            serde_json::Value::Object(_) => Some(data),
+            _ => None,
+        };
+        let Some(entry) = entry else {
+            return Ok(None);
+        };
+
+        let number = |field: &str| entry.get(field).and_then(|v| v.as_u64()).unwrap_or(0);
+        Ok(Some(ValidatorInfo {
+            pubkey: entry
+                .get("pubkey")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            index: number("validatorindex"),
+            balance_gwei: number("balance"),
+            effective_balance_gwei: number("effectivebalance"),
+            status: ValidatorStatus::from_api(
+                entry
+                    .get("status")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default(),
+            ),
+            activation_epoch: number("activationepoch"),
+            slashed: entry
+                .get("slashed")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+        }))
+    }
+
+    /// Indices of the validators funded from an execution layer address
+    pub async fn get_validator_indices_by_depositor(&self, address: &str) -> Result<Vec<u64>> {
+        let data = self
+            .get_data(&format!("validator/eth1/{}", address))
+            .await?;
+
+        let entries = match data {
+            serde_json::Value::Array(entries) => entries,
+            serde_json::Value::Object(_) => vec![data],
+            _ => Vec::new(),
+        };
+        Ok(entries
+            .iter()
+            .filter_map(|entry| entry.get("validatorindex").and_then(|v| v.as_u64()))
+            .collect())
+    }
+}
+
+impl Default for BeaconClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
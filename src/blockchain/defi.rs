@@ -0,0 +1,225 @@
+//! DeFi swap detection
+//!
+//! A swap is recognised from a transaction's transfers: the first sender gives
+//! up one asset and receives another. The protocol comes from a known router
+//! taking part in the transfers, or from the pool's `Swap` event.
+
+use super::types::DecodedLog;
+use crate::ui::models::transaction::{TransactionDetails, TransactionTransfer};
+
+/// DEX routers on mainnet, as `(address, protocol)`
+const KNOWN_ROUTERS: &[(&str, &str)] = &[
+    ("0x7a250d5630b4cf539739df2c5dacb4c659f2488d", "Uniswap V2"),
+    ("0xe592427a0aece92de3edee1f18e0157c05861564", "Uniswap V3"),
+    ("0x68b3465833fb72a70ecdf485e0e4c7bd8665fc45", "Uniswap V3"),
+    (
+        "0xef1c6e67703c7bd7107eed8303fbe6ec2554bf6b",
+        "Uniswap Universal Router",
+    ),
+    (
+        "0x3fc91a3afd70395cd496c647d5a6cc9d4b2b7fad",
+        "Uniswap Universal Router",
+    ),
+    ("0xd9e1ce17f2641f24ae83637ab66a2cca9c378b9f", "SushiSwap"),
+];
+
+/// One side of a swap
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferLeg {
+    pub symbol: String,
+    /// `None` for ETH
+    pub token_address: Option<String>,
+    /// Net amount sent or received by the trader
+    pub amount: f64,
+    /// Indices of the transfers making up this side
+    pub transfers: Vec<usize>,
+}
+
+/// A token swap found in a transaction
+#[derive(Debug, Clone, PartialEq)]
+pub struct SwapSummary {
+    pub token_in: TransferLeg,
+    pub token_out: TransferLeg,
+    pub protocol: String,
+}
+
+impl SwapSummary {
+    /// One-line description, e.g. `Swap: 1.5 WETH for 3000 USDC via Uniswap V3`
+    pub fn description(&self) -> String {
+        format!(
+            "Swap: {} {} for {} {} via {}",
+            format_amount(self.token_in.amount),
+            self.token_in.symbol,
+            format_amount(self.token_out.amount),
+            self.token_out.symbol,
+            self.protocol
+        )
+    }
+}
+
+/// Decoder for DeFi operations in a transaction
+pub struct DeFiDecoder;
+
+impl DeFiDecoder {
+    /// Detect a swap routed through a known DEX router
+    pub fn detect_swap(transfers: &[TransactionTransfer]) -> Option<SwapSummary> {
+        let protocol = transfers.iter().find_map(|transfer| {
+            Self::router_protocol(&transfer.from).or_else(|| Self::router_protocol(&transfer.to))
+        })?;
+        swap_legs(transfers).map(|(token_in, token_out)| SwapSummary {
+            token_in,
+            token_out,
+            protocol: protocol.to_string(),
+        })
+    }
+
+    /// Detect a swap in a transaction, also when it called the router
+    /// directly or only the pool's `Swap` event identifies it
+    pub fn detect_transaction_swap(tx: &TransactionDetails) -> Option<SwapSummary> {
+        if let Some(swap) = Self::detect_swap(&tx.transfers) {
+            return Some(swap);
+        }
+        let protocol = tx
+            .to
+            .as_deref()
+            .and_then(Self::router_protocol)
+            .or_else(|| swap_event_protocol(&tx.logs))?;
+        swap_legs(&tx.transfers).map(|(token_in, token_out)| SwapSummary {
+            token_in,
+            token_out,
+            protocol: protocol.to_string(),
+        })
+    }
+
+    /// Protocol of a known DEX router
+    pub fn router_protocol(address: &str) -> Option<&'static str> {
+        KNOWN_ROUTERS
+            .iter()
+            .find(|(router, _)| router.eq_ignore_ascii_case(address))
+            .map(|(_, protocol)| *protocol)
+    }
+}
+
+/// Protocol of the first `Swap` event, told apart by the V3 price parameter
+fn swap_event_protocol(logs: &[DecodedLog]) -> Option<&'static str> {
+    let log = logs
+        .iter()
+        .find(|log| log.event_name.as_deref() == Some("Swap"))?;
+    let v3 = log
+        .decoded_params
+        .iter()
+        .any(|(name, _)| name == "sqrtPriceX96");
+    Some(if v3 { "Uniswap V3" } else { "Uniswap V2" })
+}
+
+/// Asset the first sender gave up and the one it received in return
+fn swap_legs(transfers: &[TransactionTransfer]) -> Option<(TransferLeg, TransferLeg)> {
+    let trader = &transfers.first()?.from;
+
+    // Net flow per asset for the trader, in order of first appearance
+    let mut legs: Vec<(String, TransferLeg)> = Vec::new();
+    for (index, transfer) in transfers.iter().enumerate() {
+        let sign = if transfer.from.eq_ignore_ascii_case(trader) {
+            -1.0
+        } else if transfer.to.eq_ignore_ascii_case(trader) {
+            1.0
+        } else {
+            continue;
+        };
+        let key = transfer.asset_key();
+        let position = match legs.iter().position(|(asset, _)| *asset == key) {
+            Some(position) => position,
+            None => {
+                legs.push((
+                    key,
+                    TransferLeg {
+                        symbol: transfer
+                            .token_symbol
+                            .clone()
+                            .unwrap_or_else(|| "ETH".to_string()),
+                        token_address: transfer.token_address.clone(),
+                        amount: 0.0,
+                        transfers: Vec::new(),
+                    },
+                ));
+                legs.len() - 1
+            }
+        };
+        let leg = &mut legs[position].1;
+        leg.amount += sign * transfer.value;
+        leg.transfers.push(index);
+    }
+
+    let mut token_in = legs
+        .iter()
+        .map(|(_, leg)| leg)
+        .find(|leg| leg.amount < 0.0)?
+        .clone();
+    let token_out = legs
+        .iter()
+        .map(|(_, leg)| leg)
+        .rfind(|leg| leg.amount > 0.0)?
+        .clone();
+    token_in.amount = -token_in.amount;
+    Some((token_in, token_out))
+}
+
+/// Amount with four decimals from 1 and six below, without trailing zeros
+fn format_amount(amount: f64) -> String {
+    let decimals = if amount >= 1.0 { 4 } else { 6 };
+    let text = format!("{:.*}", decimals, amount);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::models::transaction::TransferType;
+
+    fn transfer(from: &str, to: &str, value: f64, symbol: Option<&str>) -> TransactionTransfer {
+        TransactionTransfer {
+            transfer_type: match symbol {
+                Some(_) => TransferType::Token,
+                None => TransferType::ETH,
+            },
+            from: from.to_string(),
+            to: to.to_string(),
+            value,
+            token_symbol: symbol.map(str::to_string),
+            token_name: None,
+            token_address: symbol.map(|symbol| format!("0x{}", symbol.to_lowercase())),
+        }
+    }
+
+    #[test]
+    fn test_detect_swap() {
+        let router = "0x7a250d5630b4cf539739df2c5dacb4c659f2488d";
+        // ETH for USDC through the V2 router: ETH in, WETH to the pair, USDC out
+        let transfers = [
+            transfer("0xuser", router, 1.5, None),
+            transfer(router, "0xpair", 1.5, Some("WETH")),
+            transfer("0xpair", "0xuser", 3000.0, Some("USDC")),
+        ];
+        let swap = DeFiDecoder::detect_swap(&transfers).unwrap();
+        assert_eq!(swap.protocol, "Uniswap V2");
+        assert_eq!(swap.token_in.symbol, "ETH");
+        assert_eq!(swap.token_in.transfers, [0]);
+        assert_eq!(swap.token_out.symbol, "USDC");
+        assert_eq!(swap.token_out.transfers, [2]);
+        assert_eq!(
+            swap.description(),
+            "Swap: 1.5 ETH for 3000 USDC via Uniswap V2"
+        );
+
+        // The same flow without a known router is not labelled
+        let direct = [
+            transfer("0xuser", "0xpair", 1.5, Some("WETH")),
+            transfer("0xpair", "0xuser", 3000.0, Some("USDC")),
+        ];
+        assert!(DeFiDecoder::detect_swap(&direct).is_none());
+
+        // A router payment without anything coming back is not a swap
+        let payment = [transfer("0xuser", router, 1.0, None)];
+        assert!(DeFiDecoder::detect_swap(&payment).is_none());
+    }
+}
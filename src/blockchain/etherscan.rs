@@ -1,9 +1,18 @@
 //! Etherscan V2 multichain client
 
-use crate::blockchain::types::{AddressTx, TransactionStatus};
+use crate::blockchain::types::{
+    AddressTx, ContractCreationInfo, ContractSource, TransactionStatus, VerificationSource,
+};
+use crate::config::RateLimit;
 use crate::error::{Error, Result};
-use ethers::types::U256;
-use reqwest::Client;
+use ethers::types::{transaction::eip2930::AccessList, U256};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::time::Instant;
 
 /// Supported chains for Etherscan V2
 #[derive(Debug, Clone, Copy)]
@@ -19,9 +28,11 @@ pub enum EtherscanChain {
 }
 
 /// Token transfer data structure for Etherscan API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TokenTransfer {
     pub token_id: Option<String>,
+    /// Lowercase address of the token contract
+    pub contract_address: String,
     pub txn_hash: String,
     pub from: String,
     pub to: String,
@@ -32,7 +43,7 @@ pub struct TokenTransfer {
 }
 
 /// Internal transaction data structure for Etherscan API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct InternalTransaction {
     pub parent_tx_hash: String,
     pub block: u64,
@@ -46,17 +57,239 @@ pub struct InternalTransaction {
 }
 
 /// Token balance information from Etherscan API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TokenBalance {
     pub contract_address: String,
     pub name: String,
     pub symbol: String,
     pub decimals: u8,
     pub balance: f64,
+    /// Token standard as reported by the API (e.g. "ERC-20", "ERC-721")
+    pub token_type: Option<String>,
+}
+
+/// Category of a known-address name tag
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TagType {
+    Exchange,
+    Token,
+    Defi,
+    Hack,
+    Whale,
+    Team,
+}
+
+/// Holder of an ERC-20 token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenHolder {
+    pub address: String,
+    /// Raw balance in the token's smallest unit
+    pub quantity: String,
+    /// Percentage of the total supply held
+    pub share: f64,
+}
+
+/// Validator withdrawal (EIP-4895) paid out by the beacon chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BeaconWithdrawal {
+    pub block_number: u64,
+    pub withdrawal_index: u64,
+    pub validator_index: u64,
+    pub amount_gwei: u64,
+    pub timestamp: u64,
+}
+
+/// An ERC-20 allowance granted by an address, from its latest `Approval` event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenApproval {
+    pub token_address: String,
+    pub spender: String,
+    /// Allowance in the token's smallest unit
+    pub value: U256,
+    pub block_number: u64,
+    pub tx_hash: String,
+}
+
+impl TokenApproval {
+    /// Whether the allowance is one of the "unlimited" values wallets grant
+    pub fn is_unlimited(&self) -> bool {
+        self.value >= U256::MAX >> 1
+    }
+}
+
+impl BeaconWithdrawal {
+    /// Withdrawn amount in ETH
+    pub fn amount_eth(&self) -> f64 {
+        self.amount_gwei as f64 / 1_000_000_000.0
+    }
+}
+
+/// Static block and uncle inclusion rewards of a PoW block (amounts in wei)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockRewardInfo {
+    pub block_miner: String,
+    pub block_reward: String,
+    pub uncle_inclusion_rewards: String,
+    pub uncle_miners: Vec<String>,
+    pub uncle_rewards: Vec<String>,
+}
+
+/// Latest ETH price from the `stats` module
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthPrice {
+    pub usd: f64,
+    pub btc: f64,
+    pub usd_timestamp: u64,
+    /// 24h USD price change in percent, when Etherscan reports `ethusd_change`
+    pub usd_change: Option<f64>,
+}
+
+/// ETH supply breakdown from the `stats` module (amounts in wei)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthSupply {
+    pub eth_supply: String,
+    pub eth2_staking: String,
+    pub burned_fees: String,
+}
+
+/// Name tag attached to a known address
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddressTag {
+    pub label: String,
+    pub tag_type: TagType,
+}
+
+/// Centralized exchanges recognized in name tags, matched case-insensitively
+const EXCHANGE_NAMES: &[&str] = &[
+    "Binance",
+    "Coinbase",
+    "Kraken",
+    "OKX",
+    "Bitfinex",
+    "Bybit",
+    "KuCoin",
+    "Gemini",
+    "Bitstamp",
+    "Huobi",
+    "HTX",
+    "Crypto.com",
+    "Gate.io",
+];
+
+impl AddressTag {
+    /// Name of the exchange this tag belongs to, if it names one
+    pub fn exchange_name(&self) -> Option<&'static str> {
+        let label = self.label.to_lowercase();
+        EXCHANGE_NAMES
+            .iter()
+            .find(|name| label.contains(&name.to_lowercase()))
+            .copied()
+    }
+}
+
+/// Split a label into lowercase words at separators and CamelCase boundaries
+///
+/// `UniswapV2Router02` becomes `uniswap v2 router 02` and `ERC20Token`
+/// becomes `erc20 token`.
+fn label_words(label: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in label.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            let prev = i.checked_sub(1).map(|j| chars[j]);
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some(p) if c.is_ascii_uppercase() => {
+                    p.is_ascii_lowercase()
+                        || p.is_ascii_digit()
+                        || (p.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()))
+                }
+                Some(p) => c.is_ascii_digit() && p.is_ascii_lowercase(),
+                None => false,
+            };
+            if boundary && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.push(c.to_ascii_lowercase());
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+impl TagType {
+    /// Classify a contract name or label by keyword
+    ///
+    /// Keywords match whole words, so `SteamVault` is not a team wallet and
+    /// `HackathonPOAP` is not a hack. Space-separated keywords match
+    /// consecutive words.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let words = label_words(label);
+        let has = |keywords: &[&str]| {
+            keywords.iter().any(|keyword| {
+                let keyword: Vec<&str> = keyword.split(' ').collect();
+                words
+                    .windows(keyword.len())
+                    .any(|window| window.iter().zip(&keyword).all(|(w, k)| w == k))
+            })
+        };
+
+        if has(&[
+            "exploit",
+            "exploiter",
+            "hack",
+            "hacker",
+            "attacker",
+            "drainer",
+            "phish",
+            "scam",
+        ]) {
+            Some(TagType::Hack)
+        } else if has(&[
+            "exchange",
+            "binance",
+            "coinbase",
+            "kraken",
+            "okx",
+            "bitfinex",
+            "hot wallet",
+            "hotwallet",
+        ]) {
+            Some(TagType::Exchange)
+        } else if has(&[
+            "swap", "router", "pool", "vault", "lending", "staking", "uniswap", "aave", "curve",
+        ]) {
+            Some(TagType::Defi)
+        } else if has(&["multisig", "gnosis safe", "treasury", "vesting", "team"]) {
+            Some(TagType::Team)
+        } else if has(&["token", "erc20", "erc721", "erc1155", "coin", "stablecoin"]) {
+            Some(TagType::Token)
+        } else {
+            None
+        }
+    }
 }
 
 impl EtherscanChain {
-    fn chain_id(&self) -> u64 {
+    /// Chain for a numeric chain ID
+    pub fn from_chain_id(chain_id: u64) -> Self {
+        match chain_id {
+            1 => EtherscanChain::Ethereum,
+            5 => EtherscanChain::Goerli,
+            11155111 => EtherscanChain::Sepolia,
+            137 => EtherscanChain::Polygon,
+            42161 => EtherscanChain::Arbitrum,
+            10 => EtherscanChain::Optimism,
+            8453 => EtherscanChain::Base,
+            other => EtherscanChain::Custom(other),
+        }
+    }
+
+    /// Numeric chain ID
+    pub fn chain_id(&self) -> u64 {
         match self {
             EtherscanChain::Ethereum => 1,
             EtherscanChain::Goerli => 5,
@@ -70,17 +303,83 @@ impl EtherscanChain {
     }
 }
 
+/// `keccak256("Approval(address,address,uint256)")`
+const APPROVAL_TOPIC: &str = "0x8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925";
+
+/// Etherscan V2 unified endpoint
+const ETHERSCAN_API_URL: &str = "https://api.etherscan.io/v2/api";
+
+/// Wait used when a 429 response has no usable `Retry-After` header
+const DEFAULT_RETRY_AFTER_SECS: u64 = 1;
+
+/// Longest a 429 response is waited out before failing, so a long
+/// `Retry-After` cannot stall the caller
+const MAX_RETRY_AFTER_WAIT_SECS: u64 = 5;
+
+/// Sliding-window limit on requests per second
+///
+/// Callers queue on a single-permit semaphore, so they are let through in
+/// arrival order; the first in line waits until the oldest request leaves
+/// the one-second window.
+pub struct RateLimiter {
+    requests_per_second: usize,
+    queue: Semaphore,
+    window: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    /// Limiter for `limit`; `None` or zero requests per second lets every
+    /// request through
+    pub fn new(limit: Option<RateLimit>) -> Self {
+        let requests_per_second = limit.map_or(0, |limit| limit.requests_per_second as usize);
+        Self {
+            requests_per_second,
+            queue: Semaphore::new(1),
+            window: Mutex::new(VecDeque::with_capacity(requests_per_second)),
+        }
+    }
+
+    /// Wait until another request fits in the window, then record it
+    pub async fn acquire(&self) {
+        if self.requests_per_second == 0 {
+            return;
+        }
+        let _turn = self.queue.acquire().await.expect("Rate limiter closed");
+        loop {
+            let wait = {
+                let now = Instant::now();
+                let mut window = self.window.lock().unwrap();
+                while window
+                    .front()
+                    .is_some_and(|sent| now.duration_since(*sent) >= Duration::from_secs(1))
+                {
+                    window.pop_front();
+                }
+                if window.len() < self.requests_per_second {
+                    window.push_back(now);
+                    return;
+                }
+                window[0] + Duration::from_secs(1) - now
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
 /// Simple Etherscan V2 client
 #[derive(Clone)]
 pub struct EtherscanClient {
     api_key: String,
     client: Client,
     chain: EtherscanChain,
+    base_url: String,
+    /// Shared by clones, so the limit holds across background tasks
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl EtherscanClient {
-    /// Create a new client with the provided API key and chain
-    pub fn new(api_key: String, chain: EtherscanChain) -> Self {
+    /// Create a new client with the provided API key, chain and request rate limit
+    pub fn new(api_key: String, chain: EtherscanChain, rate_limit: Option<RateLimit>) -> Self {
         // Optimize HTTP client with connection pooling and timeouts
         let client = Client::builder()
             .timeout(std::time::Duration::from_secs(10)) // 10 second timeout
@@ -92,13 +391,48 @@ impl EtherscanClient {
             api_key,
             client,
             chain,
+            base_url: ETHERSCAN_API_URL.to_string(),
+            rate_limiter: Arc::new(RateLimiter::new(rate_limit)),
         }
     }
 
+    /// Send requests to another Etherscan-compatible endpoint
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
     /// Base URL for Etherscan V2
-    fn base_url(&self) -> &'static str {
-        // Etherscan V2 unified endpoint
-        "https://api.etherscan.io/v2/api"
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Send a request once the rate limiter lets it through
+    ///
+    /// A 429 response waits out its `Retry-After`, up to
+    /// `MAX_RETRY_AFTER_WAIT_SECS`, before failing with the time still left,
+    /// so a retry by the caller is not rejected again straight away.
+    async fn send(&self, request: RequestBuilder) -> Result<Response> {
+        self.rate_limiter.acquire().await;
+        crate::metrics::global().record_etherscan_call();
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+
+        if resp.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+            tracing::warn!(target: "warpscan", "Etherscan rate limit hit, retry after {}s", retry_after);
+            let waited = retry_after.min(MAX_RETRY_AFTER_WAIT_SECS);
+            tokio::time::sleep(Duration::from_secs(waited)).await;
+            return Err(Error::ratelimit(Some(retry_after - waited)));
+        }
+        Ok(resp)
     }
 
     /// Get address balance via Etherscan V2
@@ -106,25 +440,18 @@ impl EtherscanClient {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
         let resp = self
-            .client
-            .get(url)
-            .query(&[
+            .send(self.client.get(url).query(&[
                 ("chainid", chain_id.to_string()),
                 ("module", "account".to_string()),
                 ("action", "balance".to_string()),
                 ("address", address.to_string()),
                 ("tag", "latest".to_string()),
                 ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+            ]))
+            .await?;
 
         if !resp.status().is_success() {
-            return Err(Error::network(format!(
-                "Etherscan HTTP error: {}",
-                resp.status()
-            )));
+            return Err(http_error(&resp));
         }
 
         let text = resp
@@ -148,32 +475,33 @@ impl EtherscanClient {
         Ok(balance)
     }
 
-    /// Get normal transactions for an address via Etherscan V2
-    pub async fn get_address_transactions(&self, address: &str) -> Result<Vec<AddressTx>> {
+    /// Get one page of normal transactions for an address via Etherscan V2,
+    /// newest first; `page` starts at 1
+    pub async fn get_address_transactions(
+        &self,
+        address: &str,
+        page: u64,
+        page_size: u64,
+    ) -> Result<Vec<AddressTx>> {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
         let resp = self
-            .client
-            .get(url)
-            .query(&[
+            .send(self.client.get(url).query(&[
                 ("chainid", chain_id.to_string()),
                 ("module", "account".to_string()),
                 ("action", "txlist".to_string()),
                 ("address", address.to_string()),
                 ("startblock", "0".to_string()),
                 ("endblock", "99999999".to_string()),
+                ("page", page.to_string()),
+                ("offset", page_size.to_string()),
                 ("sort", "desc".to_string()),
                 ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+            ]))
+            .await?;
 
         if !resp.status().is_success() {
-            return Err(Error::network(format!(
-                "Etherscan HTTP error: {}",
-                resp.status()
-            )));
+            return Err(http_error(&resp));
         }
 
         let text = resp
@@ -309,32 +637,50 @@ impl EtherscanClient {
         Ok(txs)
     }
 
-    /// Get token transfers for an address via Etherscan V2
+    /// Get ERC-20 and ERC-721 transfers for an address via Etherscan V2,
+    /// newest first
+    ///
+    /// NFT transfers only add token IDs to the list, so failing to fetch them
+    /// is logged rather than failing the lookup.
     pub async fn get_token_transfers(&self, address: &str) -> Result<Vec<TokenTransfer>> {
+        let (tokens, nfts) = tokio::join!(
+            self.get_transfers_by_action(address, "tokentx"),
+            self.get_transfers_by_action(address, "tokennfttx"),
+        );
+        let mut transfers = tokens?;
+        match nfts {
+            Ok(nfts) => transfers.extend(nfts),
+            Err(e) => {
+                tracing::warn!(target: "warpscan", "NFT transfers unavailable for {}: {}", address, e)
+            }
+        }
+        transfers.sort_by_key(|t| std::cmp::Reverse(t.timestamp));
+        Ok(transfers)
+    }
+
+    /// Get the transfers of one token standard (`tokentx`, `tokennfttx`)
+    async fn get_transfers_by_action(
+        &self,
+        address: &str,
+        action: &str,
+    ) -> Result<Vec<TokenTransfer>> {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
         let resp = self
-            .client
-            .get(url)
-            .query(&[
+            .send(self.client.get(url).query(&[
                 ("chainid", chain_id.to_string()),
                 ("module", "account".to_string()),
-                ("action", "tokentx".to_string()),
+                ("action", action.to_string()),
                 ("address", address.to_string()),
                 ("startblock", "0".to_string()),
                 ("endblock", "99999999".to_string()),
                 ("sort", "desc".to_string()),
                 ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+            ]))
+            .await?;
 
         if !resp.status().is_success() {
-            return Err(Error::network(format!(
-                "Etherscan HTTP error: {}",
-                resp.status()
-            )));
+            return Err(http_error(&resp));
         }
 
         let text = resp
@@ -410,7 +756,9 @@ impl EtherscanClient {
                     .and_then(|v| v.as_str())
                     .unwrap_or("")
                     .to_string();
-                let value_str = item.get("value")?.as_str()?.to_string();
+                let contract_address = item.get("contractAddress")?.as_str()?.to_lowercase();
+                // NFT transfers carry no value; each moves a single token
+                let value_str = item.get("value").and_then(|v| v.as_str()).unwrap_or("1");
                 let decimals_str = item
                     .get("tokenDecimal")
                     .and_then(|v| v.as_str())
@@ -425,7 +773,7 @@ impl EtherscanClient {
                     .map(|s| s.to_string());
 
                 // Convert value with proper decimals
-                let value_wei = U256::from_dec_str(&value_str).ok()?;
+                let value_wei = U256::from_dec_str(value_str).ok()?;
                 // Use U256 for precise division instead of lossy float/string conversions
                 let divisor = U256::exp10(decimals as usize);
                 let amount = if divisor > U256::zero() {
@@ -441,6 +789,7 @@ impl EtherscanClient {
 
                 Some(TokenTransfer {
                     token_id,
+                    contract_address,
                     txn_hash,
                     from,
                     to,
@@ -459,31 +808,45 @@ impl EtherscanClient {
     pub async fn get_internal_transactions(
         &self,
         address: &str,
+    ) -> Result<Vec<InternalTransaction>> {
+        self.fetch_internal_transactions(&[
+            ("address", address.to_string()),
+            ("startblock", "0".to_string()),
+            ("endblock", "99999999".to_string()),
+            ("sort", "desc".to_string()),
+        ])
+        .await
+    }
+
+    /// Get the internal transactions created by a single transaction via
+    /// Etherscan V2, whichever addresses they touch
+    pub async fn get_internal_transactions_by_tx_hash(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Vec<InternalTransaction>> {
+        self.fetch_internal_transactions(&[("txhash", tx_hash.to_string())])
+            .await
+    }
+
+    /// Run a `txlistinternal` query filtered by `filter` (an address or a
+    /// transaction hash)
+    async fn fetch_internal_transactions(
+        &self,
+        filter: &[(&str, String)],
     ) -> Result<Vec<InternalTransaction>> {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
-        let resp = self
-            .client
-            .get(url)
-            .query(&[
-                ("chainid", chain_id.to_string()),
-                ("module", "account".to_string()),
-                ("action", "txlistinternal".to_string()),
-                ("address", address.to_string()),
-                ("startblock", "0".to_string()),
-                ("endblock", "99999999".to_string()),
-                ("sort", "desc".to_string()),
-                ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+        let mut query = vec![
+            ("chainid", chain_id.to_string()),
+            ("module", "account".to_string()),
+            ("action", "txlistinternal".to_string()),
+        ];
+        query.extend_from_slice(filter);
+        query.push(("apikey", self.api_key.clone()));
+        let resp = self.send(self.client.get(url).query(&query)).await?;
 
         if !resp.status().is_success() {
-            return Err(Error::network(format!(
-                "Etherscan HTTP error: {}",
-                resp.status()
-            )));
+            return Err(http_error(&resp));
         }
 
         let text = resp
@@ -505,6 +868,10 @@ impl EtherscanClient {
                         .get("message")
                         .and_then(|m| m.as_str())
                         .unwrap_or("Unknown error");
+                    // An empty result is reported as an error, not an empty list
+                    if message == "No transactions found" {
+                        return Ok(vec![]);
+                    }
                     let result_msg = json.get("result").and_then(|r| r.as_str()).unwrap_or("");
                     return Err(Error::network(format!(
                         "Etherscan API error (status={}): {} | result: {}",
@@ -524,8 +891,8 @@ impl EtherscanClient {
                 // This is fine, just return empty list
                 tracing::info!(
                     target: "warpscan",
-                    "Etherscan returned no internal transactions for address: {}",
-                    address
+                    "Etherscan returned no internal transactions for {:?}",
+                    filter
                 );
                 return Ok(vec![]);
             }
@@ -551,7 +918,15 @@ impl EtherscanClient {
                 let parent_tx_hash = item.get("hash")?.as_str()?.to_string();
                 let block = item.get("blockNumber")?.as_str()?.parse::<u64>().ok()?;
                 let from = item.get("from")?.as_str()?.to_string();
-                let to = item.get("to")?.as_str()?.to_string();
+                // Contract creations have no `to`; report the created contract instead
+                let to = match item.get("to")?.as_str()? {
+                    "" => item
+                        .get("contractAddress")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    to => to.to_string(),
+                };
                 let value_str = item.get("value")?.as_str()?.to_string();
                 let gas_limit = item
                     .get("gas")
@@ -593,6 +968,135 @@ impl EtherscanClient {
         Ok(internal_txns)
     }
 
+    /// Get the beacon chain withdrawals (EIP-4895) credited to an address,
+    /// newest first
+    pub async fn get_beacon_withdrawals(&self, address: &str) -> Result<Vec<BeaconWithdrawal>> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let resp = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "account".to_string()),
+                ("action", "txsBeaconWithdrawal".to_string()),
+                ("address", address.to_string()),
+                ("startblock", "0".to_string()),
+                ("endblock", "99999999".to_string()),
+                ("sort", "desc".to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(http_error(&resp));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan response read failed: {}", e)))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        if json.get("status").and_then(|s| s.as_str()) != Some("1") {
+            let message = json
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("Unknown error");
+            // An empty result is reported as an error, not an empty list
+            if message.starts_with("No transactions found") {
+                return Ok(vec![]);
+            }
+            let result = json.get("result").and_then(|r| r.as_str()).unwrap_or("");
+            return Err(Error::network(format!(
+                "Etherscan txsBeaconWithdrawal error: {} | result: {}",
+                message, result
+            )));
+        }
+
+        // Every field is a decimal string
+        let field = |item: &serde_json::Value, key: &str| -> Option<u64> {
+            item.get(key)?.as_str()?.parse().ok()
+        };
+        let withdrawals = json
+            .get("result")
+            .and_then(|r| r.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| {
+                        Some(BeaconWithdrawal {
+                            block_number: field(item, "blockNumber")?,
+                            withdrawal_index: field(item, "withdrawalIndex")?,
+                            validator_index: field(item, "validatorIndex")?,
+                            amount_gwei: field(item, "amount")?,
+                            timestamp: field(item, "timestamp")?,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(withdrawals)
+    }
+
+    /// Get the ERC-20 allowances an address still grants, newest first
+    ///
+    /// Reads the owner's `Approval` events (at most 1000, Etherscan's page
+    /// size) and keeps the latest one per token and spender; allowances set
+    /// back to zero are dropped. ERC-721 approvals index the token id as a
+    /// fourth topic and are skipped.
+    pub async fn get_token_approvals(&self, owner: &str) -> Result<Vec<TokenApproval>> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let owner_topic = format!("0x{:0>64}", owner.trim_start_matches("0x").to_lowercase());
+        let resp = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "logs".to_string()),
+                ("action", "getLogs".to_string()),
+                ("fromBlock", "0".to_string()),
+                ("toBlock", "latest".to_string()),
+                ("topic0", APPROVAL_TOPIC.to_string()),
+                ("topic1", owner_topic),
+                ("topic0_1_opr", "and".to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(http_error(&resp));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan response read failed: {}", e)))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        if json.get("status").and_then(|s| s.as_str()) != Some("1") {
+            let message = json
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("Unknown error");
+            if message.starts_with("No records found") {
+                return Ok(vec![]);
+            }
+            let result = json.get("result").and_then(|r| r.as_str()).unwrap_or("");
+            return Err(Error::network(format!(
+                "Etherscan getLogs error: {} | result: {}",
+                message, result
+            )));
+        }
+
+        let logs = json
+            .get("result")
+            .and_then(|r| r.as_array())
+            .cloned()
+            .unwrap_or_default();
+        Ok(current_approvals(&logs))
+    }
+
     /// Get token transfers for a specific transaction hash
     /// This gets transfers from both from and to addresses and filters by tx_hash
     pub async fn get_transaction_token_transfers(
@@ -615,29 +1119,429 @@ impl EtherscanClient {
         Ok(vec![])
     }
 
+    /// Get name tags for an address via Etherscan V2 `getsourcecode`
+    ///
+    /// Verified contracts carry a contract name, which is classified by keyword.
+    /// The deployer picks that name, so it never yields a `Hack` tag.
+    pub async fn get_address_tags(&self, address: &str) -> Result<Vec<AddressTag>> {
+        let tags = self
+            .get_source_code_entries(address)
+            .await?
+            .iter()
+            .filter_map(|entry| entry.get("ContractName")?.as_str())
+            .filter(|name| !name.is_empty())
+            .filter_map(|name| {
+                TagType::from_label(name)
+                    .filter(|tag_type| *tag_type != TagType::Hack)
+                    .map(|tag_type| AddressTag {
+                        label: name.to_string(),
+                        tag_type,
+                    })
+            })
+            .collect();
+
+        Ok(tags)
+    }
+
+    /// Get the verified source code of a contract via Etherscan V2 `getsourcecode`
+    ///
+    /// Returns `None` for unverified contracts and plain accounts.
+    pub async fn get_contract_source(&self, address: &str) -> Result<Option<ContractSource>> {
+        let entries = self.get_source_code_entries(address).await?;
+        let Some(entry) = entries.first() else {
+            return Ok(None);
+        };
+        let field = |name: &str| {
+            entry
+                .get(name)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+
+        let source_code = field("SourceCode");
+        if source_code.is_empty() {
+            return Ok(None);
+        }
+        let contract_name = field("ContractName");
+        let source_files = parse_source_files(&source_code, &contract_name);
+
+        Ok(Some(ContractSource {
+            contract_name,
+            compiler_version: field("CompilerVersion"),
+            source_files,
+            abi: field("ABI"),
+            verified_via: VerificationSource::Etherscan,
+            match_type: None,
+            optimization_used: field("OptimizationUsed") == "1",
+            runs: field("Runs").parse().unwrap_or(0),
+            constructor_arguments: field("ConstructorArguments"),
+        }))
+    }
+
+    /// Get the deployer and deployment transaction of a contract via
+    /// Etherscan V2 `getcontractcreation`
+    ///
+    /// Returns `None` for addresses that are not contracts. The creation block
+    /// is 0 when Etherscan does not report it.
+    pub async fn get_contract_creation(
+        &self,
+        address: &str,
+    ) -> Result<Option<ContractCreationInfo>> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let resp = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "contract".to_string()),
+                ("action", "getcontractcreation".to_string()),
+                ("contractaddresses", address.to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(http_error(&resp));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan response read failed: {}", e)))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        // Non-contracts come back as status 0 with a null result
+        let Some(entry) = json
+            .get("result")
+            .and_then(|r| r.as_array())
+            .and_then(|entries| entries.first())
+        else {
+            return Ok(None);
+        };
+        let field = |name: &str| entry.get(name).and_then(|v| v.as_str());
+
+        let (Some(creator), Some(tx_hash)) = (field("contractCreator"), field("txHash")) else {
+            return Ok(None);
+        };
+        Ok(Some(ContractCreationInfo {
+            creator: creator.to_string(),
+            tx_hash: tx_hash.to_string(),
+            creation_block: field("blockNumber")
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(0),
+        }))
+    }
+
+    /// Raw `result` entries of a `getsourcecode` call
+    async fn get_source_code_entries(&self, address: &str) -> Result<Vec<serde_json::Value>> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let resp = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "contract".to_string()),
+                ("action", "getsourcecode".to_string()),
+                ("address", address.to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(http_error(&resp));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan response read failed: {}", e)))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        if json.get("status").and_then(|s| s.as_str()) != Some("1") {
+            let message = json
+                .get("result")
+                .and_then(|r| r.as_str())
+                .unwrap_or("Unknown error");
+            return Err(Error::network(format!(
+                "Etherscan getsourcecode error: {}",
+                message
+            )));
+        }
+
+        Ok(json
+            .get("result")
+            .and_then(|r| r.as_array())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Get the miner rewards of a block via Etherscan V2 `getblockreward`
+    pub async fn get_block_rewards(&self, block_number: u64) -> Result<BlockRewardInfo> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let resp = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "block".to_string()),
+                ("action", "getblockreward".to_string()),
+                ("blockno", block_number.to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(http_error(&resp));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan response read failed: {}", e)))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        if json.get("status").and_then(|s| s.as_str()) != Some("1") {
+            let message = json
+                .get("result")
+                .and_then(|r| r.as_str())
+                .unwrap_or("Unknown error");
+            return Err(Error::network(format!(
+                "Etherscan getblockreward error: {}",
+                message
+            )));
+        }
+
+        let result = json
+            .get("result")
+            .ok_or_else(|| Error::parse("Missing result in getblockreward response"))?;
+        let text_field = |value: &serde_json::Value, field: &str| {
+            value
+                .get(field)
+                .and_then(|v| v.as_str())
+                .unwrap_or("0")
+                .to_string()
+        };
+        let uncles = result
+            .get("uncles")
+            .and_then(|u| u.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(BlockRewardInfo {
+            block_miner: text_field(result, "blockMiner"),
+            block_reward: text_field(result, "blockReward"),
+            uncle_inclusion_rewards: text_field(result, "uncleInclusionReward"),
+            uncle_miners: uncles.iter().map(|u| text_field(u, "miner")).collect(),
+            uncle_rewards: uncles
+                .iter()
+                .map(|u| text_field(u, "blockreward"))
+                .collect(),
+        })
+    }
+
+    /// Get the last ETH price via Etherscan V2 `ethprice`
+    pub async fn get_eth_price(&self) -> Result<EthPrice> {
+        let result = self.get_stats("ethprice").await?;
+        let number = |field: &str| {
+            result
+                .get(field)
+                .and_then(|v| v.as_str())
+                .and_then(|v| v.parse::<f64>().ok())
+        };
+
+        Ok(EthPrice {
+            usd: number("ethusd")
+                .ok_or_else(|| Error::parse("Missing ethusd in ethprice response"))?,
+            btc: number("ethbtc").unwrap_or(0.0),
+            usd_timestamp: number("ethusd_timestamp").map_or(0, |t| t as u64),
+            usd_change: number("ethusd_change"),
+        })
+    }
+
+    /// Get the ETH supply, staked ETH and burned fees via Etherscan V2 `ethsupply2`
+    pub async fn get_eth_supply(&self) -> Result<EthSupply> {
+        let result = self.get_stats("ethsupply2").await?;
+        let text_field = |field: &str| {
+            result
+                .get(field)
+                .and_then(|v| v.as_str())
+                .unwrap_or("0")
+                .to_string()
+        };
+
+        Ok(EthSupply {
+            eth_supply: text_field("EthSupply"),
+            eth2_staking: text_field("Eth2Staking"),
+            burned_fees: text_field("BurntFees"),
+        })
+    }
+
+    /// Call a `stats` module action and return its `result` object
+    async fn get_stats(&self, action: &str) -> Result<serde_json::Value> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let resp = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "stats".to_string()),
+                ("action", action.to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?;
+
+        if !resp.status().is_success() {
+            return Err(http_error(&resp));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan response read failed: {}", e)))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        if json.get("status").and_then(|s| s.as_str()) != Some("1") {
+            let message = json
+                .get("result")
+                .and_then(|r| r.as_str())
+                .unwrap_or("Unknown error");
+            return Err(Error::network(format!(
+                "Etherscan {} error: {}",
+                action, message
+            )));
+        }
+
+        json.get("result")
+            .cloned()
+            .ok_or_else(|| Error::parse(format!("Missing result in {} response", action)))
+    }
+
+    /// Get the holders of a token via Etherscan V2 `tokenholderlist` (Pro plan only)
+    pub async fn get_token_holders(
+        &self,
+        contract_address: &str,
+        page: u64,
+        offset: u64,
+    ) -> Result<Vec<TokenHolder>> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let resp = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "token".to_string()),
+                ("action", "tokenholderlist".to_string()),
+                ("contractaddress", contract_address.to_string()),
+                ("page", page.to_string()),
+                ("offset", offset.to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?;
+
+        if resp.status() == reqwest::StatusCode::FORBIDDEN {
+            return Err(Error::premium_required(
+                "Etherscan tokenholderlist needs a Pro API key",
+            ));
+        }
+        if !resp.status().is_success() {
+            return Err(http_error(&resp));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Etherscan response read failed: {}", e)))?;
+        let json: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        if json.get("status").and_then(|s| s.as_str()) != Some("1") {
+            let message = json
+                .get("result")
+                .and_then(|r| r.as_str())
+                .unwrap_or("Unknown error");
+            // Free keys get a 200 with an upgrade notice instead of a 403
+            if message.contains("API Pro") || message.contains("PRO") {
+                return Err(Error::premium_required(message.to_string()));
+            }
+            return Err(Error::network(format!(
+                "Etherscan tokenholderlist error: {}",
+                message
+            )));
+        }
+
+        // Raw supplies overflow u128 for some tokens, so shares are computed in f64
+        let total_supply = self
+            .get_token_supply(contract_address)
+            .await
+            .ok()
+            .and_then(|supply| supply.to_string().parse::<f64>().ok())
+            .unwrap_or(0.0);
+
+        let holders = json
+            .get("result")
+            .and_then(|r| r.as_array())
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter_map(|entry| {
+                        let address = entry.get("TokenHolderAddress")?.as_str()?.to_string();
+                        let quantity = entry.get("TokenHolderQuantity")?.as_str()?.to_string();
+                        let share = match quantity.parse::<f64>() {
+                            Ok(qty) if total_supply > 0.0 => qty / total_supply * 100.0,
+                            _ => 0.0,
+                        };
+                        Some(TokenHolder {
+                            address,
+                            quantity,
+                            share,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(holders)
+    }
+
+    /// Get the total supply of a token via Etherscan V2 `tokensupply`
+    async fn get_token_supply(&self, contract_address: &str) -> Result<U256> {
+        let url = self.base_url();
+        let chain_id = self.chain.chain_id();
+        let json: serde_json::Value = self
+            .send(self.client.get(url).query(&[
+                ("chainid", chain_id.to_string()),
+                ("module", "stats".to_string()),
+                ("action", "tokensupply".to_string()),
+                ("contractaddress", contract_address.to_string()),
+                ("apikey", self.api_key.clone()),
+            ]))
+            .await?
+            .json()
+            .await
+            .map_err(|e| Error::parse(format!("Failed to parse Etherscan JSON: {}", e)))?;
+
+        json.get("result")
+            .and_then(|r| r.as_str())
+            .and_then(|supply| U256::from_dec_str(supply).ok())
+            .ok_or_else(|| Error::parse("Invalid tokensupply response"))
+    }
+
     /// Get token balances for an address via Etherscan V2
     pub async fn get_token_balances(&self, address: &str) -> Result<Vec<TokenBalance>> {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
         let resp = self
-            .client
-            .get(url)
-            .query(&[
+            .send(self.client.get(url).query(&[
                 ("chainid", chain_id.to_string()),
                 ("module", "account".to_string()),
                 ("action", "tokenlist".to_string()),
                 ("address", address.to_string()),
                 ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+            ]))
+            .await?;
 
         if !resp.status().is_success() {
-            return Err(Error::network(format!(
-                "Etherscan HTTP error: {}",
-                resp.status()
-            )));
+            return Err(http_error(&resp));
         }
 
         let text = resp
@@ -679,6 +1583,10 @@ impl EtherscanClient {
                 let divisor = 10_u64.pow(decimals as u32) as f64;
                 // OPTIMIZE: Use U256 directly, avoid string conversion
                 let balance = balance_wei.as_u128() as f64 / divisor;
+                let token_type = item
+                    .get("type")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string());
 
                 Some(TokenBalance {
                     contract_address,
@@ -686,6 +1594,7 @@ impl EtherscanClient {
                     symbol,
                     decimals,
                     balance,
+                    token_type,
                 })
             })
             .collect();
@@ -695,7 +1604,7 @@ impl EtherscanClient {
 }
 
 /// Transaction details from Etherscan API
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EtherscanTransactionDetails {
     pub hash: String,
     pub block_number: u64,
@@ -711,6 +1620,11 @@ pub struct EtherscanTransactionDetails {
     pub input_data: String,
     pub is_error: bool,
     pub contract_address: Option<String>,
+    /// EIP-2718 envelope type (0 for legacy)
+    pub tx_type: u8,
+    pub max_fee_per_gas: Option<u64>,          // In gwei
+    pub max_priority_fee_per_gas: Option<u64>, // In gwei
+    pub access_list: AccessList,
 }
 
 impl EtherscanClient {
@@ -722,24 +1636,17 @@ impl EtherscanClient {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
         let resp = self
-            .client
-            .get(url)
-            .query(&[
+            .send(self.client.get(url).query(&[
                 ("chainid", chain_id.to_string()),
                 ("module", "proxy".to_string()),
                 ("action", "eth_getTransactionByHash".to_string()),
                 ("txhash", tx_hash.to_string()),
                 ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+            ]))
+            .await?;
 
         if !resp.status().is_success() {
-            return Err(Error::network(format!(
-                "Etherscan HTTP error: {}",
-                resp.status()
-            )));
+            return Err(http_error(&resp));
         }
 
         let text = resp
@@ -806,6 +1713,27 @@ impl EtherscanClient {
         const GWEI_TO_ETH: f64 = 1_000_000_000.0;
         let gas_price = (gas_price_wei.as_u128() as f64 / GWEI_TO_ETH) as u64;
 
+        // EIP-1559 fields are absent from legacy transactions
+        let tx_type = result
+            .get("type")
+            .and_then(|v| v.as_str())
+            .and_then(|s| u8::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+            .unwrap_or(0);
+        let gwei_field = |field: &str| {
+            result
+                .get(field)
+                .and_then(|v| v.as_str())
+                .and_then(|s| U256::from_str_radix(s.trim_start_matches("0x"), 16).ok())
+                .map(|wei| (wei.as_u128() as f64 / GWEI_TO_ETH) as u64)
+        };
+        let max_fee_per_gas = gwei_field("maxFeePerGas");
+        let max_priority_fee_per_gas = gwei_field("maxPriorityFeePerGas");
+        let access_list = result
+            .get("accessList")
+            .cloned()
+            .and_then(|list| serde_json::from_value(list).ok())
+            .unwrap_or_default();
+
         let nonce_str = result
             .get("nonce")
             .and_then(|v| v.as_str())
@@ -873,6 +1801,10 @@ impl EtherscanClient {
             input_data,
             is_error,
             contract_address,
+            tx_type,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            access_list,
         })
     }
 
@@ -881,18 +1813,14 @@ impl EtherscanClient {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
         let resp = self
-            .client
-            .get(url)
-            .query(&[
+            .send(self.client.get(url).query(&[
                 ("chainid", chain_id.to_string()),
                 ("module", "proxy".to_string()),
                 ("action", "eth_getTransactionReceipt".to_string()),
                 ("txhash", tx_hash.to_string()),
                 ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+            ]))
+            .await?;
 
         if !resp.status().is_success() {
             return Ok(None);
@@ -913,25 +1841,18 @@ impl EtherscanClient {
         let url = self.base_url();
         let chain_id = self.chain.chain_id();
         let resp = self
-            .client
-            .get(url)
-            .query(&[
+            .send(self.client.get(url).query(&[
                 ("chainid", chain_id.to_string()),
                 ("module", "proxy".to_string()),
                 ("action", "eth_getBlockByNumber".to_string()),
                 ("tag", format!("0x{:x}", block_number)),
                 ("boolean", "false".to_string()),
                 ("apikey", self.api_key.clone()),
-            ])
-            .send()
-            .await
-            .map_err(|e| Error::network(format!("Etherscan request failed: {}", e)))?;
+            ]))
+            .await?;
 
         if !resp.status().is_success() {
-            return Err(Error::network(format!(
-                "Etherscan HTTP error: {}",
-                resp.status()
-            )));
+            return Err(http_error(&resp));
         }
 
         let text = resp
@@ -954,3 +1875,291 @@ impl EtherscanClient {
         Ok(timestamp)
     }
 }
+
+/// Split Etherscan's `SourceCode` field into files keyed by path
+///
+/// Multi-file contracts come as Standard JSON Input wrapped in an extra pair
+/// of braces, or as a bare `{path: {content}}` map; single-file contracts are
+/// the plain source, named after the contract.
+fn parse_source_files(source_code: &str, contract_name: &str) -> BTreeMap<String, String> {
+    let json_text = source_code
+        .strip_prefix("{{")
+        .and_then(|s| s.strip_suffix("}}"))
+        .map(|inner| format!("{{{}}}", inner));
+    let json_text = json_text.as_deref().unwrap_or(source_code);
+
+    let parsed = json_text
+        .starts_with('{')
+        .then(|| serde_json::from_str::<serde_json::Value>(json_text).ok())
+        .flatten();
+    let sources = parsed
+        .as_ref()
+        .and_then(|json| json.get("sources").or(Some(json)))
+        .and_then(|sources| sources.as_object());
+
+    match sources {
+        Some(sources) => sources
+            .iter()
+            .filter_map(|(path, file)| {
+                let content = file.get("content")?.as_str()?;
+                Some((path.clone(), content.to_string()))
+            })
+            .collect(),
+        None => BTreeMap::from([(format!("{}.sol", contract_name), source_code.to_string())]),
+    }
+}
+
+/// Latest non-zero allowance per `(token, spender)` among `Approval` logs,
+/// newest first
+fn current_approvals(logs: &[serde_json::Value]) -> Vec<TokenApproval> {
+    let hex_u64 = |value: Option<&serde_json::Value>| -> Option<u64> {
+        u64::from_str_radix(value?.as_str()?.trim_start_matches("0x"), 16).ok()
+    };
+
+    let mut latest: BTreeMap<(String, String), (u64, TokenApproval)> = BTreeMap::new();
+    for log in logs {
+        let Some(topics) = log.get("topics").and_then(|t| t.as_array()) else {
+            continue;
+        };
+        let Some(spender) = topics.get(2).and_then(|t| t.as_str()) else {
+            continue;
+        };
+        if topics.len() != 3 {
+            continue;
+        }
+        let data = log.get("data").and_then(|d| d.as_str()).unwrap_or("0x");
+        let Ok(value) = U256::from_str_radix(data.trim_start_matches("0x"), 16) else {
+            continue;
+        };
+        let token_address = log
+            .get("address")
+            .and_then(|a| a.as_str())
+            .unwrap_or_default()
+            .to_lowercase();
+        let spender = format!("0x{}", &spender[spender.len().saturating_sub(40)..]);
+        let block_number = hex_u64(log.get("blockNumber")).unwrap_or(0);
+        // Order events within a block by log index
+        let order = (block_number << 20) | hex_u64(log.get("logIndex")).unwrap_or(0);
+
+        let key = (token_address.clone(), spender.clone());
+        if latest.get(&key).is_some_and(|(seen, _)| *seen > order) {
+            continue;
+        }
+        latest.insert(
+            key,
+            (
+                order,
+                TokenApproval {
+                    token_address,
+                    spender,
+                    value,
+                    block_number,
+                    tx_hash: log
+                        .get("transactionHash")
+                        .and_then(|h| h.as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                },
+            ),
+        );
+    }
+
+    let mut approvals: Vec<(u64, TokenApproval)> = latest
+        .into_values()
+        .filter(|(_, approval)| !approval.value.is_zero())
+        .collect();
+    approvals.sort_by_key(|(order, _)| std::cmp::Reverse(*order));
+    approvals
+        .into_iter()
+        .map(|(_, approval)| approval)
+        .collect()
+}
+
+/// Error for a non-success Etherscan response
+///
+/// A 404 means the requested action does not exist, so it is reported as
+/// not found rather than as a failed request worth retrying.
+fn http_error(resp: &Response) -> Error {
+    let status = resp.status();
+    if status == StatusCode::NOT_FOUND {
+        let action = resp
+            .url()
+            .query_pairs()
+            .find(|(key, _)| key == "action")
+            .map(|(_, action)| action.into_owned())
+            .unwrap_or_else(|| resp.url().path().to_string());
+        return Error::not_found("Etherscan action", action);
+    }
+    Error::network(crate::error::NetworkError::with_status(
+        "Etherscan HTTP error",
+        status.as_u16(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serve `status` with `headers` and a balance body to every request,
+    /// recording when each one arrived
+    async fn mock_etherscan(
+        status: &'static str,
+        headers: &'static str,
+    ) -> (String, Arc<Mutex<Vec<Instant>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/v2/api", listener.local_addr().unwrap());
+        let arrivals = Arc::new(Mutex::new(Vec::new()));
+        let recorded = arrivals.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let recorded = recorded.clone();
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0u8; 1024];
+                    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                        let n = socket.read(&mut buf).await.unwrap();
+                        if n == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buf[..n]);
+                    }
+                    recorded.lock().unwrap().push(Instant::now());
+                    let body = r#"{"status":"1","message":"OK","result":"1000"}"#;
+                    let response = format!(
+                        "HTTP/1.1 {}\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        headers,
+                        body.len(),
+                        body
+                    );
+                    socket.write_all(response.as_bytes()).await.unwrap();
+                });
+            }
+        });
+        (url, arrivals)
+    }
+
+    fn client(url: &str, requests_per_second: u8) -> EtherscanClient {
+        EtherscanClient::new(
+            "test".to_string(),
+            EtherscanChain::Ethereum,
+            Some(RateLimit {
+                requests_per_second,
+            }),
+        )
+        .with_base_url(url)
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_caps_requests_per_second() {
+        tokio::time::pause();
+        let (url, arrivals) = mock_etherscan("200 OK", "").await;
+        let client = client(&url, 5);
+
+        let started = Instant::now();
+        let requests = (0..12).map(|_| client.get_address_balance("0x0"));
+        for balance in futures::future::join_all(requests).await {
+            assert_eq!(balance.unwrap(), U256::from(1000));
+        }
+
+        // 12 requests at 5 per second need two full windows of waiting
+        assert!(started.elapsed() >= Duration::from_secs(2));
+        let arrivals = arrivals.lock().unwrap();
+        assert_eq!(arrivals.len(), 12);
+        for (i, arrival) in arrivals.iter().enumerate() {
+            let in_window = arrivals[i..]
+                .iter()
+                .take_while(|later| later.duration_since(*arrival) < Duration::from_secs(1))
+                .count();
+            assert!(in_window <= 5, "{} requests within one second", in_window);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_waits_for_retry_after() {
+        tokio::time::pause();
+        let (url, _) = mock_etherscan("429 Too Many Requests", "Retry-After: 3\r\n").await;
+        let client = client(&url, 5);
+
+        let started = Instant::now();
+        let error = client.get_address_balance("0x0").await.unwrap_err();
+        assert_eq!(error.kind(), "rate_limit");
+        assert_eq!(error.retry_after_secs(), Some(0));
+        assert!(started.elapsed() >= Duration::from_secs(3));
+    }
+
+    #[tokio::test]
+    async fn test_too_many_requests_caps_the_wait() {
+        tokio::time::pause();
+        let (url, _) = mock_etherscan("429 Too Many Requests", "Retry-After: 3600\r\n").await;
+        let client = client(&url, 5);
+
+        // Only the capped wait was slept off
+        let error = client.get_address_balance("0x0").await.unwrap_err();
+        assert_eq!(
+            error.retry_after_secs(),
+            Some(3600 - MAX_RETRY_AFTER_WAIT_SECS)
+        );
+    }
+
+    #[test]
+    fn test_current_approvals_keeps_latest_non_zero() {
+        let token = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+        let log = |spender: &str, value: &str, block: &str| {
+            serde_json::json!({
+                "address": token,
+                "topics": [
+                    APPROVAL_TOPIC,
+                    "0x0000000000000000000000001111111111111111111111111111111111111111",
+                    format!("0x000000000000000000000000{}", spender),
+                ],
+                "data": value,
+                "blockNumber": block,
+                "logIndex": "0x0",
+                "transactionHash": format!("0x{}", block),
+            })
+        };
+        let router = "7a250d5630b4cf539739df2c5dacb4c659f2488d";
+        let old = "2222222222222222222222222222222222222222";
+        let logs = [
+            log(router, "0x64", "0x10"),
+            log(router, &format!("0x{}", "f".repeat(64)), "0x20"),
+            log(old, "0x64", "0x11"),
+            log(old, "0x0", "0x30"),
+        ];
+
+        let approvals = current_approvals(&logs);
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].spender, format!("0x{}", router));
+        assert_eq!(approvals[0].block_number, 0x20);
+        assert!(approvals[0].is_unlimited());
+    }
+
+    #[test]
+    fn test_tag_type_matches_whole_words() {
+        assert_eq!(
+            label_words("UniswapV2Router02"),
+            ["uniswap", "v2", "router", "02"]
+        );
+        assert_eq!(TagType::from_label("ERC20Token"), Some(TagType::Token));
+        assert_eq!(TagType::from_label("GnosisSafeProxy"), Some(TagType::Team));
+        assert_eq!(TagType::from_label("Euler Exploiter"), Some(TagType::Hack));
+        assert_eq!(TagType::from_label("HackathonPOAP"), None);
+        assert_eq!(TagType::from_label("ShackToken"), Some(TagType::Token));
+        assert_eq!(TagType::from_label("SteamVault"), Some(TagType::Defi));
+        assert_eq!(TagType::from_label("Bitcoiner"), None);
+    }
+
+    #[tokio::test]
+    async fn test_not_found_names_the_action() {
+        let (url, _) = mock_etherscan("404 Not Found", "").await;
+        let client = client(&url, 5);
+
+        let error = client.get_address_balance("0x0").await.unwrap_err();
+        assert_eq!(error.kind(), "not_found");
+        assert_eq!(error.to_string(), "Not found: Etherscan action balance");
+    }
+}
@@ -0,0 +1,97 @@
+//! Built-in labels for well-known mainnet addresses
+
+/// Exchange hot wallets, protocol contracts and special addresses, as
+/// `(address, label, category)`
+const KNOWN_ADDRESSES: &[(&str, &str, &str)] = &[
+    (
+        "0x28c6c06298d514db089934071355e5743bf21d60",
+        "Binance 14",
+        "exchange",
+    ),
+    (
+        "0xf977814e90da44bfa03b6295a0616a897441acec",
+        "Binance 8",
+        "exchange",
+    ),
+    (
+        "0xbe0eb53f46cd790cd13851d5eff43d12404d33e8",
+        "Binance 7",
+        "exchange",
+    ),
+    (
+        "0x71660c4005ba85c37ccec55d0c4493e66fe775d3",
+        "Coinbase 1",
+        "exchange",
+    ),
+    (
+        "0xa9d1e08c7793af67e9d92fe308d5697fb81d3e43",
+        "Coinbase 10",
+        "exchange",
+    ),
+    (
+        "0x2910543af39aba0cd09dbb2d50200b3e800a63d2",
+        "Kraken",
+        "exchange",
+    ),
+    (
+        "0x7a250d5630b4cf539739df2c5dacb4c659f2488d",
+        "Uniswap V2: Router",
+        "defi",
+    ),
+    (
+        "0xe592427a0aece92de3edee1f18e0157c05861564",
+        "Uniswap V3: Router",
+        "defi",
+    ),
+    (
+        "0x1111111254eeb25477b68fb85ed929f73a960582",
+        "1inch v5: Router",
+        "defi",
+    ),
+    (
+        "0x87870bca3f3fd6335c3f4ce8392d69350b4fa4e2",
+        "Aave V3: Pool",
+        "defi",
+    ),
+    (
+        "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2",
+        "Wrapped Ether",
+        "token",
+    ),
+    (
+        "0x00000000219ab540356cbb839cbe05303d7705fa",
+        "Beacon Deposit Contract",
+        "staking",
+    ),
+    (
+        "0x000000000000000000000000000000000000dead",
+        "Burn Address",
+        "special",
+    ),
+    (
+        "0x0000000000000000000000000000000000000000",
+        "Null Address",
+        "special",
+    ),
+];
+
+/// Lookup table of well-known address labels
+pub struct KnownAddresses;
+
+impl KnownAddresses {
+    /// Label of a well-known address (case-insensitive)
+    pub fn lookup(address: &str) -> Option<&'static str> {
+        Self::find(address).map(|(_, label, _)| *label)
+    }
+
+    /// Category of a well-known address, e.g. "exchange" or "defi"
+    pub fn category(address: &str) -> Option<&'static str> {
+        Self::find(address).map(|(_, _, category)| *category)
+    }
+
+    fn find(address: &str) -> Option<&'static (&'static str, &'static str, &'static str)> {
+        KNOWN_ADDRESSES
+            .iter()
+            .find(|(known, _, _)| known.eq_ignore_ascii_case(address))
+    }
+}
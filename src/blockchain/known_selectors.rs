@@ -0,0 +1,161 @@
+//! Built-in event signatures for decoding common logs
+
+use super::types::DecodedLog;
+use ethers::abi::{Event, HumanReadableParser, RawLog, Token};
+use ethers::types::{Log, H256};
+
+/// Widely emitted token, DEX and ownership events, in human-readable ABI form
+///
+/// ERC-20 and ERC-721 `Transfer`/`Approval` share a topic and differ only in
+/// which parameters are indexed, so both are listed; the first one whose
+/// topic count matches the log wins.
+const KNOWN_EVENTS: &[&str] = &[
+    "event Transfer(address indexed from, address indexed to, uint256 value)",
+    "event Transfer(address indexed from, address indexed to, uint256 indexed tokenId)",
+    "event Approval(address indexed owner, address indexed spender, uint256 value)",
+    "event Approval(address indexed owner, address indexed approved, uint256 indexed tokenId)",
+    "event ApprovalForAll(address indexed owner, address indexed operator, bool approved)",
+    "event TransferSingle(address indexed operator, address indexed from, address indexed to, uint256 id, uint256 value)",
+    "event TransferBatch(address indexed operator, address indexed from, address indexed to, uint256[] ids, uint256[] values)",
+    "event Deposit(address indexed dst, uint256 wad)",
+    "event Withdrawal(address indexed src, uint256 wad)",
+    "event Swap(address indexed sender, uint256 amount0In, uint256 amount1In, uint256 amount0Out, uint256 amount1Out, address indexed to)",
+    "event Swap(address indexed sender, address indexed recipient, int256 amount0, int256 amount1, uint160 sqrtPriceX96, uint128 liquidity, int24 tick)",
+    "event Sync(uint112 reserve0, uint112 reserve1)",
+    "event Mint(address indexed sender, uint256 amount0, uint256 amount1)",
+    "event Burn(address indexed sender, uint256 amount0, uint256 amount1, address indexed to)",
+    "event OwnershipTransferred(address indexed previousOwner, address indexed newOwner)",
+];
+
+/// Known events whose signature hashes to `topic0`
+fn events_for_topic(topic0: H256) -> impl Iterator<Item = Event> {
+    KNOWN_EVENTS
+        .iter()
+        .filter_map(|signature| HumanReadableParser::parse_event(signature).ok())
+        .filter(move |event| event.signature() == topic0)
+}
+
+/// Decode a log against the known events
+///
+/// Logs of unknown events keep their raw data, and their indexed topics are
+/// listed as `topic1`, `topic2`, ... parameters.
+pub fn decode_log(log: &Log) -> DecodedLog {
+    let raw_data = format!("0x{}", hex::encode(&log.data));
+    let raw_log = RawLog {
+        topics: log.topics.clone(),
+        data: log.data.to_vec(),
+    };
+    let decoded = log.topics.first().and_then(|topic0| {
+        events_for_topic(*topic0).find_map(|event| {
+            event
+                .parse_log(raw_log.clone())
+                .ok()
+                .map(|parsed| (event, parsed))
+        })
+    });
+
+    match decoded {
+        Some((event, parsed)) => DecodedLog {
+            address: format!("{:?}", log.address),
+            event_name: Some(event.name),
+            raw_data,
+            decoded_params: parsed
+                .params
+                .into_iter()
+                .map(|param| (param.name, format_token(&param.value)))
+                .collect(),
+        },
+        None => DecodedLog {
+            address: format!("{:?}", log.address),
+            event_name: None,
+            raw_data,
+            decoded_params: log
+                .topics
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(index, topic)| (format!("topic{}", index), format!("{:?}", topic)))
+                .collect(),
+        },
+    }
+}
+
+/// Display a decoded value: addresses with `0x`, numbers in decimal
+fn format_token(token: &Token) -> String {
+    match token {
+        Token::Address(address) => format!("{:?}", address),
+        Token::Uint(value) => value.to_string(),
+        Token::Int(value) => ethers::types::I256::from_raw(*value).to_string(),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        Token::Array(tokens) | Token::FixedArray(tokens) => format!(
+            "[{}]",
+            tokens
+                .iter()
+                .map(format_token)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Token::Tuple(tokens) => format!(
+            "({})",
+            tokens
+                .iter()
+                .map(format_token)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        token => token.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethers::types::{Address, U256};
+
+    #[test]
+    fn test_decode_erc20_and_erc721_transfers() {
+        let from = Address::repeat_byte(0x11);
+        let to = Address::repeat_byte(0x22);
+        let transfer = HumanReadableParser::parse_event(KNOWN_EVENTS[0])
+            .unwrap()
+            .signature();
+        let mut amount = [0u8; 32];
+        U256::from(1_000u64).to_big_endian(&mut amount);
+
+        // ERC-20: the amount is in the data
+        let log = Log {
+            topics: vec![transfer, from.into(), to.into()],
+            data: amount.to_vec().into(),
+            ..Default::default()
+        };
+        let decoded = decode_log(&log);
+        assert_eq!(decoded.event_name.as_deref(), Some("Transfer"));
+        assert_eq!(
+            decoded.decoded_params,
+            vec![
+                ("from".to_string(), format!("{:?}", from)),
+                ("to".to_string(), format!("{:?}", to)),
+                ("value".to_string(), "1000".to_string()),
+            ]
+        );
+
+        // ERC-721: the token id is a fourth topic
+        let log = Log {
+            topics: vec![transfer, from.into(), to.into(), H256::from(amount)],
+            ..Default::default()
+        };
+        let decoded = decode_log(&log);
+        assert_eq!(
+            decoded.decoded_params[2],
+            ("tokenId".to_string(), "1000".to_string())
+        );
+
+        let unknown = Log {
+            topics: vec![H256::repeat_byte(0xff), H256::zero()],
+            ..Default::default()
+        };
+        let decoded = decode_log(&unknown);
+        assert!(decoded.event_name.is_none());
+        assert_eq!(decoded.decoded_params.len(), 1);
+    }
+}
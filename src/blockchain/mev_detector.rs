@@ -0,0 +1,186 @@
+//! Heuristic MEV pattern detection
+//!
+//! These checks only look at the transfers of a single transaction, so they are
+//! informational hints rather than proof of MEV activity.
+
+use crate::ui::models::transaction::{TransactionDetails, TransactionTransfer, TransferType};
+use std::collections::HashMap;
+
+const ZERO_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+
+/// Kind of MEV pattern detected
+#[derive(Debug, Clone, PartialEq)]
+pub enum MevKind {
+    Arbitrage,
+    LiquidityRemoval,
+    FlashLoan,
+}
+
+impl MevKind {
+    /// Display label for the pattern
+    pub fn label(&self) -> &'static str {
+        match self {
+            MevKind::Arbitrage => "Arbitrage",
+            MevKind::LiquidityRemoval => "Liquidity Removal",
+            MevKind::FlashLoan => "Flash Loan",
+        }
+    }
+}
+
+/// A detected MEV pattern
+#[derive(Debug, Clone)]
+pub struct MevPattern {
+    pub kind: MevKind,
+    /// Heuristic confidence between 0.0 and 1.0
+    pub confidence: f32,
+    pub description: String,
+}
+
+/// Detect a likely MEV pattern from a transaction's transfers
+///
+/// Checks are ordered from most to least specific; the first match wins.
+pub fn detect_mev_pattern(
+    tx: &TransactionDetails,
+    transfers: &[TransactionTransfer],
+) -> Option<MevPattern> {
+    detect_flash_loan(transfers)
+        .or_else(|| detect_liquidity_removal(transfers))
+        .or_else(|| detect_arbitrage(tx, transfers))
+}
+
+/// Display name of the asset moved by a transfer
+fn asset_name(transfer: &TransactionTransfer) -> String {
+    transfer
+        .token_symbol
+        .clone()
+        .unwrap_or_else(|| "ETH".to_string())
+}
+
+/// Borrow and repay of the same token between the same two parties
+fn detect_flash_loan(transfers: &[TransactionTransfer]) -> Option<MevPattern> {
+    for (i, borrow) in transfers.iter().enumerate() {
+        if !matches!(borrow.transfer_type, TransferType::Token) {
+            continue;
+        }
+        let repaid = transfers[i + 1..].iter().any(|repay| {
+            repay.asset_key() == borrow.asset_key()
+                && repay.from.eq_ignore_ascii_case(&borrow.to)
+                && repay.to.eq_ignore_ascii_case(&borrow.from)
+                && repay.value >= borrow.value
+        });
+        if repaid {
+            return Some(MevPattern {
+                kind: MevKind::FlashLoan,
+                confidence: 0.6,
+                description: format!(
+                    "{} {} borrowed and repaid within the same transaction",
+                    borrow.value,
+                    asset_name(borrow)
+                ),
+            });
+        }
+    }
+    None
+}
+
+/// Token burn followed by ETH transfers
+fn detect_liquidity_removal(transfers: &[TransactionTransfer]) -> Option<MevPattern> {
+    let burn_index = transfers.iter().position(|t| {
+        matches!(t.transfer_type, TransferType::Token) && t.to.eq_ignore_ascii_case(ZERO_ADDRESS)
+    })?;
+
+    let eth_out = transfers[burn_index + 1..]
+        .iter()
+        .filter(|t| matches!(t.transfer_type, TransferType::ETH | TransferType::Internal))
+        .count();
+    if eth_out == 0 {
+        return None;
+    }
+
+    Some(MevPattern {
+        kind: MevKind::LiquidityRemoval,
+        confidence: 0.5,
+        description: format!(
+            "{} burned followed by {} ETH transfer(s)",
+            asset_name(&transfers[burn_index]),
+            eth_out
+        ),
+    })
+}
+
+/// Sender both sends and receives the same asset and ends up with more of it
+fn detect_arbitrage(
+    tx: &TransactionDetails,
+    transfers: &[TransactionTransfer],
+) -> Option<MevPattern> {
+    // asset -> (sent, received, name)
+    let mut flows: HashMap<String, (f64, f64, String)> = HashMap::new();
+    for transfer in transfers {
+        let entry = flows
+            .entry(transfer.asset_key())
+            .or_insert_with(|| (0.0, 0.0, asset_name(transfer)));
+        if transfer.from.eq_ignore_ascii_case(&tx.from) {
+            entry.0 += transfer.value;
+        }
+        if transfer.to.eq_ignore_ascii_case(&tx.from) {
+            entry.1 += transfer.value;
+        }
+    }
+
+    flows
+        .into_values()
+        .find(|(sent, received, _)| *sent > 0.0 && received > sent)
+        .map(|(sent, received, name)| MevPattern {
+            kind: MevKind::Arbitrage,
+            confidence: 0.4,
+            description: format!(
+                "Sender sent {} and received {} {} (net +{})",
+                sent,
+                received,
+                name,
+                received - sent
+            ),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_transfer(from: &str, to: &str, value: f64) -> TransactionTransfer {
+        TransactionTransfer {
+            transfer_type: TransferType::Token,
+            from: from.to_string(),
+            to: to.to_string(),
+            value,
+            token_symbol: Some("WETH".to_string()),
+            token_name: Some("Wrapped Ether".to_string()),
+            token_address: Some("0xc02a".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_detect_mev_pattern() {
+        let tx = TransactionDetails {
+            from: "0xbot".to_string(),
+            ..Default::default()
+        };
+
+        let arbitrage = [
+            token_transfer("0xbot", "0xpool1", 10.0),
+            token_transfer("0xpool2", "0xbot", 10.5),
+        ];
+        let pattern = detect_mev_pattern(&tx, &arbitrage).unwrap();
+        assert_eq!(pattern.kind, MevKind::Arbitrage);
+
+        let flash_loan = [
+            token_transfer("0xlender", "0xbot", 100.0),
+            token_transfer("0xbot", "0xlender", 100.09),
+        ];
+        let pattern = detect_mev_pattern(&tx, &flash_loan).unwrap();
+        assert_eq!(pattern.kind, MevKind::FlashLoan);
+
+        let plain = [token_transfer("0xbot", "0xfriend", 1.0)];
+        assert!(detect_mev_pattern(&tx, &plain).is_none());
+    }
+}
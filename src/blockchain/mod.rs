@@ -3,13 +3,43 @@
 //! This module provides the interface for interacting with Ethereum blockchain
 //! using ethers.rs library.
 
+pub mod abi;
+pub mod address_utils;
+pub mod beacon;
+pub mod defi;
 pub mod etherscan;
+pub mod known_addresses;
+pub mod known_selectors;
+pub mod mev_detector;
+pub mod opensea;
+pub mod price;
+pub mod safe;
 pub mod service;
+pub mod simulator;
+pub mod sourcify;
 pub mod subscriptions;
 pub mod types;
 
 // Re-export commonly used types and structs
-pub use etherscan::{EtherscanChain, EtherscanClient};
+pub use abi::MethodDecoder;
+pub use beacon::{BeaconClient, ValidatorInfo, ValidatorStatus, DEPOSIT_CONTRACT_ADDRESS};
+pub use defi::{DeFiDecoder, SwapSummary, TransferLeg};
+pub use etherscan::{
+    AddressTag, BeaconWithdrawal, BlockRewardInfo, EthPrice, EthSupply, EtherscanChain,
+    EtherscanClient, TagType, TokenApproval, TokenHolder,
+};
+pub use known_addresses::KnownAddresses;
+pub use mev_detector::{detect_mev_pattern, MevKind, MevPattern};
+pub use opensea::OpenSeaClient;
+pub use price::PriceClient;
+pub use safe::SafeDeployment;
 pub use service::BlockchainService;
+pub use simulator::HostedSimulator;
+pub use sourcify::{SourcifyClient, SourcifyResult};
 pub use subscriptions::{SubscriptionEvent, SubscriptionManager};
-pub use types::{GasPrices, TransactionStatus};
+pub use types::{
+    BlockFinalityInfo, BlockGasMetric, BlockRangeSummary, ContractCreationInfo, ContractSource,
+    DecodedLog, Eip1559FeeEstimate, Eip1559Tier, EventFilter, GasPrices, MevInfo, NftAttribute,
+    NftMetadata, NonceAnalysis, PriorityFeeSource, SelfDestructInfo, SimulationResult,
+    TransactionReceiptWithLogs, TransactionStatus, TxConfirmation, VerificationSource,
+};
@@ -0,0 +1,74 @@
+//! OpenSea API client for NFT collection data
+
+use crate::error::{Error, Result};
+use reqwest::Client;
+
+/// Simple OpenSea v2 client (Ethereum mainnet only)
+#[derive(Clone)]
+pub struct OpenSeaClient {
+    api_key: String,
+    client: Client,
+}
+
+impl OpenSeaClient {
+    /// Create a new client with the provided API key
+    pub fn new(api_key: String) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .expect("Failed to build HTTP client");
+        Self { api_key, client }
+    }
+
+    /// Base URL for OpenSea v2
+    fn base_url(&self) -> &'static str {
+        "https://api.opensea.io/api/v2"
+    }
+
+    /// Send an authenticated GET request and parse the JSON body
+    async fn get_json(&self, url: &str) -> Result<serde_json::Value> {
+        let resp = self
+            .client
+            .get(url)
+            .header("X-API-KEY", &self.api_key)
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("OpenSea request failed: {}", e)))?;
+
+        if !resp.status().is_success() {
+            return Err(Error::network(format!(
+                "OpenSea HTTP error: {}",
+                resp.status()
+            )));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("OpenSea response read failed: {}", e)))?;
+        serde_json::from_str(&text).map_err(Error::serialization)
+    }
+
+    /// Get the floor price (in ETH) of the collection a contract belongs to
+    pub async fn get_floor_price(&self, contract_address: &str) -> Result<Option<f64>> {
+        // Resolve the collection slug for the contract first
+        let contract_url = format!(
+            "{}/chain/ethereum/contract/{}",
+            self.base_url(),
+            contract_address
+        );
+        let contract = self.get_json(&contract_url).await?;
+        let slug = match contract.get("collection").and_then(|v| v.as_str()) {
+            Some(slug) => slug.to_string(),
+            None => return Ok(None),
+        };
+
+        let stats_url = format!("{}/collections/{}/stats", self.base_url(), slug);
+        let stats = self.get_json(&stats_url).await?;
+
+        Ok(stats
+            .get("total")
+            .and_then(|total| total.get("floor_price"))
+            .and_then(|v| v.as_f64()))
+    }
+}
@@ -0,0 +1,92 @@
+//! CoinGecko API client for ERC-20 token prices
+
+use super::etherscan::RateLimiter;
+use crate::config::RateLimit;
+use crate::error::{Error, Result};
+use reqwest::{Client, StatusCode};
+use std::sync::Arc;
+
+/// Base URL of the public CoinGecko API
+const COINGECKO_API_URL: &str = "https://api.coingecko.com/api/v3";
+
+/// The free tier allows around 30 requests per minute; one per second keeps
+/// short bursts (a wallet's token list) from being rejected outright
+const COINGECKO_RATE_LIMIT: RateLimit = RateLimit {
+    requests_per_second: 1,
+};
+
+/// CoinGecko price client; works without a key at lower limits
+#[derive(Clone)]
+pub struct PriceClient {
+    api_key: Option<String>,
+    client: Client,
+    /// Shared by clones, so the limit holds across background tasks
+    rate_limiter: Arc<RateLimiter>,
+}
+
+impl PriceClient {
+    /// Create a new client, with a CoinGecko demo API key if one is set
+    pub fn new(api_key: Option<String>) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .expect("Failed to build HTTP client");
+        Self {
+            api_key,
+            client,
+            rate_limiter: Arc::new(RateLimiter::new(Some(COINGECKO_RATE_LIMIT))),
+        }
+    }
+
+    /// Price of one whole token of an Ethereum mainnet contract, in
+    /// `vs_currency` (e.g. `usd`)
+    ///
+    /// Tokens CoinGecko does not list are priced at 0.
+    pub async fn get_token_price(&self, contract_address: &str, vs_currency: &str) -> Result<f64> {
+        let contract_address = contract_address.to_lowercase();
+        let vs_currency = vs_currency.to_lowercase();
+        let url = format!("{}/simple/token_price/ethereum", COINGECKO_API_URL);
+        let mut request = self.client.get(&url).query(&[
+            ("contract_addresses", contract_address.as_str()),
+            ("vs_currencies", vs_currency.as_str()),
+        ]);
+        if let Some(ref key) = self.api_key {
+            request = request.header("x-cg-demo-api-key", key);
+        }
+
+        self.rate_limiter.acquire().await;
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("CoinGecko request failed: {}", e)))?;
+
+        if resp.status() == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse::<u64>().ok());
+            return Err(Error::ratelimit(retry_after));
+        }
+        if !resp.status().is_success() {
+            return Err(Error::network(format!(
+                "CoinGecko HTTP error: {}",
+                resp.status()
+            )));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("CoinGecko response read failed: {}", e)))?;
+        let prices: serde_json::Value = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse CoinGecko JSON: {}", e)))?;
+
+        // {"0xa0b8...": {"usd": 1.0}}, or {} for unlisted tokens
+        Ok(prices
+            .get(&contract_address)
+            .and_then(|price| price.get(&vs_currency))
+            .and_then(|price| price.as_f64())
+            .unwrap_or(0.0))
+    }
+}
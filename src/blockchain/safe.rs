@@ -0,0 +1,178 @@
+//! Safe (formerly Gnosis Safe) v1.3.0 deployment payloads and transaction hashes
+//!
+//! Safes are deployed as proxies through the canonical proxy factory, whose
+//! `createProxyWithNonce` call uses CREATE2, so the address is known before
+//! the deployment is sent.
+
+use crate::error::{Error, Result};
+use ethers::abi::{self, Token};
+use ethers::types::{Address, Bytes, H256, U256};
+use ethers::utils::{get_create2_address_from_hash, id, keccak256};
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Canonical `GnosisSafeProxyFactory` 1.3.0, at the same address on most chains
+pub const SAFE_PROXY_FACTORY: &str = "0xa6B71E26C5e0845f74c812102Ca7114b6a896AB2";
+
+/// Canonical `GnosisSafe` 1.3.0 singleton the proxies delegate to
+pub const SAFE_SINGLETON: &str = "0xd9Db270c1B5E3Bd161E8c8503c55cEABeE709552";
+
+/// Canonical `CompatibilityFallbackHandler` 1.3.0
+pub const SAFE_FALLBACK_HANDLER: &str = "0xf48f2B2d2a534e402487b3ee7C18c33Aec0Fe5e4";
+
+/// `SafeTx` EIP-712 type, hashed into every Safe transaction hash
+const SAFE_TX_TYPE: &str = "SafeTx(address to,uint256 value,bytes data,uint8 operation,uint256 safeTxGas,uint256 baseGas,uint256 gasPrice,address gasToken,address refundReceiver,uint256 nonce)";
+
+/// EIP-712 domain of Safes from 1.3.0 on
+const SAFE_DOMAIN_TYPE: &str = "EIP712Domain(uint256 chainId,address verifyingContract)";
+
+/// Unsigned transaction deploying a new Safe through the proxy factory
+#[derive(Debug, Clone, Serialize)]
+pub struct SafeDeployment {
+    pub owners: Vec<Address>,
+    pub threshold: u32,
+    pub salt_nonce: U256,
+    /// Calldata of the Safe's `setup` call, run when the proxy is created
+    pub initializer: Bytes,
+    /// The proxy factory
+    pub to: Address,
+    /// Calldata of `createProxyWithNonce`
+    pub data: Bytes,
+    /// CREATE2 address of the new Safe, once the factory's proxy code is known
+    pub predicted_address: Option<Address>,
+}
+
+impl SafeDeployment {
+    /// Build the deployment of a Safe owned by `owners` with a `threshold`
+    /// of required signatures
+    pub fn new(owners: Vec<Address>, threshold: u32, salt_nonce: U256) -> Result<Self> {
+        if owners.is_empty() {
+            return Err(Error::validation("At least one owner is required"));
+        }
+        if threshold == 0 || threshold as usize > owners.len() {
+            return Err(Error::validation(format!(
+                "Threshold must be between 1 and {}",
+                owners.len()
+            )));
+        }
+
+        let singleton = parse_address(SAFE_SINGLETON);
+        let initializer = encode_call(
+            "setup(address[],uint256,address,bytes,address,address,uint256,address)",
+            &[
+                Token::Array(owners.iter().copied().map(Token::Address).collect()),
+                Token::Uint(threshold.into()),
+                Token::Address(Address::zero()),
+                Token::Bytes(Vec::new()),
+                Token::Address(parse_address(SAFE_FALLBACK_HANDLER)),
+                Token::Address(Address::zero()),
+                Token::Uint(U256::zero()),
+                Token::Address(Address::zero()),
+            ],
+        );
+        let data = encode_call(
+            "createProxyWithNonce(address,bytes,uint256)",
+            &[
+                Token::Address(singleton),
+                Token::Bytes(initializer.clone()),
+                Token::Uint(salt_nonce),
+            ],
+        );
+
+        Ok(Self {
+            owners,
+            threshold,
+            salt_nonce,
+            initializer: initializer.into(),
+            to: parse_address(SAFE_PROXY_FACTORY),
+            data: data.into(),
+            predicted_address: None,
+        })
+    }
+
+    /// CREATE2 address of the Safe, given the factory's `proxyCreationCode()`
+    pub fn predict_address(&self, proxy_creation_code: &[u8]) -> Address {
+        let mut salt = keccak256(&self.initializer).to_vec();
+        salt.extend(abi::encode(&[Token::Uint(self.salt_nonce)]));
+
+        let mut init_code = proxy_creation_code.to_vec();
+        init_code.extend(abi::encode(&[Token::Address(parse_address(
+            SAFE_SINGLETON,
+        ))]));
+
+        get_create2_address_from_hash(self.to, keccak256(salt), keccak256(init_code))
+    }
+}
+
+/// Calldata of the factory's `proxyCreationCode()` view
+pub fn proxy_creation_code_call() -> Vec<u8> {
+    encode_call("proxyCreationCode()", &[])
+}
+
+/// EIP-712 hash that Safe owners sign to approve a plain call from `safe`
+pub fn safe_tx_hash(
+    chain_id: u64,
+    safe: Address,
+    to: Address,
+    value: U256,
+    data: &[u8],
+    nonce: u64,
+) -> H256 {
+    let domain_separator = keccak256(abi::encode(&[
+        Token::FixedBytes(keccak256(SAFE_DOMAIN_TYPE).to_vec()),
+        Token::Uint(chain_id.into()),
+        Token::Address(safe),
+    ]));
+    // Operation 0 (call) with no gas refund
+    let safe_tx = keccak256(abi::encode(&[
+        Token::FixedBytes(keccak256(SAFE_TX_TYPE).to_vec()),
+        Token::Address(to),
+        Token::Uint(value),
+        Token::FixedBytes(keccak256(data).to_vec()),
+        Token::Uint(U256::zero()),
+        Token::Uint(U256::zero()),
+        Token::Uint(U256::zero()),
+        Token::Uint(U256::zero()),
+        Token::Address(Address::zero()),
+        Token::Address(Address::zero()),
+        Token::Uint(nonce.into()),
+    ]));
+
+    let mut message = vec![0x19, 0x01];
+    message.extend(domain_separator);
+    message.extend(safe_tx);
+    H256(keccak256(message))
+}
+
+/// Selector of `signature` followed by the ABI-encoded arguments
+fn encode_call(signature: &str, args: &[Token]) -> Vec<u8> {
+    let mut data = id(signature).to_vec();
+    data.extend(abi::encode(args));
+    data
+}
+
+fn parse_address(address: &str) -> Address {
+    Address::from_str(address).expect("Safe contract addresses are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safe_deployment_payload() {
+        let owners = vec![
+            parse_address("0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045"),
+            parse_address("0x71C7656EC7ab88b098defB751B7401B5f6d8976F"),
+        ];
+        let deployment = SafeDeployment::new(owners.clone(), 2, U256::from(7)).unwrap();
+        assert_eq!(deployment.to, parse_address(SAFE_PROXY_FACTORY));
+        // createProxyWithNonce(address,bytes,uint256) wrapping setup(...)
+        assert_eq!(&deployment.data[..4], &[0x16, 0x88, 0xf0, 0xb9]);
+        assert_eq!(&deployment.initializer[..4], &[0xb6, 0x3e, 0x80, 0x0d]);
+
+        assert!(SafeDeployment::new(owners.clone(), 3, U256::zero()).is_err());
+        assert!(SafeDeployment::new(owners, 0, U256::zero()).is_err());
+        assert!(SafeDeployment::new(Vec::new(), 1, U256::zero()).is_err());
+    }
+}
@@ -1,32 +1,91 @@
 //! Blockchain service implementation
 
+use super::abi::MethodDecoder;
+use super::beacon::{BeaconClient, ValidatorInfo};
 use super::etherscan::{
-    EtherscanChain, EtherscanClient, InternalTransaction as EtherscanInternalTransaction,
-    TokenBalance as EtherscanTokenBalance, TokenTransfer as EtherscanTokenTransfer,
+    AddressTag, BeaconWithdrawal, BlockRewardInfo, EthPrice, EthSupply, EtherscanChain,
+    EtherscanClient, InternalTransaction as EtherscanInternalTransaction, TokenApproval,
+    TokenBalance as EtherscanTokenBalance, TokenHolder, TokenTransfer as EtherscanTokenTransfer,
 };
+use super::known_selectors;
+use super::opensea::OpenSeaClient;
+use super::price::PriceClient;
+use super::safe;
+use super::simulator::HostedSimulator;
+use super::sourcify::SourcifyClient;
 use super::subscriptions::{SubscriptionEvent, SubscriptionManager};
 use super::types::AddressTx;
-use super::types::GasPrices;
+use super::types::BlockRangeSummary;
+use super::types::NonceAnalysis;
+use super::types::SelfDestructInfo;
+use super::types::SimulationResult;
+use super::types::{
+    BlockFinalityInfo, BlockGasMetric, ContractCreationInfo, ContractSource, Eip1559FeeEstimate,
+    Eip1559Tier, EventFilter, GasPrices, PriorityFeeSource, TransactionReceiptWithLogs,
+    TransactionStatus, TxConfirmation,
+};
+use super::types::{MevInfo, NftAttribute, NftMetadata};
 use crate::cache::{AddressInfo, CacheManager};
-use crate::config::Config;
+use crate::config::{Config, NetworkConfig};
 use crate::error::{Error, Result};
+use crate::metrics::MetricsSnapshot;
 use ethers::{
-    providers::{Http, Middleware, Provider, Ws},
+    abi::{self, ParamType, Token},
+    providers::{Http, Middleware, MiddlewareError, Provider, Ws},
     types::{
-        transaction::eip2718::TypedTransaction, Address, Block, Transaction, TransactionReceipt,
-        TransactionRequest, H256, U256,
+        transaction::eip2718::TypedTransaction, Address, Block, BlockId, BlockNumber, FeeHistory,
+        Log, Transaction, TransactionReceipt, TransactionRequest, H256, U256,
     },
 };
+use futures::stream::{FuturesUnordered, StreamExt};
+use std::future::Future;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+/// Largest number of blocks a range summary may span
+const MAX_BLOCK_RANGE: u64 = 100;
+
+/// JSON-RPC error code for a method the node does not implement
+const METHOD_NOT_FOUND_CODE: i64 = -32601;
+
+/// Last proof-of-work block on Ethereum mainnet (The Merge)
+pub const MERGE_BLOCK: u64 = 15_537_393;
+
+/// Delay before the first retry of a failed RPC request, doubled on each further retry
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest wait between two attempts of a failed RPC request
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Where `switch_to_local_node` expects an Anvil or Hardhat node
+const LOCAL_NODE_RPC_URL: &str = "http://127.0.0.1:8545";
+
+/// Widest block range searched for logs at once; most providers reject more
+const MAX_LOG_RANGE_BLOCKS: u64 = 10_000;
+
+/// Flashbots endpoint listing the bundle a mainnet transaction landed in
+const FLASHBOTS_TRANSACTIONS_URL: &str = "https://blocks.flashbots.net/v1/transactions";
+
+/// Selector of ERC-721 `tokenURI(uint256)`
+const TOKEN_URI_SELECTOR: [u8; 4] = [0xc8, 0x7b, 0x56, 0xdd];
 
 /// Blockchain service for interacting with Ethereum
 pub struct BlockchainService {
-    provider: Arc<Provider<Http>>,
-    ws_provider: Option<Arc<Provider<Ws>>>,
+    /// Behind a lock so `switch_to_local_node` can swap it while background
+    /// handles share the same provider
+    provider: Arc<RwLock<Provider<Http>>>,
+    ws_provider: Arc<RwLock<Option<Arc<Provider<Ws>>>>>,
     cache: Arc<CacheManager>,
-    config: Config,
-    etherscan: Option<EtherscanClient>,
+    /// Shared with background handles; never held across an `.await`
+    config: Arc<std::sync::RwLock<Config>>,
+    etherscan: Arc<RwLock<Option<EtherscanClient>>>,
+    opensea: Option<OpenSeaClient>,
+    price: PriceClient,
+    sourcify: SourcifyClient,
+    http_client: reqwest::Client,
+    beacon: BeaconClient,
     subscription_manager: Option<Arc<tokio::sync::Mutex<SubscriptionManager>>>,
     subscription_receiver: Option<tokio::sync::mpsc::UnboundedReceiver<SubscriptionEvent>>,
 }
@@ -35,11 +94,9 @@ impl BlockchainService {
     /// Create a new blockchain service
     pub async fn new(config: Config, cache: Arc<CacheManager>) -> Result<Self> {
         // Create provider - this is fast, no network call
-        let provider = Provider::<Http>::try_from(&config.network.rpc_url)
+        let provider = Provider::<Http>::try_from(http_rpc_url(&config.network.rpc_url))
             .map_err(|e| Error::network(format!("Failed to create provider: {}", e)))?;
 
-        let provider = Arc::new(provider);
-
         // Skip connection test during initialization to allow offline startup
         // Connection will be tested when first network call is made
 
@@ -50,38 +107,69 @@ impl BlockchainService {
             .or_else(|| config.etherscan_api_key.clone());
 
         let etherscan = api_key.map(|key| {
-            // Use a match expression directly instead of storing in variable
-            let chain = match config.network.chain_id {
-                1 => EtherscanChain::Ethereum,
-                5 => EtherscanChain::Goerli,
-                11155111 => EtherscanChain::Sepolia,
-                137 => EtherscanChain::Polygon,
-                42161 => EtherscanChain::Arbitrum,
-                10 => EtherscanChain::Optimism,
-                8453 => EtherscanChain::Base,
-                other => EtherscanChain::Custom(other),
-            };
-            EtherscanClient::new(key, chain)
+            EtherscanClient::new(
+                key,
+                EtherscanChain::from_chain_id(config.network.chain_id),
+                config.etherscan_rate_limit,
+            )
         });
 
-        // Try to create WebSocket provider from HTTP URL
-        let ws_provider = Self::create_ws_provider(&config.network.rpc_url).await;
+        // Initialize OpenSea client if API key present (used for NFT floor prices)
+        let opensea = std::env::var("OPENSEA_API_KEY")
+            .ok()
+            .map(OpenSeaClient::new);
+
+        // CoinGecko token prices; the key only raises the rate limit
+        let price = PriceClient::new(std::env::var("COINGECKO_API_KEY").ok());
+
+        // Plain HTTP client for fetching off-chain NFT metadata
+        let http_client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .map_err(|e| Error::network(format!("Failed to build HTTP client: {}", e)))?;
+
+        // Connect the WebSocket provider if a ws:// or wss:// endpoint is configured
+        let ws_provider = Self::create_ws_provider(&config).await;
 
         // Initialize subscription manager
         let (subscription_manager, subscription_receiver) =
-            SubscriptionManager::new(ws_provider.clone(), provider.clone());
+            SubscriptionManager::new(ws_provider.clone(), Arc::new(provider.clone()));
 
         Ok(Self {
-            provider,
-            ws_provider,
+            provider: Arc::new(RwLock::new(provider)),
+            ws_provider: Arc::new(RwLock::new(ws_provider)),
             cache,
-            config,
-            etherscan,
+            config: Arc::new(std::sync::RwLock::new(config)),
+            etherscan: Arc::new(RwLock::new(etherscan)),
+            opensea,
+            price,
+            sourcify: SourcifyClient::new(),
+            http_client,
+            beacon: BeaconClient::new(),
             subscription_manager: Some(Arc::new(tokio::sync::Mutex::new(subscription_manager))),
             subscription_receiver: Some(subscription_receiver),
         })
     }
 
+    /// A service sharing this one's providers, cache and API clients, for
+    /// background tasks; it holds no subscription receiver
+    pub fn background_handle(&self) -> Self {
+        Self {
+            provider: self.provider.clone(),
+            ws_provider: self.ws_provider.clone(),
+            cache: self.cache.clone(),
+            config: self.config.clone(),
+            etherscan: self.etherscan.clone(),
+            opensea: self.opensea.clone(),
+            price: self.price.clone(),
+            sourcify: self.sourcify.clone(),
+            http_client: self.http_client.clone(),
+            beacon: self.beacon.clone(),
+            subscription_manager: self.subscription_manager.clone(),
+            subscription_receiver: None,
+        }
+    }
+
     /// Get subscription event receiver
     pub fn subscription_receiver(
         &mut self,
@@ -89,12 +177,17 @@ impl BlockchainService {
         self.subscription_receiver.take()
     }
 
-    /// Create WebSocket provider from HTTP URL
-    async fn create_ws_provider(rpc_url: &str) -> Option<Arc<Provider<Ws>>> {
-        // Convert HTTP URL to WebSocket URL
-        let ws_url = rpc_url
-            .replace("http://", "ws://")
-            .replace("https://", "wss://");
+    /// Connect to the WebSocket endpoint of `config`, if it has one
+    ///
+    /// Without a WebSocket provider, subscriptions fall back to HTTP polling.
+    async fn create_ws_provider(config: &Config) -> Option<Arc<Provider<Ws>>> {
+        let Some(ws_url) = ws_rpc_url(config) else {
+            tracing::info!(
+                target: "warpscan",
+                "No WebSocket endpoint configured, using HTTP polling for subscriptions"
+            );
+            return None;
+        };
 
         match Provider::<Ws>::connect(&ws_url).await {
             Ok(provider) => {
@@ -117,6 +210,16 @@ impl BlockchainService {
         }
     }
 
+    /// Chain ID of the network the service currently talks to
+    pub fn chain_id(&self) -> u64 {
+        self.config().network.chain_id
+    }
+
+    /// Current configuration; the guard must be dropped before any `.await`
+    fn config(&self) -> std::sync::RwLockReadGuard<'_, Config> {
+        self.config.read().unwrap()
+    }
+
     /// Get subscription manager
     pub fn subscription_manager(&self) -> Option<Arc<tokio::sync::Mutex<SubscriptionManager>>> {
         self.subscription_manager.clone()
@@ -124,9 +227,17 @@ impl BlockchainService {
 
     /// Switch provider to use local Anvil/Hardhat node directly
     /// This is called when user selects "Local Node" mode
-    pub async fn switch_to_local_node(&mut self) -> Result<()> {
-        let local_rpc = "http://127.0.0.1:8545";
-        let local_ws = "ws://127.0.0.1:8545";
+    ///
+    /// The node's chain ID is checked before anything is swapped, so a
+    /// failed switch leaves the service on its current network. Calls already
+    /// in flight finish against the old provider.
+    pub async fn switch_to_local_node(&self) -> Result<()> {
+        self.switch_to_node_at(LOCAL_NODE_RPC_URL).await
+    }
+
+    /// Switch to the local development node listening at `local_rpc`
+    async fn switch_to_node_at(&self, local_rpc: &str) -> Result<()> {
+        let local_ws = local_rpc.replacen("http", "ws", 1);
 
         tracing::info!(
             target: "warpscan",
@@ -138,8 +249,25 @@ impl BlockchainService {
         let provider = Provider::<Http>::try_from(local_rpc)
             .map_err(|e| Error::network(format!("Failed to create local provider: {}", e)))?;
 
+        let timeout = Duration::from_secs(self.config().network.timeout_seconds);
+        let chain_id = match tokio::time::timeout(timeout, provider.get_chainid()).await {
+            Ok(Ok(chain_id)) => chain_id.as_u64(),
+            Ok(Err(e)) => {
+                return Err(Error::network(format!(
+                    "No local node at {}: {}",
+                    local_rpc, e
+                )))
+            }
+            Err(_) => {
+                return Err(Error::timeout(format!(
+                    "connecting to the local node at {}",
+                    local_rpc
+                )))
+            }
+        };
+
         // Try to create WebSocket provider
-        let ws_provider = match Provider::<Ws>::connect(local_ws).await {
+        let ws_provider = match Provider::<Ws>::connect(&local_ws).await {
             Ok(ws) => {
                 tracing::info!(
                     target: "warpscan",
@@ -158,50 +286,179 @@ impl BlockchainService {
             }
         };
 
-        // Wrap new HTTP provider in Arc
-        let provider = Arc::new(provider);
-
-        // Update providers stored on the service
-        self.provider = provider.clone();
-        self.ws_provider = ws_provider.clone();
+        // Update providers stored on the service (and on background handles)
+        *self.provider.write().await = provider.clone();
+        *self.ws_provider.write().await = ws_provider.clone();
 
         // Update existing subscription manager with new providers, if present,
         // without recreating the event channel. This keeps the mpsc receiver
         // that the UI is already listening on.
         if let Some(manager_arc) = &self.subscription_manager {
             let mut manager = manager_arc.lock().await;
-            manager.update_providers(ws_provider, provider.clone());
+            manager.update_providers(ws_provider, Arc::new(provider));
         }
 
         // Update config to reflect local node
-        self.config.network.rpc_url = local_rpc.to_string();
-        self.config.network.node_type = Some("anvil".to_string());
-        self.config.network.chain_id = 31337; // Anvil default
-        self.config.network.name = "Anvil Local".to_string();
+        {
+            let mut config = self.config.write().unwrap();
+            config.network.rpc_url = local_rpc.to_string();
+            config.network.node_type = Some("anvil".to_string());
+            config.network.chain_id = chain_id;
+            config.network.name = "Anvil Local".to_string();
+        }
 
         tracing::info!(
             target: "warpscan",
-            "Successfully switched to local node at {} (Chain ID: 31337)",
-            local_rpc
+            "Successfully switched to local node at {} (Chain ID: {})",
+            local_rpc,
+            chain_id
         );
 
         Ok(())
     }
 
+    /// Point the service at the network and Etherscan key of `config`
+    ///
+    /// Used after the setup wizard; like `switch_to_local_node`, the
+    /// subscription channel the UI listens on is kept.
+    pub async fn reconfigure(&self, config: Config) -> Result<()> {
+        let provider = Provider::<Http>::try_from(http_rpc_url(&config.network.rpc_url))
+            .map_err(|e| Error::network(format!("Failed to create provider: {}", e)))?;
+        let ws_provider = Self::create_ws_provider(&config).await;
+
+        *self.provider.write().await = provider.clone();
+        *self.ws_provider.write().await = ws_provider.clone();
+        if let Some(manager_arc) = &self.subscription_manager {
+            let mut manager = manager_arc.lock().await;
+            manager.update_providers(ws_provider, Arc::new(provider));
+        }
+
+        let api_key = std::env::var("ETHERSCAN_API_KEY")
+            .ok()
+            .or_else(|| config.etherscan_api_key.clone());
+        *self.etherscan.write().await = api_key.map(|key| {
+            EtherscanClient::new(
+                key,
+                EtherscanChain::from_chain_id(config.network.chain_id),
+                config.etherscan_rate_limit,
+            )
+        });
+
+        tracing::info!(
+            target: "warpscan",
+            "Reconfigured for {} at {} (Chain ID: {})",
+            config.network.name,
+            config.network.rpc_url,
+            config.network.chain_id
+        );
+        *self.config.write().unwrap() = config;
+        Ok(())
+    }
+
+    /// Connect to the RPC endpoint of `network`
+    ///
+    /// The endpoint has to answer `eth_chainId` with `network.chain_id`
+    /// before the service switches to it, so a mistyped URL or a node of
+    /// another chain leaves the working connection in place.
+    pub async fn reconnect(&self, network: &NetworkConfig) -> Result<()> {
+        let rpc_url = &network.rpc_url;
+        let provider = Provider::<Http>::try_from(http_rpc_url(rpc_url))
+            .map_err(|e| Error::network(format!("Failed to create provider: {}", e)))?;
+        let timeout = Duration::from_secs(self.config().network.timeout_seconds);
+        let chain_id = match tokio::time::timeout(timeout, provider.get_chainid()).await {
+            Ok(Ok(chain_id)) => chain_id.as_u64(),
+            Ok(Err(e)) => {
+                return Err(Error::network(format!(
+                    "Failed to connect to {}: {}",
+                    rpc_url, e
+                )))
+            }
+            Err(_) => return Err(Error::timeout(format!("connecting to {}", rpc_url))),
+        };
+        if chain_id != network.chain_id {
+            return Err(Error::validation(format!(
+                "{} is on chain {}, not chain {}",
+                rpc_url, chain_id, network.chain_id
+            )));
+        }
+
+        let mut config = self.config().clone();
+        config.network = network.clone();
+        self.reconfigure(config).await
+    }
+
+    /// Move to another network without restarting
+    ///
+    /// Recreates the providers and the Etherscan client for the new chain.
+    /// `ws_rpc_url` belongs to the old network, so subscriptions poll over
+    /// HTTP unless the new RPC URL is itself a WebSocket URL.
+    pub async fn switch_network(&self, network: NetworkConfig) -> Result<()> {
+        let mut config = self.config().clone();
+        config.network = network;
+        config.ws_rpc_url = None;
+        self.reconfigure(config).await
+    }
+
+    /// HTTP provider for a single JSON-RPC call, counted in the metrics registry
+    ///
+    /// The provider is cloned out of the lock so no guard is held across the
+    /// call and a concurrent `switch_to_local_node` never waits on a slow
+    /// request.
+    async fn rpc(&self) -> Provider<Http> {
+        crate::metrics::global().record_rpc_call();
+        self.provider.read().await.clone()
+    }
+
+    /// Run an RPC request with the configured timeout per attempt, retrying
+    /// transport failures and timeouts up to `network.max_retries` times
+    async fn with_retry<T, F, Fut>(&self, mut op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let (timeout, max_retries) = {
+            let network = &self.config().network;
+            (
+                Duration::from_secs(network.timeout_seconds),
+                network.max_retries,
+            )
+        };
+        retry_with_backoff(max_retries, RETRY_BASE_DELAY, || {
+            let attempt = op();
+            async move {
+                tokio::time::timeout(timeout, attempt)
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(Error::timeout(format!(
+                            "RPC request after {}s",
+                            timeout.as_secs()
+                        )))
+                    })
+            }
+        })
+        .await
+    }
+
+    /// Etherscan client, if an API key is configured
+    async fn etherscan(&self) -> Option<EtherscanClient> {
+        self.etherscan.read().await.clone()
+    }
+
+    /// Get a snapshot of RPC, Etherscan, cache and error counters
+    pub fn get_metrics(&self) -> MetricsSnapshot {
+        crate::metrics::global().snapshot()
+    }
+
     /// Test network connection
     pub async fn test_connection(&self) -> Result<u64> {
-        match tokio::time::timeout(
-            std::time::Duration::from_secs(self.config.network.timeout_seconds),
-            self.provider.get_chainid(),
-        )
-        .await
-        {
+        let timeout = std::time::Duration::from_secs(self.config().network.timeout_seconds);
+        match tokio::time::timeout(timeout, self.rpc().await.get_chainid()).await {
             Ok(Ok(chain_id)) => Ok(chain_id.as_u64()),
             Ok(Err(e)) => Err(Error::network(format!(
                 "Failed to connect to network: {}",
                 e
             ))),
-            Err(_) => Err(Error::network("Connection timeout".to_string())),
+            Err(_) => Err(Error::timeout("connecting to the network")),
         }
     }
 
@@ -213,10 +470,14 @@ impl BlockchainService {
         }
 
         let block = self
-            .provider
-            .get_block(block_number)
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block(block_number)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         // Store in cache if found
         if let Some(ref block) = block {
@@ -226,24 +487,393 @@ impl BlockchainService {
         Ok(block)
     }
 
+    /// Get several blocks by number in one JSON-RPC batch request
+    ///
+    /// Cached blocks are not requested again. Not every node accepts batches;
+    /// if the batch fails the blocks are fetched one by one instead.
+    pub async fn batch_get_blocks(&self, numbers: &[u64]) -> Result<Vec<Option<Block<H256>>>> {
+        let mut blocks: Vec<Option<Block<H256>>> =
+            numbers.iter().map(|&n| self.cache.get_block(n)).collect();
+        let missing: Vec<usize> = (0..numbers.len())
+            .filter(|&i| blocks[i].is_none())
+            .collect();
+        if missing.is_empty() {
+            return Ok(blocks);
+        }
+
+        let requested: Vec<u64> = missing.iter().map(|&i| numbers[i]).collect();
+        match self.send_block_batch(&requested).await {
+            Ok(fetched) => {
+                for (&i, block) in missing.iter().zip(fetched) {
+                    if let Some(ref block) = block {
+                        self.cache.store_block(numbers[i], block.clone());
+                    }
+                    blocks[i] = block;
+                }
+            }
+            Err(e) => {
+                tracing::debug!(target: "warpscan", "Block batch failed, fetching one by one: {}", e);
+                for &i in &missing {
+                    blocks[i] = self.get_block_by_number(numbers[i]).await?;
+                }
+            }
+        }
+        Ok(blocks)
+    }
+
+    /// POST one `eth_getBlockByNumber` call per block as a JSON-RPC batch,
+    /// returning the blocks in the order requested
+    async fn send_block_batch(&self, numbers: &[u64]) -> Result<Vec<Option<Block<H256>>>> {
+        let batch: Vec<serde_json::Value> = numbers
+            .iter()
+            .enumerate()
+            .map(|(id, number)| {
+                serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "method": "eth_getBlockByNumber",
+                    "params": [format!("{:#x}", number), false],
+                })
+            })
+            .collect();
+
+        // The provider's URL, which follows `switch_to_local_node`
+        let url = self.rpc().await.as_ref().url().clone();
+        let response = self
+            .http_client
+            .post(url)
+            .json(&batch)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| Error::network(format!("Batch request failed: {}", e)))?;
+        // A node without batch support answers with a single error object
+        let responses: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .map_err(|e| Error::parse(format!("Invalid batch response: {}", e)))?;
+        if responses.len() != numbers.len() {
+            return Err(Error::parse(format!(
+                "Batch response has {} results for {} requests",
+                responses.len(),
+                numbers.len()
+            )));
+        }
+
+        // Responses may come back in any order; match them up by id
+        let mut blocks = vec![None; numbers.len()];
+        for response in responses {
+            if let Some(error) = response.get("error") {
+                return Err(Error::blockchain(format!(
+                    "Batch request failed: {}",
+                    error
+                )));
+            }
+            let slot = response
+                .get("id")
+                .and_then(|id| id.as_u64())
+                .and_then(|id| blocks.get_mut(id as usize))
+                .ok_or_else(|| Error::parse("Batch response with an unknown id"))?;
+            *slot = serde_json::from_value(response["result"].clone())
+                .map_err(|e| Error::parse(format!("Invalid block in batch response: {}", e)))?;
+        }
+        Ok(blocks)
+    }
+
+    /// Get a block header and transaction hashes by `0x` block hash
+    pub async fn get_block_by_hash(&self, block_hash: &str) -> Result<Option<Block<H256>>> {
+        if let Some(cached_block) = self.cache.get_block_by_hash(block_hash) {
+            return Ok(Some(cached_block));
+        }
+
+        let hash = H256::from_str(block_hash)
+            .map_err(|e| Error::validation(format!("Invalid block hash: {}", e)))?;
+        let block = self
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block(hash)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
+
+        if let Some(ref block) = block {
+            self.cache.store_block_by_hash(block_hash, block.clone());
+        }
+
+        Ok(block)
+    }
+
+    /// Get a block with its full transaction objects in a single RPC call
+    ///
+    /// The transactions are cached individually so a later detail lookup is free.
+    pub async fn get_block_with_transactions(
+        &self,
+        block_number: u64,
+    ) -> Result<Option<Block<Transaction>>> {
+        let block = self
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block_with_txs(block_number)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
+
+        if let Some(ref block) = block {
+            for tx in &block.transactions {
+                self.cache
+                    .store_transaction(format!("{:#x}", tx.hash), tx.clone());
+            }
+        }
+
+        Ok(block)
+    }
+
+    /// Get a block by its hash, with full transaction objects
+    pub async fn get_block_with_transactions_by_hash(
+        &self,
+        block_hash: &str,
+    ) -> Result<Option<Block<Transaction>>> {
+        let hash = H256::from_str(block_hash)
+            .map_err(|e| Error::validation(format!("Invalid block hash: {}", e)))?;
+
+        let block = self
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block_with_txs(hash)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
+
+        if let Some(ref block) = block {
+            for tx in &block.transactions {
+                self.cache
+                    .store_transaction(format!("{:#x}", tx.hash), tx.clone());
+            }
+        }
+
+        Ok(block)
+    }
+
+    /// Get the full header and transactions of a block, by number or `0x` hash
+    ///
+    /// Gas used and status of each transaction come from the block's
+    /// receipts; nodes without `eth_getBlockReceipts` leave them unset.
+    pub async fn get_block_details(
+        &self,
+        block_number_or_hash: &str,
+    ) -> Result<crate::ui::models::BlockDetails> {
+        use crate::ui::models::{BlockDetails, TransactionInfo, TransactionStatus};
+
+        let input = block_number_or_hash.trim();
+        let block = if input.starts_with("0x") {
+            self.get_block_with_transactions_by_hash(input).await?
+        } else {
+            let number = input
+                .parse::<u64>()
+                .map_err(|e| Error::validation(format!("Invalid block number: {}", e)))?;
+            self.get_block_with_transactions(number).await?
+        }
+        .ok_or_else(|| Error::blockchain(format!("Block {} not found", input)))?;
+
+        let number = block.number.map(|n| n.as_u64()).unwrap_or_default();
+        let timestamp = block.timestamp.as_u64();
+        let mut transactions: Vec<TransactionInfo> = block
+            .transactions
+            .iter()
+            .map(|tx| TransactionInfo::from_block_transaction(tx, number, timestamp))
+            .collect();
+
+        if !transactions.is_empty() {
+            match self.rpc().await.get_block_receipts(number).await {
+                Ok(receipts) => {
+                    for (tx, receipt) in transactions.iter_mut().zip(&receipts) {
+                        tx.gas_used = receipt.gas_used.map(|g| g.as_u64()).unwrap_or(0);
+                        tx.status = if receipt.status.map(|s| s.as_u64()) == Some(1) {
+                            TransactionStatus::Success
+                        } else {
+                            TransactionStatus::Failed
+                        };
+                        if let Some(price) = receipt.effective_gas_price {
+                            tx.transaction_fee =
+                                (price.as_u128() * tx.gas_used as u128) as f64 / 1e18;
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(target: "warpscan", "Failed to fetch receipts of block {}: {}", number, e)
+                }
+            }
+        }
+
+        Ok(BlockDetails {
+            number,
+            hash: block
+                .hash
+                .map(|h| format!("{:#x}", h))
+                .unwrap_or_else(|| "0x0".to_string()),
+            parent_hash: format!("{:#x}", block.parent_hash),
+            state_root: format!("{:#x}", block.state_root),
+            transactions_root: format!("{:#x}", block.transactions_root),
+            receipts_root: format!("{:#x}", block.receipts_root),
+            miner: block
+                .author
+                .map(|a| format!("{:#x}", a))
+                .unwrap_or_else(|| "0x0".to_string()),
+            difficulty: block.difficulty.to_string(),
+            total_difficulty: block.total_difficulty.map(|td| td.to_string()),
+            size: block.size.map(|s| s.as_u64()).unwrap_or(0),
+            gas_limit: block.gas_limit.as_u64(),
+            gas_used: block.gas_used.as_u64(),
+            timestamp,
+            extra_data: format!("0x{}", hex::encode(&block.extra_data)),
+            base_fee_per_gas: block.base_fee_per_gas.map(|fee| fee.as_u64()),
+            uncle_count: block.uncles.len(),
+            transactions,
+        })
+    }
+
     /// Get latest block
     pub async fn get_latest_block(&self) -> Result<Option<Block<H256>>> {
         let block = self
-            .provider
-            .get_block(ethers::types::BlockNumber::Latest)
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block(ethers::types::BlockNumber::Latest)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         // Store in cache if found
         if let Some(ref block) = block {
             if let Some(number) = block.number {
                 self.cache.store_block(number.as_u64(), block.clone());
+                crate::metrics::global().set_current_block(number.as_u64());
             }
         }
 
         Ok(block)
     }
 
+    /// Get the latest, safe and finalized block numbers
+    ///
+    /// Nodes without a beacon chain (e.g. Anvil) report the latest block for
+    /// all three labels.
+    pub async fn get_block_finality_labels(&self) -> Result<BlockFinalityInfo> {
+        if let Some(info) = self.cache.get_block_finality() {
+            return Ok(info);
+        }
+
+        let block = |tag: BlockNumber| {
+            self.with_retry(move || async move {
+                self.rpc()
+                    .await
+                    .get_block(tag)
+                    .await
+                    .map_err(Error::provider)
+            })
+        };
+        let (latest, safe, finalized) = tokio::join!(
+            block(BlockNumber::Latest),
+            block(BlockNumber::Safe),
+            block(BlockNumber::Finalized),
+        );
+        let number = |label: &str, block: Result<Option<Block<H256>>>| {
+            block?
+                .and_then(|b| b.number)
+                .map(|n| n.as_u64())
+                .ok_or_else(|| Error::blockchain(format!("No {} block reported", label)))
+        };
+        let info = BlockFinalityInfo {
+            latest: number("latest", latest)?,
+            safe: number("safe", safe)?,
+            finalized: number("finalized", finalized)?,
+        };
+
+        self.cache.store_block_finality(info);
+        Ok(info)
+    }
+
+    /// Summarise a block range (inclusive) by fetching its blocks concurrently
+    pub async fn get_block_range_summary(&self, start: u64, end: u64) -> Result<BlockRangeSummary> {
+        if start > end {
+            return Err(Error::validation(format!(
+                "Start block {} is after end block {}",
+                start, end
+            )));
+        }
+        if end - start >= MAX_BLOCK_RANGE {
+            return Err(Error::validation(format!(
+                "Block range is limited to {} blocks",
+                MAX_BLOCK_RANGE
+            )));
+        }
+
+        if let Some(summary) = self.cache.get_block_range_summary(start, end) {
+            return Ok(summary);
+        }
+
+        let mut requests: FuturesUnordered<_> = (start..=end)
+            .map(|number| self.get_block_by_number(number))
+            .collect();
+        let mut blocks = Vec::with_capacity((end - start + 1) as usize);
+        while let Some(result) = requests.next().await {
+            match result? {
+                Some(block) => blocks.push(block),
+                None => {
+                    return Err(Error::blockchain(
+                        "Block range extends past the chain head".to_string(),
+                    ))
+                }
+            }
+        }
+        blocks.sort_by_key(|block| block.number.map(|n| n.as_u64()).unwrap_or(0));
+
+        let block_count = blocks.len() as u64;
+        let total_transactions = blocks.iter().map(|b| b.transactions.len() as u64).sum();
+        let total_gas_used: f64 = blocks.iter().map(|b| b.gas_used.as_u128() as f64).sum();
+
+        let avg_block_time_secs = match (blocks.first(), blocks.last()) {
+            (Some(first), Some(last)) if block_count > 1 => {
+                last.timestamp.saturating_sub(first.timestamp).as_u64() as f64
+                    / (block_count - 1) as f64
+            }
+            _ => 0.0,
+        };
+
+        let base_fees: Vec<u64> = blocks
+            .iter()
+            .filter_map(|b| b.base_fee_per_gas.map(|fee| fee.as_u64()))
+            .collect();
+        let total_wei_burned: f64 = blocks
+            .iter()
+            .filter_map(|b| {
+                b.base_fee_per_gas
+                    .map(|fee| fee.as_u128() as f64 * b.gas_used.as_u128() as f64)
+            })
+            .sum();
+
+        let summary = BlockRangeSummary {
+            block_count,
+            total_transactions,
+            avg_gas_used: total_gas_used / block_count as f64,
+            avg_block_time_secs,
+            min_base_fee: base_fees.iter().copied().min().unwrap_or(0),
+            max_base_fee: base_fees.iter().copied().max().unwrap_or(0),
+            total_eth_burned: total_wei_burned / 1e18,
+        };
+
+        self.cache
+            .store_block_range_summary(start, end, summary.clone());
+        Ok(summary)
+    }
+
     /// Get transaction by hash
     pub async fn get_transaction_by_hash(&self, tx_hash: &str) -> Result<Option<Transaction>> {
         // Check cache first
@@ -255,10 +885,14 @@ impl BlockchainService {
             .map_err(|e| Error::validation(format!("Invalid transaction hash: {}", e)))?;
 
         let tx = self
-            .provider
-            .get_transaction(hash)
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_transaction(hash)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         // Store in cache if found
         if let Some(ref tx) = tx {
@@ -277,10 +911,62 @@ impl BlockchainService {
         let hash = H256::from_str(tx_hash)
             .map_err(|e| Error::validation(format!("Invalid transaction hash: {}", e)))?;
 
-        self.provider
-            .get_transaction_receipt(hash)
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))
+        self.with_retry(|| async {
+            self.rpc()
+                .await
+                .get_transaction_receipt(hash)
+                .await
+                .map_err(Error::provider)
+        })
+        .await
+    }
+
+    /// Transaction receipt with its event logs decoded against the known
+    /// event signatures
+    pub async fn get_transaction_receipt_with_logs(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Option<TransactionReceiptWithLogs>> {
+        Ok(self
+            .get_transaction_receipt(tx_hash)
+            .await?
+            .map(|receipt| TransactionReceiptWithLogs {
+                decoded_logs: receipt
+                    .logs
+                    .iter()
+                    .map(known_selectors::decode_log)
+                    .collect(),
+                receipt,
+            }))
+    }
+
+    /// Status and confirmation count of a transaction
+    ///
+    /// A transaction without a receipt is reported as pending with no
+    /// confirmations; the inclusion block counts as the first confirmation.
+    pub async fn get_confirmation_count(&self, tx_hash: &str) -> Result<TxConfirmation> {
+        let receipt = self.get_transaction_receipt(tx_hash).await?;
+        let Some((receipt, block_number)) =
+            receipt.and_then(|r| r.block_number.map(|n| (r, n.as_u64())))
+        else {
+            return Ok(TxConfirmation {
+                status: TransactionStatus::Pending,
+                block_number: None,
+                confirmations: 0,
+            });
+        };
+
+        let latest = self.get_block_number().await?;
+        let status = match receipt.status.map(|s| s.as_u64()) {
+            Some(1) => TransactionStatus::Success,
+            Some(_) => TransactionStatus::Failed,
+            None => TransactionStatus::Unknown,
+        };
+        Ok(TxConfirmation {
+            status,
+            block_number: Some(block_number),
+            confirmations: latest.saturating_sub(block_number) + 1,
+        })
     }
 
     /// Get address balance
@@ -296,7 +982,7 @@ impl BlockchainService {
     ) -> Result<U256> {
         // Check if this is a local node - skip Etherscan for local nodes
         let is_local_node = self
-            .config
+            .config()
             .network
             .node_type
             .as_ref()
@@ -305,7 +991,7 @@ impl BlockchainService {
 
         // Use Etherscan only if requested and not a local node
         if use_etherscan && !is_local_node {
-            if let Some(ref client) = self.etherscan {
+            if let Some(ref client) = self.etherscan().await {
                 match client.get_address_balance(address).await {
                     Ok(bal) => return Ok(bal),
                     Err(err) => {
@@ -325,10 +1011,14 @@ impl BlockchainService {
             .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
 
         let balance = self
-            .provider
-            .get_balance(addr, None)
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_balance(addr, None)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         // Log the raw balance value for debugging
         let balance_str = balance.to_string();
@@ -370,10 +1060,14 @@ impl BlockchainService {
             .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
 
         let count = self
-            .provider
-            .get_transaction_count(addr, None)
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_transaction_count(addr, None)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         tracing::debug!(
             target: "warpscan",
@@ -385,16 +1079,70 @@ impl BlockchainService {
         Ok(count)
     }
 
+    /// Balance of an address at the pending block
+    ///
+    /// The pending nonce comes from `get_nonce_gap_analysis`.
+    pub async fn get_pending_balance(&self, address: &str) -> Result<U256> {
+        let addr = Address::from_str(address)
+            .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
+
+        self.with_retry(|| async {
+            self.rpc()
+                .await
+                .get_balance(addr, Some(BlockId::Number(BlockNumber::Pending)))
+                .await
+                .map_err(Error::provider)
+        })
+        .await
+    }
+
+    /// Compare the confirmed and pending nonce of an address to detect nonce gaps
+    pub async fn get_nonce_gap_analysis(&self, address: &str) -> Result<NonceAnalysis> {
+        let addr = Address::from_str(address)
+            .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
+
+        let nonce_at = |tag: BlockNumber| {
+            self.with_retry(move || async move {
+                self.rpc()
+                    .await
+                    .get_transaction_count(addr, Some(BlockId::Number(tag)))
+                    .await
+                    .map_err(Error::provider)
+            })
+        };
+        let (current_result, pending_result) = tokio::join!(
+            nonce_at(BlockNumber::Latest),
+            nonce_at(BlockNumber::Pending)
+        );
+
+        let current_nonce = current_result?.as_u64();
+        let pending_nonce = pending_result?.as_u64();
+
+        let has_gap = pending_nonce > current_nonce + 1;
+        let gap_count = pending_nonce.saturating_sub(current_nonce + 1);
+
+        Ok(NonceAnalysis {
+            current_nonce,
+            pending_nonce,
+            gap_count,
+            has_gap,
+        })
+    }
+
     /// Check if address is a contract
     pub async fn is_contract(&self, address: &str) -> Result<bool> {
         let addr = Address::from_str(address)
             .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
 
         let code = self
-            .provider
-            .get_code(addr, None)
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_code(addr, None)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         // An address is a contract if it has code
         // Empty code means it's an EOA (Externally Owned Account)
@@ -418,53 +1166,63 @@ impl BlockchainService {
         Ok(is_contract)
     }
 
-    /// Get comprehensive address information
+    /// Get comprehensive address information, preferring Etherscan for the
+    /// balance; alias for `get_address_info_with_mode(address, true)`
     pub async fn get_address_info(&self, address: &str) -> Result<AddressInfo> {
         self.get_address_info_with_mode(address, true).await
     }
 
     /// Get comprehensive address information with mode selection
+    ///
+    /// With `use_etherscan` the balance comes from Etherscan (falling back to
+    /// the provider on error) while the nonce and contract status always come
+    /// from the provider. Without it every field is read from the provider and
+    /// the cache is bypassed, since local chains change under us.
     pub async fn get_address_info_with_mode(
         &self,
         address: &str,
         use_etherscan: bool,
     ) -> Result<AddressInfo> {
-        // Check if this is a local node - for local nodes, always re-check contract status
-        // as it might have changed (contracts can be deployed)
         let is_local_node = self
-            .config
+            .config()
             .network
             .node_type
             .as_ref()
             .map(|t| t == "anvil" || t == "hardhat" || t == "local")
             .unwrap_or(false);
 
-        // Check cache first, but for local nodes, we'll re-check contract status
-        if let Some(cached_info) = self.cache.get_address_info(address) {
-            // For local nodes, always re-check contract status to ensure accuracy
-            if !is_local_node {
+        // Contracts can be deployed at any time on a local chain, so only
+        // trust the cache for Etherscan lookups against a public network
+        if use_etherscan && !is_local_node {
+            if let Some(cached_info) = self.cache.get_address_info(address) {
                 return Ok(cached_info);
             }
-            // For local nodes, continue to re-check contract status below
         }
 
         // PARALLELIZE: Fetch balance, transaction count, and contract status concurrently
         // These calls are independent and can be done in parallel
         // In Local Node mode, balance fetch will use RPC directly (skip Etherscan)
-        let (balance_result, transaction_count_result, is_contract_result) = tokio::join!(
+        let (balance_result, transaction_count_result, is_contract_result, pending_result) = tokio::join!(
             self.get_address_balance_with_mode(address, use_etherscan),
             self.get_address_transaction_count(address),
             self.is_contract(address),
+            self.get_pending_balance(address),
         );
 
         let balance = balance_result?;
         let transaction_count = transaction_count_result?;
         let is_contract = is_contract_result?;
+        // Not every node serves the pending block; show the confirmed balance then
+        let pending_balance = pending_result.unwrap_or_else(|e| {
+            tracing::warn!(target: "warpscan", "Pending balance unavailable for {}: {}", address, e);
+            balance
+        });
 
         let info = AddressInfo {
             address: address.to_string(),
             balance: balance.to_string(),
             transaction_count: transaction_count.as_u64(),
+            pending_balance: pending_balance.to_string(),
             is_contract,
             last_updated: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -479,17 +1237,29 @@ impl BlockchainService {
         Ok(info)
     }
 
-    /// Get address transactions (normal transactions)
-    pub async fn get_address_transactions(&self, address: &str) -> Result<Vec<AddressTx>> {
+    /// Get one page of address transactions (normal transactions)
+    ///
+    /// Only the first page is cached, keyed by address.
+    pub async fn get_address_transactions(
+        &self,
+        address: &str,
+        page: u64,
+        page_size: u64,
+    ) -> Result<Vec<AddressTx>> {
         // Check cache first
-        if let Some(cached_txs) = self.cache.get_address_transactions(address) {
-            tracing::debug!(target: "warpscan", "Cache hit for address transactions: {}", address);
-            return Ok(cached_txs);
+        if page == 1 {
+            if let Some(cached_txs) = self.cache.get_address_transactions(address) {
+                tracing::debug!(target: "warpscan", "Cache hit for address transactions: {}", address);
+                return Ok(cached_txs);
+            }
         }
 
         // Prefer Etherscan V2 when configured
-        if let Some(ref client) = self.etherscan {
-            match client.get_address_transactions(address).await {
+        if let Some(ref client) = self.etherscan().await {
+            match client
+                .get_address_transactions(address, page, page_size)
+                .await
+            {
                 Ok(txs) => {
                     tracing::info!(
                         target: "warpscan",
@@ -498,9 +1268,11 @@ impl BlockchainService {
                         address
                     );
                     // Store in cache for future use
-                    self.cache
-                        .store_address_transactions(address.to_string(), txs.clone());
-                    tracing::debug!(target: "warpscan", "Cached address transactions for: {}", address);
+                    if page == 1 {
+                        self.cache
+                            .store_address_transactions(address.to_string(), txs.clone());
+                        tracing::debug!(target: "warpscan", "Cached address transactions for: {}", address);
+                    }
                     return Ok(txs);
                 }
                 Err(err) => {
@@ -523,15 +1295,19 @@ impl BlockchainService {
         &self,
         address: &str,
         use_etherscan: bool,
+        page: u64,
+        page_size: u64,
     ) -> Result<Vec<AddressTx>> {
         tracing::info!(
             target: "warpscan",
-            "🔍 get_address_transactions_with_mode: address={}, use_etherscan={}",
+            "🔍 get_address_transactions_with_mode: address={}, use_etherscan={}, page={}",
             address,
-            use_etherscan
+            use_etherscan,
+            page
         );
         if use_etherscan {
-            self.get_address_transactions(address).await
+            self.get_address_transactions(address, page, page_size)
+                .await
         } else {
             // Local mode: fetch transactions from blocks by scanning recent blocks
             self.get_address_transactions_from_rpc(address).await
@@ -548,10 +1324,14 @@ impl BlockchainService {
 
         // Get current block number
         let latest_block = self
-            .provider
-            .get_block_number()
-            .await
-            .map_err(|e| Error::blockchain(format!("Failed to get block number: {}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block_number()
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
         let latest_block_num = latest_block.as_u64();
 
         // For local nodes, scan the last 100 blocks (or all blocks if less than 100)
@@ -642,7 +1422,7 @@ impl BlockchainService {
         }
 
         // Sort by block number descending (newest first)
-        transactions.sort_by(|a, b| b.block_number.cmp(&a.block_number));
+        transactions.sort_by_key(|tx| std::cmp::Reverse(tx.block_number));
 
         tracing::info!(
             target: "warpscan",
@@ -662,7 +1442,7 @@ impl BlockchainService {
             return Ok(cached_transfers);
         }
 
-        if let Some(ref client) = self.etherscan {
+        if let Some(ref client) = self.etherscan().await {
             match client.get_token_transfers(address).await {
                 Ok(transfers) => {
                     // Store in cache for future use
@@ -709,7 +1489,7 @@ impl BlockchainService {
             return Ok(cached_txns);
         }
 
-        if let Some(ref client) = self.etherscan {
+        if let Some(ref client) = self.etherscan().await {
             match client.get_internal_transactions(address).await {
                 Ok(txns) => {
                     // Store in cache for future use
@@ -731,15 +1511,262 @@ impl BlockchainService {
         Ok(vec![])
     }
 
-    /// Get token balances for an address
-    pub async fn get_token_balances(&self, address: &str) -> Result<Vec<EtherscanTokenBalance>> {
-        // Check cache first
-        if let Some(cached_balances) = self.cache.get_token_balances(address) {
-            tracing::debug!(target: "warpscan", "Cache hit for token balances: {}", address);
-            return Ok(cached_balances);
+    /// Get Etherscan name tags for an address (empty without an API key)
+    pub async fn get_address_tags(&self, address: &str) -> Result<Vec<AddressTag>> {
+        let key = address.to_lowercase();
+        if let Some(tags) = self.cache.get_address_tags(&key) {
+            return Ok(tags);
         }
 
-        if let Some(ref client) = self.etherscan {
+        let Some(client) = self.etherscan().await else {
+            return Ok(vec![]);
+        };
+
+        match client.get_address_tags(address).await {
+            Ok(tags) => {
+                self.cache.store_address_tags(key, tags.clone());
+                Ok(tags)
+            }
+            Err(err) => {
+                tracing::warn!(
+                    target: "warpscan",
+                    "Etherscan failed for address tags: {}. Returning no tags.",
+                    err
+                );
+                Ok(vec![])
+            }
+        }
+    }
+
+    /// Verified source code of a contract, from Etherscan or else Sourcify
+    ///
+    /// Returns `None` if neither has verified it. Unverified results are
+    /// cached too, unless Etherscan failed and might still know the contract.
+    pub async fn get_contract_source_code(&self, address: &str) -> Result<Option<ContractSource>> {
+        let parsed = Address::from_str(address)
+            .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
+        let key = address.to_lowercase();
+        if let Some(source) = self.cache.get_contract_source(&key) {
+            return Ok(source);
+        }
+
+        let mut etherscan_failed = false;
+        if let Some(client) = self.etherscan().await {
+            match client.get_contract_source(address).await {
+                Ok(Some(source)) => {
+                    self.cache.store_contract_source(key, Some(source.clone()));
+                    return Ok(Some(source));
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    tracing::warn!(
+                        target: "warpscan",
+                        "Etherscan failed for contract source: {}. Trying Sourcify.",
+                        err
+                    );
+                    etherscan_failed = true;
+                }
+            }
+        }
+
+        let source = self
+            .sourcify
+            .get_contract_metadata(self.chain_id(), &ethers::utils::to_checksum(&parsed, None))
+            .await?
+            .map(|result| result.into_contract_source());
+        if source.is_some() || !etherscan_failed {
+            self.cache.store_contract_source(key, source.clone());
+        }
+        Ok(source)
+    }
+
+    /// ABI JSON of a verified contract, from Etherscan or Sourcify
+    ///
+    /// Returns `None` if the contract is not verified on either.
+    pub async fn get_contract_abi(&self, address: &str) -> Result<Option<String>> {
+        Ok(self
+            .get_contract_source_code(address)
+            .await?
+            .map(|source| source.abi))
+    }
+
+    /// Top holders of an ERC-20 token, largest first
+    ///
+    /// Needs an Etherscan Pro key; free keys yield `Error::PremiumRequired`.
+    pub async fn get_token_holders(&self, contract_address: &str) -> Result<Vec<TokenHolder>> {
+        const TOP_HOLDERS: u64 = 25;
+
+        let Some(client) = self.etherscan().await else {
+            return Err(Error::validation(
+                "Token holders require an Etherscan API key",
+            ));
+        };
+
+        let mut holders = client
+            .get_token_holders(contract_address, 1, TOP_HOLDERS)
+            .await?;
+        holders.sort_by(|a, b| {
+            let qty = |h: &TokenHolder| U256::from_dec_str(&h.quantity).unwrap_or_default();
+            qty(b).cmp(&qty(a))
+        });
+        holders.truncate(TOP_HOLDERS as usize);
+        Ok(holders)
+    }
+
+    /// Get the miner rewards of a proof-of-work block
+    ///
+    /// Returns `None` for post-Merge blocks, which have no static reward, and
+    /// when no Etherscan API key is configured.
+    pub async fn get_block_rewards(&self, block_number: u64) -> Result<Option<BlockRewardInfo>> {
+        if block_number > MERGE_BLOCK {
+            return Ok(None);
+        }
+        if let Some(rewards) = self.cache.get_block_rewards(block_number) {
+            return Ok(Some(rewards));
+        }
+
+        let Some(client) = self.etherscan().await else {
+            return Ok(None);
+        };
+
+        let rewards = client.get_block_rewards(block_number).await?;
+        self.cache
+            .store_block_rewards(block_number, rewards.clone());
+        Ok(Some(rewards))
+    }
+
+    /// Get the internal transactions created by a single transaction
+    pub async fn get_internal_transactions_by_tx_hash(
+        &self,
+        tx_hash: &str,
+    ) -> Result<Vec<EtherscanInternalTransaction>> {
+        if let Some(cached_txns) = self.cache.get_internal_transactions_by_hash(tx_hash) {
+            tracing::debug!(target: "warpscan", "Cache hit for internal transactions of tx: {}", tx_hash);
+            return Ok(cached_txns);
+        }
+
+        let Some(client) = self.etherscan().await else {
+            return Ok(vec![]);
+        };
+        let txns = client.get_internal_transactions_by_tx_hash(tx_hash).await?;
+        self.cache
+            .store_internal_transactions_by_hash(tx_hash.to_string(), txns.clone());
+        Ok(txns)
+    }
+
+    /// Deployer and deployment transaction of a contract
+    ///
+    /// Asks Etherscan's `getcontractcreation` first and falls back to a
+    /// `create` entry for the contract in its internal transactions, which
+    /// covers contracts deployed by factories. Results are cached without
+    /// expiry; `None` (including without an Etherscan key) is not cached.
+    pub async fn get_contract_creation_info(
+        &self,
+        address: &str,
+    ) -> Result<Option<ContractCreationInfo>> {
+        let key = address.to_lowercase();
+        if let Some(info) = self.cache.get_contract_creation(&key) {
+            return Ok(Some(info));
+        }
+        let Some(client) = self.etherscan().await else {
+            return Ok(None);
+        };
+
+        let creation = client
+            .get_contract_creation(address)
+            .await
+            .unwrap_or_else(|err| {
+                tracing::warn!(
+                    target: "warpscan",
+                    "Etherscan failed for contract creation: {}. Scanning internal transactions.",
+                    err
+                );
+                None
+            });
+        let mut creation = match creation {
+            Some(creation) => creation,
+            None => {
+                let internal_txs = self.get_internal_transactions(address).await?;
+                let Some(entry) = internal_txs.iter().find(|tx| {
+                    tx.tx_type.to_lowercase().starts_with("create")
+                        && tx.to.eq_ignore_ascii_case(address)
+                }) else {
+                    return Ok(None);
+                };
+                ContractCreationInfo {
+                    creator: entry.from.clone(),
+                    tx_hash: entry.parent_tx_hash.clone(),
+                    creation_block: entry.block,
+                }
+            }
+        };
+
+        // Older Etherscan responses omit the block number
+        if creation.creation_block == 0 {
+            if let Ok(Some(tx)) = self.get_transaction_by_hash(&creation.tx_hash).await {
+                creation.creation_block = tx.block_number.map_or(0, |n| n.as_u64());
+            }
+        }
+
+        self.cache.store_contract_creation(key, creation.clone());
+        Ok(Some(creation))
+    }
+
+    /// Look for a SELFDESTRUCT of this contract in its internal transactions
+    ///
+    /// A detection is only reported when the address also has no code left,
+    /// and confirmed results are cached without expiry.
+    pub async fn detect_self_destruct_history(
+        &self,
+        address: &str,
+    ) -> Result<Option<SelfDestructInfo>> {
+        let key = address.to_lowercase();
+        if let Some(info) = self.cache.get_self_destruct(&key) {
+            return Ok(Some(info));
+        }
+
+        let internal_txs = self.get_internal_transactions(address).await?;
+        let Some(entry) = internal_txs.iter().find(|tx| {
+            let tx_type = tx.tx_type.to_lowercase();
+            (tx_type == "selfdestruct" || tx_type == "suicide")
+                && tx.from.eq_ignore_ascii_case(address)
+        }) else {
+            return Ok(None);
+        };
+
+        let addr = Address::from_str(address)
+            .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
+        let code = self
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_code(addr, None)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
+        if !code.is_empty() {
+            return Ok(None);
+        }
+
+        let info = SelfDestructInfo {
+            tx_hash: entry.parent_tx_hash.clone(),
+            block_number: entry.block,
+            refund_to: entry.to.clone(),
+        };
+        self.cache.store_self_destruct(key, info.clone());
+        Ok(Some(info))
+    }
+
+    /// Get token balances for an address
+    pub async fn get_token_balances(&self, address: &str) -> Result<Vec<EtherscanTokenBalance>> {
+        // Check cache first
+        if let Some(cached_balances) = self.cache.get_token_balances(address) {
+            tracing::debug!(target: "warpscan", "Cache hit for token balances: {}", address);
+            return Ok(cached_balances);
+        }
+
+        if let Some(ref client) = self.etherscan().await {
             match client.get_token_balances(address).await {
                 Ok(tokens) => {
                     // Store in cache for future use
@@ -775,6 +1802,29 @@ impl BlockchainService {
         }
     }
 
+    /// Get the beacon chain withdrawals credited to an address, newest first
+    ///
+    /// Withdrawals are not transactions, so `txlist` misses them; they come
+    /// from Etherscan's `txsBeaconWithdrawal`. Without an Etherscan key the
+    /// list is empty, as local nodes have no beacon chain.
+    pub async fn get_staking_withdrawals(&self, address: &str) -> Result<Vec<BeaconWithdrawal>> {
+        match self.etherscan().await {
+            Some(ref client) => client.get_beacon_withdrawals(address).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Get the ERC-20 allowances an address still grants, newest first
+    ///
+    /// Approvals are found through Etherscan's log index, so the list is
+    /// empty without an Etherscan key.
+    pub async fn get_token_approvals(&self, address: &str) -> Result<Vec<TokenApproval>> {
+        match self.etherscan().await {
+            Some(ref client) => client.get_token_approvals(address).await,
+            None => Ok(vec![]),
+        }
+    }
+
     /// Get token balances with mode selection
     pub async fn get_token_balances_with_mode(
         &self,
@@ -791,21 +1841,55 @@ impl BlockchainService {
 
     /// Get current gas prices
     pub async fn get_gas_prices(&self) -> Result<GasPrices> {
-        let gas_price = self
-            .provider
-            .get_gas_price()
-            .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))?;
+        let (gas_price, latest, oracle_fee) = tokio::join!(
+            self.with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_gas_price()
+                    .await
+                    .map_err(Error::provider)
+            }),
+            self.with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block(BlockNumber::Latest)
+                    .await
+                    .map_err(Error::provider)
+            }),
+            self.get_priority_fee_from_oracle()
+        );
+        let gas_price = gas_price?;
+        let latest = latest?;
 
         // Simple gas price estimation (in a real implementation, you might use a gas oracle)
         let slow = gas_price * 80 / 100; // 80% of current
         let standard = gas_price;
         let fast = gas_price * 120 / 100; // 120% of current
 
+        let (base_fee, next_base_fee) = latest
+            .and_then(|block| {
+                let base_fee = block.base_fee_per_gas?;
+                Some((
+                    base_fee,
+                    next_base_fee(base_fee, block.gas_used, block.gas_limit),
+                ))
+            })
+            .unwrap_or_default();
+        // Without the oracle, the tip is whatever the legacy price pays above the base fee
+        let priority_fee = match oracle_fee {
+            Ok(Some(fee)) => U256::from(fee),
+            _ => gas_price.saturating_sub(base_fee),
+        };
+
         Ok(GasPrices {
             slow,
             standard,
             fast,
+            base_fee,
+            max_priority_fee_slow: priority_fee * 80 / 100,
+            max_priority_fee_standard: priority_fee,
+            max_priority_fee_fast: priority_fee * 120 / 100,
+            next_base_fee,
             timestamp: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
@@ -813,22 +1897,255 @@ impl BlockchainService {
         })
     }
 
+    /// Get the base fees and priority fee percentiles of recent blocks
+    ///
+    /// `newest_block` is a block tag such as `"latest"` or a block number.
+    pub async fn get_fee_history(
+        &self,
+        block_count: u64,
+        newest_block: &str,
+        reward_percentiles: &[f64],
+    ) -> Result<FeeHistory> {
+        let newest_block: BlockNumber = newest_block
+            .parse()
+            .map_err(|e| Error::validation(format!("Invalid block tag: {}", e)))?;
+        self.with_retry(|| async {
+            self.rpc()
+                .await
+                .fee_history(block_count, newest_block, reward_percentiles)
+                .await
+                .map_err(Error::provider)
+        })
+        .await
+    }
+
+    /// Priority fee in wei suggested by the node's `eth_maxPriorityFeePerGas`
+    ///
+    /// Returns `None` if the node does not implement the method.
+    pub async fn get_priority_fee_from_oracle(&self) -> Result<Option<u64>> {
+        match self
+            .rpc()
+            .await
+            .request::<(), U256>("eth_maxPriorityFeePerGas", ())
+            .await
+        {
+            Ok(fee) => Ok(Some(fee.low_u64())),
+            Err(e)
+                if e.as_error_response()
+                    .is_some_and(|r| r.code == METHOD_NOT_FOUND_CODE) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(Error::blockchain(format!("{}", e))),
+        }
+    }
+
+    /// Suggest EIP-1559 fees from the last 10 blocks
+    ///
+    /// The next block's base fee comes straight from the fee history. When the
+    /// node suggests a priority fee it becomes the standard tip, with slow and
+    /// fast at 80% and 120% of it; otherwise the slow/standard/fast tips are
+    /// the 10th/50th/90th reward percentiles averaged over those blocks. Max
+    /// fees leave room for the base fee to double before the transaction is
+    /// included.
+    pub async fn estimate_eip1559_fees(&self) -> Result<Eip1559FeeEstimate> {
+        let (history, oracle_fee) = tokio::join!(
+            self.get_fee_history(10, "latest", &[10.0, 50.0, 90.0]),
+            self.get_priority_fee_from_oracle()
+        );
+        let history = history?;
+        // The fee history still works without the oracle, so its errors are not fatal
+        let oracle_fee = oracle_fee.unwrap_or_else(|e| {
+            tracing::debug!(target: "warpscan", "Priority fee oracle failed: {}", e);
+            None
+        });
+
+        let base_fee_next = history
+            .base_fee_per_gas
+            .last()
+            .copied()
+            .ok_or_else(|| Error::blockchain("Fee history returned no base fees"))?;
+
+        let average_reward = |percentile: usize| {
+            let rewards: Vec<U256> = history
+                .reward
+                .iter()
+                .filter_map(|block| block.get(percentile).copied())
+                .collect();
+            if rewards.is_empty() {
+                U256::zero()
+            } else {
+                rewards.iter().fold(U256::zero(), |sum, r| sum + r) / rewards.len()
+            }
+        };
+        let tier = |priority_fee: U256, estimated_wait_blocks: u8| Eip1559Tier {
+            max_priority_fee_gwei: wei_to_gwei_ceil(priority_fee),
+            max_fee_gwei: wei_to_gwei_ceil(base_fee_next * 2 + priority_fee),
+            estimated_wait_blocks,
+        };
+
+        let (slow, standard, fast, priority_fee_source) = match oracle_fee {
+            Some(fee) => {
+                let fee = U256::from(fee);
+                (
+                    fee * 80 / 100,
+                    fee,
+                    fee * 120 / 100,
+                    PriorityFeeSource::Oracle,
+                )
+            }
+            None => (
+                average_reward(0),
+                average_reward(1),
+                average_reward(2),
+                PriorityFeeSource::FeeHistory,
+            ),
+        };
+
+        Ok(Eip1559FeeEstimate {
+            base_fee_gwei: wei_to_gwei_ceil(base_fee_next),
+            slow: tier(slow, 6),
+            standard: tier(standard, 3),
+            fast: tier(fast, 1),
+            priority_fee_source,
+        })
+    }
+
+    /// Base fee, gas used ratio and median priority fee of the last `block_count` blocks
+    pub async fn get_gas_history(&self, block_count: u64) -> Result<Vec<BlockGasMetric>> {
+        let history = self.get_fee_history(block_count, "latest", &[50.0]).await?;
+        let oldest_block = history.oldest_block.as_u64();
+
+        Ok(history
+            .gas_used_ratio
+            .iter()
+            .enumerate()
+            .map(|(i, ratio)| BlockGasMetric {
+                block_number: oldest_block + i as u64,
+                base_fee_gwei: history
+                    .base_fee_per_gas
+                    .get(i)
+                    .map_or(0, |fee| wei_to_gwei_round(*fee)),
+                gas_used_ratio: *ratio,
+                avg_priority_fee_gwei: history
+                    .reward
+                    .get(i)
+                    .and_then(|rewards| rewards.first())
+                    .map_or(0, |fee| wei_to_gwei_round(*fee)),
+            })
+            .collect())
+    }
+
+    /// Total ETH burned by EIP-1559 base fees in blocks `from_block..=to_block`
+    ///
+    /// Fee history reports gas used only as a share of the gas limit, so the
+    /// limit of `to_block` is applied to the whole range; it can move by at
+    /// most 1/1024 per block.
+    pub async fn get_total_eth_burned_since(&self, from_block: u64, to_block: u64) -> Result<f64> {
+        if from_block > to_block {
+            return Err(Error::validation(format!(
+                "Invalid block range: {} is after {}",
+                from_block, to_block
+            )));
+        }
+        let block_count = to_block - from_block + 1;
+        let max_range = self.config().gas.max_range_blocks;
+        if block_count > max_range {
+            return Err(Error::validation(format!(
+                "Block range of {} blocks exceeds the limit of {}",
+                block_count, max_range
+            )));
+        }
+
+        if let Some(burned) = self.cache.get_burned_eth(from_block, to_block) {
+            return Ok(burned);
+        }
+
+        let newest_tag = format!("{:#x}", to_block);
+        let (history, newest) = tokio::join!(
+            self.get_fee_history(block_count, &newest_tag, &[]),
+            self.with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_block(to_block)
+                    .await
+                    .map_err(Error::provider)
+            }),
+        );
+        let history = history?;
+        let gas_limit = newest?
+            .ok_or_else(|| Error::blockchain(format!("Block {} not found", to_block)))?
+            .gas_limit
+            .as_u64() as f64;
+
+        // base_fee_per_gas has one extra entry for the next block; zip drops it
+        let burned_wei: f64 = history
+            .base_fee_per_gas
+            .iter()
+            .zip(&history.gas_used_ratio)
+            .map(|(base_fee, ratio)| base_fee.as_u128() as f64 * ratio * gas_limit)
+            .sum();
+        let burned = burned_wei / 1_000_000_000_000_000_000.0;
+
+        self.cache.store_burned_eth(from_block, to_block, burned);
+        Ok(burned)
+    }
+
+    /// Last ETH price, from Etherscan (None without an API key)
+    pub async fn get_eth_price(&self) -> Result<Option<EthPrice>> {
+        if let Some(price) = self.cache.get_eth_price() {
+            return Ok(Some(price));
+        }
+
+        let Some(client) = self.etherscan().await else {
+            return Ok(None);
+        };
+
+        let price = client.get_eth_price().await?;
+        self.cache.store_eth_price(price.clone());
+        Ok(Some(price))
+    }
+
+    /// Last ETH price in USD, from Etherscan (None without an API key)
+    pub async fn get_eth_price_usd(&self) -> Result<Option<f64>> {
+        Ok(self.get_eth_price().await?.map(|price| price.usd))
+    }
+
+    /// Current ETH supply breakdown, from Etherscan (None without an API key)
+    pub async fn get_eth_supply(&self) -> Result<Option<EthSupply>> {
+        match self.etherscan().await {
+            Some(ref client) => client.get_eth_supply().await.map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// Get current block number
     pub async fn get_block_number(&self) -> Result<u64> {
-        self.provider
-            .get_block_number()
-            .await
-            .map(|n| n.as_u64())
-            .map_err(|e| Error::blockchain(format!("{}", e)))
+        self.with_retry(|| async {
+            self.rpc()
+                .await
+                .get_block_number()
+                .await
+                .map(|n| {
+                    crate::metrics::global().set_current_block(n.as_u64());
+                    n.as_u64()
+                })
+                .map_err(Error::provider)
+        })
+        .await
     }
 
     /// Get chain ID
     pub async fn get_chain_id(&self) -> Result<u64> {
-        self.provider
-            .get_chainid()
-            .await
-            .map(|n| n.as_u64())
-            .map_err(|e| Error::blockchain(format!("{}", e)))
+        self.with_retry(|| async {
+            self.rpc()
+                .await
+                .get_chainid()
+                .await
+                .map(|n| n.as_u64())
+                .map_err(Error::provider)
+        })
+        .await
     }
 
     /// Estimate gas for a transaction
@@ -857,21 +2174,357 @@ impl BlockchainService {
         }
 
         let typed_tx = TypedTransaction::Legacy(tx);
-        self.provider
-            .estimate_gas(&typed_tx, None)
+        self.with_retry(|| async {
+            self.rpc()
+                .await
+                .estimate_gas(&typed_tx, None)
+                .await
+                .map_err(Error::provider)
+        })
+        .await
+    }
+
+    /// Simulate a transaction without sending it, and price its gas
+    ///
+    /// Runs against the given block height, or the latest block when `None`.
+    /// Latest-block simulations go through the `[simulation]` API when one is
+    /// configured, falling back to `eth_call` if it fails. Reverts are
+    /// reported in the result rather than as an error.
+    pub async fn simulate_transaction(
+        &self,
+        from: &str,
+        to: &str,
+        data: &str,
+        value: U256,
+        block: Option<u64>,
+    ) -> Result<SimulationResult> {
+        let simulation = self.config().simulation.clone();
+        let hosted = match simulation {
+            Some(provider) if block.is_none() => {
+                let data = format!("0x{}", data.trim_start_matches("0x"));
+                HostedSimulator::new(provider)
+                    .simulate(self.chain_id(), from, to, &data, value)
+                    .await
+                    .inspect_err(|e| {
+                        tracing::warn!(target: "warpscan", "Hosted simulation failed, using eth_call: {}", e)
+                    })
+                    .ok()
+            }
+            _ => None,
+        };
+        let mut result = match hosted {
+            Some(result) => result,
+            None => self.call_transaction(from, to, data, value, block).await?,
+        };
+
+        if result.gas_used > 0 {
+            if let Ok(gas_price) = self.rpc().await.get_gas_price().await {
+                result.gas_cost_eth = (gas_price.as_u128() * result.gas_used as u128) as f64 / 1e18;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Dry-run a transaction with `eth_call`
+    async fn call_transaction(
+        &self,
+        from: &str,
+        to: &str,
+        data: &str,
+        value: U256,
+        block: Option<u64>,
+    ) -> Result<SimulationResult> {
+        let from_addr = Address::from_str(from)
+            .map_err(|e| Error::validation(format!("Invalid from address: {}", e)))?;
+        let to_addr = Address::from_str(to)
+            .map_err(|e| Error::validation(format!("Invalid to address: {}", e)))?;
+        let data_bytes = hex::decode(data.trim_start_matches("0x"))
+            .map_err(|e| Error::validation(format!("Invalid data: {}", e)))?;
+
+        let tx = TransactionRequest::new()
+            .from(from_addr)
+            .to(to_addr)
+            .data(data_bytes)
+            .value(value);
+        let typed_tx = TypedTransaction::Legacy(tx);
+        let block_id = block.map(|n| BlockId::Number(BlockNumber::Number(n.into())));
+
+        match self.rpc().await.call(&typed_tx, block_id).await {
+            Ok(return_data) => {
+                // Gas estimation is best-effort; the call itself already succeeded
+                let gas_used = self
+                    .rpc()
+                    .await
+                    .estimate_gas(&typed_tx, block_id)
+                    .await
+                    .map(|g| g.as_u64())
+                    .unwrap_or(0);
+
+                Ok(SimulationResult {
+                    success: true,
+                    return_data: format!("0x{}", hex::encode(&return_data)),
+                    gas_used,
+                    gas_cost_eth: 0.0,
+                    revert_reason: None,
+                })
+            }
+            Err(e) => {
+                let revert_data = e.as_error_response().and_then(|r| r.as_revert_data());
+                match revert_data {
+                    Some(revert_data) => {
+                        tracing::debug!(target: "warpscan", "Simulation reverted: {}", e);
+                        Ok(SimulationResult {
+                            success: false,
+                            return_data: format!("0x{}", hex::encode(&revert_data)),
+                            gas_used: 0,
+                            gas_cost_eth: 0.0,
+                            revert_reason: Some(decode_revert_reason(&revert_data)),
+                        })
+                    }
+                    None => Err(Error::blockchain(format!("Simulation failed: {}", e))),
+                }
+            }
+        }
+    }
+
+    /// Get the floor price (in ETH) of an NFT collection
+    ///
+    /// Returns `None` when no OpenSea API key is configured or outside of mainnet.
+    pub async fn get_nft_floor_price(&self, contract_address: &str) -> Result<Option<f64>> {
+        if self.chain_id() != 1 {
+            return Ok(None);
+        }
+
+        let Some(client) = &self.opensea else {
+            tracing::debug!(target: "warpscan", "OpenSea API key not configured, skipping floor price");
+            return Ok(None);
+        };
+        if let Some(floor_price) = self.cache.get_nft_floor_price(contract_address) {
+            return Ok(floor_price);
+        }
+
+        let floor_price = client.get_floor_price(contract_address).await?;
+        self.cache
+            .store_nft_floor_price(contract_address, floor_price);
+        Ok(floor_price)
+    }
+
+    /// Price in USD of one whole ERC-20 token, from CoinGecko
+    ///
+    /// Prices are only known for mainnet tokens; elsewhere they are 0.
+    pub async fn get_token_price_usd(&self, contract_address: &str) -> Result<f64> {
+        if self.chain_id() != 1 {
+            return Ok(0.0);
+        }
+        if let Some(price) = self.cache.get_token_price(contract_address, "usd") {
+            return Ok(price);
+        }
+
+        let price = self.price.get_token_price(contract_address, "usd").await?;
+        self.cache.store_token_price(contract_address, "usd", price);
+        Ok(price)
+    }
+
+    /// Creation code of the Safe proxy factory's proxies, for predicting the
+    /// address of a new Safe
+    pub async fn get_safe_proxy_creation_code(&self) -> Result<Vec<u8>> {
+        let factory = Address::from_str(safe::SAFE_PROXY_FACTORY)
+            .map_err(|e| Error::validation(format!("Invalid factory address: {}", e)))?;
+        let tx = TypedTransaction::Legacy(
+            TransactionRequest::new()
+                .to(factory)
+                .data(safe::proxy_creation_code_call()),
+        );
+        let output = self
+            .rpc()
+            .await
+            .call(&tx, None)
             .await
-            .map_err(|e| Error::blockchain(format!("{}", e)))
+            .map_err(|e| Error::contract(format!("proxyCreationCode call failed: {}", e)))?;
+
+        match abi::decode(&[ParamType::Bytes], &output) {
+            Ok(tokens) => match tokens.into_iter().next() {
+                Some(Token::Bytes(code)) if !code.is_empty() => Ok(code),
+                _ => Err(Error::contract(
+                    "The Safe proxy factory is not deployed on this network",
+                )),
+            },
+            Err(e) => Err(Error::parse(format!(
+                "Invalid proxyCreationCode output: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Past logs matching the event monitor's filter
+    pub async fn get_logs(&self, filter: &EventFilter) -> Result<Vec<Log>> {
+        let (from, to) = filter.block_range;
+        if from > to {
+            return Err(Error::validation(format!(
+                "Block range start {} is after its end {}",
+                from, to
+            )));
+        }
+        if to - from >= MAX_LOG_RANGE_BLOCKS {
+            return Err(Error::validation(format!(
+                "Search at most {} blocks at once",
+                MAX_LOG_RANGE_BLOCKS
+            )));
+        }
+
+        let query = filter.to_filter(true);
+        self.with_retry(|| async {
+            self.rpc()
+                .await
+                .get_logs(&query)
+                .await
+                .map_err(Error::provider)
+        })
+        .await
+    }
+
+    /// `eth_call` a contract on the latest block and return the raw output
+    pub async fn call_contract(&self, to: &str, data: Vec<u8>) -> Result<Vec<u8>> {
+        let tx = contract_call_tx(to, data)?;
+        let output = self
+            .rpc()
+            .await
+            .call(&tx, None)
+            .await
+            .map_err(|e| Error::contract(format!("Call failed: {}", e)))?;
+        Ok(output.to_vec())
+    }
+
+    /// Estimate the gas of a contract call sent without a sender
+    pub async fn estimate_contract_gas(&self, to: &str, data: Vec<u8>) -> Result<U256> {
+        let tx = contract_call_tx(to, data)?;
+        self.rpc()
+            .await
+            .estimate_gas(&tx, None)
+            .await
+            .map_err(|e| Error::contract(format!("Gas estimation failed: {}", e)))
+    }
+
+    /// Resolve and fetch the metadata JSON behind an NFT's `tokenURI`
+    pub async fn get_nft_metadata(
+        &self,
+        contract_address: &str,
+        token_id: &str,
+    ) -> Result<NftMetadata> {
+        let key = format!("{}:{}", contract_address.to_lowercase(), token_id);
+        if let Some(metadata) = self.cache.get_nft_metadata(&key) {
+            return Ok(metadata);
+        }
+
+        let contract = Address::from_str(contract_address)
+            .map_err(|e| Error::validation(format!("Invalid contract address: {}", e)))?;
+        let id = U256::from_dec_str(token_id)
+            .map_err(|e| Error::validation(format!("Invalid token ID: {}", e)))?;
+
+        let mut data = TOKEN_URI_SELECTOR.to_vec();
+        data.extend(abi::encode(&[Token::Uint(id)]));
+        let tx = TypedTransaction::Legacy(TransactionRequest::new().to(contract).data(data));
+        let output = self
+            .rpc()
+            .await
+            .call(&tx, None)
+            .await
+            .map_err(|e| Error::contract(format!("tokenURI call failed: {}", e)))?;
+
+        let uri = match abi::decode(&[ParamType::String], &output) {
+            Ok(tokens) => match tokens.into_iter().next() {
+                Some(Token::String(uri)) => uri,
+                _ => return Err(Error::parse("tokenURI returned no string")),
+            },
+            Err(e) => return Err(Error::parse(format!("Invalid tokenURI output: {}", e))),
+        };
+
+        let gateway = self.config().ipfs_gateway.clone();
+        // On-chain collections embed the metadata in the URI itself
+        let json: serde_json::Value = match decode_json_data_uri(&uri) {
+            Some(json) => json?,
+            None => self
+                .http_client
+                .get(ipfs_to_gateway(&gateway, &uri))
+                .send()
+                .await
+                .map_err(|e| Error::network(format!("Metadata request failed: {}", e)))?
+                .json()
+                .await
+                .map_err(|e| Error::parse(format!("Invalid metadata JSON: {}", e)))?,
+        };
+
+        let text = |field: &str| json.get(field).and_then(|v| v.as_str()).map(str::to_string);
+        let attributes = json
+            .get("attributes")
+            .and_then(|v| v.as_array())
+            .map(|attrs| {
+                attrs
+                    .iter()
+                    .map(|attr| NftAttribute {
+                        trait_type: attr
+                            .get("trait_type")
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("Trait")
+                            .to_string(),
+                        value: match attr.get("value") {
+                            Some(serde_json::Value::String(s)) => s.clone(),
+                            Some(other) => other.to_string(),
+                            None => String::new(),
+                        },
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let metadata = NftMetadata {
+            name: text("name").unwrap_or_else(|| format!("#{}", token_id)),
+            description: text("description"),
+            image_url: text("image").map(|url| ipfs_to_gateway(&gateway, &url)),
+            attributes,
+            external_url: text("external_url"),
+        };
+
+        self.cache.store_nft_metadata(key, metadata.clone());
+        Ok(metadata)
+    }
+
+    /// Look up a consensus layer validator by depositor address, public key or index
+    ///
+    /// For an address the first validator it funded is returned. Only mainnet is
+    /// supported; other chains return `None`.
+    pub async fn get_validator_info(
+        &self,
+        address_or_pubkey: &str,
+    ) -> Result<Option<ValidatorInfo>> {
+        if self.chain_id() != 1 {
+            return Ok(None);
+        }
+
+        let is_address = address_or_pubkey.starts_with("0x") && address_or_pubkey.len() == 42;
+        if !is_address {
+            return self.beacon.get_validator(address_or_pubkey).await;
+        }
+
+        let indices = self
+            .beacon
+            .get_validator_indices_by_depositor(address_or_pubkey)
+            .await?;
+        match indices.first() {
+            Some(index) => self.beacon.get_validator(&index.to_string()).await,
+            None => Ok(None),
+        }
     }
 
     /// Get network name based on chain ID
     pub fn get_network_name(&self) -> String {
-        self.config.network.name.clone()
+        self.config().network.name.clone()
     }
 
     /// Resolve ENS name for an address (only works on mainnet)
     pub async fn resolve_ens_name(&self, address: &str) -> Result<Option<String>> {
         // Only resolve ENS on Ethereum mainnet (chain ID 1)
-        if self.config.network.chain_id != 1 {
+        if self.chain_id() != 1 {
             return Ok(None);
         }
 
@@ -885,7 +2538,7 @@ impl BlockchainService {
             .map_err(|e| Error::validation(format!("Invalid address: {}", e)))?;
 
         // Use ethers-rs ENS resolver
-        let ens_result = match self.provider.lookup_address(addr).await {
+        let ens_result = match self.rpc().await.lookup_address(addr).await {
             Ok(name) => Ok(Some(name)),
             Err(_) => {
                 // ENS resolution failed - address might not have an ENS name
@@ -904,6 +2557,60 @@ impl BlockchainService {
         ens_result
     }
 
+    /// Resolve an ENS name such as `vitalik.eth` to a checksummed address
+    /// (only works on mainnet)
+    pub async fn resolve_ens_address(&self, name: &str) -> Result<Option<String>> {
+        if self.chain_id() != 1 {
+            return Ok(None);
+        }
+
+        // ENS names are case-insensitive
+        let name = name.to_lowercase();
+        if let Some(cached) = self.cache.get_ens_address(&name) {
+            tracing::debug!(target: "warpscan", "Cache hit for ENS address: {}", name);
+            return Ok(cached);
+        }
+
+        // An unregistered name is not an error, there is just no address
+        let address = match self.rpc().await.resolve_name(&name).await {
+            Ok(address) => Some(ethers::utils::to_checksum(&address, None)),
+            Err(_) => None,
+        };
+        self.cache.store_ens_address(name, address.clone());
+        Ok(address)
+    }
+
+    /// Flashbots bundle that included a transaction
+    ///
+    /// Returns `None` for transactions that were not part of a bundle. Only
+    /// mainnet is covered by Flashbots, so callers check the network first.
+    pub async fn check_mev_bundle(&self, tx_hash: &str) -> Result<Option<MevInfo>> {
+        if let Some(bundle) = self.cache.get_mev_bundle(tx_hash) {
+            return Ok(bundle);
+        }
+
+        let response = self
+            .http_client
+            .get(format!("{}/{}", FLASHBOTS_TRANSACTIONS_URL, tx_hash))
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("Flashbots request failed: {}", e)))?;
+        let bundle = if response.status() == reqwest::StatusCode::NOT_FOUND {
+            None
+        } else {
+            let json: serde_json::Value = response
+                .error_for_status()
+                .map_err(|e| Error::network(format!("Flashbots request failed: {}", e)))?
+                .json()
+                .await
+                .map_err(|e| Error::parse(format!("Invalid Flashbots response: {}", e)))?;
+            parse_mev_bundle(&json, tx_hash)
+        };
+
+        self.cache.store_mev_bundle(tx_hash, bundle.clone());
+        Ok(bundle)
+    }
+
     /// Get transaction details - tries Etherscan first, falls back to RPC (for local nodes)
     pub async fn get_transaction_details(
         &self,
@@ -913,16 +2620,39 @@ impl BlockchainService {
     }
 
     /// Get transaction details with mode selection
+    ///
+    /// On mainnet the transaction is also looked up in Flashbots bundles; a
+    /// failed lookup leaves `mev_bundle` empty rather than failing the details.
     pub async fn get_transaction_details_with_mode(
         &self,
         tx_hash: &str,
         use_etherscan: bool,
     ) -> Result<crate::ui::models::TransactionDetails> {
-        use crate::ui::models::{TransactionDetails, TransactionStatus};
+        let mut details = self
+            .fetch_transaction_details(tx_hash, use_etherscan)
+            .await?;
+        if self.chain_id() == 1 {
+            match self.check_mev_bundle(tx_hash).await {
+                Ok(bundle) => details.mev_bundle = bundle,
+                Err(e) => {
+                    tracing::debug!(target: "warpscan", "MEV bundle check failed for {}: {}", tx_hash, e)
+                }
+            }
+        }
+        Ok(details)
+    }
+
+    /// Transaction details from Etherscan or, failing that, the node
+    async fn fetch_transaction_details(
+        &self,
+        tx_hash: &str,
+        use_etherscan: bool,
+    ) -> Result<crate::ui::models::TransactionDetails> {
+        use crate::ui::models::{AccessEntry, TransactionDetails, TransactionStatus};
 
         // Check if this is a local node - skip Etherscan for local nodes
         let is_local_node = self
-            .config
+            .config()
             .network
             .node_type
             .as_ref()
@@ -931,7 +2661,7 @@ impl BlockchainService {
 
         // Try Etherscan first if mode is Etherscan and not a local node
         if use_etherscan && !is_local_node {
-            if let Some(ref client) = self.etherscan {
+            if let Some(ref client) = self.etherscan().await {
                 match client.get_transaction_details(tx_hash).await {
                     Ok(etherscan_tx) => {
                         // Convert Etherscan format to UI model
@@ -944,13 +2674,13 @@ impl BlockchainService {
                             0
                         };
 
-                        // Try to decode method from input data (first 4 bytes = method selector)
-                        let method = if etherscan_tx.input_data.len() >= 10 {
-                            // Could decode method selector here, but for now return None
-                            None
-                        } else {
-                            None
-                        };
+                        // Decode the method from the first 4 bytes of the input
+                        let method = etherscan_tx
+                            .input_data
+                            .trim_start_matches("0x")
+                            .get(..8)
+                            .and_then(|selector| hex::decode(selector).ok())
+                            .and_then(|selector| MethodDecoder::decode_selector(&selector));
 
                         // Calculate transaction fee (gas_used * gas_price in wei, convert to ETH)
                         // gas_price is in gwei, so convert to wei first
@@ -989,6 +2719,15 @@ impl BlockchainService {
                             }
                         }
 
+                        // Etherscan's transaction has no logs; read them from the receipt
+                        let logs = match self.get_transaction_receipt_with_logs(tx_hash).await {
+                            Ok(receipt) => receipt.map(|r| r.decoded_logs).unwrap_or_default(),
+                            Err(e) => {
+                                tracing::warn!(target: "warpscan", "Failed to fetch logs for {}: {}", tx_hash, e);
+                                Vec::new()
+                            }
+                        };
+
                         return Ok(TransactionDetails {
                             hash: etherscan_tx.hash,
                             status: if etherscan_tx.is_error {
@@ -1012,6 +2751,17 @@ impl BlockchainService {
                             contract_address: etherscan_tx.contract_address,
                             confirmations,
                             transfers,
+                            logs,
+                            tx_type: etherscan_tx.tx_type,
+                            max_fee_per_gas: etherscan_tx.max_fee_per_gas,
+                            max_priority_fee_per_gas: etherscan_tx.max_priority_fee_per_gas,
+                            access_list: etherscan_tx
+                                .access_list
+                                .0
+                                .iter()
+                                .map(AccessEntry::from)
+                                .collect(),
+                            mev_bundle: None,
                         });
                     }
                     Err(err) => {
@@ -1031,18 +2781,26 @@ impl BlockchainService {
             .map_err(|e| Error::validation(format!("Invalid transaction hash: {}", e)))?;
 
         let tx = self
-            .provider
-            .get_transaction(hash)
-            .await
-            .map_err(|e| Error::blockchain(format!("Failed to get transaction: {}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_transaction(hash)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         let tx = tx.ok_or_else(|| Error::blockchain("Transaction not found".to_string()))?;
 
         let receipt = self
-            .provider
-            .get_transaction_receipt(hash)
-            .await
-            .map_err(|e| Error::blockchain(format!("Failed to get receipt: {}", e)))?;
+            .with_retry(|| async {
+                self.rpc()
+                    .await
+                    .get_transaction_receipt(hash)
+                    .await
+                    .map_err(Error::provider)
+            })
+            .await?;
 
         let block_number = tx.block_number.map(|n| n.as_u64()).unwrap_or(0);
         let current_block = self.get_block_number().await.unwrap_or(0);
@@ -1090,13 +2848,8 @@ impl BlockchainService {
             TransactionStatus::Pending
         };
 
-        // Try to decode method from input data
-        let method = if tx.input.len() >= 4 {
-            // Could decode method selector here
-            None
-        } else {
-            None
-        };
+        // Decode the method from the first 4 bytes of the input
+        let method = MethodDecoder::decode_selector(&tx.input);
 
         // Fetch transfers for this transaction
         let from_addr = format!("{:?}", tx.from);
@@ -1146,6 +2899,19 @@ impl BlockchainService {
                 .and_then(|r| r.contract_address.map(|a| format!("{:?}", a))),
             confirmations,
             transfers,
+            logs: receipt
+                .as_ref()
+                .map(|r| r.logs.iter().map(known_selectors::decode_log).collect())
+                .unwrap_or_default(),
+            tx_type: tx.transaction_type.map_or(0, |t| t.as_u64() as u8),
+            max_fee_per_gas: tx.max_fee_per_gas.map(wei_to_gwei_round),
+            max_priority_fee_per_gas: tx.max_priority_fee_per_gas.map(wei_to_gwei_round),
+            access_list: tx
+                .access_list
+                .as_ref()
+                .map(|list| list.0.iter().map(AccessEntry::from).collect())
+                .unwrap_or_default(),
+            mev_bundle: None,
         })
     }
 
@@ -1161,7 +2927,7 @@ impl BlockchainService {
         let mut transfers = Vec::new();
 
         // Get token transfers for this transaction
-        if let Some(ref client) = self.etherscan {
+        if let Some(ref client) = self.etherscan().await {
             // Get token transfers from both from and to addresses, filter by tx_hash
             let from_transfers = client.get_token_transfers(from).await.unwrap_or_default();
             let to_transfers = if let Some(to_addr) = to {
@@ -1187,37 +2953,583 @@ impl BlockchainService {
                     });
                 }
             }
+        }
 
-            // Get internal transactions
-            let from_internal = client
-                .get_internal_transactions(from)
-                .await
-                .unwrap_or_default();
-            let to_internal = if let Some(to_addr) = to {
-                client
-                    .get_internal_transactions(to_addr)
-                    .await
-                    .unwrap_or_default()
-            } else {
+        // Internal transactions are looked up by hash, which also catches
+        // calls between contracts other than the sender and recipient
+        let internal_txns = self
+            .get_internal_transactions_by_tx_hash(tx_hash)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(target: "warpscan", "Failed to fetch internal transactions for {}: {}", tx_hash, e);
                 Vec::new()
-            };
+            });
+        for internal in internal_txns {
+            transfers.push(TransactionTransfer {
+                transfer_type: TransferType::Internal,
+                from: internal.from,
+                to: internal.to,
+                value: internal.value,
+                token_symbol: None,
+                token_name: None,
+                token_address: None,
+            });
+        }
 
-            // Filter by parent transaction hash
-            for internal in from_internal.iter().chain(to_internal.iter()) {
-                if internal.parent_tx_hash.to_lowercase() == tx_hash.to_lowercase() {
-                    transfers.push(TransactionTransfer {
-                        transfer_type: TransferType::Internal,
-                        from: internal.from.clone(),
-                        to: internal.to.clone(),
-                        value: internal.value,
-                        token_symbol: None,
-                        token_name: None,
-                        token_address: None,
-                    });
+        Ok(transfers)
+    }
+}
+
+/// Decode revert data into a human readable reason
+///
+/// Handles `Error(string)` and `Panic(uint256)`; any other selector is reported
+/// as a custom error with its raw selector.
+fn decode_revert_reason(data: &[u8]) -> String {
+    const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+    const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+    if data.len() < 4 {
+        return "Execution reverted without a reason".to_string();
+    }
+
+    let (selector, payload) = data.split_at(4);
+    if selector == ERROR_STRING_SELECTOR {
+        if let Ok(tokens) = abi::decode(&[ParamType::String], payload) {
+            if let Some(Token::String(reason)) = tokens.into_iter().next() {
+                return reason;
+            }
+        }
+    } else if selector == PANIC_SELECTOR {
+        if let Ok(tokens) = abi::decode(&[ParamType::Uint(256)], payload) {
+            if let Some(Token::Uint(code)) = tokens.into_iter().next() {
+                return format!("Panic(0x{:02x})", code.low_u64());
+            }
+        }
+    }
+
+    format!("Custom error 0x{}", hex::encode(selector))
+}
+
+/// Rewrite an `ipfs://` URI to an HTTP gateway URL; other URIs are returned as-is
+fn ipfs_to_gateway(gateway: &str, uri: &str) -> String {
+    match uri.strip_prefix("ipfs://") {
+        Some(path) => format!(
+            "{}/{}",
+            gateway.trim_end_matches('/'),
+            path.trim_start_matches("ipfs/")
+        ),
+        None => uri.to_string(),
+    }
+}
+
+/// Decode a `data:application/json` URI, base64 or percent-encoded
+///
+/// Returns `None` for any other URI so it can be fetched over HTTP.
+fn decode_json_data_uri(uri: &str) -> Option<Result<serde_json::Value>> {
+    use base64::Engine;
+
+    let rest = uri.strip_prefix("data:application/json")?;
+    let (params, payload) = rest.split_once(',')?;
+    let bytes = if params.split(';').any(|p| p.eq_ignore_ascii_case("base64")) {
+        match base64::engine::general_purpose::STANDARD.decode(payload.trim()) {
+            Ok(bytes) => bytes,
+            Err(e) => return Some(Err(Error::parse(format!("Invalid base64 metadata: {}", e)))),
+        }
+    } else {
+        percent_decode(payload)
+    };
+    Some(
+        serde_json::from_slice(&bytes)
+            .map_err(|e| Error::parse(format!("Invalid metadata JSON: {}", e))),
+    )
+}
+
+/// Undo `%XX` escapes, leaving malformed escapes as-is
+fn percent_decode(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| s.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Run `op`, retrying network and timeout errors up to `attempts` times
+///
+/// The wait before retry `n` is `base_delay * 2^(n-1)`, capped at 30 seconds.
+pub async fn retry_with_backoff<T, F, Fut>(
+    attempts: u32,
+    base_delay: Duration,
+    mut op: F,
+) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut retries = 0;
+    loop {
+        match op().await {
+            Err(e) if retries < attempts && e.is_retryable() => {
+                let delay = base_delay
+                    .saturating_mul(2u32.saturating_pow(retries))
+                    .min(RETRY_MAX_DELAY);
+                retries += 1;
+                tracing::warn!(
+                    target: "warpscan",
+                    "Request failed ({}), retry {}/{} in {:?}",
+                    e,
+                    retries,
+                    attempts,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Transaction calling `to` with `data`, for `eth_call` and gas estimation
+fn contract_call_tx(to: &str, data: Vec<u8>) -> Result<TypedTransaction> {
+    let to = Address::from_str(to)
+        .map_err(|e| Error::validation(format!("Invalid contract address: {}", e)))?;
+    Ok(TypedTransaction::Legacy(
+        TransactionRequest::new().to(to).data(data),
+    ))
+}
+
+/// Whether `url` is a `ws://` or `wss://` endpoint
+fn is_ws_url(url: &str) -> bool {
+    url.starts_with("ws://") || url.starts_with("wss://")
+}
+
+/// WebSocket endpoint for subscriptions: `ws_rpc_url` if set, else the RPC
+/// URL when it is itself a WebSocket URL
+fn ws_rpc_url(config: &Config) -> Option<String> {
+    config
+        .ws_rpc_url
+        .clone()
+        .or_else(|| Some(config.network.rpc_url.clone()))
+        .filter(|url| is_ws_url(url))
+}
+
+/// HTTP endpoint for request/response calls; a WebSocket RPC URL is mapped to
+/// the same host over `http://` or `https://`
+fn http_rpc_url(rpc_url: &str) -> String {
+    if let Some(rest) = rpc_url.strip_prefix("wss://") {
+        format!("https://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("ws://") {
+        format!("http://{}", rest)
+    } else {
+        rpc_url.to_string()
+    }
+}
+
+/// Base fee of the block after one with `base_fee` that used `gas_used` of
+/// `gas_limit`, per the EIP-1559 adjustment rule
+///
+/// The base fee moves by up to 1/8 towards keeping blocks half full; a block
+/// above target always raises it by at least 1 wei.
+pub(crate) fn next_base_fee(base_fee: U256, gas_used: U256, gas_limit: U256) -> U256 {
+    const ELASTICITY_MULTIPLIER: u64 = 2;
+    const BASE_FEE_MAX_CHANGE_DENOMINATOR: u64 = 8;
+
+    let target = gas_limit / ELASTICITY_MULTIPLIER;
+    if target.is_zero() || gas_used == target {
+        return base_fee;
+    }
+    if gas_used > target {
+        let delta = base_fee * (gas_used - target) / target / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+        base_fee + delta.max(U256::one())
+    } else {
+        let delta = base_fee * (target - gas_used) / target / BASE_FEE_MAX_CHANGE_DENOMINATOR;
+        base_fee.saturating_sub(delta)
+    }
+}
+
+/// Bundle of `tx_hash` in a Flashbots response
+///
+/// Accepts the `{"transactions": [...]}` listing as well as a bare
+/// transaction object. Wei amounts come as decimal strings.
+fn parse_mev_bundle(json: &serde_json::Value, tx_hash: &str) -> Option<MevInfo> {
+    let entry = match json.get("transactions").and_then(|txs| txs.as_array()) {
+        Some(txs) => txs.iter().find(|tx| {
+            tx.get("transaction_hash")
+                .and_then(|hash| hash.as_str())
+                .is_some_and(|hash| hash.eq_ignore_ascii_case(tx_hash))
+        })?,
+        None => json,
+    };
+
+    let number = |field: &str| match entry.get(field)? {
+        serde_json::Value::Number(n) => n.as_u64(),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+    let eth = |field: &str| {
+        entry
+            .get(field)
+            .and_then(|wei| match wei {
+                serde_json::Value::String(s) => s.parse::<f64>().ok(),
+                other => other.as_f64(),
+            })
+            .map_or(0.0, |wei| wei / 1e18)
+    };
+
+    let block_number = number("block_number")?;
+    let bundle_id = match entry.get("bundle_id").and_then(|id| id.as_str()) {
+        Some(id) => id.to_string(),
+        None => format!("{}-{}", block_number, number("bundle_index").unwrap_or(0)),
+    };
+    Some(MevInfo {
+        bundle_id,
+        block_number,
+        coinbase_transfer: eth("coinbase_transfer"),
+        total_miner_reward: eth("total_miner_reward"),
+    })
+}
+
+/// Convert wei to whole gwei, rounding up so suggested fees are never too low
+fn wei_to_gwei_ceil(wei: U256) -> u64 {
+    let gwei = U256::exp10(9);
+    ((wei + gwei - 1) / gwei).low_u64()
+}
+
+/// Convert wei to the nearest whole gwei
+pub(crate) fn wei_to_gwei_round(wei: U256) -> u64 {
+    let gwei = U256::exp10(9);
+    ((wei + gwei / 2) / gwei).low_u64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mev_bundle() {
+        let hash = "0xabc";
+        let json = serde_json::json!({
+            "transactions": [
+                {"transaction_hash": "0xdef", "block_number": 1, "bundle_index": 0},
+                {
+                    "transaction_hash": "0xABC",
+                    "block_number": 17000000,
+                    "bundle_index": 2,
+                    "coinbase_transfer": "50000000000000000",
+                    "total_miner_reward": "62000000000000000"
+                }
+            ]
+        });
+        let bundle = parse_mev_bundle(&json, hash).unwrap();
+        assert_eq!(bundle.bundle_id, "17000000-2");
+        assert_eq!(bundle.block_number, 17_000_000);
+        assert!((bundle.coinbase_transfer - 0.05).abs() < 1e-12);
+        assert!((bundle.total_miner_reward - 0.062).abs() < 1e-12);
+
+        let empty = serde_json::json!({ "transactions": [] });
+        assert!(parse_mev_bundle(&empty, hash).is_none());
+    }
+
+    #[test]
+    fn test_next_base_fee() {
+        let base_fee = U256::from(10_000_000_000u64); // 10 gwei
+        let gas_limit = U256::from(30_000_000u64);
+
+        // Full block: +12.5%, empty block: -12.5%, at target: unchanged
+        assert_eq!(
+            next_base_fee(base_fee, gas_limit, gas_limit),
+            U256::from(11_250_000_000u64)
+        );
+        assert_eq!(
+            next_base_fee(base_fee, U256::zero(), gas_limit),
+            U256::from(8_750_000_000u64)
+        );
+        assert_eq!(next_base_fee(base_fee, gas_limit / 2, gas_limit), base_fee);
+
+        // 75% utilization raises it by half the maximum step
+        assert_eq!(
+            next_base_fee(base_fee, U256::from(22_500_000u64), gas_limit),
+            U256::from(10_625_000_000u64)
+        );
+
+        // A tiny base fee still rises by at least 1 wei when above target
+        assert_eq!(
+            next_base_fee(U256::from(7u64), U256::from(15_000_001u64), gas_limit),
+            U256::from(8u64)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_switch_to_local_node_during_in_flight_call() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        // Mock Anvil node answering eth_chainId and eth_blockNumber; anything
+        // else, such as the WebSocket upgrade, gets a 400
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let node_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                let header_end = loop {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                    if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                        break pos + 4;
+                    }
+                };
+                let headers = String::from_utf8_lossy(&request[..header_end]).to_lowercase();
+                let content_length: usize = headers
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length:"))
+                    .map_or(0, |value| value.trim().parse().unwrap());
+                while request.len() < header_end + content_length {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    request.extend_from_slice(&buf[..n]);
+                }
+
+                let call: serde_json::Value =
+                    serde_json::from_slice(&request[header_end..]).unwrap_or_default();
+                let result = match call["method"].as_str() {
+                    Some("eth_chainId") => "0x7a69",
+                    Some("eth_blockNumber") => "0x2a",
+                    _ => {
+                        let _ = socket
+                            .write_all(b"HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                            .await;
+                        continue;
+                    }
+                };
+                let body = format!(
+                    r#"{{"jsonrpc":"2.0","id":{},"result":"{}"}}"#,
+                    call["id"], result
+                );
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+
+        let mut config = Config::default();
+        // Nothing listens on port 1, so calls before the switch fail fast
+        config.network.rpc_url = "http://127.0.0.1:1".to_string();
+        config.network.timeout_seconds = 2;
+        config.network.max_retries = 0;
+        config.cache.enabled = false;
+        config.etherscan_api_key = None;
+        let cache = Arc::new(CacheManager::new(config.clone()).unwrap());
+        let service = BlockchainService::new(config, cache).await.unwrap();
+        let handle = service.background_handle();
+
+        let (switched, _in_flight) = tokio::time::timeout(Duration::from_secs(10), async {
+            tokio::join!(
+                service.switch_to_node_at(&node_url),
+                handle.get_block_number()
+            )
+        })
+        .await
+        .expect("the in-flight call and the switch should both finish");
+        switched.unwrap();
+
+        // The background handle follows the switch
+        assert_eq!(handle.chain_id(), 31337);
+        assert_eq!(handle.get_block_number().await.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn test_batch_get_blocks_sends_json_array() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        // Record the request body and answer both calls out of order
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            let header_end = loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break pos + 4;
                 }
+            };
+            let headers = String::from_utf8_lossy(&request[..header_end]).to_lowercase();
+            let content_length: usize = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|value| value.trim().parse().unwrap())
+                .unwrap();
+            while request.len() < header_end + content_length {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
             }
+
+            let body = r#"[{"jsonrpc":"2.0","id":1,"result":null},{"jsonrpc":"2.0","id":0,"result":null}]"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            request[header_end..].to_vec()
+        });
+
+        let mut config = Config::default();
+        config.network.rpc_url = url;
+        config.cache.enabled = false;
+        config.etherscan_api_key = None;
+        let cache = Arc::new(CacheManager::new(config.clone()).unwrap());
+        let service = BlockchainService::new(config, cache).await.unwrap();
+
+        let blocks = service.batch_get_blocks(&[2, 1]).await.unwrap();
+        assert_eq!(blocks, vec![None, None]);
+
+        let body: serde_json::Value = serde_json::from_slice(&server.await.unwrap()).unwrap();
+        let calls = body.as_array().expect("batch body should be a JSON array");
+        assert_eq!(calls.len(), 2);
+        for (call, number) in calls.iter().zip(["0x2", "0x1"]) {
+            assert_eq!(call["method"], "eth_getBlockByNumber");
+            assert_eq!(call["params"][0], number);
         }
+    }
 
-        Ok(transfers)
+    #[tokio::test]
+    async fn test_retry_with_backoff_delays() {
+        tokio::time::pause();
+        let start = tokio::time::Instant::now();
+        let mut attempts = Vec::new();
+
+        let result: Result<()> = retry_with_backoff(3, Duration::from_millis(500), || {
+            attempts.push(start.elapsed());
+            async { Err(Error::network("connection refused")) }
+        })
+        .await;
+
+        assert!(matches!(result, Err(Error::Network(_))));
+        // Three retries, waiting 500ms, 1s and 2s; the timer rounds each
+        // sleep up to the next millisecond
+        let gaps: Vec<u128> = attempts
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).as_millis())
+            .collect();
+        assert_eq!(gaps.len(), 3);
+        for (gap, expected) in gaps.into_iter().zip([500, 1000, 2000]) {
+            assert!(
+                gap.abs_diff(expected) <= 1,
+                "waited {}ms, expected {}ms",
+                gap,
+                expected
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_with_backoff_skips_rpc_error_responses() {
+        use ethers::providers::{HttpClientError, JsonRpcError, ProviderError};
+
+        tokio::time::pause();
+        let reverted = || {
+            ProviderError::JsonRpcClientError(Box::new(HttpClientError::JsonRpcError(
+                JsonRpcError {
+                    code: 3,
+                    message: "execution reverted".to_string(),
+                    data: None,
+                },
+            )))
+        };
+        assert!(matches!(Error::provider(reverted()), Error::Blockchain(_)));
+
+        let mut attempts = 0;
+        let result: Result<()> = retry_with_backoff(3, Duration::from_millis(500), || {
+            attempts += 1;
+            async { Err(Error::provider(reverted())) }
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_decode_revert_reason() {
+        let mut error_string = vec![0x08, 0xc3, 0x79, 0xa0];
+        error_string.extend(abi::encode(&[Token::String("Not owner".to_string())]));
+        assert_eq!(decode_revert_reason(&error_string), "Not owner");
+
+        let mut panic = vec![0x4e, 0x48, 0x7b, 0x71];
+        panic.extend(abi::encode(&[Token::Uint(U256::from(0x11))]));
+        assert_eq!(decode_revert_reason(&panic), "Panic(0x11)");
+
+        let custom = [0xde, 0xad, 0xbe, 0xef, 0x00];
+        assert_eq!(decode_revert_reason(&custom), "Custom error 0xdeadbeef");
+
+        assert_eq!(
+            decode_revert_reason(&[]),
+            "Execution reverted without a reason"
+        );
+    }
+    #[test]
+    fn test_decode_json_data_uri() {
+        use base64::Engine;
+
+        let json = r#"{"name":"Noun 1","image":"data:image/svg+xml;base64,PHN2Zy8+"}"#;
+        let uri = format!(
+            "data:application/json;base64,{}",
+            base64::engine::general_purpose::STANDARD.encode(json)
+        );
+        let decoded = decode_json_data_uri(&uri).unwrap().unwrap();
+        assert_eq!(decoded["name"], "Noun 1");
+
+        let plain = decode_json_data_uri("data:application/json,%7B%22name%22%3A%22Loot%22%7D");
+        assert_eq!(plain.unwrap().unwrap()["name"], "Loot");
+
+        assert!(decode_json_data_uri("ipfs://QmHash/1").is_none());
+        assert!(decode_json_data_uri("data:application/json;base64,!!!")
+            .unwrap()
+            .is_err());
+    }
+
+    #[test]
+    fn test_rpc_url_schemes() {
+        let mut config = Config::default();
+        config.network.rpc_url = "https://eth.llamarpc.com".to_string();
+        assert_eq!(ws_rpc_url(&config), None);
+        assert_eq!(
+            http_rpc_url(&config.network.rpc_url),
+            "https://eth.llamarpc.com"
+        );
+
+        config.network.rpc_url = "wss://eth.llamarpc.com".to_string();
+        assert_eq!(
+            ws_rpc_url(&config).as_deref(),
+            Some("wss://eth.llamarpc.com")
+        );
+        assert_eq!(
+            http_rpc_url(&config.network.rpc_url),
+            "https://eth.llamarpc.com"
+        );
+
+        config.network.rpc_url = "http://127.0.0.1:8545".to_string();
+        config.ws_rpc_url = Some("ws://127.0.0.1:8546".to_string());
+        assert_eq!(ws_rpc_url(&config).as_deref(), Some("ws://127.0.0.1:8546"));
     }
 }
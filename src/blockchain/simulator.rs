@@ -0,0 +1,189 @@
+//! Hosted transaction simulation through Tenderly or Alchemy
+
+use super::types::SimulationResult;
+use crate::config::SimulationProvider;
+use crate::error::{Error, Result};
+use ethers::types::U256;
+use reqwest::Client;
+use serde_json::{json, Value};
+
+/// Gas limit given to Tenderly simulations, enough for any single transaction
+const TENDERLY_GAS_LIMIT: u64 = 30_000_000;
+
+/// Client for the simulation API configured in `[simulation]`
+#[derive(Clone)]
+pub struct HostedSimulator {
+    provider: SimulationProvider,
+    client: Client,
+}
+
+impl HostedSimulator {
+    /// Create a new client for `provider`
+    pub fn new(provider: SimulationProvider) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(20))
+            .build()
+            .expect("Failed to build HTTP client");
+        Self { provider, client }
+    }
+
+    /// Simulate a transaction on the latest block
+    ///
+    /// Reverts are reported in the result; `return_data` is `0x` when the
+    /// provider does not report it.
+    pub async fn simulate(
+        &self,
+        chain_id: u64,
+        from: &str,
+        to: &str,
+        data: &str,
+        value: U256,
+    ) -> Result<SimulationResult> {
+        match self.provider {
+            SimulationProvider::Tenderly {
+                ref account,
+                ref project,
+                ref access_key,
+            } => {
+                let url = format!(
+                    "https://api.tenderly.co/api/v1/account/{}/project/{}/simulate",
+                    account, project
+                );
+                let body = json!({
+                    "network_id": chain_id.to_string(),
+                    "from": from,
+                    "to": to,
+                    "input": data,
+                    "value": value.to_string(),
+                    "gas": TENDERLY_GAS_LIMIT,
+                    "save": false,
+                    "simulation_type": "quick",
+                });
+                let request = self
+                    .client
+                    .post(&url)
+                    .header("X-Access-Key", access_key)
+                    .json(&body);
+                let response = self.send("Tenderly", request).await?;
+                parse_tenderly(&response)
+            }
+            SimulationProvider::Alchemy { ref url } => {
+                let body = json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "alchemy_simulateAssetChanges",
+                    "params": [{
+                        "from": from,
+                        "to": to,
+                        "value": format!("{:#x}", value),
+                        "data": data,
+                    }],
+                });
+                let response = self
+                    .send("Alchemy", self.client.post(url).json(&body))
+                    .await?;
+                parse_alchemy(&response)
+            }
+        }
+    }
+
+    /// Send a request and parse the JSON body
+    async fn send(&self, name: &str, request: reqwest::RequestBuilder) -> Result<Value> {
+        let resp = request
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("{} request failed: {}", name, e)))?;
+        if !resp.status().is_success() {
+            return Err(Error::network(format!(
+                "{} HTTP error: {}",
+                name,
+                resp.status()
+            )));
+        }
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("{} response read failed: {}", name, e)))?;
+        serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse {} JSON: {}", name, e)))
+    }
+}
+
+/// Read a Tenderly `simulate` response
+fn parse_tenderly(response: &Value) -> Result<SimulationResult> {
+    let transaction = response
+        .get("transaction")
+        .ok_or_else(|| Error::parse("Tenderly response has no transaction"))?;
+    let success = transaction["status"].as_bool().unwrap_or(false);
+    let call_trace = &transaction["transaction_info"]["call_trace"];
+    let revert_reason = (!success).then(|| {
+        call_trace["error_reason"]
+            .as_str()
+            .or_else(|| transaction["error_message"].as_str())
+            .unwrap_or("execution reverted")
+            .to_string()
+    });
+    Ok(SimulationResult {
+        success,
+        return_data: call_trace["output"].as_str().unwrap_or("0x").to_string(),
+        gas_used: transaction["gas_used"].as_u64().unwrap_or(0),
+        gas_cost_eth: 0.0,
+        revert_reason,
+    })
+}
+
+/// Read an `alchemy_simulateAssetChanges` response
+fn parse_alchemy(response: &Value) -> Result<SimulationResult> {
+    if let Some(message) = response["error"]["message"].as_str() {
+        return Err(Error::blockchain(format!(
+            "Alchemy simulation failed: {}",
+            message
+        )));
+    }
+    let result = &response["result"];
+    let gas_used = result["gasUsed"]
+        .as_str()
+        .and_then(|gas| u64::from_str_radix(gas.trim_start_matches("0x"), 16).ok())
+        .unwrap_or(0);
+    let revert_reason = result["error"]["message"]
+        .as_str()
+        .map(str::to_string)
+        .or_else(|| result["error"].as_str().map(str::to_string));
+    Ok(SimulationResult {
+        success: revert_reason.is_none(),
+        return_data: "0x".to_string(),
+        gas_used,
+        gas_cost_eth: 0.0,
+        revert_reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tenderly_revert() {
+        let response = json!({
+            "transaction": {
+                "status": false,
+                "gas_used": 23_512,
+                "error_message": "execution reverted",
+                "transaction_info": {
+                    "call_trace": {
+                        "error_reason": "ERC20: transfer amount exceeds balance",
+                        "output": "0x08c379a0"
+                    }
+                }
+            }
+        });
+        let result = parse_tenderly(&response).unwrap();
+        assert!(!result.success);
+        assert_eq!(result.gas_used, 23_512);
+        assert_eq!(result.return_data, "0x08c379a0");
+        assert_eq!(
+            result.revert_reason.as_deref(),
+            Some("ERC20: transfer amount exceeds balance")
+        );
+    }
+}
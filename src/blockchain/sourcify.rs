@@ -0,0 +1,181 @@
+//! Sourcify API client for decentralized contract verification
+
+use super::types::{ContractSource, VerificationSource};
+use crate::error::{Error, Result};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Base URL of the public Sourcify server
+const SOURCIFY_API_URL: &str = "https://sourcify.dev/server";
+
+/// Verified files of a contract as returned by Sourcify
+#[derive(Debug, Clone)]
+pub struct SourcifyResult {
+    /// File contents keyed by source path
+    pub source_files: HashMap<String, String>,
+    /// ABI as a JSON string
+    pub abi: String,
+    /// The `settings` object of the Solidity metadata, plus the compiler version
+    pub compiler_settings: serde_json::Value,
+    /// Match type, `full` or `partial`
+    pub verification_status: String,
+}
+
+impl SourcifyResult {
+    /// Contract compiled into the verified bytecode, from the compilation target
+    pub fn contract_name(&self) -> String {
+        self.compiler_settings
+            .get("compilationTarget")
+            .and_then(|target| target.as_object())
+            .and_then(|target| target.values().next())
+            .and_then(|name| name.as_str())
+            .unwrap_or("Unknown")
+            .to_string()
+    }
+
+    /// Convert to the source shape shared with Etherscan results
+    pub fn into_contract_source(self) -> ContractSource {
+        let contract_name = self.contract_name();
+        let compiler_version = self
+            .compiler_settings
+            .get("compilerVersion")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown")
+            .to_string();
+        let optimizer = self.compiler_settings.get("optimizer");
+        let optimization_used = optimizer
+            .and_then(|optimizer| optimizer.get("enabled"))
+            .and_then(|enabled| enabled.as_bool())
+            .unwrap_or(false);
+        let runs = optimizer
+            .and_then(|optimizer| optimizer.get("runs"))
+            .and_then(|runs| runs.as_u64())
+            .unwrap_or(0) as u32;
+        ContractSource {
+            contract_name,
+            compiler_version,
+            source_files: self.source_files.into_iter().collect(),
+            abi: self.abi,
+            verified_via: VerificationSource::Sourcify,
+            match_type: Some(self.verification_status),
+            optimization_used,
+            runs,
+            constructor_arguments: String::new(),
+        }
+    }
+}
+
+/// A file in a Sourcify `files/any` response
+#[derive(Debug, Deserialize)]
+struct SourcifyFile {
+    name: String,
+    path: String,
+    content: String,
+}
+
+/// Body of a Sourcify `files/any` response
+#[derive(Debug, Deserialize)]
+struct SourcifyFilesResponse {
+    status: String,
+    files: Vec<SourcifyFile>,
+}
+
+/// Client for the Sourcify verification server; needs no API key
+#[derive(Clone)]
+pub struct SourcifyClient {
+    client: Client,
+}
+
+impl Default for SourcifyClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SourcifyClient {
+    /// Create a new client
+    pub fn new() -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .expect("Failed to build HTTP client");
+        Self { client }
+    }
+
+    /// Get the verified files of a contract, full or partial match
+    ///
+    /// Returns `None` if Sourcify has not verified the contract.
+    pub async fn get_contract_metadata(
+        &self,
+        chain_id: u64,
+        address: &str,
+    ) -> Result<Option<SourcifyResult>> {
+        let url = format!("{}/files/any/{}/{}", SOURCIFY_API_URL, chain_id, address);
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| Error::network(format!("Sourcify request failed: {}", e)))?;
+
+        if resp.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !resp.status().is_success() {
+            return Err(Error::network(format!(
+                "Sourcify HTTP error: {}",
+                resp.status()
+            )));
+        }
+
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| Error::network(format!("Sourcify response read failed: {}", e)))?;
+        let response: SourcifyFilesResponse = serde_json::from_str(&text)
+            .map_err(|e| Error::parse(format!("Failed to parse Sourcify JSON: {}", e)))?;
+
+        let mut metadata = None;
+        let mut source_files = HashMap::new();
+        for file in response.files {
+            if file.name == "metadata.json" {
+                metadata = Some(file.content);
+                continue;
+            }
+            // Paths look like `.../full_match/1/0x.../sources/contracts/Token.sol`
+            let path = file
+                .path
+                .split_once("/sources/")
+                .map_or(file.name, |(_, path)| path.to_string());
+            source_files.insert(path, file.content);
+        }
+
+        let metadata: serde_json::Value = match metadata {
+            Some(metadata) => serde_json::from_str(&metadata)
+                .map_err(|e| Error::parse(format!("Invalid Sourcify metadata: {}", e)))?,
+            None => serde_json::Value::Null,
+        };
+        let abi = metadata
+            .pointer("/output/abi")
+            .map(|abi| abi.to_string())
+            .unwrap_or_else(|| "[]".to_string());
+        let mut compiler_settings = metadata
+            .get("settings")
+            .cloned()
+            .unwrap_or_else(|| serde_json::json!({}));
+        if let (Some(settings), Some(version)) = (
+            compiler_settings.as_object_mut(),
+            metadata.pointer("/compiler/version").cloned(),
+        ) {
+            settings.insert("compilerVersion".to_string(), version);
+        }
+
+        Ok(Some(SourcifyResult {
+            source_files,
+            abi,
+            compiler_settings,
+            verification_status: response.status,
+        }))
+    }
+}
@@ -5,7 +5,7 @@
 use crate::error::{Error, Result};
 use ethers::{
     providers::{Middleware, Provider, StreamExt, Ws},
-    types::{Address, Log, Transaction, H256},
+    types::{Address, Filter, Log, Transaction, H256},
 };
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -13,6 +13,13 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
+/// How often the safe and finalized heads are polled; they move once per
+/// epoch (6.4 minutes) at most, so this is plenty
+const FINALITY_POLL_INTERVAL_SECS: u64 = 30;
+
+/// Pending transactions looked up at once after their hashes arrive
+const PENDING_TX_FETCH_CONCURRENCY: usize = 16;
+
 /// Subscription handle for managing active subscriptions
 pub struct SubscriptionHandle {
     handle: JoinHandle<()>,
@@ -37,6 +44,10 @@ impl SubscriptionHandle {
 pub enum SubscriptionEvent {
     /// New block mined
     NewBlock { block_number: u64, block_hash: H256 },
+    /// The `safe` head moved
+    SafeHead { block_number: u64 },
+    /// The `finalized` head moved
+    FinalizedHead { block_number: u64 },
     /// New transaction involving an address
     NewAddressTransaction {
         address: String,
@@ -131,27 +142,28 @@ impl SubscriptionManager {
         let _id = subscription_id.clone();
 
         let handle = tokio::spawn(async move {
-            match provider.watch_blocks().await {
+            // eth_subscribe("newHeads"): the node pushes each header as it arrives
+            match provider.subscribe_blocks().await {
                 Ok(mut stream) => {
                     tracing::info!(target: "warpscan", "Subscribed to new blocks");
-                    while let Some(block_hash) = stream.next().await {
-                        // Fetch full block details
-                        if let Ok(Some(block)) = provider.get_block(block_hash).await {
-                            if let Some(block_number) = block.number {
-                                let block_num = block_number.as_u64();
-                                tracing::info!(
-                                    target: "warpscan",
-                                    "📦 NewBlock event: block_number={}, block_hash={:#x}",
-                                    block_num,
-                                    block_hash
-                                );
-                                let _ = sender.send(SubscriptionEvent::NewBlock {
-                                    block_number: block_num,
-                                    block_hash,
-                                });
-                            }
+                    while let Some(header) = stream.next().await {
+                        if let (Some(block_number), Some(block_hash)) = (header.number, header.hash)
+                        {
+                            let block_num = block_number.as_u64();
+                            tracing::info!(
+                                target: "warpscan",
+                                "📦 NewBlock event: block_number={}, block_hash={:#x}",
+                                block_num,
+                                block_hash
+                            );
+                            crate::metrics::global().set_current_block(block_num);
+                            let _ = sender.send(SubscriptionEvent::NewBlock {
+                                block_number: block_num,
+                                block_hash,
+                            });
                         }
                     }
+                    tracing::warn!(target: "warpscan", "Block subscription stream closed");
                 }
                 Err(e) => {
                     tracing::error!(target: "warpscan", "Failed to subscribe to blocks: {}", e);
@@ -193,6 +205,7 @@ impl SubscriptionManager {
                                 .await
                             {
                                 if let Some(hash) = block.hash {
+                                    crate::metrics::global().set_current_block(current);
                                     let _ = sender.send(SubscriptionEvent::NewBlock {
                                         block_number: current,
                                         block_hash: hash,
@@ -376,6 +389,158 @@ impl SubscriptionManager {
         Ok(())
     }
 
+    /// Subscribe to the node's pending transactions
+    ///
+    /// `newPendingTransactions` only carries hashes, so each transaction is
+    /// looked up as its hash arrives; ones already mined or dropped by then
+    /// are skipped. Needs a WebSocket provider; there is no polling fallback.
+    pub async fn subscribe_to_pending_transactions(
+        &mut self,
+        subscription_id: String,
+    ) -> Result<()> {
+        if let Some(handle) = self.subscriptions.remove(&subscription_id) {
+            handle.abort();
+        }
+
+        let provider = self.ws_provider.clone().ok_or_else(|| {
+            Error::network("A WebSocket endpoint is required to watch pending transactions")
+        })?;
+        let sender = self.event_sender.clone();
+        let _id = subscription_id.clone();
+
+        let handle = tokio::spawn(async move {
+            match provider.subscribe_pending_txs().await {
+                Ok(stream) => {
+                    tracing::info!(target: "warpscan", "Subscribed to pending transactions");
+                    let mut transactions =
+                        stream.transactions_unordered(PENDING_TX_FETCH_CONCURRENCY);
+                    while let Some(result) = transactions.next().await {
+                        if let Ok(transaction) = result {
+                            let _ =
+                                sender.send(SubscriptionEvent::PendingTransaction { transaction });
+                        }
+                    }
+                    tracing::warn!(target: "warpscan", "Pending transaction stream closed");
+                }
+                Err(e) => {
+                    tracing::error!(
+                        target: "warpscan",
+                        "Failed to subscribe to pending transactions: {}",
+                        e
+                    );
+                    let _ = sender.send(SubscriptionEvent::Error {
+                        subscription_id: _id.clone(),
+                        error: format!("Failed to subscribe to pending transactions: {}", e),
+                    });
+                }
+            }
+        });
+
+        self.subscriptions.insert(
+            subscription_id,
+            SubscriptionHandle::new(handle, self.event_sender.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Stream logs matching `filter` as their blocks arrive
+    ///
+    /// Needs a WebSocket provider; there is no polling fallback.
+    pub async fn subscribe_to_logs(
+        &mut self,
+        subscription_id: String,
+        filter: Filter,
+    ) -> Result<()> {
+        if let Some(handle) = self.subscriptions.remove(&subscription_id) {
+            handle.abort();
+        }
+
+        let provider = self
+            .ws_provider
+            .clone()
+            .ok_or_else(|| Error::network("A WebSocket endpoint is required to stream logs"))?;
+        let sender = self.event_sender.clone();
+        let _id = subscription_id.clone();
+
+        let handle = tokio::spawn(async move {
+            match provider.subscribe_logs(&filter).await {
+                Ok(mut stream) => {
+                    tracing::info!(target: "warpscan", "Subscribed to logs");
+                    while let Some(log) = stream.next().await {
+                        let _ = sender.send(SubscriptionEvent::NewLog { log });
+                    }
+                    tracing::warn!(target: "warpscan", "Log subscription stream closed");
+                }
+                Err(e) => {
+                    tracing::error!(target: "warpscan", "Failed to subscribe to logs: {}", e);
+                    let _ = sender.send(SubscriptionEvent::Error {
+                        subscription_id: _id.clone(),
+                        error: format!("Failed to subscribe to logs: {}", e),
+                    });
+                }
+            }
+        });
+
+        self.subscriptions.insert(
+            subscription_id,
+            SubscriptionHandle::new(handle, self.event_sender.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Watch the safe and finalized heads
+    ///
+    /// Nodes only push `newHeads` for the latest block, so both heads are
+    /// polled over HTTP; an event is sent whenever either one moves.
+    pub async fn subscribe_to_finality(&mut self, subscription_id: String) -> Result<()> {
+        if let Some(handle) = self.subscriptions.remove(&subscription_id) {
+            handle.abort();
+        }
+
+        let http_provider = self.http_provider.clone();
+        let sender = self.event_sender.clone();
+
+        let handle = tokio::spawn(async move {
+            let head = |block: std::result::Result<Option<ethers::types::Block<H256>>, _>| {
+                block
+                    .ok()
+                    .flatten()
+                    .and_then(|b| b.number)
+                    .map(|n| n.as_u64())
+            };
+            let mut last_safe = None;
+            let mut last_finalized = None;
+            loop {
+                let (safe, finalized) = tokio::join!(
+                    http_provider.get_block(ethers::types::BlockNumber::Safe),
+                    http_provider.get_block(ethers::types::BlockNumber::Finalized),
+                );
+                // Pre-Merge and some local nodes reject the tags; stay quiet
+                if let Some(block_number) = head(safe).filter(|n| last_safe != Some(*n)) {
+                    last_safe = Some(block_number);
+                    let _ = sender.send(SubscriptionEvent::SafeHead { block_number });
+                }
+                if let Some(block_number) = head(finalized).filter(|n| last_finalized != Some(*n)) {
+                    last_finalized = Some(block_number);
+                    let _ = sender.send(SubscriptionEvent::FinalizedHead { block_number });
+                }
+                tokio::time::sleep(tokio::time::Duration::from_secs(
+                    FINALITY_POLL_INTERVAL_SECS,
+                ))
+                .await;
+            }
+        });
+
+        self.subscriptions.insert(
+            subscription_id,
+            SubscriptionHandle::new(handle, self.event_sender.clone()),
+        );
+
+        Ok(())
+    }
+
     /// Unsubscribe from a subscription
     pub fn unsubscribe(&mut self, subscription_id: &str) {
         if let Some(handle) = self.subscriptions.remove(subscription_id) {
@@ -1,19 +1,99 @@
 //! Blockchain types and data structures
 
-use ethers::types::U256;
+use ethers::types::{Address, Filter, TransactionReceipt, H256, U256};
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Gas price information
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GasPrices {
     pub slow: U256,
     pub standard: U256,
     pub fast: U256,
+    /// Base fee of the latest block, zero before London
+    pub base_fee: U256,
+    pub max_priority_fee_slow: U256,
+    pub max_priority_fee_standard: U256,
+    pub max_priority_fee_fast: U256,
+    /// Base fee of the next block, from the latest block's gas usage
+    pub next_base_fee: U256,
     pub timestamp: u64,
 }
 
+/// EIP-1559 fee suggestion for one confirmation speed
+#[derive(Debug, Clone, Serialize)]
+pub struct Eip1559Tier {
+    pub max_priority_fee_gwei: u64,
+    pub max_fee_gwei: u64,
+    /// Rough number of blocks until inclusion
+    pub estimated_wait_blocks: u8,
+}
+
+/// Where the priority fee tiers of an estimate came from
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum PriorityFeeSource {
+    /// The node's `eth_maxPriorityFeePerGas` suggestion
+    Oracle,
+    /// Reward percentiles of recent blocks
+    FeeHistory,
+}
+
+impl PriorityFeeSource {
+    /// Label shown next to the fee tiers
+    pub fn label(&self) -> &'static str {
+        match self {
+            PriorityFeeSource::Oracle => "On-chain oracle",
+            PriorityFeeSource::FeeHistory => "Fee history P50",
+        }
+    }
+}
+
+/// EIP-1559 fee suggestions derived from recent fee history
+#[derive(Debug, Clone, Serialize)]
+pub struct Eip1559FeeEstimate {
+    /// Base fee of the next block
+    pub base_fee_gwei: u64,
+    pub slow: Eip1559Tier,
+    pub standard: Eip1559Tier,
+    pub fast: Eip1559Tier,
+    pub priority_fee_source: PriorityFeeSource,
+}
+
+/// Heads of the chain under each post-Merge finality label
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct BlockFinalityInfo {
+    pub latest: u64,
+    /// Newest block attested by a majority of validators; unlikely to reorg
+    pub safe: u64,
+    /// Newest block finalized by the beacon chain; cannot reorg
+    pub finalized: u64,
+}
+
+impl BlockFinalityInfo {
+    /// Finality label of a block: `Finalized ✓`, `Safe`, or `None` if neither
+    pub fn label(&self, block_number: u64) -> Option<&'static str> {
+        if block_number <= self.finalized {
+            Some("Finalized ✓")
+        } else if block_number <= self.safe {
+            Some("Safe")
+        } else {
+            None
+        }
+    }
+}
+
+/// Gas usage and fees of a single block, for the gas tracker history
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockGasMetric {
+    pub block_number: u64,
+    pub base_fee_gwei: u64,
+    /// Fraction of the gas limit used, 0.0 to 1.0
+    pub gas_used_ratio: f64,
+    pub avg_priority_fee_gwei: u64,
+}
+
 /// Transaction status
-#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, serde::Deserialize)]
 pub enum TransactionStatus {
     Pending,
     Success,
@@ -21,6 +101,16 @@ pub enum TransactionStatus {
     Unknown,
 }
 
+/// Inclusion status of a transaction and how deep it is buried
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TxConfirmation {
+    pub status: TransactionStatus,
+    /// Block the transaction was included in, `None` while pending
+    pub block_number: Option<u64>,
+    /// Blocks on top of the inclusion block, counting that block itself
+    pub confirmations: u64,
+}
+
 /// Simplified address transaction model used by services
 #[derive(Debug, Clone, Serialize, serde::Deserialize)]
 pub struct AddressTx {
@@ -34,3 +124,187 @@ pub struct AddressTx {
     pub fee_eth: f64,
     pub status: TransactionStatus,
 }
+
+/// Outcome of a dry-run `eth_call` simulation
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct SimulationResult {
+    pub success: bool,
+    /// Hex-encoded return data (or raw revert data when the call reverted)
+    pub return_data: String,
+    pub gas_used: u64,
+    /// `gas_used` at the current gas price
+    #[serde(default)]
+    pub gas_cost_eth: f64,
+    pub revert_reason: Option<String>,
+}
+
+/// Confirmed vs pending nonce comparison for an address
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct NonceAnalysis {
+    pub current_nonce: u64,
+    pub pending_nonce: u64,
+    pub gap_count: u64,
+    pub has_gap: bool,
+}
+
+/// Evidence that a contract removed itself with SELFDESTRUCT
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct SelfDestructInfo {
+    pub tx_hash: String,
+    pub block_number: u64,
+    pub refund_to: String,
+}
+
+/// Where a contract's verified source code was found
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, serde::Deserialize)]
+pub enum VerificationSource {
+    Etherscan,
+    Sourcify,
+}
+
+impl VerificationSource {
+    /// Name of the verification service
+    pub fn label(&self) -> &'static str {
+        match self {
+            VerificationSource::Etherscan => "Etherscan",
+            VerificationSource::Sourcify => "Sourcify",
+        }
+    }
+}
+
+/// Verified source code of a contract
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct ContractSource {
+    pub contract_name: String,
+    pub compiler_version: String,
+    /// File contents keyed by source path
+    pub source_files: BTreeMap<String, String>,
+    /// ABI as a JSON string
+    pub abi: String,
+    pub verified_via: VerificationSource,
+    /// Sourcify match type (`full` or `partial`); Etherscan reports none
+    pub match_type: Option<String>,
+    /// Whether the optimizer was enabled
+    #[serde(default)]
+    pub optimization_used: bool,
+    /// Optimizer runs, 0 when the optimizer was off
+    #[serde(default)]
+    pub runs: u32,
+    /// Hex-encoded constructor arguments, without `0x`; Sourcify reports none
+    #[serde(default)]
+    pub constructor_arguments: String,
+}
+
+impl ContractSource {
+    /// The ABI indented for display, or as stored if it is not valid JSON
+    pub fn pretty_abi(&self) -> String {
+        serde_json::from_str::<serde_json::Value>(&self.abi)
+            .and_then(|abi| serde_json::to_string_pretty(&abi))
+            .unwrap_or_else(|_| self.abi.clone())
+    }
+}
+
+/// An event log, decoded when its signature is known
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct DecodedLog {
+    /// Contract that emitted the log
+    pub address: String,
+    /// Event name, `None` for unknown signatures
+    pub event_name: Option<String>,
+    /// Hex-encoded non-indexed data
+    pub raw_data: String,
+    /// Parameter names and display values
+    pub decoded_params: Vec<(String, String)>,
+}
+
+/// A transaction receipt with its logs decoded
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionReceiptWithLogs {
+    pub receipt: TransactionReceipt,
+    pub decoded_logs: Vec<DecodedLog>,
+}
+
+/// Deployer and deployment transaction of a contract
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct ContractCreationInfo {
+    pub creator: String,
+    pub tx_hash: String,
+    pub creation_block: u64,
+}
+
+/// Aggregate statistics over a contiguous range of blocks
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct BlockRangeSummary {
+    pub block_count: u64,
+    pub total_transactions: u64,
+    pub avg_gas_used: f64,
+    pub avg_block_time_secs: f64,
+    /// Lowest base fee in the range, in wei (0 for pre-London blocks)
+    pub min_base_fee: u64,
+    /// Highest base fee in the range, in wei
+    pub max_base_fee: u64,
+    pub total_eth_burned: f64,
+}
+
+/// A single trait of an NFT, with its value rendered as text
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct NftAttribute {
+    pub trait_type: String,
+    pub value: String,
+}
+
+/// Token metadata resolved from an NFT's `tokenURI`
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+pub struct NftMetadata {
+    pub name: String,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub attributes: Vec<NftAttribute>,
+    pub external_url: Option<String>,
+}
+
+/// Flashbots bundle that included a transaction
+#[derive(Debug, Clone, PartialEq, Serialize, serde::Deserialize)]
+pub struct MevInfo {
+    pub bundle_id: String,
+    pub block_number: u64,
+    /// ETH paid straight to the block builder's coinbase
+    pub coinbase_transfer: f64,
+    /// Coinbase transfer plus priority fees, in ETH
+    pub total_miner_reward: f64,
+}
+
+/// Log query of the event monitor
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    /// Emitting contract; any contract when unset
+    pub contract_address: Option<String>,
+    /// Event signature hash; any event when unset
+    pub topic0: Option<H256>,
+    /// Inclusive block range searched for past logs
+    pub block_range: (u64, u64),
+}
+
+impl EventFilter {
+    /// `eth_getLogs` filter over `block_range`, or without a block range for
+    /// a live `eth_subscribe("logs")` stream
+    pub fn to_filter(&self, with_range: bool) -> Filter {
+        let mut filter = Filter::new();
+        if with_range {
+            filter = filter
+                .from_block(self.block_range.0)
+                .to_block(self.block_range.1);
+        }
+        if let Some(address) = self
+            .contract_address
+            .as_deref()
+            .and_then(|a| a.parse::<Address>().ok())
+        {
+            filter = filter.address(address);
+        }
+        if let Some(topic0) = self.topic0 {
+            filter = filter.topic0(topic0);
+        }
+        filter
+    }
+}
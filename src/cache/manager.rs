@@ -3,22 +3,105 @@
 //! This module provides the main cache manager for storing and retrieving
 //! blockchain data with TTL support.
 
+use super::persistence::{chain_dir, Expiring, Permanent, PersistedCache};
 use super::types::{
-    AddressInfo, CacheEntry, CacheStats, CachedAddressTransactions, CachedEnsName,
-    CachedInternalTransactions, CachedTokenBalances, CachedTokenTransfers, ContractInfo, TokenInfo,
+    AddressInfo, CacheDebugInfo, CacheEntry, CacheStats, CacheType, CachedAddressTransactions,
+    CachedEnsAddress, CachedEnsName, CachedInternalTransactions, CachedTokenBalances,
+    CachedTokenTransfers, ContractInfo, TokenInfo,
+};
+use crate::blockchain::etherscan::{AddressTag, BlockRewardInfo, EthPrice};
+use crate::blockchain::types::{
+    BlockFinalityInfo, BlockRangeSummary, ContractCreationInfo, ContractSource, MevInfo,
+    NftMetadata, SelfDestructInfo,
 };
 use crate::config::Config;
 use crate::error::Result;
 use ethers::types::{Block, Transaction, H256};
 use lru::LruCache;
+use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::sync::{Arc, Mutex};
 
+/// Name tags are curated slowly, so keep them for a day
+const ADDRESS_TAGS_TTL_SECONDS: u64 = 86_400;
+
+/// Token metadata is effectively immutable once minted, so keep it for a week
+const NFT_METADATA_TTL_SECONDS: u64 = 604_800;
+
+/// Rewards of historical blocks never change, so keep them for a month
+const BLOCK_REWARDS_TTL_SECONDS: u64 = 2_592_000;
+
+/// The ETH price is only used for rough USD estimates, so a minute is fresh enough
+const ETH_PRICE_TTL_SECONDS: u64 = 60;
+
+/// Token prices move quickly, so like the ETH price keep them for a minute
+const TOKEN_PRICE_TTL_SECONDS: u64 = 60;
+
+/// Verified source never changes, so keep it for a week
+const CONTRACT_SOURCE_TTL_SECONDS: u64 = 604_800;
+
+/// Unverified contracts may be verified at any time, so recheck after an hour
+const UNVERIFIED_CONTRACT_TTL_SECONDS: u64 = 3_600;
+
+/// Flashbots data is only corrected rarely, so keep bundle lookups for an hour
+const MEV_BUNDLE_TTL_SECONDS: u64 = 3_600;
+
+/// Floor prices move with every listing, so recheck after five minutes
+const NFT_FLOOR_PRICE_TTL_SECONDS: u64 = 300;
+
+/// Finality heads move every slot, so keep them for one block
+const BLOCK_FINALITY_TTL_SECONDS: u64 = 12;
+
+/// Shortest TTL an address entry adapts down to
+const MIN_ADDRESS_TTL_SECONDS: u64 = 10;
+
+/// Background task sweeping expired entries, aborted when the last
+/// `CacheManager` clone is dropped
+struct CleanupTask(Option<tokio::task::JoinHandle<()>>);
+
+impl Drop for CleanupTask {
+    fn drop(&mut self) {
+        if let Some(handle) = self.0.take() {
+            handle.abort();
+        }
+    }
+}
+
+/// Inclusive `(start, end)` block range used as a summary cache key
+type BlockRange = (u64, u64);
+
+/// Key prefixes understood by the cache inspector, one per TTL cache
+pub const CACHE_KEY_PREFIXES: &[&str] = &[
+    "block",
+    "block_hash",
+    "tx",
+    "address",
+    "contract",
+    "token",
+    "address_txs",
+    "token_transfers",
+    "token_balances",
+    "internal_txs",
+    "internal_txs_by_hash",
+    "ens",
+    "ens_address",
+    "tags",
+    "range",
+    "nft",
+    "block_rewards",
+    "source",
+    "price",
+    "mev",
+    "floor",
+];
+
 /// Main cache manager
 #[derive(Clone)]
 pub struct CacheManager {
     /// In-memory LRU caches
     blocks: Arc<Mutex<LruCache<u64, CacheEntry<Block<H256>>>>>,
+    /// Blocks looked up by `0x` hash, keyed by the lowercase hash
+    blocks_by_hash: Arc<Mutex<LruCache<String, CacheEntry<Block<H256>>>>>,
     transactions: Arc<Mutex<LruCache<String, CacheEntry<Transaction>>>>,
     addresses: Arc<Mutex<LruCache<String, CacheEntry<AddressInfo>>>>,
     contracts: Arc<Mutex<LruCache<String, CacheEntry<ContractInfo>>>>,
@@ -28,7 +111,39 @@ pub struct CacheManager {
     token_transfers: Arc<Mutex<LruCache<String, CacheEntry<CachedTokenTransfers>>>>,
     token_balances: Arc<Mutex<LruCache<String, CacheEntry<CachedTokenBalances>>>>,
     internal_transactions: Arc<Mutex<LruCache<String, CacheEntry<CachedInternalTransactions>>>>,
+    internal_txns_by_hash: Arc<Mutex<LruCache<String, CacheEntry<CachedInternalTransactions>>>>,
     ens_names: Arc<Mutex<LruCache<String, CacheEntry<CachedEnsName>>>>,
+    ens_addresses: Arc<Mutex<LruCache<String, CacheEntry<CachedEnsAddress>>>>,
+    address_tags: Arc<Mutex<LruCache<String, CacheEntry<Vec<AddressTag>>>>>,
+    // Self-destructs are final, so these entries never expire
+    self_destructs: Arc<Mutex<LruCache<String, SelfDestructInfo>>>,
+    // A contract's deployment never changes
+    contract_creations: Arc<Mutex<LruCache<String, ContractCreationInfo>>>,
+    // Fees burned in past blocks never change either
+    burned_eth: Arc<Mutex<LruCache<BlockRange, f64>>>,
+    block_range_summaries: Arc<Mutex<LruCache<BlockRange, CacheEntry<BlockRangeSummary>>>>,
+    nft_metadata: Arc<Mutex<LruCache<String, CacheEntry<NftMetadata>>>>,
+    block_rewards: Arc<Mutex<LruCache<u64, CacheEntry<BlockRewardInfo>>>>,
+    /// `None` records a contract known to be unverified
+    contract_sources: Arc<Mutex<LruCache<String, CacheEntry<Option<ContractSource>>>>>,
+    /// Keyed by lowercase transaction hash; `None` records a transaction
+    /// outside any bundle
+    mev_bundles: Arc<Mutex<LruCache<String, CacheEntry<Option<MevInfo>>>>>,
+    /// Keyed by lowercase collection address; `None` records a collection
+    /// without a floor price
+    nft_floor_prices: Arc<Mutex<LruCache<String, CacheEntry<Option<f64>>>>>,
+    eth_price: Arc<Mutex<Option<CacheEntry<EthPrice>>>>,
+    /// Keyed by `{contract}:{vs_currency}`
+    prices: Arc<Mutex<LruCache<String, CacheEntry<f64>>>>,
+    block_finality: Arc<Mutex<Option<CacheEntry<BlockFinalityInfo>>>>,
+    /// TTL given to new entries of each namespace, shared by all clones so
+    /// `set_ttl` applies everywhere
+    ttls: Arc<Mutex<HashMap<CacheType, u64>>>,
+    /// Unix time of the last clear, full or expired only
+    last_cleared: Arc<Mutex<Option<u64>>>,
+    /// Periodic sweep of expired entries; the task itself holds a clone
+    /// without this handle, so it never keeps itself alive
+    cleanup_task: Arc<CleanupTask>,
 
     /// Configuration
     config: Config,
@@ -49,8 +164,9 @@ impl CacheManager {
         // Use a single cache size constant to avoid repeated unwrap calls
         let cache_size = NonZeroUsize::new(1000).unwrap(); // Default cache size
 
-        Ok(Self {
+        let manager = Self {
             blocks: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            blocks_by_hash: Arc::new(Mutex::new(LruCache::new(cache_size))),
             transactions: Arc::new(Mutex::new(LruCache::new(cache_size))),
             addresses: Arc::new(Mutex::new(LruCache::new(cache_size))),
             contracts: Arc::new(Mutex::new(LruCache::new(cache_size))),
@@ -59,9 +175,174 @@ impl CacheManager {
             token_transfers: Arc::new(Mutex::new(LruCache::new(cache_size))),
             token_balances: Arc::new(Mutex::new(LruCache::new(cache_size))),
             internal_transactions: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            internal_txns_by_hash: Arc::new(Mutex::new(LruCache::new(cache_size))),
             ens_names: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            ens_addresses: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            address_tags: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            self_destructs: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            contract_creations: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            burned_eth: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            block_range_summaries: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            nft_metadata: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            block_rewards: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            contract_sources: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            mev_bundles: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            nft_floor_prices: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            eth_price: Arc::new(Mutex::new(None)),
+            prices: Arc::new(Mutex::new(LruCache::new(cache_size))),
+            block_finality: Arc::new(Mutex::new(None)),
+            ttls: Arc::new(Mutex::new(
+                CacheType::ALL
+                    .into_iter()
+                    .map(|cache| (cache, cache.config_ttl(&config.cache)))
+                    .collect(),
+            )),
+            last_cleared: Arc::new(Mutex::new(None)),
+            cleanup_task: Arc::new(CleanupTask(None)),
             config,
-        })
+        };
+        if manager.persistence_enabled() {
+            manager.load_from_disk();
+        }
+        Ok(manager.with_cleanup_task())
+    }
+
+    /// TTL given to new entries of a namespace
+    pub fn ttl(&self, cache: CacheType) -> u64 {
+        self.ttls.lock().unwrap()[&cache]
+    }
+
+    /// Change the TTL of a namespace at runtime
+    ///
+    /// Only entries stored from now on get the new TTL; existing entries
+    /// keep the one they were stored with.
+    pub fn set_ttl(&self, cache: CacheType, seconds: u64) {
+        self.ttls.lock().unwrap().insert(cache, seconds);
+    }
+
+    /// Whether entries are kept on disk between runs
+    fn persistence_enabled(&self) -> bool {
+        self.config.cache.enabled && self.config.cache.persist_to_disk
+    }
+
+    /// Every cache kept on disk, with the name of its file
+    ///
+    /// The ETH and token prices and the finality heads go stale within a
+    /// minute and are not written.
+    fn persisted_caches(&self) -> Vec<(&'static str, Box<dyn PersistedCache + '_>)> {
+        vec![
+            ("blocks", Box::new(Expiring(&self.blocks))),
+            ("blocks_by_hash", Box::new(Expiring(&self.blocks_by_hash))),
+            ("transactions", Box::new(Expiring(&self.transactions))),
+            ("addresses", Box::new(Expiring(&self.addresses))),
+            ("contracts", Box::new(Expiring(&self.contracts))),
+            ("tokens", Box::new(Expiring(&self.tokens))),
+            (
+                "address_transactions",
+                Box::new(Expiring(&self.address_transactions)),
+            ),
+            ("token_transfers", Box::new(Expiring(&self.token_transfers))),
+            ("token_balances", Box::new(Expiring(&self.token_balances))),
+            (
+                "internal_transactions",
+                Box::new(Expiring(&self.internal_transactions)),
+            ),
+            (
+                "internal_txns_by_hash",
+                Box::new(Expiring(&self.internal_txns_by_hash)),
+            ),
+            ("ens_names", Box::new(Expiring(&self.ens_names))),
+            ("ens_addresses", Box::new(Expiring(&self.ens_addresses))),
+            ("address_tags", Box::new(Expiring(&self.address_tags))),
+            ("self_destructs", Box::new(Permanent(&self.self_destructs))),
+            (
+                "contract_creations",
+                Box::new(Permanent(&self.contract_creations)),
+            ),
+            ("burned_eth", Box::new(Permanent(&self.burned_eth))),
+            (
+                "block_range_summaries",
+                Box::new(Expiring(&self.block_range_summaries)),
+            ),
+            ("nft_metadata", Box::new(Expiring(&self.nft_metadata))),
+            ("block_rewards", Box::new(Expiring(&self.block_rewards))),
+            (
+                "contract_sources",
+                Box::new(Expiring(&self.contract_sources)),
+            ),
+            ("mev_bundles", Box::new(Expiring(&self.mev_bundles))),
+        ]
+    }
+
+    /// Fill the caches from the files the last `flush_to_disk` wrote for the
+    /// configured chain, skipping expired entries
+    ///
+    /// Unreadable files are logged and skipped so a corrupt cache never
+    /// blocks startup.
+    fn load_from_disk(&self) {
+        let dir = match Config::cache_dir() {
+            Ok(dir) => chain_dir(&dir, self.config.network.chain_id),
+            Err(e) => {
+                tracing::warn!(target: "warpscan", "Cache directory unavailable: {}", e);
+                return;
+            }
+        };
+        let now = unix_now();
+        let mut loaded = 0;
+        for (name, cache) in self.persisted_caches() {
+            match cache.load(&dir, name, now) {
+                Ok(count) => loaded += count,
+                Err(e) => tracing::warn!(target: "warpscan", "Skipping cache file: {}", e),
+            }
+        }
+        tracing::info!(target: "warpscan", "Loaded {} cache entries from disk", loaded);
+    }
+
+    /// Write every persisted cache to its file under the directory of
+    /// `chain_id`, the chain the cached entries belong to
+    pub fn flush_to_disk(&self, chain_id: u64) -> Result<()> {
+        if !self.persistence_enabled() {
+            return Ok(());
+        }
+        let dir = chain_dir(&Config::cache_dir()?, chain_id);
+        std::fs::create_dir_all(&dir)?;
+        for (name, cache) in self.persisted_caches() {
+            cache.save(&dir, name)?;
+        }
+        Ok(())
+    }
+
+    /// Start the periodic sweep of expired entries
+    ///
+    /// Without a tokio runtime (e.g. in synchronous tests) entries are still
+    /// dropped lazily when read, so the task is simply skipped.
+    fn with_cleanup_task(mut self) -> Self {
+        if !self.config.cache.enabled {
+            return self;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return self;
+        };
+
+        let interval =
+            std::time::Duration::from_secs(self.config.cache.cleanup_interval_secs.max(1));
+        let worker = self.clone();
+        let handle = runtime.spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // The first tick completes immediately and the cache is empty
+            ticker.tick().await;
+            loop {
+                ticker.tick().await;
+                let removed = worker.remove_all_expired(unix_now());
+                tracing::debug!(
+                    target: "warpscan",
+                    "Cache cleanup evicted {} expired entries",
+                    removed
+                );
+            }
+        });
+        self.cleanup_task = Arc::new(CleanupTask(Some(handle)));
+        self
     }
 
     /// Get block from cache
@@ -73,11 +354,13 @@ impl CacheManager {
         let mut cache = self.blocks.lock().unwrap();
         if let Some(entry) = cache.get(&block_number) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 return Some(entry.data.clone());
             } else {
                 cache.pop(&block_number);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
@@ -93,13 +376,52 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.block_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::Blocks),
         };
 
         let mut cache = self.blocks.lock().unwrap();
         cache.put(block_number, entry);
     }
 
+    /// Get a block looked up by hash from cache
+    pub fn get_block_by_hash(&self, block_hash: &str) -> Option<Block<H256>> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let key = block_hash.to_lowercase();
+        let mut cache = self.blocks_by_hash.lock().unwrap();
+        if let Some(entry) = cache.get(&key) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            } else {
+                cache.pop(&key);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store a block looked up by hash in cache, with the block TTL
+    pub fn store_block_by_hash(&self, block_hash: &str, block: Block<H256>) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: block,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: self.ttl(CacheType::Blocks),
+        };
+
+        let mut cache = self.blocks_by_hash.lock().unwrap();
+        cache.put(block_hash.to_lowercase(), entry);
+    }
+
     /// Get transaction from cache
     pub fn get_transaction(&self, tx_hash: &str) -> Option<Transaction> {
         if !self.config.cache.enabled {
@@ -109,11 +431,13 @@ impl CacheManager {
         let mut cache = self.transactions.lock().unwrap();
         if let Some(entry) = cache.get(tx_hash) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 return Some(entry.data.clone());
             } else {
                 cache.pop(tx_hash);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
@@ -129,7 +453,7 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.transaction_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::Transactions),
         };
 
         let mut cache = self.transactions.lock().unwrap();
@@ -142,33 +466,38 @@ impl CacheManager {
             return None;
         }
 
+        // Expired entries stay until the refetch replaces them, so their TTL
+        // can adapt; the background sweep drops them if no refetch comes
         let mut cache = self.addresses.lock().unwrap();
         if let Some(entry) = cache.get(address) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 return Some(entry.data.clone());
-            } else {
-                cache.pop(address);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
     /// Store address info in cache
+    ///
+    /// The TTL adapts to how often the address changes: it halves whenever
+    /// the balance or transaction count differs from the previous entry and
+    /// doubles while they stay the same, between `MIN_ADDRESS_TTL_SECONDS`
+    /// and the configured `address_ttl_seconds`.
     pub fn store_address_info(&self, address: String, info: AddressInfo) {
         if !self.config.cache.enabled {
             return;
         }
 
+        let mut cache = self.addresses.lock().unwrap();
+        let ttl_seconds =
+            adaptive_address_ttl(cache.peek(&address), &info, self.ttl(CacheType::Addresses));
         let entry = CacheEntry {
             data: info,
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
-            ttl_seconds: self.config.cache.address_ttl_seconds,
+            timestamp: unix_now(),
+            ttl_seconds,
         };
-
-        let mut cache = self.addresses.lock().unwrap();
         cache.put(address, entry);
     }
 
@@ -181,11 +510,13 @@ impl CacheManager {
         let mut cache = self.contracts.lock().unwrap();
         if let Some(entry) = cache.get(address) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 return Some(entry.data.clone());
             } else {
                 cache.pop(address);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
@@ -201,7 +532,7 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.contract_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::Contracts),
         };
 
         let mut cache = self.contracts.lock().unwrap();
@@ -229,6 +560,7 @@ impl CacheManager {
         let mut cache = self.address_transactions.lock().unwrap();
         if let Some(entry) = cache.get(address) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 // Convert from serializable format back to AddressTx
                 return Some(
                     entry
@@ -261,6 +593,7 @@ impl CacheManager {
                 cache.pop(address);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
@@ -311,13 +644,18 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.address_transactions_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::AddressTransactions),
         };
 
         let mut cache = self.address_transactions.lock().unwrap();
         cache.put(address, entry);
     }
 
+    /// Drop the cached transactions of `address`
+    pub fn invalidate_address_transactions(&self, address: &str) {
+        self.address_transactions.lock().unwrap().pop(address);
+    }
+
     /// Get token transfers from cache
     pub fn get_token_transfers(
         &self,
@@ -330,6 +668,7 @@ impl CacheManager {
         let mut cache = self.token_transfers.lock().unwrap();
         if let Some(entry) = cache.get(address) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 // Convert from serializable format back to TokenTransfer
                 return Some(
                     entry
@@ -338,6 +677,7 @@ impl CacheManager {
                         .iter()
                         .map(|t| crate::blockchain::etherscan::TokenTransfer {
                             token_id: t.token_id.clone(),
+                            contract_address: t.contract_address.clone(),
                             txn_hash: t.txn_hash.clone(),
                             from: t.from.clone(),
                             to: t.to.clone(),
@@ -352,6 +692,7 @@ impl CacheManager {
                 cache.pop(address);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
@@ -370,6 +711,7 @@ impl CacheManager {
             .iter()
             .map(|t| super::types::SerializableTokenTransfer {
                 token_id: t.token_id.clone(),
+                contract_address: t.contract_address.clone(),
                 txn_hash: t.txn_hash.clone(),
                 from: t.from.clone(),
                 to: t.to.clone(),
@@ -393,7 +735,7 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.token_transfers_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::TokenTransfers),
         };
 
         let mut cache = self.token_transfers.lock().unwrap();
@@ -412,6 +754,7 @@ impl CacheManager {
         let mut cache = self.token_balances.lock().unwrap();
         if let Some(entry) = cache.get(address) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 // Convert from serializable format back to TokenBalance
                 return Some(
                     entry
@@ -424,6 +767,7 @@ impl CacheManager {
                             symbol: b.symbol.clone(),
                             decimals: b.decimals,
                             balance: b.balance,
+                            token_type: b.token_type.clone(),
                         })
                         .collect(),
                 );
@@ -431,6 +775,7 @@ impl CacheManager {
                 cache.pop(address);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
@@ -453,6 +798,7 @@ impl CacheManager {
                 symbol: b.symbol.clone(),
                 decimals: b.decimals,
                 balance: b.balance,
+                token_type: b.token_type.clone(),
             })
             .collect();
 
@@ -469,7 +815,7 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.token_balances_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::TokenBalances),
         };
 
         let mut cache = self.token_balances.lock().unwrap();
@@ -480,14 +826,50 @@ impl CacheManager {
     pub fn get_internal_transactions(
         &self,
         address: &str,
+    ) -> Option<Vec<crate::blockchain::etherscan::InternalTransaction>> {
+        self.get_cached_internal_transactions(&self.internal_transactions, address)
+    }
+
+    /// Store internal transactions in cache
+    pub fn store_internal_transactions(
+        &self,
+        address: String,
+        transactions: Vec<crate::blockchain::etherscan::InternalTransaction>,
+    ) {
+        self.store_cached_internal_transactions(&self.internal_transactions, address, transactions);
+    }
+
+    /// Get the internal transactions of a single transaction from cache
+    pub fn get_internal_transactions_by_hash(
+        &self,
+        tx_hash: &str,
+    ) -> Option<Vec<crate::blockchain::etherscan::InternalTransaction>> {
+        self.get_cached_internal_transactions(&self.internal_txns_by_hash, tx_hash)
+    }
+
+    /// Store the internal transactions of a single transaction in cache
+    pub fn store_internal_transactions_by_hash(
+        &self,
+        tx_hash: String,
+        transactions: Vec<crate::blockchain::etherscan::InternalTransaction>,
+    ) {
+        self.store_cached_internal_transactions(&self.internal_txns_by_hash, tx_hash, transactions);
+    }
+
+    /// Read internal transactions keyed by address or transaction hash
+    fn get_cached_internal_transactions(
+        &self,
+        lru: &Mutex<LruCache<String, CacheEntry<CachedInternalTransactions>>>,
+        key: &str,
     ) -> Option<Vec<crate::blockchain::etherscan::InternalTransaction>> {
         if !self.config.cache.enabled {
             return None;
         }
 
-        let mut cache = self.internal_transactions.lock().unwrap();
-        if let Some(entry) = cache.get(address) {
+        let mut cache = lru.lock().unwrap();
+        if let Some(entry) = cache.get(key) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 // Convert from serializable format back to InternalTransaction
                 return Some(
                     entry
@@ -508,16 +890,18 @@ impl CacheManager {
                         .collect(),
                 );
             } else {
-                cache.pop(address);
+                cache.pop(key);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
-    /// Store internal transactions in cache
-    pub fn store_internal_transactions(
+    /// Store internal transactions keyed by address or transaction hash
+    fn store_cached_internal_transactions(
         &self,
-        address: String,
+        lru: &Mutex<LruCache<String, CacheEntry<CachedInternalTransactions>>>,
+        key: String,
         transactions: Vec<crate::blockchain::etherscan::InternalTransaction>,
     ) {
         if !self.config.cache.enabled {
@@ -542,7 +926,7 @@ impl CacheManager {
 
         let entry = CacheEntry {
             data: CachedInternalTransactions {
-                address: address.clone(),
+                address: key.clone(),
                 transactions: serializable_txns,
                 last_updated: std::time::SystemTime::now()
                     .duration_since(std::time::UNIX_EPOCH)
@@ -553,11 +937,11 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.internal_transactions_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::InternalTransactions),
         };
 
-        let mut cache = self.internal_transactions.lock().unwrap();
-        cache.put(address, entry);
+        let mut cache = lru.lock().unwrap();
+        cache.put(key, entry);
     }
 
     /// Get ENS name from cache
@@ -569,11 +953,13 @@ impl CacheManager {
         let mut cache = self.ens_names.lock().unwrap();
         if let Some(entry) = cache.get(address) {
             if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
                 return Some(entry.data.ens_name.clone());
             } else {
                 cache.pop(address);
             }
         }
+        crate::metrics::global().record_cache_miss();
         None
     }
 
@@ -596,16 +982,588 @@ impl CacheManager {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs(),
-            ttl_seconds: self.config.cache.ens_names_ttl_seconds,
+            ttl_seconds: self.ttl(CacheType::EnsNames),
         };
 
         let mut cache = self.ens_names.lock().unwrap();
         cache.put(address, entry);
     }
 
+    /// Get the address an ENS name resolves to from cache
+    ///
+    /// `Some(None)` records a name known not to resolve.
+    pub fn get_ens_address(&self, name: &str) -> Option<Option<String>> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.ens_addresses.lock().unwrap();
+        if let Some(entry) = cache.get(name) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.address.clone());
+            } else {
+                cache.pop(name);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store the address an ENS name resolves to in cache
+    pub fn store_ens_address(&self, name: String, address: Option<String>) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let now = unix_now();
+        let entry = CacheEntry {
+            data: CachedEnsAddress {
+                name: name.clone(),
+                address,
+                last_updated: now,
+            },
+            timestamp: now,
+            ttl_seconds: self.ttl(CacheType::EnsNames),
+        };
+
+        let mut cache = self.ens_addresses.lock().unwrap();
+        cache.put(name, entry);
+    }
+
+    /// Get address name tags from cache
+    pub fn get_address_tags(&self, address: &str) -> Option<Vec<AddressTag>> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.address_tags.lock().unwrap();
+        if let Some(entry) = cache.get(address) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            } else {
+                cache.pop(address);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store address name tags in cache
+    pub fn store_address_tags(&self, address: String, tags: Vec<AddressTag>) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: tags,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: ADDRESS_TAGS_TTL_SECONDS,
+        };
+
+        let mut cache = self.address_tags.lock().unwrap();
+        cache.put(address, entry);
+    }
+
+    /// Get a recorded self-destruct for an address
+    pub fn get_self_destruct(&self, address: &str) -> Option<SelfDestructInfo> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.self_destructs.lock().unwrap();
+        if let Some(info) = cache.get(address) {
+            crate::metrics::global().record_cache_hit();
+            return Some(info.clone());
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store a self-destruct for an address (no TTL, the state is final)
+    pub fn store_self_destruct(&self, address: String, info: SelfDestructInfo) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let mut cache = self.self_destructs.lock().unwrap();
+        cache.put(address, info);
+    }
+
+    /// Get the recorded deployment of a contract
+    pub fn get_contract_creation(&self, address: &str) -> Option<ContractCreationInfo> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.contract_creations.lock().unwrap();
+        if let Some(info) = cache.get(address) {
+            crate::metrics::global().record_cache_hit();
+            return Some(info.clone());
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store the deployment of a contract (no TTL, it never changes)
+    pub fn store_contract_creation(&self, address: String, info: ContractCreationInfo) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let mut cache = self.contract_creations.lock().unwrap();
+        cache.put(address, info);
+    }
+
+    /// Get the ETH burned in an inclusive block range from cache
+    pub fn get_burned_eth(&self, start: u64, end: u64) -> Option<f64> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.burned_eth.lock().unwrap();
+        if let Some(burned) = cache.get(&(start, end)) {
+            crate::metrics::global().record_cache_hit();
+            return Some(*burned);
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store the ETH burned in a block range (no TTL, past blocks are final)
+    pub fn store_burned_eth(&self, start: u64, end: u64, burned: f64) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let mut cache = self.burned_eth.lock().unwrap();
+        cache.put((start, end), burned);
+    }
+
+    /// Get a block range summary from cache
+    pub fn get_block_range_summary(&self, start: u64, end: u64) -> Option<BlockRangeSummary> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.block_range_summaries.lock().unwrap();
+        if let Some(entry) = cache.get(&(start, end)) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            } else {
+                cache.pop(&(start, end));
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store a block range summary in cache
+    pub fn store_block_range_summary(&self, start: u64, end: u64, summary: BlockRangeSummary) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: summary,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: self.ttl(CacheType::Blocks),
+        };
+
+        let mut cache = self.block_range_summaries.lock().unwrap();
+        cache.put((start, end), entry);
+    }
+
+    /// Get NFT metadata from cache, keyed by `contract:token_id`
+    pub fn get_nft_metadata(&self, key: &str) -> Option<NftMetadata> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.nft_metadata.lock().unwrap();
+        if let Some(entry) = cache.get(key) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            } else {
+                cache.pop(key);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store NFT metadata in cache
+    pub fn store_nft_metadata(&self, key: String, metadata: NftMetadata) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: metadata,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: NFT_METADATA_TTL_SECONDS,
+        };
+
+        let mut cache = self.nft_metadata.lock().unwrap();
+        cache.put(key, entry);
+    }
+
+    /// Get block rewards from cache
+    pub fn get_block_rewards(&self, block_number: u64) -> Option<BlockRewardInfo> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.block_rewards.lock().unwrap();
+        if let Some(entry) = cache.get(&block_number) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            } else {
+                cache.pop(&block_number);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store block rewards in cache
+    pub fn store_block_rewards(&self, block_number: u64, rewards: BlockRewardInfo) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: rewards,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: BLOCK_REWARDS_TTL_SECONDS,
+        };
+
+        let mut cache = self.block_rewards.lock().unwrap();
+        cache.put(block_number, entry);
+    }
+
+    /// Get a contract's verified source from cache
+    ///
+    /// `Some(None)` means the contract was recently found to be unverified.
+    pub fn get_contract_source(&self, address: &str) -> Option<Option<ContractSource>> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.contract_sources.lock().unwrap();
+        if let Some(entry) = cache.get(address) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            } else {
+                cache.pop(address);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store a contract's verified source, or `None` if it is unverified
+    pub fn store_contract_source(&self, address: String, source: Option<ContractSource>) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let ttl_seconds = if source.is_some() {
+            CONTRACT_SOURCE_TTL_SECONDS
+        } else {
+            UNVERIFIED_CONTRACT_TTL_SECONDS
+        };
+        let entry = CacheEntry {
+            data: source,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds,
+        };
+
+        let mut cache = self.contract_sources.lock().unwrap();
+        cache.put(address, entry);
+    }
+
+    /// Get the Flashbots bundle of a transaction from cache
+    ///
+    /// `Some(None)` means the transaction was recently found outside any bundle.
+    pub fn get_mev_bundle(&self, tx_hash: &str) -> Option<Option<MevInfo>> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let key = tx_hash.to_lowercase();
+        let mut cache = self.mev_bundles.lock().unwrap();
+        if let Some(entry) = cache.get(&key) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            } else {
+                cache.pop(&key);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store the Flashbots bundle of a transaction, or `None` if it had none
+    pub fn store_mev_bundle(&self, tx_hash: &str, bundle: Option<MevInfo>) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: bundle,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: MEV_BUNDLE_TTL_SECONDS,
+        };
+
+        let mut cache = self.mev_bundles.lock().unwrap();
+        cache.put(tx_hash.to_lowercase(), entry);
+    }
+
+    /// Get the floor price of an NFT collection; `Some(None)` means the
+    /// collection is known to have no floor
+    pub fn get_nft_floor_price(&self, contract_address: &str) -> Option<Option<f64>> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let key = contract_address.to_lowercase();
+        let mut cache = self.nft_floor_prices.lock().unwrap();
+        if let Some(entry) = cache.get(&key) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data);
+            } else {
+                cache.pop(&key);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store the floor price of an NFT collection, or `None` if it has none
+    pub fn store_nft_floor_price(&self, contract_address: &str, floor_price: Option<f64>) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: floor_price,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: NFT_FLOOR_PRICE_TTL_SECONDS,
+        };
+
+        let mut cache = self.nft_floor_prices.lock().unwrap();
+        cache.put(contract_address.to_lowercase(), entry);
+    }
+
+    /// Get the ETH price from cache
+    pub fn get_eth_price(&self) -> Option<EthPrice> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.eth_price.lock().unwrap();
+        if let Some(ref entry) = *cache {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data.clone());
+            }
+            *cache = None;
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store the ETH price in cache
+    pub fn store_eth_price(&self, price: EthPrice) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: price,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: ETH_PRICE_TTL_SECONDS,
+        };
+
+        *self.eth_price.lock().unwrap() = Some(entry);
+    }
+
+    /// Get a token price from cache
+    pub fn get_token_price(&self, contract_address: &str, vs_currency: &str) -> Option<f64> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let key = format!("{}:{}", contract_address.to_lowercase(), vs_currency);
+        let mut cache = self.prices.lock().unwrap();
+        if let Some(entry) = cache.get(&key) {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data);
+            } else {
+                cache.pop(&key);
+            }
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store a token price in cache
+    pub fn store_token_price(&self, contract_address: &str, vs_currency: &str, price: f64) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: price,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            ttl_seconds: TOKEN_PRICE_TTL_SECONDS,
+        };
+
+        let key = format!("{}:{}", contract_address.to_lowercase(), vs_currency);
+        self.prices.lock().unwrap().put(key, entry);
+    }
+
+    /// Get the finality heads from cache
+    pub fn get_block_finality(&self) -> Option<BlockFinalityInfo> {
+        if !self.config.cache.enabled {
+            return None;
+        }
+
+        let mut cache = self.block_finality.lock().unwrap();
+        if let Some(ref entry) = *cache {
+            if !self.is_expired(entry) {
+                crate::metrics::global().record_cache_hit();
+                return Some(entry.data);
+            }
+            *cache = None;
+        }
+        crate::metrics::global().record_cache_miss();
+        None
+    }
+
+    /// Store the finality heads in cache
+    pub fn store_block_finality(&self, info: BlockFinalityInfo) {
+        if !self.config.cache.enabled {
+            return;
+        }
+
+        let entry = CacheEntry {
+            data: info,
+            timestamp: unix_now(),
+            ttl_seconds: BLOCK_FINALITY_TTL_SECONDS,
+        };
+
+        *self.block_finality.lock().unwrap() = Some(entry);
+    }
+
+    /// Remove the blocks in `from..=to` and their transactions after a re-org
+    pub fn invalidate_block_range(&self, from: u64, to: u64) {
+        let mut blocks = self.blocks.lock().unwrap();
+        for block_number in from..=to {
+            blocks.pop(&block_number);
+        }
+        drop(blocks);
+
+        let mut blocks_by_hash = self.blocks_by_hash.lock().unwrap();
+        let orphaned: Vec<String> = blocks_by_hash
+            .iter()
+            .filter(|(_, entry)| {
+                entry
+                    .data
+                    .number
+                    .is_some_and(|n| (from..=to).contains(&n.as_u64()))
+            })
+            .map(|(hash, _)| hash.clone())
+            .collect();
+        for hash in &orphaned {
+            blocks_by_hash.pop(hash);
+        }
+        drop(blocks_by_hash);
+
+        let mut transactions = self.transactions.lock().unwrap();
+        let orphaned: Vec<String> = transactions
+            .iter()
+            .filter(|(_, entry)| {
+                entry
+                    .data
+                    .block_number
+                    .is_some_and(|n| (from..=to).contains(&n.as_u64()))
+            })
+            .map(|(hash, _)| hash.clone())
+            .collect();
+        for hash in &orphaned {
+            transactions.pop(hash);
+        }
+    }
+
+    /// Remove everything cached about `address` so its next lookup refetches
+    ///
+    /// Entries are keyed by the address as looked up or in lowercase; both
+    /// are removed.
+    pub fn evict_address(&self, address: &str) {
+        for key in [address.to_string(), address.to_lowercase()] {
+            self.addresses.lock().unwrap().pop(&key);
+            self.contracts.lock().unwrap().pop(&key);
+            self.tokens.lock().unwrap().pop(&key);
+            self.address_transactions.lock().unwrap().pop(&key);
+            self.token_transfers.lock().unwrap().pop(&key);
+            self.token_balances.lock().unwrap().pop(&key);
+            self.internal_transactions.lock().unwrap().pop(&key);
+            self.ens_names.lock().unwrap().pop(&key);
+            self.address_tags.lock().unwrap().pop(&key);
+            self.contract_sources.lock().unwrap().pop(&key);
+        }
+    }
+
+    /// Remove a cached transaction and its internal transactions
+    pub fn evict_transaction(&self, tx_hash: &str) {
+        for key in [tx_hash.to_string(), tx_hash.to_lowercase()] {
+            self.transactions.lock().unwrap().pop(&key);
+            self.internal_txns_by_hash.lock().unwrap().pop(&key);
+            self.mev_bundles.lock().unwrap().pop(&key);
+        }
+    }
+
     /// Clear all caches
     pub fn clear_all(&self) {
         self.blocks.lock().unwrap().clear();
+        self.blocks_by_hash.lock().unwrap().clear();
         self.transactions.lock().unwrap().clear();
         self.addresses.lock().unwrap().clear();
         self.contracts.lock().unwrap().clear();
@@ -614,7 +1572,176 @@ impl CacheManager {
         self.token_transfers.lock().unwrap().clear();
         self.token_balances.lock().unwrap().clear();
         self.internal_transactions.lock().unwrap().clear();
+        self.internal_txns_by_hash.lock().unwrap().clear();
         self.ens_names.lock().unwrap().clear();
+        self.ens_addresses.lock().unwrap().clear();
+        self.address_tags.lock().unwrap().clear();
+        self.self_destructs.lock().unwrap().clear();
+        self.contract_creations.lock().unwrap().clear();
+        self.burned_eth.lock().unwrap().clear();
+        self.block_range_summaries.lock().unwrap().clear();
+        self.nft_metadata.lock().unwrap().clear();
+        self.block_rewards.lock().unwrap().clear();
+        self.contract_sources.lock().unwrap().clear();
+        self.mev_bundles.lock().unwrap().clear();
+        self.nft_floor_prices.lock().unwrap().clear();
+        *self.eth_price.lock().unwrap() = None;
+        self.prices.lock().unwrap().clear();
+        *self.block_finality.lock().unwrap() = None;
+        *self.last_cleared.lock().unwrap() = Some(unix_now());
+    }
+
+    /// Remove expired entries from every TTL cache, returning how many were removed
+    pub fn clear_expired(&self) -> usize {
+        let now = unix_now();
+        let removed = self.remove_all_expired(now);
+        *self.last_cleared.lock().unwrap() = Some(now);
+        removed
+    }
+
+    /// Pop entries that expired before `now` from every TTL cache
+    fn remove_all_expired(&self, now: u64) -> usize {
+        remove_expired(&self.blocks, now)
+            + remove_expired(&self.blocks_by_hash, now)
+            + remove_expired(&self.transactions, now)
+            + remove_expired(&self.addresses, now)
+            + remove_expired(&self.contracts, now)
+            + remove_expired(&self.tokens, now)
+            + remove_expired(&self.address_transactions, now)
+            + remove_expired(&self.token_transfers, now)
+            + remove_expired(&self.token_balances, now)
+            + remove_expired(&self.internal_transactions, now)
+            + remove_expired(&self.internal_txns_by_hash, now)
+            + remove_expired(&self.ens_names, now)
+            + remove_expired(&self.ens_addresses, now)
+            + remove_expired(&self.address_tags, now)
+            + remove_expired(&self.block_range_summaries, now)
+            + remove_expired(&self.nft_metadata, now)
+            + remove_expired(&self.block_rewards, now)
+            + remove_expired(&self.contract_sources, now)
+            + remove_expired(&self.mev_bundles, now)
+            + remove_expired(&self.nft_floor_prices, now)
+            + remove_expired(&self.prices, now)
+            + remove_expired_single(&self.eth_price, now)
+            + remove_expired_single(&self.block_finality, now)
+    }
+
+    /// Inspect the entry stored under a prefixed key such as `block:12345`,
+    /// `address:0x...` or `ens:0x...`
+    ///
+    /// Returns `None` unless `debug_mode` is enabled, since measuring the entry
+    /// means serializing it. Entries are peeked, so LRU order and hit/miss
+    /// metrics are left untouched.
+    pub fn get_cache_entry_debug_info(&self, key: &str) -> Option<CacheDebugInfo> {
+        if !self.config.debug_mode {
+            return None;
+        }
+
+        let (prefix, id) = key.split_once(':')?;
+        match prefix {
+            "block" => peek_debug_info(&self.blocks, &id.parse::<u64>().ok()?, key),
+            "block_hash" => peek_str_debug_info(&self.blocks_by_hash, id, key),
+            "tx" => peek_str_debug_info(&self.transactions, id, key),
+            "address" => peek_str_debug_info(&self.addresses, id, key),
+            "contract" => peek_str_debug_info(&self.contracts, id, key),
+            "token" => peek_str_debug_info(&self.tokens, id, key),
+            "address_txs" => peek_str_debug_info(&self.address_transactions, id, key),
+            "token_transfers" => peek_str_debug_info(&self.token_transfers, id, key),
+            "token_balances" => peek_str_debug_info(&self.token_balances, id, key),
+            "internal_txs" => peek_str_debug_info(&self.internal_transactions, id, key),
+            "internal_txs_by_hash" => peek_str_debug_info(&self.internal_txns_by_hash, id, key),
+            "ens" => peek_str_debug_info(&self.ens_names, id, key),
+            "ens_address" => peek_str_debug_info(&self.ens_addresses, id, key),
+            "tags" => peek_str_debug_info(&self.address_tags, id, key),
+            "nft" => peek_str_debug_info(&self.nft_metadata, id, key),
+            "block_rewards" => peek_debug_info(&self.block_rewards, &id.parse::<u64>().ok()?, key),
+            "source" => peek_str_debug_info(&self.contract_sources, id, key),
+            "price" => peek_str_debug_info(&self.prices, id, key),
+            "mev" => peek_str_debug_info(&self.mev_bundles, id, key),
+            "floor" => peek_str_debug_info(&self.nft_floor_prices, id, key),
+            "range" => {
+                let (start, end) = id.split_once('-')?;
+                let range: BlockRange = (start.trim().parse().ok()?, end.trim().parse().ok()?);
+                peek_debug_info(&self.block_range_summaries, &range, key)
+            }
+            _ => None,
+        }
+    }
+
+    /// Inspect every entry matching `query`
+    ///
+    /// A prefixed key matches at most one entry; a bare value such as an
+    /// address is looked up under every prefix.
+    pub fn find_cache_entries_debug_info(&self, query: &str) -> Vec<CacheDebugInfo> {
+        let query = query.trim();
+        let is_prefixed = query
+            .split_once(':')
+            .is_some_and(|(prefix, _)| CACHE_KEY_PREFIXES.contains(&prefix));
+        if is_prefixed {
+            return self.get_cache_entry_debug_info(query).into_iter().collect();
+        }
+
+        CACHE_KEY_PREFIXES
+            .iter()
+            .filter_map(|prefix| self.get_cache_entry_debug_info(&format!("{}:{}", prefix, query)))
+            .collect()
+    }
+
+    /// Describe every cache as JSON for the settings screen
+    ///
+    /// Each cache lists its entry count, the creation time of its oldest
+    /// entry (`null` for caches whose entries never expire) and an approximate
+    /// memory use of `size_of` the stored entry times the count; heap data
+    /// such as strings is not counted. Hits and misses are process-wide.
+    pub fn export_stats_to_json(&self) -> serde_json::Value {
+        let caches = vec![
+            ttl_cache_stats("blocks", &self.blocks),
+            ttl_cache_stats("blocks_by_hash", &self.blocks_by_hash),
+            ttl_cache_stats("transactions", &self.transactions),
+            ttl_cache_stats("addresses", &self.addresses),
+            ttl_cache_stats("contracts", &self.contracts),
+            ttl_cache_stats("tokens", &self.tokens),
+            ttl_cache_stats("address_transactions", &self.address_transactions),
+            ttl_cache_stats("token_transfers", &self.token_transfers),
+            ttl_cache_stats("token_balances", &self.token_balances),
+            ttl_cache_stats("internal_transactions", &self.internal_transactions),
+            ttl_cache_stats("internal_txns_by_hash", &self.internal_txns_by_hash),
+            ttl_cache_stats("ens_names", &self.ens_names),
+            ttl_cache_stats("ens_addresses", &self.ens_addresses),
+            ttl_cache_stats("address_tags", &self.address_tags),
+            permanent_cache_stats("self_destructs", &self.self_destructs),
+            permanent_cache_stats("contract_creations", &self.contract_creations),
+            permanent_cache_stats("burned_eth", &self.burned_eth),
+            ttl_cache_stats("block_range_summaries", &self.block_range_summaries),
+            ttl_cache_stats("nft_metadata", &self.nft_metadata),
+            ttl_cache_stats("block_rewards", &self.block_rewards),
+            ttl_cache_stats("contract_sources", &self.contract_sources),
+            ttl_cache_stats("mev_bundles", &self.mev_bundles),
+            ttl_cache_stats("nft_floor_prices", &self.nft_floor_prices),
+            single_cache_stats("eth_price", &self.eth_price),
+            ttl_cache_stats("prices", &self.prices),
+            single_cache_stats("block_finality", &self.block_finality),
+        ];
+
+        let total_entries: u64 = caches.iter().filter_map(|c| c["entries"].as_u64()).sum();
+        let total_memory_bytes: u64 = caches
+            .iter()
+            .filter_map(|c| c["approx_memory_bytes"].as_u64())
+            .sum();
+        let metrics = crate::metrics::global().snapshot();
+        let lookups = metrics.cache_hits + metrics.cache_misses;
+        let hit_ratio = (lookups > 0).then(|| metrics.cache_hits as f64 / lookups as f64);
+
+        serde_json::json!({
+            "enabled": self.config.cache.enabled,
+            "caches": caches,
+            "total_entries": total_entries,
+            "total_approx_memory_bytes": total_memory_bytes,
+            "hits": metrics.cache_hits,
+            "misses": metrics.cache_misses,
+            "hit_ratio": hit_ratio,
+            "last_cleared": *self.last_cleared.lock().unwrap(),
+        })
     }
 
     /// Get cache statistics
@@ -654,3 +1781,195 @@ impl CacheManager {
         }
     }
 }
+
+/// Current unix time in seconds
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// TTL for a new address entry given the entry it replaces, capped at `max_ttl`
+fn adaptive_address_ttl(
+    previous: Option<&CacheEntry<AddressInfo>>,
+    info: &AddressInfo,
+    max_ttl: u64,
+) -> u64 {
+    let min_ttl = MIN_ADDRESS_TTL_SECONDS.min(max_ttl);
+    match previous {
+        None => max_ttl,
+        Some(previous)
+            if previous.data.balance != info.balance
+                || previous.data.transaction_count != info.transaction_count =>
+        {
+            (previous.ttl_seconds / 2).clamp(min_ttl, max_ttl)
+        }
+        Some(previous) => previous
+            .ttl_seconds
+            .saturating_mul(2)
+            .clamp(min_ttl, max_ttl),
+    }
+}
+
+/// Pop every entry that expired before `now`, returning how many were removed
+fn remove_expired<K, T>(cache: &Mutex<LruCache<K, CacheEntry<T>>>, now: u64) -> usize
+where
+    K: std::hash::Hash + Eq + Clone,
+{
+    let mut cache = cache.lock().unwrap();
+    let expired: Vec<K> = cache
+        .iter()
+        .filter(|(_, entry)| now > entry.timestamp + entry.ttl_seconds)
+        .map(|(key, _)| key.clone())
+        .collect();
+    for key in &expired {
+        cache.pop(key);
+    }
+    expired.len()
+}
+
+/// Drop a single-entry cache's entry if it expired before `now`
+fn remove_expired_single<T>(cache: &Mutex<Option<CacheEntry<T>>>, now: u64) -> usize {
+    let mut cache = cache.lock().unwrap();
+    if cache
+        .as_ref()
+        .is_some_and(|entry| now > entry.timestamp + entry.ttl_seconds)
+    {
+        *cache = None;
+        return 1;
+    }
+    0
+}
+
+/// Stats of a cache holding at most one entry
+fn single_cache_stats<T>(name: &str, cache: &Mutex<Option<CacheEntry<T>>>) -> serde_json::Value {
+    let cache = cache.lock().unwrap();
+    let entries = usize::from(cache.is_some());
+    serde_json::json!({
+        "name": name,
+        "entries": entries,
+        "oldest_entry": cache.as_ref().map(|entry| entry.timestamp),
+        "approx_memory_bytes": entries * std::mem::size_of::<CacheEntry<T>>(),
+    })
+}
+
+/// Stats of a cache whose entries expire
+fn ttl_cache_stats<K, T>(name: &str, cache: &Mutex<LruCache<K, CacheEntry<T>>>) -> serde_json::Value
+where
+    K: std::hash::Hash + Eq,
+{
+    let cache = cache.lock().unwrap();
+    let oldest = cache.iter().map(|(_, entry)| entry.timestamp).min();
+    serde_json::json!({
+        "name": name,
+        "entries": cache.len(),
+        "oldest_entry": oldest,
+        "approx_memory_bytes": cache.len() * std::mem::size_of::<CacheEntry<T>>(),
+    })
+}
+
+/// Stats of a cache whose entries never expire and carry no timestamp
+fn permanent_cache_stats<K, V>(name: &str, cache: &Mutex<LruCache<K, V>>) -> serde_json::Value
+where
+    K: std::hash::Hash + Eq,
+{
+    let cache = cache.lock().unwrap();
+    serde_json::json!({
+        "name": name,
+        "entries": cache.len(),
+        "oldest_entry": null,
+        "approx_memory_bytes": cache.len() * std::mem::size_of::<V>(),
+    })
+}
+
+/// Describe the entry stored under `id` without touching its LRU position
+fn peek_debug_info<K, T>(
+    cache: &Mutex<LruCache<K, CacheEntry<T>>>,
+    id: &K,
+    key: &str,
+) -> Option<CacheDebugInfo>
+where
+    K: std::hash::Hash + Eq,
+    T: serde::Serialize,
+{
+    cache
+        .lock()
+        .unwrap()
+        .peek(id)
+        .map(|entry| entry_debug_info(key, entry))
+}
+
+/// Like `peek_debug_info`, also trying the lowercased id since address keyed
+/// caches store either the input as typed or lowercased
+fn peek_str_debug_info<T: serde::Serialize>(
+    cache: &Mutex<LruCache<String, CacheEntry<T>>>,
+    id: &str,
+    key: &str,
+) -> Option<CacheDebugInfo> {
+    peek_debug_info(cache, &id.to_string(), key)
+        .or_else(|| peek_debug_info(cache, &id.to_lowercase(), key))
+}
+
+/// Describe a cache entry, measuring its data as serialized JSON
+fn entry_debug_info<T: serde::Serialize>(key: &str, entry: &CacheEntry<T>) -> CacheDebugInfo {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let expires_at = entry.timestamp + entry.ttl_seconds;
+    CacheDebugInfo {
+        key: key.to_string(),
+        data_size_bytes: serde_json::to_vec(&entry.data).map_or(0, |bytes| bytes.len()),
+        created_at: entry.timestamp,
+        ttl_seconds: entry.ttl_seconds,
+        expires_at,
+        is_expired: now > expires_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn address_entry(
+        balance: &str,
+        transaction_count: u64,
+        ttl_seconds: u64,
+    ) -> CacheEntry<AddressInfo> {
+        CacheEntry {
+            data: AddressInfo {
+                address: "0xabc".to_string(),
+                balance: balance.to_string(),
+                transaction_count,
+                pending_balance: balance.to_string(),
+                is_contract: false,
+                last_updated: 0,
+            },
+            timestamp: 0,
+            ttl_seconds,
+        }
+    }
+
+    #[test]
+    fn test_adaptive_address_ttl() {
+        let max = 1800;
+        let fresh = address_entry("1", 5, 0).data;
+        assert_eq!(adaptive_address_ttl(None, &fresh, max), max);
+
+        // Changes halve the TTL down to the minimum
+        let previous = address_entry("2", 5, 40);
+        assert_eq!(adaptive_address_ttl(Some(&previous), &fresh, max), 20);
+        let previous = address_entry("1", 4, 15);
+        assert_eq!(
+            adaptive_address_ttl(Some(&previous), &fresh, max),
+            MIN_ADDRESS_TTL_SECONDS
+        );
+
+        // Unchanged data doubles it up to the configured maximum
+        let previous = address_entry("1", 5, 40);
+        assert_eq!(adaptive_address_ttl(Some(&previous), &fresh, max), 80);
+        let previous = address_entry("1", 5, 1500);
+        assert_eq!(adaptive_address_ttl(Some(&previous), &fresh, max), max);
+    }
+}
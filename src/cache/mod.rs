@@ -4,8 +4,11 @@
 //! to improve performance and reduce API calls.
 
 pub mod manager;
+pub mod persistence;
 pub mod types;
 
 // Re-export commonly used types and structs
 pub use manager::CacheManager;
-pub use types::{AddressInfo, CacheEntry, CacheStats, ContractInfo, TokenInfo};
+pub use types::{
+    AddressInfo, CacheDebugInfo, CacheEntry, CacheStats, CacheType, ContractInfo, TokenInfo,
+};
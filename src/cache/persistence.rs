@@ -0,0 +1,225 @@
+//! Flat file store for cache entries
+//!
+//! Each cache namespace is written to `{chain_id}/{name}.json` in the cache
+//! directory as a list of `(key, entry)` pairs, least recently used first, so
+//! loading them in order restores the LRU order. Keeping one directory per
+//! chain means entries of one network are never loaded on another.
+
+use super::types::CacheEntry;
+use crate::error::{Error, Result};
+use lru::LruCache;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Directory holding the cache files of one chain
+pub fn chain_dir(cache_dir: &Path, chain_id: u64) -> PathBuf {
+    cache_dir.join(chain_id.to_string())
+}
+
+/// A cache that can be written to and read back from its namespace file
+pub trait PersistedCache {
+    /// Write the entries to `{dir}/{name}.json`
+    fn save(&self, dir: &Path, name: &str) -> Result<()>;
+
+    /// Read `{dir}/{name}.json`, skipping entries expired at `now`
+    fn load(&self, dir: &Path, name: &str, now: u64) -> Result<usize>;
+}
+
+/// A cache whose entries carry a TTL
+pub struct Expiring<'a, K: Hash + Eq, T>(pub &'a Mutex<LruCache<K, CacheEntry<T>>>);
+
+/// A cache of entries that never go stale
+pub struct Permanent<'a, K: Hash + Eq, V>(pub &'a Mutex<LruCache<K, V>>);
+
+impl<K, T> PersistedCache for Expiring<'_, K, T>
+where
+    K: Hash + Eq + Serialize + DeserializeOwned,
+    T: Serialize + DeserializeOwned,
+{
+    fn save(&self, dir: &Path, name: &str) -> Result<()> {
+        save_cache(dir, name, self.0)
+    }
+
+    fn load(&self, dir: &Path, name: &str, now: u64) -> Result<usize> {
+        load_ttl_cache(dir, name, self.0, now)
+    }
+}
+
+impl<K, V> PersistedCache for Permanent<'_, K, V>
+where
+    K: Hash + Eq + Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    fn save(&self, dir: &Path, name: &str) -> Result<()> {
+        save_cache(dir, name, self.0)
+    }
+
+    fn load(&self, dir: &Path, name: &str, _now: u64) -> Result<usize> {
+        load_cache(dir, name, self.0)
+    }
+}
+
+/// File holding the entries of one cache namespace
+fn namespace_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{}.json", name))
+}
+
+/// Write a cache's entries to `{dir}/{name}.json`
+pub fn save_cache<K, V>(dir: &Path, name: &str, cache: &Mutex<LruCache<K, V>>) -> Result<()>
+where
+    K: Hash + Eq + Serialize,
+    V: Serialize,
+{
+    let cache = cache.lock().unwrap();
+    let entries: Vec<(&K, &V)> = cache.iter().rev().collect();
+    let path = namespace_path(dir, name);
+    let file = std::fs::File::create(&path)?;
+    serde_json::to_writer(std::io::BufWriter::new(file), &entries)
+        .map_err(|e| Error::parse(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+/// Read `{dir}/{name}.json` into a cache, returning how many entries were read
+///
+/// A missing file is an empty namespace.
+pub fn load_cache<K, V>(dir: &Path, name: &str, cache: &Mutex<LruCache<K, V>>) -> Result<usize>
+where
+    K: Hash + Eq + DeserializeOwned,
+    V: DeserializeOwned,
+{
+    load_filtered(dir, name, cache, |_| true)
+}
+
+/// Like [`load_cache`] for caches whose entries expire, skipping entries
+/// already expired at `now`
+pub fn load_ttl_cache<K, T>(
+    dir: &Path,
+    name: &str,
+    cache: &Mutex<LruCache<K, CacheEntry<T>>>,
+    now: u64,
+) -> Result<usize>
+where
+    K: Hash + Eq + DeserializeOwned,
+    T: DeserializeOwned,
+{
+    load_filtered(dir, name, cache, |entry| {
+        now <= entry.timestamp + entry.ttl_seconds
+    })
+}
+
+fn load_filtered<K, V>(
+    dir: &Path,
+    name: &str,
+    cache: &Mutex<LruCache<K, V>>,
+    keep: impl Fn(&V) -> bool,
+) -> Result<usize>
+where
+    K: Hash + Eq + DeserializeOwned,
+    V: DeserializeOwned,
+{
+    let path = namespace_path(dir, name);
+    if !path.exists() {
+        return Ok(0);
+    }
+    let file = std::fs::File::open(&path)?;
+    let entries: Vec<(K, V)> = serde_json::from_reader(std::io::BufReader::new(file))
+        .map_err(|e| Error::parse(format!("Invalid cache file {}: {}", path.display(), e)))?;
+
+    let mut cache = cache.lock().unwrap();
+    let mut loaded = 0;
+    for (key, value) in entries.into_iter().filter(|(_, value)| keep(value)) {
+        cache.put(key, value);
+        loaded += 1;
+    }
+    Ok(loaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::AddressInfo;
+    use std::num::NonZeroUsize;
+
+    fn address_entry(address: &str, timestamp: u64) -> CacheEntry<AddressInfo> {
+        CacheEntry {
+            data: AddressInfo {
+                address: address.to_string(),
+                balance: "1000".to_string(),
+                transaction_count: 7,
+                pending_balance: "1000".to_string(),
+                is_contract: false,
+                last_updated: timestamp,
+            },
+            timestamp,
+            ttl_seconds: 60,
+        }
+    }
+
+    #[test]
+    fn test_address_entries_round_trip_and_expire() {
+        let dir = std::env::temp_dir().join(format!("warpscan-cache-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let cache = Mutex::new(LruCache::new(NonZeroUsize::new(10).unwrap()));
+        {
+            let mut entries = cache.lock().unwrap();
+            entries.put("0xold".to_string(), address_entry("0xold", 1_000));
+            entries.put("0xnew".to_string(), address_entry("0xnew", 2_000));
+        }
+        save_cache(&dir, "addresses", &cache).unwrap();
+
+        // At t=2030 only the newer entry is still within its 60s TTL
+        let reloaded: Mutex<LruCache<String, CacheEntry<AddressInfo>>> =
+            Mutex::new(LruCache::new(NonZeroUsize::new(10).unwrap()));
+        let loaded = load_ttl_cache(&dir, "addresses", &reloaded, 2_030).unwrap();
+        assert_eq!(loaded, 1);
+        let mut reloaded = reloaded.lock().unwrap();
+        let entry = reloaded.get("0xnew").unwrap();
+        assert_eq!(entry.data.transaction_count, 7);
+        assert_eq!(entry.ttl_seconds, 60);
+        assert!(reloaded.get("0xold").is_none());
+        drop(reloaded);
+
+        // Nothing survives once the newer entry expires too
+        let expired: Mutex<LruCache<String, CacheEntry<AddressInfo>>> =
+            Mutex::new(LruCache::new(NonZeroUsize::new(10).unwrap()));
+        assert_eq!(
+            load_ttl_cache(&dir, "addresses", &expired, 2_061).unwrap(),
+            0
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_entries_stay_with_their_chain() {
+        let root = std::env::temp_dir().join(format!("warpscan-chain-test-{}", std::process::id()));
+        let mainnet = chain_dir(&root, 1);
+        let sepolia = chain_dir(&root, 11_155_111);
+        std::fs::create_dir_all(&mainnet).unwrap();
+        std::fs::create_dir_all(&sepolia).unwrap();
+
+        let cache = Mutex::new(LruCache::new(NonZeroUsize::new(10).unwrap()));
+        cache.lock().unwrap().put((1u64, 2u64), 0.5f64);
+        Permanent(&cache).save(&mainnet, "burned_eth").unwrap();
+
+        let reloaded: Mutex<LruCache<(u64, u64), f64>> =
+            Mutex::new(LruCache::new(NonZeroUsize::new(10).unwrap()));
+        assert_eq!(
+            Permanent(&reloaded)
+                .load(&sepolia, "burned_eth", 0)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            Permanent(&reloaded)
+                .load(&mainnet, "burned_eth", 0)
+                .unwrap(),
+            1
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -2,8 +2,89 @@
 //!
 //! This module defines the data structures used for caching blockchain data.
 
+use crate::config::CacheConfig;
 use serde::{Deserialize, Serialize};
 
+/// Cache namespace with its own configurable TTL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CacheType {
+    /// Fallback `ttl_seconds`
+    Default,
+    Blocks,
+    Transactions,
+    Addresses,
+    Contracts,
+    AddressTransactions,
+    TokenTransfers,
+    TokenBalances,
+    InternalTransactions,
+    EnsNames,
+}
+
+impl CacheType {
+    /// Every namespace, in settings screen order
+    pub const ALL: [CacheType; 10] = [
+        CacheType::Default,
+        CacheType::Blocks,
+        CacheType::Transactions,
+        CacheType::Addresses,
+        CacheType::Contracts,
+        CacheType::AddressTransactions,
+        CacheType::TokenTransfers,
+        CacheType::TokenBalances,
+        CacheType::InternalTransactions,
+        CacheType::EnsNames,
+    ];
+
+    /// Name shown on the settings screen
+    pub fn label(&self) -> &'static str {
+        match self {
+            CacheType::Default => "Default",
+            CacheType::Blocks => "Blocks",
+            CacheType::Transactions => "Transactions",
+            CacheType::Addresses => "Addresses",
+            CacheType::Contracts => "Contracts",
+            CacheType::AddressTransactions => "Address transactions",
+            CacheType::TokenTransfers => "Token transfers",
+            CacheType::TokenBalances => "Token balances",
+            CacheType::InternalTransactions => "Internal transactions",
+            CacheType::EnsNames => "ENS names",
+        }
+    }
+
+    /// The `*_ttl_seconds` field of this namespace in the cache config
+    pub fn config_ttl_mut(self, config: &mut CacheConfig) -> &mut u64 {
+        match self {
+            CacheType::Default => &mut config.ttl_seconds,
+            CacheType::Blocks => &mut config.block_ttl_seconds,
+            CacheType::Transactions => &mut config.transaction_ttl_seconds,
+            CacheType::Addresses => &mut config.address_ttl_seconds,
+            CacheType::Contracts => &mut config.contract_ttl_seconds,
+            CacheType::AddressTransactions => &mut config.address_transactions_ttl_seconds,
+            CacheType::TokenTransfers => &mut config.token_transfers_ttl_seconds,
+            CacheType::TokenBalances => &mut config.token_balances_ttl_seconds,
+            CacheType::InternalTransactions => &mut config.internal_transactions_ttl_seconds,
+            CacheType::EnsNames => &mut config.ens_names_ttl_seconds,
+        }
+    }
+
+    /// TTL of this namespace in the cache config
+    pub fn config_ttl(self, config: &CacheConfig) -> u64 {
+        match self {
+            CacheType::Default => config.ttl_seconds,
+            CacheType::Blocks => config.block_ttl_seconds,
+            CacheType::Transactions => config.transaction_ttl_seconds,
+            CacheType::Addresses => config.address_ttl_seconds,
+            CacheType::Contracts => config.contract_ttl_seconds,
+            CacheType::AddressTransactions => config.address_transactions_ttl_seconds,
+            CacheType::TokenTransfers => config.token_transfers_ttl_seconds,
+            CacheType::TokenBalances => config.token_balances_ttl_seconds,
+            CacheType::InternalTransactions => config.internal_transactions_ttl_seconds,
+            CacheType::EnsNames => config.ens_names_ttl_seconds,
+        }
+    }
+}
+
 /// Cache entry with timestamp for TTL management
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheEntry<T> {
@@ -18,6 +99,9 @@ pub struct AddressInfo {
     pub address: String,
     pub balance: String, // Using String to avoid U256 serialization issues
     pub transaction_count: u64,
+    /// Balance in wei at the pending block; empty in entries from older versions
+    #[serde(default)]
+    pub pending_balance: String,
     pub is_contract: bool,
     pub last_updated: u64,
 }
@@ -49,6 +133,9 @@ pub struct TokenInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SerializableTokenTransfer {
     pub token_id: Option<String>,
+    /// Empty in entries from older versions
+    #[serde(default)]
+    pub contract_address: String,
     pub txn_hash: String,
     pub from: String,
     pub to: String,
@@ -80,6 +167,8 @@ pub struct SerializableTokenBalance {
     pub symbol: String,
     pub decimals: u8,
     pub balance: f64,
+    #[serde(default)]
+    pub token_type: Option<String>,
 }
 
 /// Serializable address transaction for caching
@@ -136,8 +225,30 @@ pub struct CachedEnsName {
     pub last_updated: u64,
 }
 
+/// Forward ENS resolution for caching
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedEnsAddress {
+    pub name: String,
+    /// `None` records a name that does not resolve
+    pub address: Option<String>,
+    pub last_updated: u64,
+}
+
+/// Inspection details of a single cache entry (debug mode only)
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheDebugInfo {
+    /// Prefixed key, e.g. `block:12345`
+    pub key: String,
+    /// Size of the entry's data serialized as JSON
+    pub data_size_bytes: usize,
+    pub created_at: u64,
+    pub ttl_seconds: u64,
+    pub expires_at: u64,
+    pub is_expired: bool,
+}
+
 /// Cache statistics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CacheStats {
     pub blocks_count: usize,
     pub transactions_count: usize,
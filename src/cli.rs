@@ -0,0 +1,66 @@
+//! Command line interface
+//!
+//! By default WarpScan starts the TUI. With `--output json` it runs a single
+//! query, prints the result to stdout and exits, which makes it scriptable.
+
+use clap::{Parser, ValueEnum};
+
+/// Terminal-based Ethereum blockchain explorer
+#[derive(Debug, Clone, Parser)]
+#[command(name = "warpscan", version, about)]
+pub struct Cli {
+    /// Output mode
+    #[arg(long, value_enum, default_value_t = OutputFormat::Tui)]
+    pub output: OutputFormat,
+
+    /// What to query in JSON mode (inferred from the other flags when omitted)
+    #[arg(long, value_enum)]
+    pub query: Option<QueryType>,
+
+    /// Address to look up
+    #[arg(long)]
+    pub address: Option<String>,
+
+    /// Transaction hash to look up
+    #[arg(long)]
+    pub transaction: Option<String>,
+
+    /// Block number to look up (latest block when omitted)
+    #[arg(long)]
+    pub block: Option<u64>,
+
+    /// Open a `warpscan://address|tx|block/...` deep link on startup
+    #[arg(long, value_name = "URL")]
+    pub open: Option<String>,
+}
+
+/// Output mode
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Tui,
+    Json,
+}
+
+/// Query type for headless mode
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum QueryType {
+    Address,
+    Transaction,
+    Block,
+    Gas,
+}
+
+impl Cli {
+    /// Resolve the query type, inferring it from the given flags
+    pub fn query_type(&self) -> QueryType {
+        self.query.unwrap_or(if self.address.is_some() {
+            QueryType::Address
+        } else if self.transaction.is_some() {
+            QueryType::Transaction
+        } else if self.block.is_some() {
+            QueryType::Block
+        } else {
+            QueryType::Gas
+        })
+    }
+}
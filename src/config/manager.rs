@@ -4,7 +4,15 @@
 //! application configuration.
 
 use super::node_detection;
-use super::types::{CacheConfig, Config, GasConfig, NetworkConfig, UiConfig};
+use super::types::Network;
+use super::types::{
+    CacheConfig, Config, GasConfig, NetworkConfig, RateLimit, UiConfig,
+    DEFAULT_CLEANUP_INTERVAL_SECS, DEFAULT_DEBOUNCE_DELAY_MS, DEFAULT_GAS_HISTORY_SAMPLES,
+    DEFAULT_IPFS_GATEWAY, DEFAULT_MAX_RANGE_BLOCKS, DEFAULT_MAX_RETRIES, MAX_CACHE_TTL_SECONDS,
+    MIN_CACHE_TTL_SECONDS,
+};
+use crate::blockchain::EtherscanChain;
+use crate::cache::CacheType;
 use crate::error::{Error, Result};
 use dotenvy::dotenv;
 use std::path::PathBuf;
@@ -18,6 +26,7 @@ impl Default for Config {
                 chain_id: 1,
                 timeout_seconds: 30,
                 node_type: Some("custom".to_string()),
+                max_retries: DEFAULT_MAX_RETRIES,
             },
             cache: CacheConfig {
                 enabled: true,
@@ -35,18 +44,74 @@ impl Default for Config {
                 internal_transactions_ttl_seconds: 3600, // 1 hour
                 // Very long TTL for ENS (rarely changes)
                 ens_names_ttl_seconds: 86400, // 24 hours
+                cleanup_interval_secs: DEFAULT_CLEANUP_INTERVAL_SECS,
+                re_org_detection: true,
+                persist_to_disk: true,
             },
             ui: UiConfig {
                 theme: "dark".to_string(),
                 refresh_interval_ms: 5000,
                 max_results_per_page: 20,
                 log_level: "info".to_string(),
+                debounce_delay_ms: DEFAULT_DEBOUNCE_DELAY_MS,
+                enable_prefetch: true,
             },
             gas: GasConfig {
                 update_interval_seconds: 15,
                 history_days: 7,
+                max_range_blocks: DEFAULT_MAX_RANGE_BLOCKS,
+                history_samples: DEFAULT_GAS_HISTORY_SAMPLES,
             },
+            ws_rpc_url: None,
             etherscan_api_key: std::env::var("ETHERSCAN_API_KEY").ok(),
+            etherscan_rate_limit: Some(RateLimit {
+                requests_per_second: 5,
+            }),
+            metrics_port: None,
+            saved_deployers: Vec::new(),
+            debug_mode: false,
+            simulation: None,
+            ipfs_gateway: DEFAULT_IPFS_GATEWAY.to_string(),
+        }
+    }
+}
+
+impl Network {
+    /// Chains offered by the network switcher on the settings screen
+    pub const PRESET_CHAINS: [EtherscanChain; 7] = [
+        EtherscanChain::Ethereum,
+        EtherscanChain::Sepolia,
+        EtherscanChain::Goerli,
+        EtherscanChain::Polygon,
+        EtherscanChain::Arbitrum,
+        EtherscanChain::Optimism,
+        EtherscanChain::Base,
+    ];
+
+    /// Network settings for a known chain on its free public RPC endpoint
+    ///
+    /// Other chains get a local node URL to be edited in the config.
+    pub fn preset(chain: EtherscanChain) -> NetworkConfig {
+        let (name, rpc_url) = match chain {
+            EtherscanChain::Ethereum => ("Ethereum Mainnet", "https://eth.llamarpc.com"),
+            EtherscanChain::Sepolia => (
+                "Sepolia Testnet",
+                "https://ethereum-sepolia-rpc.publicnode.com",
+            ),
+            EtherscanChain::Goerli => ("Goerli Testnet", "https://rpc.ankr.com/eth_goerli"),
+            EtherscanChain::Polygon => ("Polygon", "https://polygon-rpc.com"),
+            EtherscanChain::Arbitrum => ("Arbitrum One", "https://arb1.arbitrum.io/rpc"),
+            EtherscanChain::Optimism => ("OP Mainnet", "https://mainnet.optimism.io"),
+            EtherscanChain::Base => ("Base", "https://mainnet.base.org"),
+            EtherscanChain::Custom(_) => ("Custom Network", "http://127.0.0.1:8545"),
+        };
+        NetworkConfig {
+            name: name.to_string(),
+            rpc_url: rpc_url.to_string(),
+            chain_id: chain.chain_id(),
+            timeout_seconds: 30,
+            node_type: None,
+            max_retries: DEFAULT_MAX_RETRIES,
         }
     }
 }
@@ -68,12 +133,16 @@ impl Config {
             }
             Ok(config)
         } else {
-            let default_config = Config::default();
-            default_config.save()?;
-            Ok(default_config)
+            // Left unsaved so the setup wizard can write the first config
+            Ok(Config::default())
         }
     }
 
+    /// Whether no config file has been written yet
+    pub fn is_first_run() -> bool {
+        Self::config_path().is_ok_and(|path| !path.exists())
+    }
+
     /// Load configuration and auto-detect local nodes
     pub async fn load_with_auto_detect() -> Result<Self> {
         let mut config = Self::load()?;
@@ -145,6 +214,78 @@ impl Config {
         Ok(())
     }
 
+    /// Write a minimal config file with only the network and Etherscan key,
+    /// leaving every other option commented out at its default
+    pub fn save_to_disk(&self) -> Result<PathBuf> {
+        let config_path = Self::config_path()?;
+        if let Some(parent) = config_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&config_path, self.to_minimal_toml())?;
+        Ok(config_path)
+    }
+
+    /// Render the network settings and Etherscan key as a commented TOML file
+    fn to_minimal_toml(&self) -> String {
+        let quote = |value: &str| toml::Value::String(value.to_string()).to_string();
+        let mut out = String::from(
+            "# WarpScan configuration, generated by the setup wizard.\n\
+             # Options not listed here use their built-in defaults.\n\n",
+        );
+
+        match self.etherscan_api_key {
+            Some(ref key) => out.push_str(&format!("etherscan_api_key = {}\n", quote(key))),
+            None => out.push_str(
+                "# Etherscan API key (sign up at https://etherscan.io/register)\n\
+                 # etherscan_api_key = \"YOUR_API_KEY\"\n",
+            ),
+        }
+        match self.ws_rpc_url {
+            Some(ref url) => out.push_str(&format!("ws_rpc_url = {}\n", quote(url))),
+            None => out.push_str(
+                "# WebSocket endpoint for live blocks; polled over HTTP when unset\n\
+                 # ws_rpc_url = \"wss://...\"\n",
+            ),
+        }
+        out.push_str("# metrics_port = 9100\n# debug_mode = false\n\n");
+
+        out.push_str("[network]\n");
+        out.push_str(&format!("name = {}\n", quote(&self.network.name)));
+        out.push_str(&format!("rpc_url = {}\n", quote(&self.network.rpc_url)));
+        out.push_str(&format!("chain_id = {}\n", self.network.chain_id));
+        out.push_str(&format!(
+            "timeout_seconds = {}\n",
+            self.network.timeout_seconds
+        ));
+        if let Some(ref node_type) = self.network.node_type {
+            out.push_str(&format!("node_type = {}\n", quote(node_type)));
+        }
+        out.push_str(
+            "\n# [cache], [ui] and [gas] sections are optional; see the README for\n\
+             # TTLs, theme, refresh interval and gas tracker settings.\n",
+        );
+        out
+    }
+
+    /// Apply a change to the configuration file on disk
+    ///
+    /// Reads the file as stored (without environment overrides or runtime
+    /// changes such as a switched node), applies `update` and saves it.
+    pub fn update_file<F: FnOnce(&mut Config)>(update: F) -> Result<()> {
+        let config_path = Self::config_path()?;
+        let mut config = if config_path.exists() {
+            let config_str = std::fs::read_to_string(&config_path)?;
+            toml::from_str(&config_str)
+                .map_err(|e| Error::parse(format!("Failed to parse config: {}", e)))?
+        } else {
+            Config::default()
+        };
+
+        update(&mut config);
+        config.save()
+    }
+
     /// Get the configuration file path
     pub fn config_path() -> Result<PathBuf> {
         let config_dir =
@@ -153,6 +294,12 @@ impl Config {
         Ok(config_dir.join("warpscan").join("config.toml"))
     }
 
+    /// Get the path of the session state kept between runs
+    pub fn session_path() -> Result<PathBuf> {
+        let config_path = Self::config_path()?;
+        Ok(config_path.with_file_name("session.json"))
+    }
+
     /// Get the cache directory path
     pub fn cache_dir() -> Result<PathBuf> {
         let cache_dir =
@@ -161,6 +308,14 @@ impl Config {
         Ok(cache_dir.join("warpscan"))
     }
 
+    /// Get the directory for user data such as encrypted keystores
+    pub fn data_dir() -> Result<PathBuf> {
+        let data_dir =
+            dirs::data_dir().ok_or_else(|| Error::app("Could not determine data directory"))?;
+
+        Ok(data_dir.join("warpscan"))
+    }
+
     /// Get the logs directory path
     pub fn logs_dir() -> Result<PathBuf> {
         let cache_dir = Self::cache_dir()?;
@@ -168,7 +323,26 @@ impl Config {
     }
 
     /// Validate configuration
-    pub fn validate(&self) -> Result<()> {
+    ///
+    /// Cache TTLs outside `MIN_CACHE_TTL_SECONDS..=MAX_CACHE_TTL_SECONDS` are
+    /// clamped to the nearest bound with a warning rather than rejected.
+    pub fn validate(&mut self) -> Result<()> {
+        // Clamp TTLs
+        for cache in CacheType::ALL {
+            let ttl = cache.config_ttl_mut(&mut self.cache);
+            let clamped = (*ttl).clamp(MIN_CACHE_TTL_SECONDS, MAX_CACHE_TTL_SECONDS);
+            if clamped != *ttl {
+                tracing::warn!(
+                    target: "warpscan",
+                    "{} cache TTL of {}s is out of range, using {}s",
+                    cache.label(),
+                    *ttl,
+                    clamped
+                );
+                *ttl = clamped;
+            }
+        }
+
         // Validate RPC URL
         if self.network.rpc_url.is_empty() {
             return Err(Error::validation("RPC URL cannot be empty"));
@@ -189,11 +363,37 @@ impl Config {
             return Err(Error::validation("Cache size must be greater than 0"));
         }
 
-        // Validate TTL
-        if self.cache.ttl_seconds == 0 {
-            return Err(Error::validation("Cache TTL must be greater than 0"));
-        }
-
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_toml_round_trips() {
+        let mut config = Config::default();
+        config.network.rpc_url = "https://eth.llamarpc.com".to_string();
+        config.etherscan_api_key = None;
+
+        let parsed: Config = toml::from_str(&config.to_minimal_toml()).unwrap();
+        assert_eq!(parsed.network.rpc_url, "https://eth.llamarpc.com");
+        assert_eq!(parsed.network.chain_id, config.network.chain_id);
+        assert!(parsed.etherscan_api_key.is_none());
+        assert_eq!(
+            parsed.cache.block_ttl_seconds,
+            config.cache.block_ttl_seconds
+        );
+    }
+    #[test]
+    fn test_validate_clamps_cache_ttls() {
+        let mut config = Config::default();
+        config.cache.block_ttl_seconds = 1;
+        config.cache.ens_names_ttl_seconds = 1_000_000;
+
+        config.validate().unwrap();
+        assert_eq!(config.cache.block_ttl_seconds, MIN_CACHE_TTL_SECONDS);
+        assert_eq!(config.cache.ens_names_ttl_seconds, MAX_CACHE_TTL_SECONDS);
+    }
+}
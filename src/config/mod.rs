@@ -8,4 +8,7 @@ pub mod node_detection;
 pub mod types;
 
 // Re-export commonly used types and structs
-pub use types::{CacheConfig, Config, GasConfig, Network, NetworkConfig, UiConfig};
+pub use types::{
+    CacheConfig, Config, GasConfig, Network, NetworkConfig, RateLimit, SimulationProvider,
+    UiConfig, MAX_CACHE_TTL_SECONDS, MIN_CACHE_TTL_SECONDS,
+};
@@ -5,10 +5,19 @@
 use serde::{Deserialize, Serialize};
 
 /// Main application configuration
+///
+/// Missing sections fall back to `Config::default()`, so hand-written or
+/// wizard-generated files only need the settings they change.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     /// Network configuration
     pub network: NetworkConfig,
+    /// WebSocket endpoint used to subscribe to new blocks
+    ///
+    /// When unset, `network.rpc_url` is used if it is a `ws://` or `wss://`
+    /// URL; otherwise new blocks are polled over HTTP.
+    pub ws_rpc_url: Option<String>,
     /// Cache configuration
     pub cache: CacheConfig,
     /// UI configuration
@@ -17,6 +26,53 @@ pub struct Config {
     pub gas: GasConfig,
     /// Optional Etherscan API key (overrides env if set in file)
     pub etherscan_api_key: Option<String>,
+    /// Cap on Etherscan requests; the free tier allows 5 per second
+    pub etherscan_rate_limit: Option<RateLimit>,
+    /// Optional port for the Prometheus metrics endpoint (disabled when unset)
+    pub metrics_port: Option<u16>,
+    /// Deployer addresses offered in the CREATE2 calculator quick-select list
+    #[serde(default)]
+    pub saved_deployers: Vec<String>,
+    /// Enable developer tooling such as the cache inspector
+    #[serde(default)]
+    pub debug_mode: bool,
+    /// Hosted simulation API used by the simulation screen instead of a
+    /// plain `eth_call`, when set
+    pub simulation: Option<SimulationProvider>,
+    /// HTTP gateway used to fetch `ipfs://` NFT metadata, ending in `/ipfs/`
+    pub ipfs_gateway: String,
+}
+
+/// Default for [`Config::ipfs_gateway`]
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+/// Hosted transaction simulation API, the `[simulation]` table
+///
+/// ```toml
+/// [simulation]
+/// provider = "tenderly"
+/// account = "my-account"
+/// project = "my-project"
+/// access_key = "..."
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "provider", rename_all = "lowercase")]
+pub enum SimulationProvider {
+    /// Tenderly simulation API
+    Tenderly {
+        account: String,
+        project: String,
+        access_key: String,
+    },
+    /// `alchemy_simulateAssetChanges` on an Alchemy RPC URL (mainnet only)
+    Alchemy { url: String },
+}
+
+/// Request rate limit for an HTTP API
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimit {
+    /// Requests allowed in any one-second window; 0 disables the limit
+    pub requests_per_second: u8,
 }
 
 /// Network configuration
@@ -32,6 +88,16 @@ pub struct NetworkConfig {
     pub timeout_seconds: u64,
     /// Preferred node type (anvil, hardhat, infura, alchemy, custom)
     pub node_type: Option<String>,
+    /// Times a failed RPC request is retried, with exponential back-off
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+/// Default for [`NetworkConfig::max_retries`]
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
 }
 
 /// Cache configuration
@@ -61,12 +127,43 @@ pub struct CacheConfig {
     pub internal_transactions_ttl_seconds: u64,
     /// ENS names cache TTL in seconds (very long TTL - ENS names rarely change)
     pub ens_names_ttl_seconds: u64,
+    /// How often expired entries are swept from memory, in seconds
+    #[serde(default = "default_cleanup_interval_secs")]
+    pub cleanup_interval_secs: u64,
+    /// Drop cached blocks and transactions of re-orged blocks when a new
+    /// block does not build on the cached chain
+    #[serde(default = "default_re_org_detection")]
+    pub re_org_detection: bool,
+    /// Keep cache entries in the cache directory across restarts
+    #[serde(default = "default_persist_to_disk")]
+    pub persist_to_disk: bool,
+}
+
+/// Shortest cache TTL accepted from the config
+pub const MIN_CACHE_TTL_SECONDS: u64 = 10;
+
+/// Longest cache TTL accepted from the config
+pub const MAX_CACHE_TTL_SECONDS: u64 = 86_400;
+
+/// Default for [`CacheConfig::cleanup_interval_secs`]
+pub const DEFAULT_CLEANUP_INTERVAL_SECS: u64 = 300;
+
+fn default_cleanup_interval_secs() -> u64 {
+    DEFAULT_CLEANUP_INTERVAL_SECS
+}
+
+fn default_re_org_detection() -> bool {
+    true
+}
+
+fn default_persist_to_disk() -> bool {
+    true
 }
 
 /// UI configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiConfig {
-    /// UI theme (dark, light, ethereum)
+    /// UI theme (dark, light, high_contrast, dracula, ethereum)
     pub theme: String,
     /// Refresh interval in milliseconds
     pub refresh_interval_ms: u64,
@@ -74,6 +171,24 @@ pub struct UiConfig {
     pub max_results_per_page: usize,
     /// Log level for the application
     pub log_level: String,
+    /// Pause after the last keystroke before search input is validated
+    #[serde(default = "default_debounce_delay_ms")]
+    pub debounce_delay_ms: u64,
+    /// Warm the address cache for addresses shown on screen; turn off to
+    /// save API quota
+    #[serde(default = "default_enable_prefetch")]
+    pub enable_prefetch: bool,
+}
+
+/// Default for [`UiConfig::debounce_delay_ms`]
+pub const DEFAULT_DEBOUNCE_DELAY_MS: u64 = 300;
+
+fn default_debounce_delay_ms() -> u64 {
+    DEFAULT_DEBOUNCE_DELAY_MS
+}
+
+fn default_enable_prefetch() -> bool {
+    true
 }
 
 /// Gas tracking configuration
@@ -83,6 +198,26 @@ pub struct GasConfig {
     pub update_interval_seconds: u64,
     /// Number of days to keep gas price history
     pub history_days: u32,
+    /// Largest block range accepted when summing burned fees
+    #[serde(default = "default_max_range_blocks")]
+    pub max_range_blocks: u64,
+    /// Gas price samples kept for the gas tracker sparklines
+    #[serde(default = "default_history_samples")]
+    pub history_samples: usize,
+}
+
+/// Default for [`GasConfig::max_range_blocks`]
+pub const DEFAULT_MAX_RANGE_BLOCKS: u64 = 1000;
+
+fn default_max_range_blocks() -> u64 {
+    DEFAULT_MAX_RANGE_BLOCKS
+}
+
+/// Default for [`GasConfig::history_samples`]
+pub const DEFAULT_GAS_HISTORY_SAMPLES: usize = 60;
+
+fn default_history_samples() -> usize {
+    DEFAULT_GAS_HISTORY_SAMPLES
 }
 
 /// Supported Ethereum networks
@@ -2,57 +2,149 @@
 //!
 //! This module provides convenient functions for creating specific error types.
 
-use super::types::Error;
+use super::types::{Error, NetworkError};
+use ethers::providers::{MiddlewareError, ProviderError};
 
 /// Helper functions for creating specific error types
 impl Error {
-    /// Create a network error
-    pub fn network<S: Into<String>>(msg: S) -> Self {
-        Error::Network(msg.into())
+    /// Create a network error from a message or a [`NetworkError`] with
+    /// the HTTP status
+    pub fn network<E: Into<NetworkError>>(err: E) -> Self {
+        Error::Network(err.into()).recorded()
     }
 
     /// Create a cache error
     pub fn cache<S: Into<String>>(msg: S) -> Self {
-        Error::Cache(msg.into())
+        Error::Cache(msg.into()).recorded()
     }
 
     /// Create a wallet error
     pub fn wallet<S: Into<String>>(msg: S) -> Self {
-        Error::Wallet(msg.into())
+        Error::Wallet(msg.into()).recorded()
     }
 
     /// Create a contract error
     pub fn contract<S: Into<String>>(msg: S) -> Self {
-        Error::Contract(msg.into())
+        Error::Contract(msg.into()).recorded()
     }
 
     /// Create a UI error
     pub fn ui<S: Into<String>>(msg: S) -> Self {
-        Error::Ui(msg.into())
+        Error::Ui(msg.into()).recorded()
     }
 
     /// Create a blockchain error
     pub fn blockchain<S: Into<String>>(msg: S) -> Self {
-        Error::Blockchain(msg.into())
+        Error::Blockchain(msg.into()).recorded()
+    }
+
+    /// Create an error from a failed provider request
+    ///
+    /// A JSON-RPC error response (e.g. "execution reverted") is the node's
+    /// final answer and becomes a blockchain error; a request that never got
+    /// an answer is a network error, so it is retried.
+    pub fn provider(err: ProviderError) -> Self {
+        let transport = err.as_error_response().is_none()
+            && matches!(
+                err,
+                ProviderError::JsonRpcClientError(_) | ProviderError::HTTPError(_)
+            );
+        if transport {
+            Error::network(err.to_string())
+        } else {
+            Error::blockchain(err.to_string())
+        }
     }
 
     /// Create a parse error
     pub fn parse<S: Into<String>>(msg: S) -> Self {
-        Error::Parse(msg.into())
+        Error::Parse(msg.into()).recorded()
     }
 
     /// Create a serialization/deserialization error
     pub fn serialization(err: serde_json::Error) -> Self {
-        Error::Serialization(err)
+        Error::Serialization(err).recorded()
     }
 
     /// Create an application error
     pub fn app<S: Into<String>>(msg: S) -> Self {
-        Error::App(msg.into())
+        Error::App(msg.into()).recorded()
     }
 
     /// Create a validation error
     pub fn validation<S: Into<String>>(msg: S) -> Self {
-        Error::Validation(msg.into())
+        Error::Validation(msg.into()).recorded()
+    }
+
+    /// Create an error for a feature that needs a paid API plan
+    pub fn premium_required<S: Into<String>>(msg: S) -> Self {
+        Error::PremiumRequired(msg.into()).recorded()
+    }
+
+    /// Create an error for a request refused by an API rate limit
+    pub fn ratelimit(retry_after_secs: Option<u64>) -> Self {
+        Error::RateLimit { retry_after_secs }.recorded()
+    }
+
+    /// Create an error for an operation that did not finish in time
+    pub fn timeout<S: Into<String>>(operation: S) -> Self {
+        Error::Timeout {
+            operation: operation.into(),
+        }
+        .recorded()
+    }
+
+    /// Create an error for a missing resource, e.g. `not_found("Block", "123")`
+    pub fn not_found<R: Into<String>, I: Into<String>>(resource_type: R, id: I) -> Self {
+        Error::NotFound {
+            resource_type: resource_type.into(),
+            id: id.into(),
+        }
+        .recorded()
+    }
+
+    /// Short, stable name of the error variant (used for metrics labels)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::Network(_) => "network",
+            Error::Cache(_) => "cache",
+            Error::Wallet(_) => "wallet",
+            Error::Contract(_) => "contract",
+            Error::Config(_) => "config",
+            Error::Ui(_) => "ui",
+            Error::Blockchain(_) => "blockchain",
+            Error::Serialization(_) => "serialization",
+            Error::Io(_) => "io",
+            Error::Parse(_) => "parse",
+            Error::App(_) => "app",
+            Error::Validation(_) => "validation",
+            Error::PremiumRequired(_) => "premium_required",
+            Error::RateLimit { .. } => "rate_limit",
+            Error::Timeout { .. } => "timeout",
+            Error::NotFound { .. } => "not_found",
+            Error::EventChannelClosed => "event_channel_closed",
+        }
+    }
+
+    /// Seconds the API asked to wait before retrying a rate-limited request
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        match self {
+            Error::RateLimit { retry_after_secs } => *retry_after_secs,
+            _ => None,
+        }
+    }
+
+    /// Whether the failure may be transient, so the request is worth retrying
+    ///
+    /// Only transport failures and timeouts qualify; an error the node
+    /// answered with would come back the same on every attempt.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Error::Network(_) | Error::Timeout { .. })
+    }
+
+    /// Count this error in the metrics registry
+    fn recorded(self) -> Self {
+        crate::metrics::global().record_error(self.kind());
+        self
     }
 }
@@ -7,4 +7,4 @@ pub mod helpers;
 pub mod types;
 
 // Re-export commonly used types
-pub use types::{Error, Result};
+pub use types::{Error, NetworkError, Result};
@@ -2,6 +2,7 @@
 //!
 //! This module defines the main error types used throughout the application.
 
+use std::fmt;
 use thiserror::Error;
 
 /// Main error type for WarpScan
@@ -9,7 +10,7 @@ use thiserror::Error;
 pub enum Error {
     /// Network connection errors
     #[error("Network error: {0}")]
-    Network(String),
+    Network(NetworkError),
 
     /// Cache-related errors
     #[error("Cache error: {0}")]
@@ -55,10 +56,80 @@ pub enum Error {
     #[error("Validation error: {0}")]
     Validation(String),
 
+    /// Feature needs a paid API plan
+    #[error("Premium API required: {0}")]
+    PremiumRequired(String),
+
+    /// An API refused the request for exceeding its rate limit
+    #[error("Rate limited: API rate limit reached{}", retry_after_text(.retry_after_secs))]
+    RateLimit {
+        /// Wait the API asked for, from its `Retry-After` header
+        retry_after_secs: Option<u64>,
+    },
+
+    /// An operation did not finish in time
+    #[error("Timed out: {operation}")]
+    Timeout { operation: String },
+
+    /// The requested block, transaction or other resource does not exist
+    #[error("Not found: {resource_type} {id}")]
+    NotFound { resource_type: String, id: String },
+
     /// Event channel closed error
     #[error("Event channel closed")]
     EventChannelClosed,
 }
 
+/// Details of a failed network request
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkError {
+    pub message: String,
+    /// HTTP status of the response, when one came back
+    pub status: Option<u16>,
+}
+
+impl NetworkError {
+    /// Failure answered with an HTTP error status
+    pub fn with_status<S: Into<String>>(message: S, status: u16) -> Self {
+        Self {
+            message: message.into(),
+            status: Some(status),
+        }
+    }
+}
+
+impl From<String> for NetworkError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            status: None,
+        }
+    }
+}
+
+impl From<&str> for NetworkError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
+}
+
+impl fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.status {
+            Some(status) => write!(f, "{} (HTTP {})", self.message, status),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// `, retry in Ns` when some of the API's requested wait is left
+fn retry_after_text(retry_after_secs: &Option<u64>) -> String {
+    match retry_after_secs {
+        Some(0) => ", retry now".to_string(),
+        Some(secs) => format!(", retry in {}s", secs),
+        None => String::new(),
+    }
+}
+
 /// Convenient Result type alias
 pub type Result<T> = std::result::Result<T, Error>;
@@ -5,9 +5,11 @@
 
 pub mod blockchain;
 pub mod cache;
+pub mod cli;
 pub mod config;
 pub mod error;
 pub mod logging;
+pub mod metrics;
 pub mod models;
 pub mod ui;
 pub mod wallet;
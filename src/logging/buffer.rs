@@ -0,0 +1,187 @@
+//! In-memory log buffer backing the log viewer screen
+//!
+//! A tracing layer copies every event into a bounded buffer so recent logs can
+//! be searched and filtered without leaving the TUI.
+
+use chrono::{DateTime, Local};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::Write as _;
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+/// Entries kept before the oldest ones are dropped
+const LOG_BUFFER_CAPACITY: usize = 1000;
+
+/// Process-wide log buffer
+static LOG_BUFFER: OnceLock<Mutex<LogBuffer>> = OnceLock::new();
+
+/// Lock the shared log buffer
+pub fn log_buffer() -> MutexGuard<'static, LogBuffer> {
+    LOG_BUFFER
+        .get_or_init(|| Mutex::new(LogBuffer::default()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// A single captured log event
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+impl LogEntry {
+    /// One-line rendering used for display, search, copy and export
+    pub fn line(&self) -> String {
+        format!(
+            "{} {:<5} {}: {}",
+            self.timestamp.format("%H:%M:%S%.3f"),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Bounded buffer of recent log entries with the log viewer's filters
+#[derive(Debug)]
+pub struct LogBuffer {
+    entries: VecDeque<LogEntry>,
+    /// Case-insensitive text the shown entries must contain
+    pub search_query: Option<String>,
+    /// Levels shown in the log viewer
+    pub level_filter: HashSet<Level>,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::with_capacity(LOG_BUFFER_CAPACITY),
+            search_query: None,
+            level_filter: [Level::ERROR, Level::WARN, Level::INFO, Level::DEBUG]
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+impl LogBuffer {
+    /// Append an entry, dropping the oldest one when full
+    pub fn push(&mut self, entry: LogEntry) {
+        if self.entries.len() == LOG_BUFFER_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Number of entries held, regardless of filters
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no entries have been captured yet
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries matching both the level filter and the search query, oldest first
+    pub fn filtered_entries(&self) -> impl Iterator<Item = &LogEntry> {
+        let query = self
+            .search_query
+            .as_deref()
+            .filter(|q| !q.is_empty())
+            .map(str::to_lowercase);
+        self.entries.iter().filter(move |entry| {
+            self.level_filter.contains(&entry.level)
+                && query
+                    .as_ref()
+                    .is_none_or(|q| entry.line().to_lowercase().contains(q))
+        })
+    }
+
+    /// Show or hide entries of `level`
+    pub fn toggle_level(&mut self, level: Level) {
+        if !self.level_filter.remove(&level) {
+            self.level_filter.insert(level);
+        }
+    }
+}
+
+/// Tracing layer that records events into the shared [`LogBuffer`]
+pub struct LogBufferLayer;
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        log_buffer().push(LogEntry {
+            timestamp: Local::now(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+        });
+    }
+}
+
+/// Collects the `message` field followed by any other fields as `key=value`
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.message, " {}={:?}", field.name(), value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.message, " {}={}", field.name(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(level: Level, message: &str) -> LogEntry {
+        LogEntry {
+            timestamp: Local::now(),
+            level,
+            target: "warpscan".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_filtered_entries_applies_level_and_query() {
+        let mut buffer = LogBuffer::default();
+        buffer.push(entry(Level::INFO, "Cache hit for block 42"));
+        buffer.push(entry(Level::WARN, "Etherscan rate limit hit"));
+        buffer.push(entry(Level::INFO, "Fetched gas prices"));
+
+        buffer.search_query = Some("HIT".to_string());
+        assert_eq!(buffer.filtered_entries().count(), 2);
+
+        buffer.toggle_level(Level::WARN);
+        let messages: Vec<&str> = buffer
+            .filtered_entries()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(messages, vec!["Cache hit for block 42"]);
+    }
+}
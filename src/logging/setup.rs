@@ -2,10 +2,12 @@
 //!
 //! This module handles the setup and initialization of the tracing framework.
 
+use super::buffer::LogBufferLayer;
 use crate::config::Config;
 use std::fs::OpenOptions;
 use tracing::Level;
 use tracing_subscriber::{
+    filter::LevelFilter,
     fmt::{self, format::FmtSpan},
     layer::SubscriberExt,
     util::SubscriberInitExt,
@@ -41,8 +43,10 @@ pub fn init_logging(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
         .open(log_file)?;
 
     // Console layer for terminal output - only show warnings and errors
-    // Info logs are suppressed to avoid interfering with the TUI
+    // Info logs are suppressed to avoid interfering with the TUI, and stderr
+    // keeps stdout clean for `--output json`
     let console_layer = fmt::layer()
+        .with_writer(std::io::stderr)
         .with_target(false)
         .with_thread_ids(false)
         .with_thread_names(false)
@@ -63,10 +67,14 @@ pub fn init_logging(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
         .with_ansi(false)
         .with_filter(EnvFilter::from_default_env().add_directive(Level::DEBUG.into()));
 
+    // In-memory layer for the log viewer screen
+    let buffer_layer = LogBufferLayer.with_filter(LevelFilter::DEBUG);
+
     // Initialize the subscriber
     tracing_subscriber::registry()
         .with(console_layer)
         .with(file_layer)
+        .with(buffer_layer)
         .try_init()
         .map_err(|e| format!("Failed to initialize tracing subscriber: {}", e))?;
     tracing::debug!("Log file location: {:?}", log_dir.join("warpscan.log"));
@@ -78,6 +86,7 @@ pub fn init_logging(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
 /// Only shows warnings and errors to avoid interfering with TUI
 pub fn init_minimal_logging() {
     let console_layer = fmt::layer()
+        .with_writer(std::io::stderr)
         .with_target(false)
         .with_thread_ids(false)
         .with_thread_names(false)
@@ -7,7 +7,10 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEventKind},
+    event::{
+        DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        KeyCode, KeyEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -15,30 +18,43 @@ use ratatui::{backend::CrosstermBackend, Terminal};
 use tokio::time::sleep;
 use tracing::{error, info, warn};
 
+use clap::Parser;
 use std::str::FromStr;
 use warpscan::{
     blockchain::{BlockchainService, SubscriptionEvent},
     cache::CacheManager,
+    cli::{Cli, OutputFormat},
     config::Config,
     error::Result,
     logging::{
         init_logging, init_minimal_logging, log_config_info, log_shutdown_info, log_startup_info,
     },
+    metrics::MetricsServer,
     ui::{
         app::{
-            events::handle_key_event, mouse::handle_mouse_event, App, AppState, ModeSelectionState,
+            events::{
+                handle_cache_keys, handle_error_popup_keys, handle_help_overlay_keys,
+                handle_history_keys, handle_key_event, handle_tracked_overlay_keys,
+            },
+            mouse::handle_mouse_event,
+            parse_deep_link, App, AppState, ModeSelectionState,
         },
+        components,
         events::{Event as AppEvent, EventHandler},
         screens,
-        theme::ThemeManager,
     },
     wallet::WalletManager,
 };
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    // Checked before loading, which does not create the file
+    let first_run = Config::is_first_run();
+    let deep_link = cli.open.as_deref().map(parse_deep_link).transpose()?;
+
     // Load configuration first with auto-detection
-    let (config, config_loaded) = match Config::load_with_auto_detect().await {
+    let (mut config, config_loaded) = match Config::load_with_auto_detect().await {
         Ok(config) => (config, true),
         Err(_) => (Config::default(), false),
     };
@@ -58,6 +74,9 @@ async fn main() -> Result<()> {
         warn!("Failed to load configuration, using defaults");
     }
 
+    if let Err(e) = config.validate() {
+        warn!("Invalid configuration: {}", e);
+    }
     log_config_info(&config);
 
     // Initialize components
@@ -65,17 +84,41 @@ async fn main() -> Result<()> {
     let blockchain_client =
         BlockchainService::new(config.clone(), Arc::new(cache_manager.clone())).await?;
     let _wallet_manager = WalletManager::new();
-    let theme_manager = ThemeManager::new();
+
+    // JSON output mode: answer a single query without starting the TUI
+    if cli.output == OutputFormat::Json {
+        let app = App::new(config.clone(), blockchain_client, cache_manager);
+        return app.run_headless(&cli).await;
+    }
+
+    // Start the Prometheus metrics endpoint when enabled in config
+    if let Some(port) = config.metrics_port {
+        if let Err(e) = MetricsServer::new(port).spawn().await {
+            warn!("Metrics server disabled: {}", e);
+        }
+    }
 
     // Initialize terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Initialize application
     let mut app = App::new(config.clone(), blockchain_client, cache_manager);
+    app.pending_deep_link = deep_link;
+    app.load_session();
+    app.load_search_history();
+    app.load_address_book();
+    if first_run {
+        app.start_setup_wizard();
+    }
 
     // Don't refresh dashboard until mode is selected
     // refresh_dashboard() will be called after mode selection
@@ -89,7 +132,16 @@ async fn main() -> Result<()> {
         // Spawn background task to forward subscription events to main event loop
         tokio::spawn(async move {
             while let Some(event) = subscription_receiver.recv().await {
-                tracing::info!(target: "warpscan", "📥 Received SubscriptionEvent: {:?}", event);
+                // The mempool and broad log filters bring hundreds of events a second;
+                // keep them out of the log
+                if matches!(
+                    event,
+                    SubscriptionEvent::PendingTransaction { .. } | SubscriptionEvent::NewLog { .. }
+                ) {
+                    tracing::trace!(target: "warpscan", "📥 Received pending transaction or log");
+                } else {
+                    tracing::info!(target: "warpscan", "📥 Received SubscriptionEvent: {:?}", event);
+                }
                 let custom_event = match event {
                     SubscriptionEvent::NewBlock {
                         block_number,
@@ -101,6 +153,18 @@ async fn main() -> Result<()> {
                             "block_hash": format!("{:#x}", block_hash)
                         }),
                     },
+                    SubscriptionEvent::SafeHead { block_number } => {
+                        warpscan::ui::events::CustomEvent::RealTimeUpdate {
+                            data_type: "safe_head".to_string(),
+                            data: serde_json::json!({ "block_number": block_number }),
+                        }
+                    }
+                    SubscriptionEvent::FinalizedHead { block_number } => {
+                        warpscan::ui::events::CustomEvent::RealTimeUpdate {
+                            data_type: "finalized_head".to_string(),
+                            data: serde_json::json!({ "block_number": block_number }),
+                        }
+                    }
                     SubscriptionEvent::NewAddressTransaction {
                         address,
                         transaction,
@@ -120,7 +184,8 @@ async fn main() -> Result<()> {
                         warpscan::ui::events::CustomEvent::RealTimeUpdate {
                             data_type: "pending_transaction".to_string(),
                             data: serde_json::json!({
-                                "transaction_hash": format!("{:#x}", transaction.hash())
+                                "transaction_hash": format!("{:#x}", transaction.hash()),
+                                "transaction": transaction
                             }),
                         }
                     }
@@ -129,7 +194,8 @@ async fn main() -> Result<()> {
                             data_type: "new_log".to_string(),
                             data: serde_json::json!({
                                 "address": format!("{:#x}", log.address),
-                                "topics": log.topics.iter().map(|t| format!("{:#x}", t)).collect::<Vec<_>>()
+                                "topics": log.topics.iter().map(|t| format!("{:#x}", t)).collect::<Vec<_>>(),
+                                "log": log
                             }),
                         }
                     }
@@ -163,17 +229,27 @@ async fn main() -> Result<()> {
     }
 
     // Main application loop
-    let result = run_app(&mut terminal, &mut app, &mut event_handler, &theme_manager).await;
+    let result = run_app(&mut terminal, &mut app, &mut event_handler).await;
 
     // Cleanup
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
+    app.save_search_history();
+    app.save_address_book();
+    // Keep the cache for the next run
+    if let Err(e) = app
+        .cache_manager
+        .flush_to_disk(app.blockchain_client.chain_id())
+    {
+        warn!("Failed to write cache to disk: {}", e);
+    }
     log_shutdown_info();
 
     if let Err(err) = result {
@@ -188,7 +264,6 @@ async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
     event_handler: &mut EventHandler,
-    theme_manager: &ThemeManager,
 ) -> Result<()> {
     loop {
         // Check if we should quit before rendering
@@ -196,9 +271,17 @@ async fn run_app<B: ratatui::backend::Backend>(
             break;
         }
 
-        // Render UI
-        let theme = theme_manager.current();
+        // Render UI (cloned so theme changes apply on the next frame)
+        let theme = &app.theme_manager.current().clone();
         terminal.draw(|frame| {
+            app.clickable_regions.clear();
+
+            // The first-run setup wizard comes before everything else
+            if app.setup_wizard.is_some() {
+                screens::render_setup_wizard(frame, app, theme);
+                return;
+            }
+
             // Show mode selection first if not yet selected
             if app.mode_selection_state == ModeSelectionState::Selecting {
                 screens::render_mode_selection(frame, app, theme);
@@ -217,19 +300,70 @@ async fn run_app<B: ratatui::backend::Backend>(
                 AppState::Settings => screens::render_settings(frame, app, theme),
                 AppState::ContractSearch => screens::render_contract_search(frame, app, theme),
                 AppState::TokenInfo => screens::render_token_info(frame, app, theme),
+                AppState::NftGallery => screens::render_nft_gallery(frame, app, theme),
                 AppState::ContractInteraction => {
                     screens::render_contract_interaction(frame, app, theme)
                 }
+                AppState::Simulation => screens::render_simulation(frame, app, theme),
+                AppState::Create2Calculator => {
+                    screens::render_create2_calculator(frame, app, theme)
+                }
                 AppState::ContractVerification => {
                     screens::render_contract_verification(frame, app, theme)
                 }
                 AppState::MultisigWallet => screens::render_multisig_wallet(frame, app, theme),
                 AppState::EventMonitor => screens::render_event_monitor(frame, app, theme),
+                AppState::MempoolMonitor => screens::render_mempool_monitor(frame, app, theme),
+                AppState::LogViewer => screens::render_log_viewer(frame, app, theme),
                 AppState::Help => screens::render_help(frame, app, theme),
                 AppState::Quit => {
                     // Should not reach here due to check above
                 }
             }
+
+            if app.show_tracked_overlay {
+                components::render_tracked_overlay(
+                    frame,
+                    frame.area(),
+                    theme,
+                    &app.tracked_transactions,
+                );
+            }
+
+            if app.show_help_overlay {
+                screens::render_help_overlay(frame, app, theme);
+            }
+
+            components::render_notifications(
+                frame,
+                frame.area(),
+                theme,
+                app.visible_notifications(),
+            );
+
+            if app.copy_notification_visible() {
+                let area = frame.area();
+                let width = 30.min(area.width);
+                let toast = ratatui::layout::Rect {
+                    x: area.x + area.width - width,
+                    y: area.y + area.height.saturating_sub(5),
+                    width,
+                    height: 5.min(area.height),
+                };
+                components::render_success(frame, toast, theme, "Copied to clipboard");
+            }
+
+            if app.error_popup_open {
+                if let Some(error) = &app.current_error {
+                    components::render_error(
+                        frame,
+                        frame.area(),
+                        theme,
+                        error,
+                        app.error_show_debug,
+                    );
+                }
+            }
         })?;
 
         // Handle events
@@ -245,6 +379,21 @@ async fn run_app<B: ratatui::backend::Backend>(
                         // Ctrl+C - do nothing or quit (user's choice)
                         continue;
                     }
+                    if handle_error_popup_keys(app, &key_event) {
+                        continue;
+                    }
+                    if handle_history_keys(app, &key_event).await {
+                        continue;
+                    }
+                    if handle_cache_keys(app, &key_event) {
+                        continue;
+                    }
+                    if handle_tracked_overlay_keys(app, &key_event) {
+                        continue;
+                    }
+                    if handle_help_overlay_keys(app, &key_event) {
+                        continue;
+                    }
                     match handle_key_event(app, key_event.code).await {
                         Ok(should_quit) => {
                             if should_quit {
@@ -252,7 +401,7 @@ async fn run_app<B: ratatui::backend::Backend>(
                             }
                         }
                         Err(e) => {
-                            app.set_error(format!("Error handling key event: {}", e));
+                            app.report_error("Error handling key event", &e);
                         }
                     }
                 }
@@ -263,12 +412,18 @@ async fn run_app<B: ratatui::backend::Backend>(
                         }
                     }
                     Err(e) => {
-                        app.set_error(format!("Error handling mouse event: {}", e));
+                        app.report_error("Error handling mouse event", &e);
                     }
                 },
+                AppEvent::Paste(text) => app.handle_paste(&text).await,
                 AppEvent::Tick => {
                     // Handle periodic updates
                     // Dashboard refresh is handled via event system (DataLoaded event)
+                    app.poll_debounce_timer();
+                    app.poll_pending_tx_status();
+                    app.poll_gas_price_history();
+                    app.prune_notifications();
+                    app.spinner_frame = app.spinner_frame.wrapping_add(1);
                 }
                 AppEvent::Custom(warpscan::ui::events::CustomEvent::RealTimeUpdate {
                     data_type,
@@ -299,6 +454,36 @@ async fn run_app<B: ratatui::backend::Backend>(
                                 }
                             }
                         }
+                        "safe_head" | "finalized_head" => {
+                            if let Some(block_number) =
+                                data.get("block_number").and_then(|v| v.as_u64())
+                            {
+                                let event = if data_type == "safe_head" {
+                                    SubscriptionEvent::SafeHead { block_number }
+                                } else {
+                                    SubscriptionEvent::FinalizedHead { block_number }
+                                };
+                                app.handle_subscription_event(event).await;
+                            }
+                        }
+                        "pending_transaction" => {
+                            if let Some(Ok(transaction)) =
+                                data.get("transaction").cloned().map(serde_json::from_value)
+                            {
+                                app.handle_subscription_event(
+                                    SubscriptionEvent::PendingTransaction { transaction },
+                                )
+                                .await;
+                            }
+                        }
+                        "new_log" => {
+                            if let Some(Ok(log)) =
+                                data.get("log").cloned().map(serde_json::from_value)
+                            {
+                                app.handle_subscription_event(SubscriptionEvent::NewLog { log })
+                                    .await;
+                            }
+                        }
                         "new_address_transaction" => {
                             // Real-time transaction for an address (from subscription manager).
                             // If the current address matches, fetch the full transaction and
@@ -357,8 +542,28 @@ async fn run_app<B: ratatui::backend::Backend>(
                 }) if operation == "dashboard_refresh_requested" => {
                     // Trigger dashboard refresh
                     // This happens after UI has updated, so it's less blocking
-                    // The yields inside refresh_dashboard help keep it somewhat responsive
-                    app.refresh_dashboard().await;
+                    // The progress bar is redrawn as each part of the load completes
+                    let theme = app.theme_manager.current().clone();
+                    let mut progress = app.dashboard_progress.subscribe();
+                    let refresh = app.refresh_dashboard();
+                    tokio::pin!(refresh);
+                    loop {
+                        tokio::select! {
+                            _ = &mut refresh => break,
+                            Ok(()) = progress.changed() => {
+                                let percent = *progress.borrow_and_update();
+                                terminal.draw(|frame| {
+                                    screens::render_dashboard_loading(frame, percent, &theme)
+                                })?;
+                            }
+                        }
+                    }
+                }
+                AppEvent::Custom(warpscan::ui::events::CustomEvent::DataLoaded {
+                    operation,
+                    data,
+                }) if operation == "nft_floor_prices" => {
+                    app.apply_nft_floor_prices(&data);
                 }
                 AppEvent::Custom(_) => {}
                 _ => {}
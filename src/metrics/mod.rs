@@ -0,0 +1,11 @@
+//! Metrics collection and export
+//!
+//! This module keeps process-wide counters for RPC, Etherscan and cache
+//! activity and can serve them in Prometheus text format over HTTP.
+
+pub mod registry;
+pub mod server;
+
+// Re-export commonly used types
+pub use registry::{global, MetricsRegistry, MetricsSnapshot};
+pub use server::MetricsServer;
@@ -0,0 +1,167 @@
+//! Metrics registry
+//!
+//! Lock-free counters shared by the blockchain service, Etherscan client and
+//! cache manager.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Process-wide metrics registry
+static REGISTRY: OnceLock<MetricsRegistry> = OnceLock::new();
+
+/// Get the shared metrics registry
+pub fn global() -> &'static MetricsRegistry {
+    REGISTRY.get_or_init(MetricsRegistry::default)
+}
+
+/// Counters for application activity
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    rpc_calls: AtomicU64,
+    etherscan_calls: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    current_block: AtomicU64,
+    errors: Mutex<BTreeMap<&'static str, u64>>,
+}
+
+/// Point-in-time copy of all metrics
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    pub rpc_calls: u64,
+    pub etherscan_calls: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub current_block: u64,
+    pub errors_by_type: BTreeMap<String, u64>,
+}
+
+impl MetricsRegistry {
+    /// Record a JSON-RPC call to the node
+    pub fn record_rpc_call(&self) {
+        self.rpc_calls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a request to the Etherscan API
+    pub fn record_etherscan_call(&self) {
+        self.etherscan_calls.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a cache lookup that returned a fresh entry
+    pub fn record_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a cache lookup that found nothing or an expired entry
+    pub fn record_cache_miss(&self) {
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an error of the given kind
+    pub fn record_error(&self, kind: &'static str) {
+        if let Ok(mut errors) = self.errors.lock() {
+            *errors.entry(kind).or_insert(0) += 1;
+        }
+    }
+
+    /// Update the latest known block number
+    pub fn set_current_block(&self, block_number: u64) {
+        self.current_block
+            .fetch_max(block_number, Ordering::Relaxed);
+    }
+
+    /// Take a snapshot of all counters
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let errors_by_type = self
+            .errors
+            .lock()
+            .map(|errors| {
+                errors
+                    .iter()
+                    .map(|(kind, count)| (kind.to_string(), *count))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        MetricsSnapshot {
+            rpc_calls: self.rpc_calls.load(Ordering::Relaxed),
+            etherscan_calls: self.etherscan_calls.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            current_block: self.current_block.load(Ordering::Relaxed),
+            errors_by_type,
+        }
+    }
+}
+
+impl MetricsSnapshot {
+    /// Render the snapshot in Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let counters = [
+            (
+                "warpscan_rpc_calls_total",
+                "Total JSON-RPC calls made to the node",
+                self.rpc_calls,
+            ),
+            (
+                "warpscan_etherscan_calls_total",
+                "Total requests made to the Etherscan API",
+                self.etherscan_calls,
+            ),
+            (
+                "warpscan_cache_hits_total",
+                "Total cache lookups that returned a fresh entry",
+                self.cache_hits,
+            ),
+            (
+                "warpscan_cache_misses_total",
+                "Total cache lookups that missed or found an expired entry",
+                self.cache_misses,
+            ),
+        ];
+
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP {} {}", name, help);
+            let _ = writeln!(out, "# TYPE {} counter", name);
+            let _ = writeln!(out, "{} {}", name, value);
+        }
+
+        let _ = writeln!(out, "# HELP warpscan_errors_total Total errors by type");
+        let _ = writeln!(out, "# TYPE warpscan_errors_total counter");
+        for (kind, count) in &self.errors_by_type {
+            let _ = writeln!(out, "warpscan_errors_total{{type=\"{}\"}} {}", kind, count);
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP warpscan_current_block Latest block number seen by the explorer"
+        );
+        let _ = writeln!(out, "# TYPE warpscan_current_block gauge");
+        let _ = writeln!(out, "warpscan_current_block {}", self.current_block);
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prometheus_output() {
+        let registry = MetricsRegistry::default();
+        registry.record_rpc_call();
+        registry.record_cache_hit();
+        registry.record_error("network");
+        registry.set_current_block(42);
+
+        let text = registry.snapshot().to_prometheus();
+        assert!(text.contains("warpscan_rpc_calls_total 1"));
+        assert!(text.contains("warpscan_cache_misses_total 0"));
+        assert!(text.contains("warpscan_errors_total{type=\"network\"} 1"));
+        assert!(text.contains("warpscan_current_block 42"));
+    }
+}
@@ -0,0 +1,80 @@
+//! Prometheus metrics HTTP endpoint
+//!
+//! A minimal HTTP/1.1 server that answers `GET /metrics` with the contents of
+//! the global metrics registry.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use super::registry::global;
+use crate::error::{Error, Result};
+
+/// Local HTTP server exposing metrics for Prometheus scraping
+#[derive(Debug, Clone, Copy)]
+pub struct MetricsServer {
+    pub port: u16,
+}
+
+impl MetricsServer {
+    /// Create a new metrics server for the given port
+    pub fn new(port: u16) -> Self {
+        Self { port }
+    }
+
+    /// Bind the listener and serve requests in a background task
+    pub async fn spawn(self) -> Result<tokio::task::JoinHandle<()>> {
+        let listener = TcpListener::bind(("127.0.0.1", self.port))
+            .await
+            .map_err(|e| {
+                Error::network(format!(
+                    "Failed to bind metrics server on port {}: {}",
+                    self.port, e
+                ))
+            })?;
+
+        tracing::info!(
+            target: "warpscan",
+            "Metrics server listening on http://127.0.0.1:{}/metrics",
+            self.port
+        );
+
+        Ok(tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        tokio::spawn(async move {
+                            if let Err(e) = handle_connection(stream).await {
+                                tracing::debug!(target: "warpscan", "Metrics request failed: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => {
+                        tracing::warn!(target: "warpscan", "Metrics server accept failed: {}", e);
+                    }
+                }
+            }
+        }))
+    }
+}
+
+/// Read a single request and write the response
+async fn handle_connection(mut stream: TcpStream) -> std::io::Result<()> {
+    let mut buf = [0u8; 1024];
+    let read = stream.read(&mut buf).await?;
+    let request = String::from_utf8_lossy(&buf[..read]);
+    let request_line = request.lines().next().unwrap_or_default();
+
+    let response = if request_line.starts_with("GET /metrics ") {
+        let body = global().snapshot().to_prometheus();
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    } else {
+        "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_string()
+    };
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
@@ -18,10 +18,18 @@ pub struct MultisigWallet {
 /// Transaction proposal for multi-signature wallets
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionProposal {
+    /// Safe transaction hash the owners sign
     pub id: String,
+    /// Multisig wallet the transaction is sent from
+    #[serde(default)]
+    pub safe_address: String,
+    /// Wallet nonce the transaction is bound to
+    #[serde(default)]
+    pub nonce: u64,
     pub to: String,
     pub value: U256,
     pub data: Vec<u8>,
+    /// Hex-encoded 65-byte owner signatures
     pub signatures: Vec<String>,
     pub executed: bool,
     pub created_at: u64,
@@ -1,12 +1,21 @@
 use ethers::types::U256;
 
 use super::super::models::{
-    AccountHistoryEntry, AddressDetails, AddressTab, AddressType, CompleteAddressData,
-    InternalTransaction, TokenInfo, TokenTransfer, TokenType,
+    AccountHistoryEntry, AddressDetails, AddressTab, AddressTransaction, AddressType,
+    CompleteAddressData, InternalTransaction, TokenInfo, TokenTransfer, TokenType,
+    TransactionStatus,
 };
 use super::core::App;
 use crate::blockchain::types::AddressTx as ServiceAddressTx;
 use crate::blockchain::types::TransactionStatus as ChainTransactionStatus;
+use crate::blockchain::{AddressTag, KnownAddresses, TagType, DEPOSIT_CONTRACT_ADDRESS};
+use crate::error::{Error, Result};
+use serde::Serialize;
+use std::io::Write;
+use std::path::Path;
+
+/// EOA balance (in ETH) from which an address is tagged as a whale
+const WHALE_BALANCE_ETH: f64 = 10_000.0;
 
 impl App {
     /// Lookup address information and populate address_data
@@ -43,6 +52,17 @@ impl App {
         };
 
         tracing::info!(target: "warpscan", "use_etherscan={} for address {}", use_etherscan, address);
+        self.set_loading_with_message(
+            "address_search",
+            format!(
+                "Fetching balance, transactions and tokens from {}...",
+                if use_etherscan {
+                    "Etherscan"
+                } else {
+                    "the local node"
+                }
+            ),
+        );
 
         // Yield control to allow event loop to process input/events
         tokio::task::yield_now().await;
@@ -57,6 +77,10 @@ impl App {
             token_transfers_result,
             token_balances_result,
             internal_transactions_result,
+            nonce_result,
+            tags_result,
+            withdrawals_result,
+            approvals_result,
         ) = tokio::join!(
             // Fetch address info (balance, transaction count, contract status) - respect mode selection
             // In Local Node mode: uses Anvil RPC directly for balance, transaction count, contract status
@@ -68,8 +92,12 @@ impl App {
             // Fetch transactions - respect mode selection
             // In Local Node mode: returns empty (RPC doesn't support per-address tx listing)
             // In Etherscan mode: uses Etherscan API
-            self.blockchain_client
-                .get_address_transactions_with_mode(address, use_etherscan),
+            self.blockchain_client.get_address_transactions_with_mode(
+                address,
+                use_etherscan,
+                1,
+                self.address_page_size()
+            ),
             // Fetch token transfers - respect mode selection
             // In Local Node mode: returns empty (local nodes don't index token transfers)
             // In Etherscan mode: uses Etherscan API
@@ -85,6 +113,34 @@ impl App {
             // In Etherscan mode: uses Etherscan API
             self.blockchain_client
                 .get_internal_transactions_with_mode(address, use_etherscan),
+            // Compare confirmed and pending nonce - always use RPC
+            self.blockchain_client.get_nonce_gap_analysis(address),
+            // Fetch Etherscan name tags - Etherscan mode only
+            async {
+                if use_etherscan {
+                    self.blockchain_client.get_address_tags(address).await
+                } else {
+                    Ok(vec![])
+                }
+            },
+            // Fetch beacon chain withdrawals - Etherscan mode only
+            async {
+                if use_etherscan {
+                    self.blockchain_client
+                        .get_staking_withdrawals(address)
+                        .await
+                } else {
+                    Ok(vec![])
+                }
+            },
+            // Fetch open ERC-20 allowances - Etherscan mode only
+            async {
+                if use_etherscan {
+                    self.blockchain_client.get_token_approvals(address).await
+                } else {
+                    Ok(vec![])
+                }
+            },
         );
 
         // Yield again after data fetching to allow UI updates
@@ -94,7 +150,7 @@ impl App {
         match address_info_result {
             Ok(address_info) => {
                 // Determine address type based on contract status
-                let address_type = if address_info.is_contract {
+                let mut address_type = if address_info.is_contract {
                     AddressType::Contract
                 } else {
                     AddressType::EOA
@@ -211,21 +267,113 @@ impl App {
                     _ => None,
                 };
 
+                // Nonce analysis is only meaningful for EOA addresses
+                let nonce_analysis = match address_type {
+                    AddressType::EOA => nonce_result.ok(),
+                    _ => None,
+                };
+
+                // A self-destructed contract has no code left and reads as an EOA,
+                // so check its internal transaction history (Etherscan mode only)
+                let self_destruct = match address_type {
+                    AddressType::EOA if use_etherscan => self
+                        .blockchain_client
+                        .detect_self_destruct_history(address)
+                        .await
+                        .unwrap_or(None),
+                    _ => None,
+                };
+
+                // Refine the classification with name tags; large EOA balances
+                // are tagged as whales locally
+                let mut tags = tags_result.unwrap_or_default();
+                if matches!(address_type, AddressType::EOA) && balance_eth >= WHALE_BALANCE_ETH {
+                    tags.push(AddressTag {
+                        label: "Whale".to_string(),
+                        tag_type: TagType::Whale,
+                    });
+                }
+                // Exchange hot wallets are recognized from the built-in list
+                // first, then from any tag naming a known exchange
+                let exchange_name = match KnownAddresses::category(address) {
+                    Some("exchange") => KnownAddresses::lookup(address).map(str::to_string),
+                    _ => tags
+                        .iter()
+                        .find_map(|t| t.exchange_name())
+                        .map(str::to_string),
+                };
+                if exchange_name.is_some() || tags.iter().any(|t| t.tag_type == TagType::Exchange) {
+                    address_type = AddressType::Exchange;
+                } else if matches!(address_type, AddressType::Contract)
+                    && tags.iter().any(|t| t.tag_type == TagType::Token)
+                {
+                    address_type = AddressType::Token;
+                }
+
+                // Depositors into the beacon chain deposit contract get their
+                // validator status looked up on beaconcha.in
+                let is_depositor = txs_result.as_ref().is_ok_and(|txs| {
+                    txs.iter()
+                        .any(|tx| tx.to.eq_ignore_ascii_case(DEPOSIT_CONTRACT_ADDRESS))
+                });
+                let validator = if is_depositor {
+                    self.blockchain_client
+                        .get_validator_info(address)
+                        .await
+                        .unwrap_or_else(|e| {
+                            tracing::warn!(target: "warpscan", "Failed to fetch validator info for {}: {}", address, e);
+                            None
+                        })
+                } else {
+                    None
+                };
+
+                // Deployer and deployment transaction (Etherscan mode only)
+                let creation = match address_type {
+                    AddressType::Contract | AddressType::Token if use_etherscan => self
+                        .blockchain_client
+                        .get_contract_creation_info(address)
+                        .await
+                        .unwrap_or_else(|e| {
+                            tracing::warn!(target: "warpscan", "Failed to fetch contract creation for {}: {}", address, e);
+                            None
+                        }),
+                    _ => None,
+                };
+
+                let withdrawals = withdrawals_result.unwrap_or_else(|e| {
+                    tracing::warn!(target: "warpscan", "Failed to fetch withdrawals for {}: {}", address, e);
+                    vec![]
+                });
+                let withdrawn_eth: f64 = withdrawals.iter().map(|w| w.amount_eth()).sum();
+                let approvals = approvals_result.unwrap_or_else(|e| {
+                    tracing::warn!(target: "warpscan", "Failed to fetch approvals for {}: {}", address, e);
+                    vec![]
+                });
+
                 // Create comprehensive address details
                 let details = AddressDetails {
                     address: address.to_string(),
                     address_type: address_type.clone(),
                     balance: balance_eth,
                     token_count: 0, // Will be updated after fetching tokens
-                    estimated_net_worth: balance_eth, // For now, just use ETH balance
+                    estimated_net_worth: balance_eth + withdrawn_eth,
                     total_transactions: address_info.transaction_count,
-                    outgoing_transfers: 0,  // TODO: Implement transfer counting
-                    total_gas_used: 0,      // TODO: Implement gas usage calculation
-                    contract_name: None,    // TODO: Implement contract name resolution
-                    contract_creator: None, // TODO: Implement contract creator lookup
-                    creation_tx_hash: None, // TODO: Implement creation tx lookup
+                    pending_balance: U256::from_dec_str(&address_info.pending_balance)
+                        .ok()
+                        .and_then(|wei| ethers::utils::format_ether(wei).parse().ok())
+                        .unwrap_or(balance_eth),
+                    outgoing_transfers: 0, // TODO: Implement transfer counting
+                    total_gas_used: 0,     // TODO: Implement gas usage calculation
+                    contract_name: exchange_name,
+                    contract_creator: creation.as_ref().map(|c| c.creator.clone()),
+                    creation_tx_hash: creation.map(|c| c.tx_hash),
                     last_activity: chrono::Utc::now().timestamp() as u64, // TODO: Get actual last activity
                     ens_name,
+                    nonce_analysis,
+                    self_destruct,
+                    tags,
+                    validator,
                 };
 
                 // Process transactions
@@ -250,91 +398,20 @@ impl App {
                     }
                 };
 
-                // OPTIMIZE: Pre-compute address lowercase once to avoid repeated conversions
-                let address_lower = address.to_lowercase();
-                let now = chrono::Utc::now().timestamp() as u64;
-
                 // Yield periodically during processing to keep UI responsive
                 tokio::task::yield_now().await;
-
-                // OPTIMIZE: Pre-allocate vectors with known capacity
-                let txs_len = txs.len();
-                let mut ui_txs = Vec::with_capacity(txs_len);
-                let mut account_history = Vec::with_capacity(txs_len);
-
-                // OPTIMIZE: Process in single pass to avoid multiple iterations
-                // Yield every 10 transactions to keep UI responsive
-                for (idx, t) in txs.iter().enumerate() {
-                    if idx % 10 == 0 {
-                        tokio::task::yield_now().await;
-                    }
-                    // Map to UI transaction model
-                    let tx_type = if t.method.is_empty() {
-                        "Transfer"
-                    } else {
-                        "Contract Call"
-                    };
-                    let status = match t.status {
-                        ChainTransactionStatus::Pending => {
-                            super::super::models::TransactionStatus::Pending
-                        }
-                        ChainTransactionStatus::Success => {
-                            super::super::models::TransactionStatus::Success
-                        }
-                        ChainTransactionStatus::Failed => {
-                            super::super::models::TransactionStatus::Failed
-                        }
-                        ChainTransactionStatus::Unknown => {
-                            super::super::models::TransactionStatus::Pending
-                        }
-                    };
-
-                    ui_txs.push(super::super::models::AddressTransaction {
-                        tx_hash: t.tx_hash.clone(),
-                        tx_type: tx_type.to_string(),
-                        method: t.method.clone(),
-                        block: t.block_number,
-                        from: t.from.clone(),
-                        to: t.to.clone(),
-                        value: t.value_eth,
-                        fee: t.fee_eth,
-                        timestamp: t.timestamp,
-                        status,
-                    });
-
-                    // OPTIMIZE: Determine action based on address (use pre-computed lowercase)
-                    // Pre-compute from/to lowercase once per transaction
-                    let from_lower = t.from.to_lowercase();
-                    let to_lower = t.to.to_lowercase();
-                    let action = if from_lower == address_lower {
-                        "Sent"
-                    } else if to_lower == address_lower {
-                        "Received"
-                    } else {
-                        "Unknown"
-                    };
-
-                    // Calculate age from timestamp (use pre-computed now)
-                    let age_seconds = now.saturating_sub(t.timestamp);
-                    let age = if age_seconds < 60 {
-                        format!("{}s ago", age_seconds)
-                    } else if age_seconds < 3600 {
-                        format!("{}m ago", age_seconds / 60)
-                    } else if age_seconds < 86400 {
-                        format!("{}h ago", age_seconds / 3600)
-                    } else {
-                        format!("{}d ago", age_seconds / 86400)
-                    };
-
-                    account_history.push(AccountHistoryEntry {
-                        age,
-                        action: action.to_string(),
-                        from: t.from.clone(),
-                        to: t.to.clone(),
-                        timestamp: t.timestamp,
-                        tx_hash: t.tx_hash.clone(),
-                    });
-                }
+                let total_pages = if use_etherscan {
+                    estimate_total_pages(
+                        1,
+                        txs.len(),
+                        self.address_page_size(),
+                        address_info.transaction_count,
+                    )
+                } else {
+                    // Local nodes return every transaction at once
+                    1
+                };
+                let (ui_txs, account_history) = map_address_transactions(address, &txs);
 
                 // Yield before processing token data
                 tokio::task::yield_now().await;
@@ -346,6 +423,7 @@ impl App {
                         for t in transfers {
                             result.push(TokenTransfer {
                                 token_id: t.token_id,
+                                contract_address: t.contract_address,
                                 txn_hash: t.txn_hash,
                                 from: t.from,
                                 to: t.to,
@@ -365,14 +443,20 @@ impl App {
                     Ok(balances) => {
                         let mut result = Vec::with_capacity(balances.len());
                         for b in balances {
+                            let token_type = b
+                                .token_type
+                                .as_deref()
+                                .map(TokenType::from_standard)
+                                .unwrap_or(TokenType::ERC20);
                             result.push(TokenInfo {
                                 contract_address: b.contract_address,
                                 name: b.name,
                                 symbol: b.symbol,
-                                token_type: TokenType::ERC20, // Default to ERC20, could be enhanced
+                                token_type,
                                 balance: b.balance,
-                                value_usd: 0.0, // TODO: Fetch USD value from price API
+                                value_usd: 0.0,
                                 decimals: b.decimals,
+                                floor_price: None,
                             });
                         }
                         result
@@ -380,6 +464,28 @@ impl App {
                     Err(_) => Vec::new(),
                 };
 
+                // Price ERC-20 balances; requests queue on the CoinGecko rate limit
+                let mut tokens = tokens;
+                let prices = futures::future::join_all(
+                    tokens.iter().filter(|t| !t.token_type.is_nft()).map(|t| {
+                        self.blockchain_client
+                            .get_token_price_usd(&t.contract_address)
+                    }),
+                )
+                .await;
+                for (token, price) in tokens
+                    .iter_mut()
+                    .filter(|t| !t.token_type.is_nft())
+                    .zip(prices)
+                {
+                    match price {
+                        Ok(price) => token.value_usd = token.balance * price,
+                        Err(e) => {
+                            tracing::debug!(target: "warpscan", "Failed to fetch price for {}: {}", token.contract_address, e)
+                        }
+                    }
+                }
+
                 // Update token count in details
                 let token_count = tokens.len() as u32;
 
@@ -417,15 +523,25 @@ impl App {
                     token_transfers,
                     tokens,
                     internal_transactions,
+                    withdrawals,
+                    approvals,
                     current_tab: AddressTab::Details, // Default to Details tab
                     selected_transaction_index: 0,
                     selected_history_index: 0,
                     selected_token_transfer_index: 0,
                     selected_token_index: 0,
                     selected_internal_txn_index: 0,
+                    selected_withdrawal_index: 0,
+                    selected_approval_index: 0,
+                    filter: Default::default(),
+                    current_page: 1,
+                    total_pages,
                 };
 
                 self.address_data = Some(complete_data);
+                self.sync_address_scrollbar();
+                // Floor prices fill in once the lookup is on screen
+                self.request_nft_floor_prices().await;
 
                 // Start subscriptions for this address
                 if let Err(e) = self.start_subscriptions().await {
@@ -440,7 +556,7 @@ impl App {
                 self.set_success(debug_info);
             }
             Err(e) => {
-                self.set_error(format!("Failed to lookup address: {}", e));
+                self.report_error("Failed to lookup address", &e);
             }
         }
 
@@ -452,6 +568,9 @@ impl App {
     /// Switch to a different address tab
     pub fn switch_address_tab(&mut self, tab: AddressTab) {
         if let Some(ref mut address_data) = self.address_data {
+            if !address_data.visible_tabs().contains(&tab) {
+                return;
+            }
             address_data.current_tab = tab;
             // Reset selection index when switching tabs
             address_data.selected_transaction_index = 0;
@@ -459,7 +578,56 @@ impl App {
             address_data.selected_token_transfer_index = 0;
             address_data.selected_token_index = 0;
             address_data.selected_internal_txn_index = 0;
+            address_data.selected_withdrawal_index = 0;
         }
+        self.sync_address_scrollbar();
+    }
+
+    /// Sync the scrollbar state of the current address tab with its selection
+    pub fn sync_address_scrollbar(&mut self) {
+        let Some(ref data) = self.address_data else {
+            return;
+        };
+
+        let (key, len, position) = match data.current_tab {
+            AddressTab::Transactions => (
+                "address_txs",
+                data.filtered_transactions().len(),
+                data.selected_transaction_index,
+            ),
+            AddressTab::AccountHistory => (
+                "address_history",
+                data.account_history.len(),
+                data.selected_history_index,
+            ),
+            AddressTab::TokenTransfers => (
+                "token_transfers",
+                data.token_transfers.len(),
+                data.selected_token_transfer_index,
+            ),
+            AddressTab::Tokens => ("tokens", data.tokens.len(), data.selected_token_index),
+            AddressTab::InternalTxns => (
+                "internal_txns",
+                data.internal_transactions.len(),
+                data.selected_internal_txn_index,
+            ),
+            AddressTab::Approvals => (
+                "approvals",
+                data.approvals.len(),
+                data.selected_approval_index,
+            ),
+            AddressTab::Withdrawals => (
+                "withdrawals",
+                data.withdrawals.len(),
+                data.selected_withdrawal_index,
+            ),
+            AddressTab::Details => return,
+        };
+
+        let state = self.scrollbar_states.entry(key.to_string()).or_default();
+        *state = state
+            .content_length(len.saturating_sub(1))
+            .position(position);
     }
 
     /// Get the current address tab
@@ -472,7 +640,7 @@ impl App {
     /// Move selection to previous transaction in the Transactions tab
     pub fn address_select_previous_transaction(&mut self) {
         if let Some(ref mut data) = self.address_data {
-            if !data.transactions.is_empty() && data.selected_transaction_index > 0 {
+            if data.selected_transaction_index > 0 {
                 data.selected_transaction_index -= 1;
             }
         }
@@ -481,8 +649,9 @@ impl App {
     /// Move selection to next transaction in the Transactions tab
     pub fn address_select_next_transaction(&mut self) {
         if let Some(ref mut data) = self.address_data {
-            if !data.transactions.is_empty() {
-                let max_index = data.transactions.len().saturating_sub(1);
+            let visible = data.filtered_transactions().len();
+            if visible > 0 {
+                let max_index = visible.saturating_sub(1);
                 if data.selected_transaction_index < max_index {
                     data.selected_transaction_index += 1;
                 }
@@ -490,26 +659,89 @@ impl App {
         }
     }
 
+    /// Open the NFT gallery for the token selected in the Tokens tab
+    ///
+    /// Does nothing unless the selected token is an ERC-721/ERC-1155 collection.
+    pub async fn open_nft_gallery(&mut self) {
+        let token = self.address_data.as_ref().and_then(|data| {
+            if data.current_tab != AddressTab::Tokens {
+                return None;
+            }
+            data.tokens
+                .get(data.selected_token_index)
+                .filter(|t| t.token_type.is_nft())
+                .cloned()
+        });
+
+        match token {
+            Some(token) => {
+                self.navigate_to(crate::ui::app::state::AppState::NftGallery)
+                    .await;
+                self.nft_gallery_token = Some(token);
+                self.load_selected_nft_metadata().await;
+            }
+            None => self.set_error("Select an NFT collection to open the gallery".to_string()),
+        }
+    }
+
     /// Navigate to an address (used for clicking on addresses)
     pub async fn navigate_to_address(&mut self, address: &str) {
         self.navigate_to(crate::ui::app::state::AppState::AddressLookup)
             .await;
         self.set_input(address.to_string());
         if let Err(e) = self.lookup_address(address).await {
-            self.set_error(format!("Failed to lookup address: {}", e));
+            self.report_error("Failed to lookup address", &e);
+        }
+    }
+
+    /// Drop everything cached about the shown address and look it up again
+    pub async fn refresh_address(&mut self) {
+        let address = match self.address_data.as_ref() {
+            Some(data) => data.details.address.clone(),
+            None => self.get_input().trim().to_string(),
+        };
+        if !super::validation::is_address(&address) {
+            self.set_error("Look up an address to refresh it".to_string());
+            return;
+        }
+
+        self.cache_manager.evict_address(&address);
+        // Without data the screen shows the loading widget until the lookup is done
+        self.address_data = None;
+        self.set_input(address.clone());
+        self.set_loading_with_message("address_search", format!("Refreshing {}...", address));
+        if let Err(e) = self.lookup_address(&address).await {
+            self.report_error("Failed to refresh address", &e);
         }
     }
 
+    /// Drop the cached copy of the shown transaction and fetch it again
+    pub async fn refresh_transaction(&mut self) {
+        let Some(tx_hash) = self.transaction_data.as_ref().map(|tx| tx.hash.clone()) else {
+            self.set_error("Look up a transaction to refresh it".to_string());
+            return;
+        };
+
+        self.cache_manager.evict_transaction(&tx_hash);
+        self.navigate_to_transaction(&tx_hash).await;
+    }
+
     /// Navigate to a transaction (used for clicking on transaction hashes)
     pub async fn navigate_to_transaction(&mut self, tx_hash: &str) {
         self.navigate_to(crate::ui::app::state::AppState::TransactionViewer)
             .await;
         self.set_input(tx_hash.to_string());
         self.input_data_expanded = false; // Reset expansion state
+        self.tx_access_list_expanded = false;
+        self.transaction_tab = Default::default();
+        self.current_list_index = 0;
 
         // Clear previous transaction data
         self.transaction_data = None;
-        self.set_loading("transaction_search", true);
+        self.set_loading_with_message(
+            "transaction_search",
+            format!("Fetching transaction {}...", tx_hash),
+        );
         self.clear_messages();
 
         // Determine if we should use Etherscan API or RPC based on selected mode
@@ -537,11 +769,24 @@ impl App {
             .await
         {
             Ok(tx_details) => {
+                let mut parties = vec![tx_details.from.clone()];
+                parties.extend(tx_details.to.clone());
+                self.prefetch_address_data(&parties);
                 self.transaction_data = Some(tx_details);
+                // Refresh the finality heads for the confirmations label
+                match self.blockchain_client.get_block_finality_labels().await {
+                    Ok(finality) => self.block_finality = Some(finality),
+                    Err(e) => {
+                        tracing::debug!(target: "warpscan", "Failed to fetch block finality: {}", e)
+                    }
+                }
+                if let Err(e) = self.start_subscriptions().await {
+                    tracing::warn!(target: "warpscan", "Failed to start transaction subscriptions: {}", e);
+                }
                 self.set_success(format!("Transaction {} loaded successfully", tx_hash));
             }
             Err(e) => {
-                self.set_error(format!("Failed to lookup transaction: {}", e));
+                self.report_error("Failed to lookup transaction", &e);
             }
         }
 
@@ -552,36 +797,39 @@ impl App {
     pub fn address_select_previous_item(&mut self) {
         if let Some(ref mut data) = self.address_data {
             match data.current_tab {
-                AddressTab::Transactions => {
-                    if !data.transactions.is_empty() && data.selected_transaction_index > 0 {
-                        data.selected_transaction_index -= 1;
-                    }
+                AddressTab::Transactions if data.selected_transaction_index > 0 => {
+                    data.selected_transaction_index -= 1;
                 }
-                AddressTab::AccountHistory => {
-                    if !data.account_history.is_empty() && data.selected_history_index > 0 {
-                        data.selected_history_index -= 1;
-                    }
+                AddressTab::AccountHistory
+                    if !data.account_history.is_empty() && data.selected_history_index > 0 =>
+                {
+                    data.selected_history_index -= 1;
                 }
-                AddressTab::TokenTransfers => {
-                    if !data.token_transfers.is_empty() && data.selected_token_transfer_index > 0 {
-                        data.selected_token_transfer_index -= 1;
-                    }
+                AddressTab::TokenTransfers
+                    if !data.token_transfers.is_empty()
+                        && data.selected_token_transfer_index > 0 =>
+                {
+                    data.selected_token_transfer_index -= 1;
                 }
-                AddressTab::Tokens => {
-                    if !data.tokens.is_empty() && data.selected_token_index > 0 {
-                        data.selected_token_index -= 1;
-                    }
+                AddressTab::Tokens if !data.tokens.is_empty() && data.selected_token_index > 0 => {
+                    data.selected_token_index -= 1;
                 }
-                AddressTab::InternalTxns => {
+                AddressTab::InternalTxns
                     if !data.internal_transactions.is_empty()
-                        && data.selected_internal_txn_index > 0
-                    {
-                        data.selected_internal_txn_index -= 1;
-                    }
+                        && data.selected_internal_txn_index > 0 =>
+                {
+                    data.selected_internal_txn_index -= 1;
+                }
+                AddressTab::Approvals if data.selected_approval_index > 0 => {
+                    data.selected_approval_index -= 1;
+                }
+                AddressTab::Withdrawals if data.selected_withdrawal_index > 0 => {
+                    data.selected_withdrawal_index -= 1;
                 }
                 _ => {}
             }
         }
+        self.sync_address_scrollbar();
     }
 
     /// Move selection to next item in current tab
@@ -589,47 +837,358 @@ impl App {
         if let Some(ref mut data) = self.address_data {
             match data.current_tab {
                 AddressTab::Transactions => {
-                    if !data.transactions.is_empty() {
-                        let max_index = data.transactions.len().saturating_sub(1);
-                        if data.selected_transaction_index < max_index {
-                            data.selected_transaction_index += 1;
-                        }
+                    let max_index = data.filtered_transactions().len().saturating_sub(1);
+                    if data.selected_transaction_index < max_index {
+                        data.selected_transaction_index += 1;
                     }
                 }
-                AddressTab::AccountHistory => {
-                    if !data.account_history.is_empty() {
-                        let max_index = data.account_history.len().saturating_sub(1);
-                        if data.selected_history_index < max_index {
-                            data.selected_history_index += 1;
-                        }
+                AddressTab::AccountHistory if !data.account_history.is_empty() => {
+                    let max_index = data.account_history.len().saturating_sub(1);
+                    if data.selected_history_index < max_index {
+                        data.selected_history_index += 1;
                     }
                 }
-                AddressTab::TokenTransfers => {
-                    if !data.token_transfers.is_empty() {
-                        let max_index = data.token_transfers.len().saturating_sub(1);
-                        if data.selected_token_transfer_index < max_index {
-                            data.selected_token_transfer_index += 1;
-                        }
+                AddressTab::TokenTransfers if !data.token_transfers.is_empty() => {
+                    let max_index = data.token_transfers.len().saturating_sub(1);
+                    if data.selected_token_transfer_index < max_index {
+                        data.selected_token_transfer_index += 1;
                     }
                 }
-                AddressTab::Tokens => {
-                    if !data.tokens.is_empty() {
-                        let max_index = data.tokens.len().saturating_sub(1);
-                        if data.selected_token_index < max_index {
-                            data.selected_token_index += 1;
-                        }
+                AddressTab::Tokens if !data.tokens.is_empty() => {
+                    let max_index = data.tokens.len().saturating_sub(1);
+                    if data.selected_token_index < max_index {
+                        data.selected_token_index += 1;
                     }
                 }
-                AddressTab::InternalTxns => {
-                    if !data.internal_transactions.is_empty() {
-                        let max_index = data.internal_transactions.len().saturating_sub(1);
-                        if data.selected_internal_txn_index < max_index {
-                            data.selected_internal_txn_index += 1;
-                        }
+                AddressTab::InternalTxns if !data.internal_transactions.is_empty() => {
+                    let max_index = data.internal_transactions.len().saturating_sub(1);
+                    if data.selected_internal_txn_index < max_index {
+                        data.selected_internal_txn_index += 1;
+                    }
+                }
+                AddressTab::Approvals => {
+                    let max_index = data.approvals.len().saturating_sub(1);
+                    if data.selected_approval_index < max_index {
+                        data.selected_approval_index += 1;
+                    }
+                }
+                AddressTab::Withdrawals => {
+                    let max_index = data.withdrawals.len().saturating_sub(1);
+                    if data.selected_withdrawal_index < max_index {
+                        data.selected_withdrawal_index += 1;
                     }
                 }
                 _ => {}
             }
         }
+        self.sync_address_scrollbar();
+    }
+
+    /// Transactions fetched per page, from `ui.max_results_per_page`
+    pub fn address_page_size(&self) -> u64 {
+        self.config.ui.max_results_per_page.max(1) as u64
+    }
+
+    /// Load the next (or previous) page of the Transactions tab
+    pub async fn change_address_transactions_page(&mut self, forward: bool) {
+        let Some(ref data) = self.address_data else {
+            return;
+        };
+        let page = if forward {
+            if data.current_page >= data.total_pages {
+                return;
+            }
+            data.current_page + 1
+        } else {
+            if data.current_page <= 1 {
+                return;
+            }
+            data.current_page - 1
+        };
+        let address = data.details.address.clone();
+        let transaction_count = data.details.total_transactions;
+        let use_etherscan = self.data_mode != Some(super::state::DataMode::LocalNode);
+        let page_size = self.address_page_size();
+
+        // The cache holds a single page per address; drop it so a stale page is not served
+        self.cache_manager.invalidate_address_transactions(&address);
+        self.set_loading_with_message("address_search", format!("Loading page {}...", page));
+        let result = self
+            .blockchain_client
+            .get_address_transactions_with_mode(&address, use_etherscan, page, page_size)
+            .await;
+        self.set_loading("address_search", false);
+
+        let txs = match result {
+            Ok(txs) => txs,
+            Err(e) => {
+                self.report_error("Failed to load transactions", &e);
+                return;
+            }
+        };
+        let Some(ref mut data) = self.address_data else {
+            return;
+        };
+        if txs.is_empty() && page > 1 {
+            // The previous page was full but was also the last one
+            data.total_pages = data.current_page;
+            return;
+        }
+        let (transactions, account_history) = map_address_transactions(&address, &txs);
+        data.transactions = transactions;
+        data.account_history = account_history;
+        data.current_page = page;
+        data.total_pages = estimate_total_pages(page, txs.len(), page_size, transaction_count);
+        data.selected_transaction_index = 0;
+        data.selected_history_index = 0;
+        self.sync_address_scrollbar();
+    }
+
+    /// Export the address history for the current tab to a CSV file in the
+    /// working directory: token transfers on the Token Transfers tab,
+    /// transactions otherwise
+    pub fn export_address_csv(&mut self) {
+        let Some(ref data) = self.address_data else {
+            self.set_error("Look up an address before exporting".to_string());
+            return;
+        };
+
+        let token_transfers = data.current_tab == AddressTab::TokenTransfers;
+        let kind = if token_transfers {
+            "token-transfers"
+        } else {
+            "transactions"
+        };
+        let path = format!("warpscan-{}-{}.csv", data.details.address, kind);
+        let result = if token_transfers {
+            self.export_token_transfers_csv(Path::new(&path))
+        } else {
+            self.export_address_transactions_csv(Path::new(&path))
+        };
+        match result {
+            Ok(()) => self.set_success(format!("Exported {} to {}", kind.replace('-', " "), path)),
+            Err(e) => self.report_error("Failed to export CSV", &e),
+        }
+    }
+
+    /// Write the loaded address's transactions to `path` as CSV
+    pub fn export_address_transactions_csv(&self, path: &Path) -> Result<()> {
+        let data = self
+            .address_data
+            .as_ref()
+            .ok_or_else(|| Error::app("No address data to export"))?;
+        write_transactions_csv(data, std::fs::File::create(path)?)?;
+        tracing::info!(target: "warpscan", "Exported address transactions to {}", path.display());
+        Ok(())
+    }
+
+    /// Write the loaded address's token transfers to `path` as CSV
+    pub fn export_token_transfers_csv(&self, path: &Path) -> Result<()> {
+        let data = self
+            .address_data
+            .as_ref()
+            .ok_or_else(|| Error::app("No address data to export"))?;
+        write_token_transfers_csv(data, std::fs::File::create(path)?)?;
+        tracing::info!(target: "warpscan", "Exported token transfers to {}", path.display());
+        Ok(())
+    }
+}
+
+/// Page count from the address's transaction count, or one past `page`
+/// while pages come back full since the count only covers sent transactions
+fn estimate_total_pages(page: u64, fetched: usize, page_size: u64, transaction_count: u64) -> u64 {
+    let known = if fetched as u64 >= page_size {
+        page + 1
+    } else {
+        page
+    };
+    transaction_count.div_ceil(page_size).max(known)
+}
+
+/// Map service transactions to the Transactions and Account History rows
+fn map_address_transactions(
+    address: &str,
+    txs: &[ServiceAddressTx],
+) -> (Vec<AddressTransaction>, Vec<AccountHistoryEntry>) {
+    let address_lower = address.to_lowercase();
+    let now = chrono::Utc::now().timestamp() as u64;
+    let mut ui_txs = Vec::with_capacity(txs.len());
+    let mut account_history = Vec::with_capacity(txs.len());
+
+    for t in txs {
+        let tx_type = if t.method.is_empty() {
+            "Transfer"
+        } else {
+            "Contract Call"
+        };
+        let status = match t.status {
+            ChainTransactionStatus::Pending => TransactionStatus::Pending,
+            ChainTransactionStatus::Success => TransactionStatus::Success,
+            ChainTransactionStatus::Failed => TransactionStatus::Failed,
+            ChainTransactionStatus::Unknown => TransactionStatus::Pending,
+        };
+
+        ui_txs.push(AddressTransaction {
+            tx_hash: t.tx_hash.clone(),
+            tx_type: tx_type.to_string(),
+            method: t.method.clone(),
+            block: t.block_number,
+            from: t.from.clone(),
+            to: t.to.clone(),
+            value: t.value_eth,
+            fee: t.fee_eth,
+            timestamp: t.timestamp,
+            status,
+        });
+
+        let action = if t.from.to_lowercase() == address_lower {
+            "Sent"
+        } else if t.to.to_lowercase() == address_lower {
+            "Received"
+        } else {
+            "Unknown"
+        };
+
+        let age_seconds = now.saturating_sub(t.timestamp);
+        let age = if age_seconds < 60 {
+            format!("{}s ago", age_seconds)
+        } else if age_seconds < 3600 {
+            format!("{}m ago", age_seconds / 60)
+        } else if age_seconds < 86400 {
+            format!("{}h ago", age_seconds / 3600)
+        } else {
+            format!("{}d ago", age_seconds / 86400)
+        };
+
+        account_history.push(AccountHistoryEntry {
+            age,
+            action: action.to_string(),
+            from: t.from.clone(),
+            to: t.to.clone(),
+            timestamp: t.timestamp,
+            tx_hash: t.tx_hash.clone(),
+        });
+    }
+    (ui_txs, account_history)
+}
+
+/// Unix time as an ISO 8601 UTC timestamp
+fn iso_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .unwrap_or_default()
+}
+
+/// One row of the transactions CSV
+#[derive(Serialize)]
+struct TransactionCsvRow<'a> {
+    hash: &'a str,
+    block: u64,
+    timestamp: String,
+    from: &'a str,
+    to: &'a str,
+    value_eth: f64,
+    fee_eth: f64,
+    method: &'a str,
+    status: String,
+}
+
+/// One row of the token transfers CSV
+#[derive(Serialize)]
+struct TokenTransferCsvRow<'a> {
+    hash: &'a str,
+    timestamp: String,
+    from: &'a str,
+    to: &'a str,
+    token_name: &'a str,
+    token_symbol: &'a str,
+    amount: f64,
+    token_id: Option<&'a str>,
+}
+
+fn write_transactions_csv(data: &CompleteAddressData, writer: impl Write) -> Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    for tx in &data.transactions {
+        csv.serialize(TransactionCsvRow {
+            hash: &tx.tx_hash,
+            block: tx.block,
+            timestamp: iso_timestamp(tx.timestamp),
+            from: &tx.from,
+            to: &tx.to,
+            value_eth: tx.value,
+            fee_eth: tx.fee,
+            method: &tx.method,
+            status: format!("{:?}", tx.status),
+        })
+        .map_err(std::io::Error::from)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+fn write_token_transfers_csv(data: &CompleteAddressData, writer: impl Write) -> Result<()> {
+    let mut csv = csv::Writer::from_writer(writer);
+    for transfer in &data.token_transfers {
+        csv.serialize(TokenTransferCsvRow {
+            hash: &transfer.txn_hash,
+            timestamp: iso_timestamp(transfer.timestamp),
+            from: &transfer.from,
+            to: &transfer.to,
+            token_name: &transfer.token_name,
+            token_symbol: &transfer.token_symbol,
+            amount: transfer.amount,
+            token_id: transfer.token_id.as_deref(),
+        })
+        .map_err(std::io::Error::from)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::models::{AddressTransaction, TransactionStatus};
+
+    #[test]
+    fn test_transactions_csv_round_trip() {
+        let data = CompleteAddressData {
+            transactions: vec![
+                AddressTransaction::default(),
+                AddressTransaction {
+                    method: "swap, exact \"in\"".to_string(),
+                    value: 0.25,
+                    status: TransactionStatus::Failed,
+                    ..AddressTransaction::default()
+                },
+            ],
+            ..CompleteAddressData::default()
+        };
+        let mut out = Vec::new();
+        write_transactions_csv(&data, &mut out).unwrap();
+
+        let mut reader = csv::Reader::from_reader(out.as_slice());
+        assert_eq!(
+            reader.headers().unwrap(),
+            vec![
+                "hash",
+                "block",
+                "timestamp",
+                "from",
+                "to",
+                "value_eth",
+                "fee_eth",
+                "method",
+                "status"
+            ]
+        );
+        let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(&rows[0][1], "21234567");
+        assert_eq!(&rows[0][2], "2022-01-01T00:00:00Z");
+        assert_eq!(&rows[0][8], "Success");
+        assert_eq!(&rows[1][5], "0.25");
+        assert_eq!(&rows[1][7], "swap, exact \"in\"");
+        assert_eq!(&rows[1][8], "Failed");
     }
 }
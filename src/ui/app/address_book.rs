@@ -0,0 +1,139 @@
+//! User labels for addresses
+//!
+//! Labels are keyed by lowercase address and replace the shortened hex in the
+//! address tables. The book is written to `address_book.json` in the data
+//! directory on shutdown and read back on startup.
+
+use super::core::App;
+use super::state::InputMode;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::ui::models::AddressLabel;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+impl App {
+    /// Label the user gave `address`, if any
+    pub fn address_label(&self, address: &str) -> Option<&AddressLabel> {
+        self.address_book.get(&address.to_lowercase())
+    }
+
+    /// Open the label prompt for the looked-up address, pre-filled with its current label
+    pub fn open_label_prompt(&mut self) {
+        let Some(ref data) = self.address_data else {
+            self.set_error("Look up an address before labelling it".to_string());
+            return;
+        };
+
+        let address = data.details.address.clone();
+        let text = self
+            .address_label(&address)
+            .map(AddressLabel::prompt_text)
+            .unwrap_or_default();
+        self.label_prompt_address = Some(address);
+        self.set_input(text);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Close the label prompt and restore the address in the input line
+    pub fn close_label_prompt(&mut self) {
+        self.input_mode = InputMode::Normal;
+        if let Some(address) = self.label_prompt_address.take() {
+            self.set_input(address);
+        }
+    }
+
+    /// Save the label entered in the prompt; an empty name removes the label
+    pub fn confirm_label_prompt(&mut self) {
+        let text = self.get_input().to_string();
+        let Some(address) = self.label_prompt_address.clone() else {
+            return;
+        };
+        self.close_label_prompt();
+
+        let key = address.to_lowercase();
+        match AddressLabel::parse(&text) {
+            Some(mut label) => {
+                // Keep a colour set by hand in the address book file
+                label.color = self
+                    .address_book
+                    .get(&key)
+                    .and_then(|old| old.color.clone());
+                self.set_success(format!("Labelled {} as {}", address, label.name));
+                self.address_book.insert(key, label);
+            }
+            None => {
+                if self.address_book.remove(&key).is_some() {
+                    self.set_success(format!("Removed the label of {}", address));
+                }
+            }
+        }
+    }
+
+    /// Restore the address book from the previous session
+    pub fn load_address_book(&mut self) {
+        match read_address_book() {
+            Ok(book) => self.address_book = book,
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to load address book: {}", e),
+        }
+    }
+
+    /// Persist the address book for the next session
+    pub fn save_address_book(&self) {
+        if let Err(e) = write_address_book(&self.address_book) {
+            tracing::warn!(target: "warpscan", "Failed to save address book: {}", e);
+        }
+    }
+}
+
+fn address_book_path() -> Result<PathBuf> {
+    Ok(Config::data_dir()?.join("address_book.json"))
+}
+
+/// Read `address_book.json`, returning an empty book if it does not exist yet
+fn read_address_book() -> Result<HashMap<String, AddressLabel>> {
+    let path = address_book_path()?;
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+    let contents = std::fs::read_to_string(&path)?;
+    let book: HashMap<String, AddressLabel> = serde_json::from_str(&contents).map_err(|e| {
+        Error::parse(format!(
+            "Invalid address book file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    // Hand-edited files may use checksummed addresses
+    Ok(book
+        .into_iter()
+        .map(|(address, label)| (address.to_lowercase(), label))
+        .collect())
+}
+
+/// Write `address_book.json`, creating the data directory if needed
+fn write_address_book(book: &HashMap<String, AddressLabel>) -> Result<()> {
+    let path = address_book_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(book)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_prompt_text_round_trip() {
+        let label = AddressLabel::parse(" Binance Hot 1 , exchange,, cex ").unwrap();
+        assert_eq!(label.name, "Binance Hot 1");
+        assert_eq!(label.tags, ["exchange", "cex"]);
+        assert_eq!(label.prompt_text(), "Binance Hot 1, exchange, cex");
+        assert_eq!(AddressLabel::parse(&label.prompt_text()), Some(label));
+
+        assert_eq!(AddressLabel::parse(""), None);
+        assert_eq!(AddressLabel::parse(" , exchange"), None);
+    }
+}
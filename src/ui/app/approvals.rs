@@ -0,0 +1,126 @@
+//! ERC-20 approval revocation from the Approvals tab
+//!
+//! WarpScan holds no keys, so revoking means handing the user the data a
+//! wallet needs; nothing is sent from here.
+
+use super::core::App;
+use super::utils::format_address_display;
+use crate::blockchain::TokenApproval;
+use crate::error::{Error, Result};
+use ethers::abi::{encode, Token};
+use ethers::types::{Address, U256};
+use ethers::utils::id;
+use std::str::FromStr;
+
+/// Parse an address, naming the field in the validation error
+fn parse_address(field: &str, value: &str) -> Result<Address> {
+    Address::from_str(value.trim())
+        .map_err(|e| Error::validation(format!("Invalid {} address: {}", field, e)))
+}
+
+impl App {
+    /// ABI-encode `approve(spender, 0)` for `token_address`, as 0x-prefixed hex
+    pub fn generate_revoke_approval_calldata(token_address: &str, spender: &str) -> Result<String> {
+        parse_address("token", token_address)?;
+        let spender = parse_address("spender", spender)?;
+
+        let mut calldata = id("approve(address,uint256)").to_vec();
+        calldata.extend(encode(&[
+            Token::Address(spender),
+            Token::Uint(U256::zero()),
+        ]));
+        Ok(format!("0x{}", hex::encode(calldata)))
+    }
+
+    /// EIP-681 payment request URI for the revocation, understood by most wallets
+    pub fn revoke_approval_uri(
+        token_address: &str,
+        spender: &str,
+        chain_id: u64,
+    ) -> Result<String> {
+        let token = parse_address("token", token_address)?;
+        let spender = parse_address("spender", spender)?;
+        Ok(format!(
+            "ethereum:{:?}@{}/approve?address={:?}&uint256=0",
+            token, chain_id, spender
+        ))
+    }
+
+    /// MetaMask mobile deep link that opens the revocation for confirmation
+    pub fn revoke_approval_metamask_link(
+        token_address: &str,
+        spender: &str,
+        chain_id: u64,
+    ) -> Result<String> {
+        let uri = Self::revoke_approval_uri(token_address, spender, chain_id)?;
+        Ok(format!(
+            "https://metamask.app.link/send/{}",
+            uri.trim_start_matches("ethereum:")
+        ))
+    }
+
+    /// Copy the revocation of the allowance selected on the Approvals tab
+    ///
+    /// The clipboard gets the token contract and call data for pasting into
+    /// a wallet, plus the EIP-681 and MetaMask links that open it directly.
+    pub fn copy_selected_revoke_data(&mut self) {
+        let Some(approval) = self
+            .address_data
+            .as_ref()
+            .and_then(|data| data.approvals.get(data.selected_approval_index))
+            .cloned()
+        else {
+            self.set_error("Select an approval to revoke".to_string());
+            return;
+        };
+
+        let text = match revoke_instructions(&approval, self.config.network.chain_id) {
+            Ok(text) => text,
+            Err(e) => {
+                self.report_error("Failed to build revoke data", &e);
+                return;
+            }
+        };
+        match self.copy_to_clipboard(&text) {
+            Ok(()) => self.set_success(format!(
+                "Copied 'approve({}, 0)' call data for token {} — paste into your wallet.",
+                format_address_display(&approval.spender, None, 0),
+                format_address_display(&approval.token_address, None, 0)
+            )),
+            Err(e) => self.report_error("Failed to copy revoke data", &e),
+        }
+    }
+}
+
+/// Clipboard text for revoking `approval`
+fn revoke_instructions(approval: &TokenApproval, chain_id: u64) -> Result<String> {
+    let token = &approval.token_address;
+    let spender = &approval.spender;
+    Ok(format!(
+        "Token contract: {}\nCall data: {}\nEIP-681: {}\nMetaMask: {}",
+        token,
+        App::generate_revoke_approval_calldata(token, spender)?,
+        App::revoke_approval_uri(token, spender, chain_id)?,
+        App::revoke_approval_metamask_link(token, spender, chain_id)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_revoke_calldata() {
+        let calldata = App::generate_revoke_approval_calldata(
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48",
+            "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D",
+        )
+        .unwrap();
+        assert_eq!(
+            calldata,
+            "0x095ea7b30000000000000000000000007a250d5630b4cf539739df2c5dacb4c659f2488d\
+             0000000000000000000000000000000000000000000000000000000000000000"
+        );
+        assert!(App::generate_revoke_approval_calldata("0xdead", "0x0").is_err());
+    }
+}
@@ -0,0 +1,207 @@
+//! Block lookup and range analysis for the block explorer
+
+use super::super::models::{BlockInfo, CompleteBlockData};
+use super::core::App;
+use super::state::InputMode;
+use super::validation::{is_block_hash, is_block_number};
+use crate::error::{Error, Result};
+
+/// Most blocks listed by one `start-end` lookup
+const MAX_BLOCK_LIST: u64 = 50;
+
+/// Parse a `start-end` block range
+pub(super) fn parse_block_range(input: &str) -> Option<(u64, u64)> {
+    let (start, end) = input.split_once('-')?;
+    Some((start.trim().parse().ok()?, end.trim().parse().ok()?))
+}
+
+impl App {
+    /// Lookup a block by number or `0x` hash and populate block_data
+    pub async fn lookup_block(&mut self, block_input: &str) {
+        let block_input = block_input.trim();
+        self.set_loading("block_search", true);
+        self.clear_messages();
+        self.block_data = None;
+        self.block_rewards = None;
+
+        if !is_block_number(block_input) && !is_block_hash(block_input) {
+            self.set_error("Enter a block number or a 0x block hash".to_string());
+            self.set_loading("block_search", false);
+            return;
+        }
+
+        match self.blockchain_client.get_block_details(block_input).await {
+            Ok(details) => {
+                let block_number = details.number;
+                self.block_data = Some(CompleteBlockData {
+                    details,
+                    selected_transaction_index: 0,
+                });
+
+                match self.blockchain_client.get_block_rewards(block_number).await {
+                    Ok(rewards) => self.block_rewards = rewards,
+                    Err(e) => {
+                        tracing::warn!(target: "warpscan", "Failed to fetch block rewards: {}", e)
+                    }
+                }
+            }
+            Err(e) => {
+                self.report_error("Failed to lookup block", &e);
+            }
+        }
+
+        self.set_loading("block_search", false);
+    }
+
+    /// Route block explorer input: a number or block hash opens the block,
+    /// a hash no block has opens the transaction with that hash
+    pub async fn search_block_explorer(&mut self, input: &str) {
+        self.block_range_data.clear();
+        if is_block_number(input) {
+            self.lookup_block(input).await;
+        } else if !is_block_hash(input) {
+            self.set_error(
+                "Enter a block number, a 0x block hash or a transaction hash".to_string(),
+            );
+        } else if let Ok(None) = self.blockchain_client.get_block_by_hash(input).await {
+            self.navigate_to_transaction(input).await;
+        } else {
+            // Lookup errors are reported by `lookup_block`
+            self.lookup_block(input).await;
+        }
+    }
+
+    /// Open the block before (or after) the one shown
+    pub async fn step_block(&mut self, forward: bool) {
+        let Some(number) = self.block_data.as_ref().map(|block| block.details.number) else {
+            return;
+        };
+        let target = if forward {
+            number.saturating_add(1)
+        } else if number > 0 {
+            number - 1
+        } else {
+            return;
+        };
+        self.set_input(target.to_string());
+        self.lookup_block(&target.to_string()).await;
+    }
+
+    /// Parse a `start-end` range and list its blocks
+    pub async fn list_block_range(&mut self, input: &str) {
+        let Some((start, end)) = parse_block_range(input) else {
+            self.set_error("Enter a range as start-end, e.g. 19000000-19000049".to_string());
+            return;
+        };
+        if let Err(e) = self.fetch_block_range(start, end).await {
+            self.report_error("Failed to list blocks", &e);
+        }
+    }
+
+    /// Fetch the blocks `start..=end` into `block_range_data`
+    pub async fn fetch_block_range(&mut self, start: u64, end: u64) -> Result<()> {
+        if start > end {
+            return Err(Error::validation(format!(
+                "Start block {} is after end block {}",
+                start, end
+            )));
+        }
+        if end - start >= MAX_BLOCK_LIST {
+            return Err(Error::validation(format!(
+                "Block lists are limited to {} blocks; use range analysis (r) for wider ranges",
+                MAX_BLOCK_LIST
+            )));
+        }
+
+        self.clear_messages();
+        self.set_loading_with_message(
+            "block_search",
+            format!("Fetching blocks {} to {}...", start, end),
+        );
+        let results = futures::future::join_all(
+            (start..=end).map(|number| self.blockchain_client.get_block_by_number(number)),
+        )
+        .await;
+        self.set_loading("block_search", false);
+
+        let mut blocks = Vec::with_capacity(results.len());
+        for result in results {
+            // Blocks past the chain head are left out
+            if let Some(block) = result? {
+                blocks.push(BlockInfo::from_block(&block));
+            }
+        }
+        if blocks.is_empty() {
+            return Err(Error::blockchain(format!(
+                "No blocks between {} and {}",
+                start, end
+            )));
+        }
+
+        self.block_data = None;
+        self.block_rewards = None;
+        self.block_range_data = blocks;
+        self.block_range_index = 0;
+        Ok(())
+    }
+
+    /// Number of the selected block in the listed range
+    pub fn selected_range_block(&self) -> Option<u64> {
+        self.block_range_data
+            .get(self.block_range_index)
+            .map(|block| block.number)
+    }
+
+    /// Move the block explorer's transaction cursor, or the row cursor of a
+    /// listed block range
+    pub fn block_select_transaction(&mut self, forward: bool) {
+        let Some(block) = self.block_data.as_mut() else {
+            let last = self.block_range_data.len().saturating_sub(1);
+            self.block_range_index = if forward {
+                (self.block_range_index + 1).min(last)
+            } else {
+                self.block_range_index.saturating_sub(1)
+            };
+            return;
+        };
+        let last = block.details.transactions.len().saturating_sub(1);
+        block.selected_transaction_index = if forward {
+            (block.selected_transaction_index + 1).min(last)
+        } else {
+            block.selected_transaction_index.saturating_sub(1)
+        };
+    }
+
+    /// Switch the block explorer between single block lookup and range analysis
+    pub fn toggle_block_range_mode(&mut self) {
+        self.block_range_mode = !self.block_range_mode;
+        self.clear_input();
+        self.clear_messages();
+        if self.block_range_mode {
+            self.input_mode = InputMode::Editing;
+        }
+    }
+
+    /// Parse a `start-end` range and load its summary statistics
+    pub async fn analyze_block_range(&mut self, input: &str) {
+        let Some((start, end)) = parse_block_range(input) else {
+            self.set_error("Enter a range as start-end, e.g. 19000000-19000099".to_string());
+            return;
+        };
+
+        self.set_loading("block_range", true);
+        self.clear_messages();
+        self.block_range_summary = None;
+
+        match self
+            .blockchain_client
+            .get_block_range_summary(start, end)
+            .await
+        {
+            Ok(summary) => self.block_range_summary = Some(summary),
+            Err(e) => self.report_error("Failed to analyze block range", &e),
+        }
+
+        self.set_loading("block_range", false);
+    }
+}
@@ -0,0 +1,292 @@
+//! Contract interaction screen handling
+
+use super::core::App;
+use super::state::InputMode;
+use super::validation::is_address;
+use crate::error::{Error, Result};
+use crate::ui::models::{AbiFunction, CallOutcome};
+use ethers::abi::token::{LenientTokenizer, Tokenizer};
+use ethers::abi::{Abi, Function, Token};
+
+impl App {
+    /// Load the verified ABI of a contract and list its functions
+    pub async fn load_contract_abi_for_interaction(&mut self, contract_address: &str) {
+        self.set_loading_with_message("contract_abi", "Loading contract ABI...".to_string());
+        match self.fetch_contract_abi(contract_address).await {
+            Ok(abi) => self.show_contract_functions(contract_address, abi),
+            Err(e) => self.report_error("Failed to load contract ABI", &e),
+        }
+        self.set_loading("contract_abi", false);
+    }
+
+    /// Load an ABI from a local JSON file: a bare ABI array or a build
+    /// artifact with an `abi` field
+    pub fn load_contract_abi_file(&mut self, contract_address: &str, path: &str) {
+        match read_abi_file(path) {
+            Ok(abi) => {
+                self.show_contract_functions(contract_address, abi);
+                self.set_success(format!("Loaded ABI from {}", path));
+            }
+            Err(e) => self.report_error("Failed to load ABI file", &e),
+        }
+    }
+
+    /// List the functions of `abi` for `contract_address`
+    fn show_contract_functions(&mut self, contract_address: &str, abi: Abi) {
+        self.loaded_abi_functions = abi.functions().map(AbiFunction::from).collect();
+        let mut functions = self.loaded_abi_functions.clone();
+        functions.sort_by(AbiFunction::display_order);
+        self.contract_interaction_state = crate::ui::models::ContractInteractionState {
+            contract_address: Some(contract_address.to_string()),
+            functions,
+            ..Default::default()
+        };
+        self.loaded_abi = Some(abi);
+    }
+
+    /// Ask for the path of an ABI file for the contract in the input
+    pub fn start_abi_file_prompt(&mut self) {
+        let address = self.get_input().trim().to_string();
+        if !is_address(&address) {
+            self.set_error("Enter a contract address before loading an ABI file".to_string());
+            return;
+        }
+        self.contract_interaction_state.contract_address = Some(address);
+        self.contract_interaction_state.abi_path_prompt = true;
+        self.clear_input();
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Finish the ABI path prompt with the entered path
+    pub fn commit_abi_file_path(&mut self, path: String) {
+        let state = &mut self.contract_interaction_state;
+        state.abi_path_prompt = false;
+        let address = state.contract_address.clone().unwrap_or_default();
+        self.set_input(address.clone());
+        if !path.is_empty() {
+            self.load_contract_abi_file(&address, &path);
+        }
+    }
+
+    /// Drop an unfinished argument or ABI path edit, keeping the contract
+    /// address in the input
+    pub fn cancel_contract_interaction_edit(&mut self) {
+        let state = &mut self.contract_interaction_state;
+        if state.abi_path_prompt || state.form_open {
+            state.abi_path_prompt = false;
+            let address = state.contract_address.clone().unwrap_or_default();
+            self.set_input(address);
+        }
+    }
+
+    /// Open the argument form of the highlighted function, calling it
+    /// straight away when it takes no arguments
+    pub async fn open_contract_function_form(&mut self) {
+        let Some(function) = self.contract_interaction_state.function() else {
+            return;
+        };
+        let args = vec![String::new(); function.inputs.len()];
+        let state = &mut self.contract_interaction_state;
+        state.args = args;
+        state.outcome = None;
+        state.form_open = true;
+        self.current_list_index = 0;
+        if self.contract_interaction_state.args.is_empty() {
+            self.submit_contract_function().await;
+        }
+    }
+
+    /// Close the argument form
+    pub fn close_contract_function_form(&mut self) {
+        let state = &mut self.contract_interaction_state;
+        state.form_open = false;
+        state.outcome = None;
+        self.current_list_index = 0;
+    }
+
+    /// Whether the Call button of the form is focused
+    pub fn contract_function_button_focused(&self) -> bool {
+        self.current_list_index + 1 == self.contract_interaction_state.form_rows()
+    }
+
+    /// Move the form focus by one row
+    pub fn contract_function_select_field(&mut self, forward: bool) {
+        let rows = self.contract_interaction_state.form_rows();
+        self.current_list_index = if forward {
+            (self.current_list_index + 1).min(rows - 1)
+        } else {
+            self.current_list_index.saturating_sub(1)
+        };
+    }
+
+    /// Start editing the focused argument
+    pub fn edit_contract_function_field(&mut self) {
+        let value = self
+            .contract_interaction_state
+            .field(self.current_list_index)
+            .to_string();
+        self.set_input(value);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Store the edited argument and restore the contract address input
+    pub fn commit_contract_function_field(&mut self, value: String) {
+        let state = &mut self.contract_interaction_state;
+        state.set_field(self.current_list_index, value);
+        let address = state.contract_address.clone().unwrap_or_default();
+        self.set_input(address);
+    }
+
+    /// Full ABI entry of a function listed in the picker
+    pub(super) fn loaded_abi_function(&self, selected: &AbiFunction) -> Option<Function> {
+        self.loaded_abi.as_ref().and_then(|abi| {
+            abi.functions_by_name(&selected.name)
+                .ok()?
+                .iter()
+                .find(|f| AbiFunction::from(*f).signature() == selected.signature())
+                .cloned()
+        })
+    }
+
+    /// Encode the form arguments and run the function: read functions are
+    /// called and their return values decoded, write functions get their
+    /// calldata and a gas estimate
+    pub async fn submit_contract_function(&mut self) {
+        let state = &self.contract_interaction_state;
+        let (Some(address), Some(selected)) =
+            (state.contract_address.clone(), state.function().cloned())
+        else {
+            return;
+        };
+        let Some(function) = self.loaded_abi_function(&selected) else {
+            return;
+        };
+        let calldata = match encode_call(&function, &state.args) {
+            Ok(calldata) => calldata,
+            Err(e) => {
+                self.set_error(e.to_string());
+                return;
+            }
+        };
+
+        self.set_loading_with_message("contract_call", format!("Calling {}...", function.name));
+        let outcome = if selected.is_view {
+            self.blockchain_client
+                .call_contract(&address, calldata)
+                .await
+                .and_then(|output| {
+                    let values = function.decode_output(&output).map_err(|e| {
+                        Error::parse(format!("Failed to decode return data: {}", e))
+                    })?;
+                    Ok(CallOutcome::Read(
+                        function
+                            .outputs
+                            .iter()
+                            .zip(values)
+                            .map(|(param, value)| (param.kind.to_string(), format_token(&value)))
+                            .collect(),
+                    ))
+                })
+        } else {
+            let gas = self
+                .blockchain_client
+                .estimate_contract_gas(&address, calldata.clone())
+                .await
+                .map(|gas| gas.as_u64())
+                .map_err(|e| e.to_string());
+            Ok(CallOutcome::Write {
+                calldata: format!("0x{}", hex::encode(&calldata)),
+                gas,
+            })
+        };
+        self.set_loading("contract_call", false);
+
+        match outcome {
+            Ok(outcome) => self.contract_interaction_state.outcome = Some(outcome),
+            Err(e) => self.report_error(&format!("Failed to call {}", function.name), &e),
+        }
+    }
+
+    /// Fetch and parse the ABI, failing for unverified contracts
+    async fn fetch_contract_abi(&self, contract_address: &str) -> Result<Abi> {
+        let json = self
+            .blockchain_client
+            .get_contract_abi(contract_address)
+            .await?
+            .ok_or_else(|| {
+                Error::blockchain(format!(
+                    "{} is not verified on Etherscan or Sourcify",
+                    contract_address
+                ))
+            })?;
+        Abi::load(json.as_bytes()).map_err(|e| Error::parse(format!("Invalid contract ABI: {}", e)))
+    }
+
+    /// Move the function selection by one, staying within the function list
+    pub fn contract_interaction_select_function(&mut self, forward: bool) {
+        let state = &mut self.contract_interaction_state;
+        state.selected_function = if forward {
+            (state.selected_function + 1).min(state.functions.len().saturating_sub(1))
+        } else {
+            state.selected_function.saturating_sub(1)
+        };
+    }
+}
+
+/// Parse an ABI file, accepting a bare ABI array or an artifact with an `abi` field
+fn read_abi_file(path: &str) -> Result<Abi> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| Error::validation(format!("Cannot read {}: {}", path, e)))?;
+    let json: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| Error::parse(format!("{} is not valid JSON: {}", path, e)))?;
+    let abi = match json {
+        serde_json::Value::Object(mut artifact) => artifact
+            .remove("abi")
+            .ok_or_else(|| Error::parse(format!("{} has no \"abi\" field", path)))?,
+        abi => abi,
+    };
+    serde_json::from_value(abi).map_err(|e| Error::parse(format!("Invalid contract ABI: {}", e)))
+}
+
+/// ABI-encode a call of `function` from its textual arguments
+fn encode_call(function: &Function, args: &[String]) -> Result<Vec<u8>> {
+    let tokens = function
+        .inputs
+        .iter()
+        .zip(args)
+        .map(|(param, arg)| {
+            LenientTokenizer::tokenize(&param.kind, arg.trim()).map_err(|e| {
+                Error::validation(format!("Invalid {} for {}: {}", param.kind, param.name, e))
+            })
+        })
+        .collect::<Result<Vec<Token>>>()?;
+    function
+        .encode_input(&tokens)
+        .map_err(|e| Error::validation(format!("Failed to encode call: {}", e)))
+}
+
+/// Render a decoded value the way it would be written as an argument
+pub(super) fn format_token(token: &Token) -> String {
+    match token {
+        Token::Address(address) => format!("{:?}", address),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        Token::Int(value) => ethers::types::I256::from_raw(*value).to_string(),
+        Token::Array(items) | Token::FixedArray(items) => format!(
+            "[{}]",
+            items
+                .iter()
+                .map(format_token)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        Token::Tuple(items) => format!(
+            "({})",
+            items
+                .iter()
+                .map(format_token)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => other.to_string(),
+    }
+}
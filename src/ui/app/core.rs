@@ -1,11 +1,23 @@
-use super::super::models::{CompleteAddressData, DashboardData};
-use super::state::{AppState, DataMode, InputMode, ModeSelectionState};
+use super::super::models::{
+    AppError, CompleteAddressData, Create2Form, DashboardData, SimulationForm,
+};
+use super::navigation::NavigationEntry;
+use super::state::{
+    AppState, ClickableRegion, DataMode, EnsPreview, InputFeedback, InputMode, ModeSelectionState,
+};
+use crate::error::Result;
+use crate::ui::theme::ThemeManager;
 use crate::{
     blockchain::BlockchainService, cache::CacheManager, config::Config, wallet::WalletManager,
 };
-use ratatui::layout::Rect;
-use std::collections::HashMap;
-use tokio::sync::mpsc;
+use ratatui::{layout::Rect, widgets::ScrollbarState};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, Semaphore};
+
+/// How long the "Copied to clipboard" popup stays up
+const COPY_NOTIFICATION_DURATION: Duration = Duration::from_secs(2);
 
 /// Main application struct
 pub struct App {
@@ -19,12 +31,20 @@ pub struct App {
     pub input_mode: InputMode,
     /// Current input text
     pub input: String,
-    /// Cursor position in input
-    pub cursor_position: usize,
+    /// Submitted searches, newest first, recalled with Up/Down while editing
+    pub search_history: VecDeque<String>,
+    /// Entry of `search_history` shown in the input, `None` while typing
+    pub history_index: Option<usize>,
+    /// Cursor position in input as a byte index, for editing the string
+    pub cursor_byte_pos: usize,
+    /// Cursor position in input in characters, for the terminal column
+    pub cursor_char_pos: usize,
     /// Current screen size
     pub size: Rect,
-    /// Navigation history
-    pub navigation_history: Vec<AppState>,
+    /// Back stack of visited screens with the input each one showed
+    pub navigation_history: Vec<NavigationEntry>,
+    /// Screens left with back navigation, most recent last
+    pub nav_forward_stack: Vec<NavigationEntry>,
     /// Current tab index for screens with tabs
     pub current_tab: usize,
     /// Current list index for scrollable lists
@@ -35,10 +55,23 @@ pub struct App {
     pub data_cache: HashMap<String, serde_json::Value>,
     /// Loading states for different operations
     pub loading_states: HashMap<String, bool>,
-    /// Error messages
-    pub error_message: Option<String>,
+    /// What each loading operation is doing, shown next to the spinner
+    pub loading_messages: HashMap<String, String>,
+    /// Loading spinner frame, advanced on every tick
+    pub spinner_frame: usize,
+    /// Last error, shown inline and in the error popup
+    pub current_error: Option<AppError>,
+    /// Whether the error popup is shown over the current screen
+    pub error_popup_open: bool,
+    /// Whether the error popup shows the raw debug representation
+    pub error_show_debug: bool,
     /// Success messages
     pub success_message: Option<String>,
+    /// System clipboard, opened on first copy and kept so X11 and Wayland
+    /// selections outlive the copy call
+    pub clipboard: Option<arboard::Clipboard>,
+    /// Until when the "Copied to clipboard" popup is shown
+    pub copy_notification_until: Option<Instant>,
     /// Configuration
     pub config: Config,
     /// Blockchain client
@@ -51,20 +84,187 @@ pub struct App {
     pub event_sender: Option<mpsc::UnboundedSender<crate::ui::events::Event>>,
     /// Dashboard data for the main screen
     pub dashboard_data: DashboardData,
+    /// Percentage of the dashboard load completed; the home screen shows a
+    /// progress bar until it reaches 100
+    pub dashboard_load_progress: u8,
+    /// Progress of the dashboard load in flight, watched by the main loop to
+    /// redraw the progress bar while the load runs
+    pub dashboard_progress: tokio::sync::watch::Sender<u8>,
     /// Address data for address lookup screen
     pub address_data: Option<CompleteAddressData>,
+    /// Block details for block explorer screen
+    pub block_data: Option<super::super::models::CompleteBlockData>,
+    /// Miner rewards of the looked-up block (PoW blocks only)
+    pub block_rewards: Option<crate::blockchain::BlockRewardInfo>,
+    /// Whether the block explorer input takes a `start-end` range
+    pub block_range_mode: bool,
+    /// Statistics for the last analysed block range
+    pub block_range_summary: Option<crate::blockchain::BlockRangeSummary>,
+    /// Blocks of the last `start-end` lookup, oldest first
+    pub block_range_data: Vec<super::super::models::BlockInfo>,
+    /// Selected row of `block_range_data`
+    pub block_range_index: usize,
     /// Transaction details for transaction viewer screen
     pub transaction_data: Option<super::super::models::TransactionDetails>,
     /// Whether input data section is expanded in transaction viewer
     pub input_data_expanded: bool,
+    /// Whether the transaction viewer draws transfers as a flow diagram
+    pub tx_diagram_mode: bool,
+    /// Whether the transaction viewer lists every access list slot
+    pub tx_access_list_expanded: bool,
+    /// Tab shown in the transaction viewer
+    pub transaction_tab: super::super::models::TransactionTab,
     /// Data source mode (Local Node or Etherscan)
     pub data_mode: Option<DataMode>,
     /// Mode selection state
     pub mode_selection_state: ModeSelectionState,
+    /// First-run setup wizard, shown before mode selection when no config exists
+    pub setup_wizard: Option<super::super::models::SetupWizard>,
     /// Pending address lookup (address being looked up in background)
     pub pending_address_lookup: Option<String>,
     /// Flag to trigger dashboard refresh in background
     pub pending_dashboard_refresh: bool,
+    /// Transaction counts of the most recent blocks received (oldest first)
+    pub block_tx_counts: VecDeque<u32>,
+    /// Timestamps of the blocks in `block_tx_counts`, used for the average block time
+    pub block_timestamps: VecDeque<u64>,
+    /// EIP-1559 fee suggestions shown on the gas tracker
+    pub gas_estimate: Option<crate::blockchain::Eip1559FeeEstimate>,
+    /// Legacy gas prices and the base fee of the latest block
+    pub gas_prices: Option<crate::blockchain::GasPrices>,
+    /// Last known ETH price in USD, for fee cost estimates
+    pub eth_price_usd: Option<f64>,
+    /// Gas metrics of recent blocks (oldest first) for the gas tracker history
+    pub gas_history_data: VecDeque<crate::blockchain::BlockGasMetric>,
+    /// Gas prices sampled while the gas tracker is open (oldest first), for the sparklines
+    pub gas_price_history: VecDeque<crate::blockchain::GasPrices>,
+    /// When the last gas price sample was requested
+    pub gas_sampled_at: Option<Instant>,
+    /// In-flight background gas price sample
+    pub gas_sample_pending:
+        Option<tokio::sync::oneshot::Receiver<crate::error::Result<crate::blockchain::GasPrices>>>,
+    /// ETH burned over the gas tracker's recent block window
+    pub gas_burned_recent: Option<f64>,
+    /// Last block added to the running burned ETH total
+    pub last_burned_block: Option<u64>,
+    /// Latest, safe and finalized block numbers, once fetched
+    pub block_finality: Option<crate::blockchain::BlockFinalityInfo>,
+    /// Form state for the transaction simulation screen
+    pub simulation_form: SimulationForm,
+    /// Scrollbar states for the address lookup tables, keyed by table name
+    pub scrollbar_states: HashMap<String, ScrollbarState>,
+    /// NFT collection shown on the gallery screen
+    pub nft_gallery_token: Option<super::super::models::TokenInfo>,
+    /// Metadata of the NFT card selected in the gallery
+    pub nft_metadata: Option<crate::blockchain::NftMetadata>,
+    /// ERC-721 token opened from the Tokens tab, as token ID and metadata
+    pub nft_detail_popup: Option<(String, crate::blockchain::NftMetadata)>,
+    /// Collection and held token IDs to pick from before opening the popup
+    pub nft_token_picker: Option<(super::super::models::TokenInfo, Vec<String>)>,
+    /// Selected row of the NFT token ID picker
+    pub nft_token_picker_index: usize,
+    /// Token shown on the token information screen
+    pub token_info_token: Option<super::super::models::TokenInfo>,
+    /// Selected tab of the token information screen
+    pub token_info_tab: super::super::models::TokenInfoTab,
+    /// Top holders of `token_info_token`, once loaded
+    pub token_holders: Option<Vec<crate::blockchain::TokenHolder>>,
+    /// Why the holders could not be loaded
+    pub token_holders_error: Option<String>,
+    /// Form state for the CREATE2 address calculator screen
+    pub create2_form: Create2Form,
+    /// Whether the report export path prompt is shown on address lookup
+    pub export_prompt_open: bool,
+    /// User labels keyed by lowercase address, saved to `address_book.json`
+    pub address_book: HashMap<String, super::super::models::AddressLabel>,
+    /// Address being labelled while the label prompt is open
+    pub label_prompt_address: Option<String>,
+    /// Whether the cache inspector panel is shown on the settings screen
+    pub cache_inspector_open: bool,
+    /// Entries matching the last cache inspector query
+    pub cache_inspector_entries: Option<Vec<crate::cache::CacheDebugInfo>>,
+    /// Whether clearing the whole cache is waiting for confirmation
+    pub cache_clear_confirm: bool,
+    /// Whether arrow keys on the settings screen edit cache TTLs instead of the theme
+    pub settings_ttl_focus: bool,
+    /// Selected row of the cache TTL table, an index into `CacheType::ALL`
+    pub settings_ttl_index: usize,
+    /// Whether the network switcher dialog is shown on the settings screen
+    pub network_picker_open: bool,
+    /// Config file editor opened from the settings screen
+    pub settings_editor: super::super::models::SettingsEditorState,
+    /// Selected row of the network switcher, an index into `Network::PRESET_CHAINS`
+    pub network_picker_index: usize,
+    /// Deep link from `--open`, applied once the data mode is selected
+    pub pending_deep_link: Option<super::navigation::DeepLink>,
+    /// Built-in themes and the one currently in use
+    pub theme_manager: ThemeManager,
+    /// Time of the last keystroke not yet validated
+    pub debounce_timer: Option<Instant>,
+    /// Validation result for the search input, updated once typing pauses
+    pub input_feedback: Option<InputFeedback>,
+    /// Time of the last keystroke not yet checked for an ENS preview
+    pub ens_preview_timer: Option<Instant>,
+    /// ENS resolution of the address lookup input, if it resolved
+    pub ens_preview: Option<EnsPreview>,
+    /// In-flight ENS preview and the input it was requested for
+    pub ens_preview_pending: Option<(String, tokio::sync::oneshot::Receiver<Option<String>>)>,
+    /// Clickable values drawn in the last frame, cleared before each render
+    pub clickable_regions: Vec<ClickableRegion>,
+    /// Contract last looked up on the verification screen
+    pub contract_source_address: Option<String>,
+    /// Its verified source, `None` if it is unverified
+    pub contract_source: Option<crate::blockchain::ContractSource>,
+    /// Tab shown for the verified contract
+    pub contract_source_tab: crate::ui::models::ContractSourceTab,
+    /// Lines scrolled past in the source or ABI view
+    pub contract_source_scroll: u16,
+    /// ABI of the contract on the interaction screen
+    pub loaded_abi: Option<ethers::abi::Abi>,
+    /// Functions of `loaded_abi`, in ABI order
+    pub loaded_abi_functions: Vec<crate::ui::models::AbiFunction>,
+    /// Multisig wallet screen state
+    pub multisig_state: crate::ui::models::MultisigState,
+    /// Generate tab of the wallet manager screen
+    pub wallet_generate: crate::ui::models::WalletGenerateState,
+    /// Pending transactions seen by the mempool monitor, newest first
+    pub mempool_txs: VecDeque<crate::ui::models::TransactionInfo>,
+    /// Pending transactions seen since the mempool monitor was opened
+    pub mempool_seen: u64,
+    /// From/to address filter of the mempool monitor
+    pub mempool_filter: String,
+    /// Whether the mempool monitor found no WebSocket provider to subscribe with
+    pub mempool_ws_missing: bool,
+    /// Log query of the event monitor
+    pub event_filter: crate::blockchain::EventFilter,
+    /// Logs matching `event_filter`, newest first; `None` until searched
+    pub event_logs: Option<Vec<ethers::types::Log>>,
+    /// Selected row of the event monitor's log table
+    pub event_log_index: usize,
+    /// Whether the event monitor's log table has focus instead of the filter
+    pub event_table_focus: bool,
+    /// Whether new logs are streamed over WebSocket
+    pub event_stream_live: bool,
+    /// Contract interaction screen state
+    pub contract_interaction_state: crate::ui::models::ContractInteractionState,
+    /// Last reported mouse position, for hover tooltips
+    pub mouse_position: Option<(u16, u16)>,
+    /// Submitted transactions whose confirmations are followed
+    pub tracked_transactions: Vec<crate::ui::models::TrackedTx>,
+    /// Whether the tracked transactions overlay is shown (Ctrl+T)
+    pub show_tracked_overlay: bool,
+    /// Whether the keybinding help is shown over the current screen (?)
+    pub show_help_overlay: bool,
+    /// Queued notifications (balance changes, new blocks), oldest first
+    pub notifications: VecDeque<crate::ui::models::Notification>,
+    /// First row of the keybinding reference shown
+    pub help_scroll: usize,
+    /// When the tracked transactions were last polled
+    pub tracked_polled_at: Option<Instant>,
+    /// In-flight poll of the tracked transactions
+    pub tracked_poll_pending: Option<tokio::sync::oneshot::Receiver<super::tracked::TrackedPoll>>,
+    /// Caps how many address prefetches run at once
+    pub prefetch_limiter: Arc<Semaphore>,
 }
 
 impl App {
@@ -74,35 +274,129 @@ impl App {
         blockchain_client: BlockchainService,
         cache_manager: CacheManager,
     ) -> Self {
+        let theme_manager = ThemeManager::new(&config.ui);
         Self {
             state: AppState::Home,
             previous_state: None,
             should_quit: false,
             input_mode: InputMode::Normal,
-            input: String::new(), // String::new() is already optimized
-            cursor_position: 0,
+            input: String::new(),
+            search_history: VecDeque::new(),
+            history_index: None,
+            // String::new() is already optimized
+            cursor_byte_pos: 0,
+            cursor_char_pos: 0,
             size: Rect::default(),
             navigation_history: Vec::new(), // Empty Vec is already optimized
+            nav_forward_stack: Vec::new(),
             current_tab: 0,
             current_list_index: 0,
             scroll_offset: 0,
             data_cache: HashMap::new(), // Empty HashMap is already optimized
             loading_states: HashMap::new(), // Empty HashMap is already optimized
-            error_message: None,
+            loading_messages: HashMap::new(),
+            spinner_frame: 0,
+            current_error: None,
+            error_popup_open: false,
+            error_show_debug: false,
             success_message: None,
+            clipboard: None,
+            copy_notification_until: None,
             config,
             blockchain_client,
             cache_manager,
             wallet_manager: WalletManager::new(),
             event_sender: None,
             dashboard_data: DashboardData::mock(), // This is the main cost, but needed for UI
+            dashboard_load_progress: 0,
+            dashboard_progress: tokio::sync::watch::Sender::new(0),
             address_data: None,
+            block_data: None,
+            block_rewards: None,
+            block_range_mode: false,
+            block_range_summary: None,
+            block_range_data: Vec::new(),
+            block_range_index: 0,
             transaction_data: None,
             input_data_expanded: false,
+            tx_diagram_mode: false,
+            tx_access_list_expanded: false,
+            transaction_tab: Default::default(),
             data_mode: None,
             mode_selection_state: ModeSelectionState::Selecting,
+            setup_wizard: None,
             pending_address_lookup: None,
             pending_dashboard_refresh: false,
+            block_tx_counts: VecDeque::new(),
+            block_timestamps: VecDeque::new(),
+            gas_estimate: None,
+            gas_prices: None,
+            eth_price_usd: None,
+            gas_history_data: VecDeque::new(),
+            gas_price_history: VecDeque::new(),
+            gas_sampled_at: None,
+            gas_sample_pending: None,
+            gas_burned_recent: None,
+            last_burned_block: None,
+            block_finality: None,
+            simulation_form: SimulationForm::default(),
+            scrollbar_states: HashMap::new(),
+            nft_gallery_token: None,
+            nft_metadata: None,
+            nft_detail_popup: None,
+            nft_token_picker: None,
+            nft_token_picker_index: 0,
+            token_info_token: None,
+            token_info_tab: Default::default(),
+            token_holders: None,
+            token_holders_error: None,
+            create2_form: Create2Form::default(),
+            export_prompt_open: false,
+            address_book: HashMap::new(),
+            label_prompt_address: None,
+            cache_inspector_open: false,
+            cache_inspector_entries: None,
+            cache_clear_confirm: false,
+            settings_ttl_focus: false,
+            settings_ttl_index: 0,
+            network_picker_open: false,
+            settings_editor: Default::default(),
+            network_picker_index: 0,
+            pending_deep_link: None,
+            theme_manager,
+            debounce_timer: None,
+            ens_preview_timer: None,
+            ens_preview: None,
+            ens_preview_pending: None,
+            clickable_regions: Vec::new(),
+            contract_source_address: None,
+            contract_source: None,
+            contract_source_tab: Default::default(),
+            contract_source_scroll: 0,
+            loaded_abi: None,
+            loaded_abi_functions: Vec::new(),
+            contract_interaction_state: Default::default(),
+            multisig_state: Default::default(),
+            wallet_generate: Default::default(),
+            mempool_txs: VecDeque::new(),
+            mempool_seen: 0,
+            mempool_filter: String::new(),
+            mempool_ws_missing: false,
+            event_filter: Default::default(),
+            event_logs: None,
+            event_log_index: 0,
+            event_table_focus: false,
+            event_stream_live: false,
+            mouse_position: None,
+            tracked_transactions: Vec::new(),
+            show_tracked_overlay: false,
+            show_help_overlay: false,
+            notifications: VecDeque::new(),
+            help_scroll: 0,
+            tracked_polled_at: None,
+            tracked_poll_pending: None,
+            input_feedback: None,
+            prefetch_limiter: Arc::new(Semaphore::new(super::prefetch::PREFETCH_CONCURRENCY)),
         }
     }
 
@@ -130,4 +424,40 @@ impl App {
     pub fn state_title(&self) -> &'static str {
         self.state.title()
     }
+
+    /// Copy text to the system clipboard and show the copy popup
+    ///
+    /// Without a system clipboard (headless sessions, SSH) the text is sent
+    /// to the terminal with OSC 52 instead.
+    pub fn copy_to_clipboard(&mut self, text: &str) -> Result<()> {
+        if self.clipboard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(e) => {
+                    tracing::warn!(target: "warpscan", "System clipboard unavailable: {}", e)
+                }
+            }
+        }
+        let copied = match self.clipboard.as_mut() {
+            Some(clipboard) => match clipboard.set_text(text) {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::warn!(target: "warpscan", "Failed to set clipboard text: {}", e);
+                    false
+                }
+            },
+            None => false,
+        };
+        if !copied {
+            crate::ui::copy_to_clipboard(text)?;
+        }
+        self.copy_notification_until = Some(Instant::now() + COPY_NOTIFICATION_DURATION);
+        Ok(())
+    }
+
+    /// Whether the "Copied to clipboard" popup should be drawn
+    pub fn copy_notification_visible(&self) -> bool {
+        self.copy_notification_until
+            .is_some_and(|until| Instant::now() < until)
+    }
 }
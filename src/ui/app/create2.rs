@@ -0,0 +1,129 @@
+//! CREATE2 address calculator handling
+
+use super::super::models::{Create2Form, CREATE2_FIELD_COUNT, KNOWN_CREATE2_DEPLOYERS};
+use super::core::App;
+use super::state::{AppState, InputMode};
+use crate::blockchain::address_utils::calculate_create2_address;
+
+impl App {
+    /// Open the CREATE2 calculator screen with an empty form
+    pub async fn open_create2_calculator(&mut self) {
+        self.navigate_to(AppState::Create2Calculator).await;
+        self.create2_form = Create2Form::default();
+    }
+
+    /// Whether the "Check if deployed" button is focused
+    pub fn create2_button_focused(&self) -> bool {
+        self.current_list_index == CREATE2_FIELD_COUNT - 1
+    }
+
+    /// Move focus to the next CREATE2 field
+    pub fn create2_next_field(&mut self) {
+        if self.current_list_index < CREATE2_FIELD_COUNT - 1 {
+            self.current_list_index += 1;
+        }
+    }
+
+    /// Start editing the focused CREATE2 field
+    pub fn edit_create2_field(&mut self) {
+        let value = self.create2_form.field(self.current_list_index).to_string();
+        self.set_input(value);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Store the edited input into the focused field and recompute the prediction
+    pub fn commit_create2_field(&mut self, value: String) {
+        self.create2_form.set_field(self.current_list_index, value);
+        self.clear_input();
+        self.recalculate_create2_address();
+    }
+
+    /// All deployers offered for quick-select: well-known factories, then saved ones
+    pub fn create2_deployer_choices(&self) -> Vec<String> {
+        let mut choices: Vec<String> = KNOWN_CREATE2_DEPLOYERS
+            .iter()
+            .map(|(_, address)| address.to_string())
+            .collect();
+        for saved in &self.config.saved_deployers {
+            if !choices.iter().any(|c| c.eq_ignore_ascii_case(saved)) {
+                choices.push(saved.clone());
+            }
+        }
+        choices
+    }
+
+    /// Fill the deployer with the next entry of the quick-select list
+    pub fn create2_quick_select_deployer(&mut self) {
+        let choices = self.create2_deployer_choices();
+        let next = self
+            .create2_form
+            .quick_select_index
+            .map_or(0, |i| (i + 1) % choices.len());
+
+        self.create2_form.quick_select_index = Some(next);
+        self.create2_form.deployer = choices[next].clone();
+        self.recalculate_create2_address();
+    }
+
+    /// Add the current deployer to the quick-select list and save it to the config
+    pub fn save_create2_deployer(&mut self) {
+        let deployer = self.create2_form.deployer.trim().to_string();
+        if !super::validation::is_address(&deployer) {
+            self.set_error("Enter a valid deployer address to save it".to_string());
+            return;
+        }
+
+        if self
+            .create2_deployer_choices()
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(&deployer))
+        {
+            self.set_success("Deployer is already in the quick-select list".to_string());
+        } else {
+            self.config.saved_deployers.push(deployer);
+            match self.config.save() {
+                Ok(()) => self.set_success("Deployer saved to the quick-select list".to_string()),
+                Err(e) => self.report_error("Failed to save deployer to config", &e),
+            }
+        }
+    }
+
+    /// Recompute the predicted address once all inputs are present
+    fn recalculate_create2_address(&mut self) {
+        let form = &mut self.create2_form;
+        form.predicted = None;
+        form.deployed = None;
+
+        if form.deployer.trim().is_empty()
+            || form.salt.trim().is_empty()
+            || form.init_code_hash.trim().is_empty()
+        {
+            return;
+        }
+
+        match calculate_create2_address(&form.deployer, &form.salt, &form.init_code_hash) {
+            Ok(address) => {
+                form.predicted = Some(address);
+                self.clear_messages();
+            }
+            Err(e) => self.set_error(format!("{}", e)),
+        }
+    }
+
+    /// Check whether code already exists at the predicted address
+    pub async fn check_create2_deployment(&mut self) {
+        let Some(predicted) = self.create2_form.predicted.clone() else {
+            self.set_error("Fill in all fields to predict an address first".to_string());
+            return;
+        };
+
+        self.set_loading("create2_check", true);
+        let result = self.blockchain_client.is_contract(&predicted).await;
+        self.set_loading("create2_check", false);
+
+        match result {
+            Ok(deployed) => self.create2_form.deployed = Some(deployed),
+            Err(e) => self.report_error("Failed to check deployment", &e),
+        }
+    }
+}
@@ -1,11 +1,28 @@
-use super::super::models::{BlockInfo, TransactionInfo};
+use super::super::models::{AppError, BlockInfo, TransactionInfo};
 use super::core::App;
+use crate::blockchain::BlockchainService;
+use crate::error::Result;
 use crate::ui::models::TransactionStatus;
+use ethers::types::{Transaction, TransactionReceipt};
 
 impl App {
     /// Set loading state for an operation
     pub fn set_loading(&mut self, operation: &str, loading: bool) {
         self.loading_states.insert(operation.to_string(), loading);
+        if !loading {
+            self.loading_messages.remove(operation);
+        }
+    }
+
+    /// Start an operation with a message describing what it is fetching
+    pub fn set_loading_with_message(&mut self, operation: &str, message: String) {
+        self.set_loading(operation, true);
+        self.loading_messages.insert(operation.to_string(), message);
+    }
+
+    /// Message of a loading operation, if one was set
+    pub fn loading_message(&self, operation: &str) -> Option<&str> {
+        self.loading_messages.get(operation).map(String::as_str)
     }
 
     /// Check if an operation is loading
@@ -15,14 +32,50 @@ impl App {
 
     /// Set error message
     pub fn set_error(&mut self, message: String) {
-        self.error_message = Some(message);
+        self.current_error = Some(AppError::from_message(message));
         self.success_message = None;
     }
 
+    /// Report a failed operation and open the error popup with its causes
+    pub fn report_error(&mut self, context: &str, error: &crate::error::Error) {
+        self.current_error = Some(AppError::from_error(context, error));
+        self.error_popup_open = true;
+        self.error_show_debug = false;
+        self.success_message = None;
+    }
+
+    /// Message of the current error, if any
+    pub fn error_message(&self) -> Option<&str> {
+        self.current_error.as_ref().map(|e| e.message.as_str())
+    }
+
+    /// Copy everything known about the current error to the clipboard
+    pub fn copy_current_error(&mut self) {
+        let Some(text) = self.current_error.as_ref().map(AppError::full_text) else {
+            return;
+        };
+
+        match crate::ui::copy_to_clipboard(&text) {
+            Ok(()) => {
+                self.error_popup_open = false;
+                self.set_success("Copied error details".to_string());
+            }
+            Err(e) => {
+                tracing::warn!(target: "warpscan", "Failed to copy error details: {}", e);
+            }
+        }
+    }
+
+    /// Close the error popup, keeping the error shown inline
+    pub fn dismiss_error_popup(&mut self) {
+        self.error_popup_open = false;
+        self.error_show_debug = false;
+    }
+
     /// Set success message
     pub fn set_success(&mut self, message: String) {
         self.success_message = Some(message);
-        self.error_message = None;
+        self.current_error = None;
     }
 
     /// Cache data for a key
@@ -40,6 +93,43 @@ impl App {
         self.data_cache.clear();
     }
 
+    /// Record the transaction count of a newly received block for TPS tracking
+    pub fn record_block_tx_count(&mut self, transaction_count: u32, timestamp: u64) {
+        const MAX_TPS_SAMPLES: usize = 30;
+
+        self.block_tx_counts.push_back(transaction_count);
+        self.block_timestamps.push_back(timestamp);
+        while self.block_tx_counts.len() > MAX_TPS_SAMPLES {
+            self.block_tx_counts.pop_front();
+            self.block_timestamps.pop_front();
+        }
+    }
+
+    /// Rolling transactions per second over the recorded blocks
+    ///
+    /// Returns `None` until at least 3 blocks have been received.
+    pub fn rolling_tps(&self) -> Option<f64> {
+        const MIN_TPS_SAMPLES: usize = 3;
+        const DEFAULT_BLOCK_TIME_SECS: f64 = 12.0;
+
+        let samples = self.block_tx_counts.len();
+        if samples < MIN_TPS_SAMPLES {
+            return None;
+        }
+
+        let first = *self.block_timestamps.front()?;
+        let last = *self.block_timestamps.back()?;
+        let avg_block_time = if last > first {
+            (last - first) as f64 / (samples - 1) as f64
+        } else {
+            // Local nodes can mine several blocks within the same second
+            DEFAULT_BLOCK_TIME_SECS
+        };
+
+        let total_txs: u64 = self.block_tx_counts.iter().map(|&c| c as u64).sum();
+        Some(total_txs as f64 / (samples as f64 * avg_block_time))
+    }
+
     /// Refresh dashboard data with real blockchain data
     pub async fn refresh_dashboard(&mut self) {
         self.set_loading("dashboard_refresh", true);
@@ -67,102 +157,91 @@ impl App {
             }
         };
 
-        // Fetch latest block and network stats in parallel
-        let (latest_block_result, block_number_result) = tokio::join!(
-            self.blockchain_client.get_latest_block(),
-            self.blockchain_client.get_block_number(),
+        self.dashboard_load_progress = 0;
+        self.dashboard_progress.send_replace(0);
+
+        // Fetch everything concurrently, advancing the progress bar as each
+        // part completes
+        let client = &self.blockchain_client;
+        let tick = || {
+            self.dashboard_progress
+                .send_modify(|progress| *progress = (*progress + DASHBOARD_LOAD_STEP).min(100))
+        };
+        let (
+            (latest_block_result, block_number_result, finality_result),
+            price_result,
+            supply_result,
+            (blocks_result, transactions),
+        ) = tokio::join!(
+            async {
+                let results = tokio::join!(
+                    client.get_latest_block(),
+                    client.get_block_number(),
+                    client.get_block_finality_labels(),
+                );
+                tick();
+                results
+            },
+            async {
+                let result = client.get_eth_price().await;
+                tick();
+                result
+            },
+            async {
+                let result = client.get_eth_supply().await;
+                tick();
+                result
+            },
+            async {
+                let blocks = fetch_latest_blocks(client).await;
+                tick();
+                let hashes = match blocks {
+                    Ok((_, ref hashes)) => hashes.as_slice(),
+                    Err(_) => &[],
+                };
+                let transactions = fetch_latest_transactions(client, hashes).await;
+                tick();
+                (blocks, transactions)
+            },
         );
 
-        // Update network stats
-        if let Ok(Some(block)) = latest_block_result {
-            if let Some(block_number) = block.number {
-                let block_num = block_number.as_u64();
-                self.dashboard_data.network_stats.latest_block = block_num;
-
-                // Update block timestamp
-                let timestamp = block.timestamp.as_u64();
-                let now = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs();
-                let seconds_ago = now.saturating_sub(timestamp);
-                self.dashboard_data.network_stats.block_time = format!("{} secs ago", seconds_ago);
-            }
-        } else if let Err(e) = latest_block_result {
-            tracing::warn!(target: "warpscan", "Failed to fetch latest block: {}", e);
-            self.set_error(format!("Failed to fetch latest block: {}", e));
+        match finality_result {
+            Ok(finality) => self.block_finality = Some(finality),
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to fetch block finality: {}", e),
         }
 
-        if let Ok(block_number) = block_number_result {
-            self.dashboard_data.network_stats.latest_block = block_number;
-        } else if let Err(e) = block_number_result {
-            tracing::warn!(target: "warpscan", "Failed to fetch block number: {}", e);
+        // ETH price and market cap come from Etherscan's stats module
+        match price_result {
+            Ok(Some(price)) => {
+                let stats = &mut self.dashboard_data.network_stats;
+                stats.ethereum_price = price.usd;
+                stats.price_change_24h = price.usd_change;
+                if let Ok(Some(supply)) = supply_result {
+                    if let Ok(wei) = supply.eth_supply.parse::<f64>() {
+                        stats.market_cap = wei / 1_000_000_000_000_000_000.0 * price.usd;
+                    }
+                }
+                self.eth_price_usd = Some(price.usd);
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to fetch ETH price: {}", e),
         }
 
-        // Yield before starting block fetching to keep UI responsive
-        tokio::task::yield_now().await;
-
-        // Fetch latest blocks and collect transaction hashes
-        let mut all_tx_hashes = Vec::new();
-        match self.blockchain_client.get_latest_block().await {
-            Ok(Some(latest_block)) => {
-                if let Some(block_number) = latest_block.number {
-                    let mut blocks = Vec::new();
-                    let start_block = block_number.as_u64();
-
-                    // Fetch last 5 blocks
-                    for i in 0..5 {
-                        // Yield every block fetch to keep UI responsive
-                        if i > 0 {
-                            tokio::task::yield_now().await;
-                        }
-                        match self
-                            .blockchain_client
-                            .get_block_by_number(start_block.saturating_sub(i))
-                            .await
-                        {
-                            Ok(Some(block)) => {
-                                if let Some(num) = block.number {
-                                    let block_num = num.as_u64();
-                                    let block_info = BlockInfo {
-                                        number: block_num,
-                                        hash: block
-                                            .hash
-                                            .map(|h| format!("{:#x}", h))
-                                            .unwrap_or_else(|| "0x0".to_string()),
-                                        transaction_count: block.transactions.len() as u32,
-                                        timestamp: block.timestamp.as_u64(),
-                                        gas_limit: block.gas_limit.as_u64(),
-                                        gas_used: block.gas_used.as_u64(),
-                                        miner: block
-                                            .author
-                                            .map(|a| format!("{:#x}", a))
-                                            .unwrap_or_else(|| "0x0".to_string()),
-                                        size: 0,     // Size not available from RPC
-                                        reward: 0.0, // Reward not available from RPC
-                                    };
-                                    blocks.push(block_info);
-
-                                    // Collect transaction hashes from this block
-                                    for tx_hash in &block.transactions {
-                                        all_tx_hashes.push((
-                                            format!("{:#x}", tx_hash),
-                                            block_num,
-                                            block.timestamp.as_u64(),
-                                        ));
-                                    }
-                                }
-                            }
-                            Ok(None) => {
-                                // Block not found - might be beyond available range
-                                tracing::debug!(target: "warpscan", "Block {} not found", start_block.saturating_sub(i));
-                            }
-                            Err(e) => {
-                                tracing::warn!(target: "warpscan", "Failed to fetch block {}: {}", start_block.saturating_sub(i), e);
-                            }
-                        }
-                    }
-                    self.dashboard_data.latest_blocks = blocks;
+        // Update network stats
+        match latest_block_result {
+            Ok(Some(block)) => {
+                if let Some(block_number) = block.number {
+                    self.dashboard_data.network_stats.latest_block = block_number.as_u64();
+
+                    // Update block timestamp
+                    let timestamp = block.timestamp.as_u64();
+                    let now = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .unwrap()
+                        .as_secs();
+                    let seconds_ago = now.saturating_sub(timestamp);
+                    self.dashboard_data.network_stats.block_time =
+                        format!("{} secs ago", seconds_ago);
                 }
             }
             Ok(None) => {
@@ -175,88 +254,172 @@ impl App {
             }
         }
 
-        // Fetch latest transactions from the collected hashes
-        // Sort by block number (descending) and take latest 5
-        all_tx_hashes.sort_by(|a, b| b.1.cmp(&a.1));
-        all_tx_hashes.truncate(5);
+        if let Ok(block_number) = block_number_result {
+            self.dashboard_data.network_stats.latest_block = block_number;
+        } else if let Err(e) = block_number_result {
+            tracing::warn!(target: "warpscan", "Failed to fetch block number: {}", e);
+        }
 
-        // Yield before fetching transactions
-        tokio::task::yield_now().await;
+        match blocks_result {
+            Ok((blocks, _)) => self.dashboard_data.latest_blocks = blocks,
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to fetch latest blocks: {}", e),
+        }
+        self.dashboard_data.latest_transactions = transactions;
 
-        let mut transactions = Vec::new();
-        for (idx, (tx_hash_str, block_num, block_timestamp)) in all_tx_hashes.iter().enumerate() {
-            // Yield every transaction fetch to keep UI responsive
-            if idx > 0 {
-                tokio::task::yield_now().await;
-            }
-            // Fetch transaction details
-            if let Ok(Some(tx)) = self
-                .blockchain_client
-                .get_transaction_by_hash(tx_hash_str)
-                .await
-            {
-                // Fetch transaction receipt for gas_used and status
-                let receipt_result = self
-                    .blockchain_client
-                    .get_transaction_receipt(tx_hash_str)
-                    .await;
-
-                let (gas_used, status, gas_price_gwei) = if let Ok(Some(receipt)) = receipt_result {
-                    let gas_used_val = receipt.gas_used.map(|g| g.as_u128() as u64).unwrap_or(0);
-                    let status_val = if receipt.status == Some(1.into()) {
-                        TransactionStatus::Success
-                    } else if receipt.status == Some(0.into()) {
-                        TransactionStatus::Failed
-                    } else {
-                        TransactionStatus::Pending
-                    };
-                    let gas_price_val = receipt
-                        .effective_gas_price
-                        .map(|p| p.as_u64() / 1_000_000_000) // Convert to gwei
-                        .unwrap_or_else(|| {
-                            // Fallback to transaction gas_price if effective_gas_price not available
-                            tx.gas_price
-                                .map(|p| p.as_u64() / 1_000_000_000)
-                                .unwrap_or(0)
-                        });
-                    (gas_used_val, status_val, gas_price_val)
-                } else {
-                    // Fallback if receipt not available (pending transaction)
-                    let gas_price_val = tx
-                        .gas_price
-                        .map(|p| p.as_u64() / 1_000_000_000)
-                        .unwrap_or(0);
-                    (0, TransactionStatus::Pending, gas_price_val)
-                };
+        self.dashboard_load_progress = 100;
+        self.dashboard_progress.send_replace(100);
+        self.set_loading("dashboard_refresh", false);
+    }
+}
 
-                // Convert value from wei to ETH
-                const WEI_TO_ETH: f64 = 1_000_000_000_000_000_000.0;
-                let value_eth = tx.value.as_u128() as f64 / WEI_TO_ETH;
-
-                // Calculate transaction fee (gas_used * gas_price in ETH)
-                let tx_fee_eth = (gas_used as f64 * gas_price_gwei as f64) / 1_000_000_000.0;
-
-                let tx_info = TransactionInfo {
-                    hash: tx_hash_str.clone(),
-                    from: format!("{:#x}", tx.from),
-                    to: tx.to.map(|a| format!("{:#x}", a)).unwrap_or_default(),
-                    value: value_eth,
-                    gas_price: gas_price_gwei,
-                    gas_used,
-                    status,
-                    timestamp: *block_timestamp,
-                    block_number: *block_num,
-                    transaction_fee: tx_fee_eth,
-                };
+/// Progress added by each of the five parts of a dashboard load
+const DASHBOARD_LOAD_STEP: u8 = 20;
 
-                transactions.push(tx_info);
-            }
+/// Transactions listed on the dashboard
+const DASHBOARD_TRANSACTIONS: usize = 10;
+
+/// Transaction hash with the number and timestamp of its block
+type BlockTxHash = (String, u64, u64);
+
+/// The latest 5 blocks, newest first, with the hashes of their transactions
+async fn fetch_latest_blocks(
+    client: &BlockchainService,
+) -> Result<(Vec<BlockInfo>, Vec<BlockTxHash>)> {
+    let latest = client.get_block_number().await?;
+
+    // Fetch the last 5 blocks in one batch request
+    let numbers: Vec<u64> = (0..5).filter_map(|i| latest.checked_sub(i)).collect();
+    let fetched = client.batch_get_blocks(&numbers).await?;
+
+    let mut blocks = Vec::new();
+    let mut tx_hashes = Vec::new();
+    for (number, block) in numbers.iter().zip(fetched) {
+        let Some(block) = block else {
+            // Block not found - might be beyond available range
+            tracing::debug!(target: "warpscan", "Block {} not found", number);
+            continue;
+        };
+        let Some(num) = block.number else {
+            continue;
+        };
+        let block_num = num.as_u64();
+        blocks.push(BlockInfo {
+            number: block_num,
+            hash: block
+                .hash
+                .map(|h| format!("{:#x}", h))
+                .unwrap_or_else(|| "0x0".to_string()),
+            transaction_count: block.transactions.len() as u32,
+            timestamp: block.timestamp.as_u64(),
+            gas_limit: block.gas_limit.as_u64(),
+            gas_used: block.gas_used.as_u64(),
+            miner: block
+                .author
+                .map(|a| format!("{:#x}", a))
+                .unwrap_or_else(|| "0x0".to_string()),
+            size: 0,     // Size not available from RPC
+            reward: 0.0, // Reward not available from RPC
+        });
+
+        // Collect transaction hashes from this block
+        for tx_hash in &block.transactions {
+            tx_hashes.push((
+                format!("{:#x}", tx_hash),
+                block_num,
+                block.timestamp.as_u64(),
+            ));
         }
+    }
+    Ok((blocks, tx_hashes))
+}
 
-        // Sort transactions by block number (descending) to show most recent first
-        transactions.sort_by(|a, b| b.block_number.cmp(&a.block_number));
-        self.dashboard_data.latest_transactions = transactions;
+/// Details of the newest transactions among `tx_hashes`, fetched concurrently
+async fn fetch_latest_transactions(
+    client: &BlockchainService,
+    tx_hashes: &[BlockTxHash],
+) -> Vec<TransactionInfo> {
+    let mut latest: Vec<&BlockTxHash> = tx_hashes.iter().collect();
+    latest.sort_by_key(|entry| std::cmp::Reverse(entry.1));
+    latest.truncate(DASHBOARD_TRANSACTIONS);
 
-        self.set_loading("dashboard_refresh", false);
+    let fetches = latest
+        .into_iter()
+        .map(|(hash, block_number, timestamp)| async move {
+            let (tx_result, receipt_result) = tokio::join!(
+                client.get_transaction_by_hash(hash),
+                client.get_transaction_receipt(hash),
+            );
+            let tx = tx_result.ok().flatten()?;
+            let receipt = receipt_result.ok().flatten();
+            Some(transaction_info(
+                hash,
+                &tx,
+                receipt.as_ref(),
+                *block_number,
+                *timestamp,
+            ))
+        });
+
+    let mut transactions: Vec<TransactionInfo> = futures::future::join_all(fetches)
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+    // Sort transactions by block number (descending) to show most recent first
+    transactions.sort_by_key(|tx| std::cmp::Reverse(tx.block_number));
+    transactions
+}
+
+/// Dashboard row for a transaction, using its receipt for gas and status
+fn transaction_info(
+    hash: &str,
+    tx: &Transaction,
+    receipt: Option<&TransactionReceipt>,
+    block_number: u64,
+    timestamp: u64,
+) -> TransactionInfo {
+    // Fallback to transaction gas_price if effective_gas_price not available
+    let tx_gas_price = tx
+        .gas_price
+        .map(|p| p.as_u64() / 1_000_000_000)
+        .unwrap_or(0);
+    let (gas_used, status, gas_price_gwei) = match receipt {
+        Some(receipt) => {
+            let gas_used_val = receipt.gas_used.map(|g| g.as_u128() as u64).unwrap_or(0);
+            let status_val = if receipt.status == Some(1.into()) {
+                TransactionStatus::Success
+            } else if receipt.status == Some(0.into()) {
+                TransactionStatus::Failed
+            } else {
+                TransactionStatus::Pending
+            };
+            let gas_price_val = receipt
+                .effective_gas_price
+                .map(|p| p.as_u64() / 1_000_000_000) // Convert to gwei
+                .unwrap_or(tx_gas_price);
+            (gas_used_val, status_val, gas_price_val)
+        }
+        // Fallback if receipt not available (pending transaction)
+        None => (0, TransactionStatus::Pending, tx_gas_price),
+    };
+
+    // Convert value from wei to ETH
+    const WEI_TO_ETH: f64 = 1_000_000_000_000_000_000.0;
+    let value_eth = tx.value.as_u128() as f64 / WEI_TO_ETH;
+
+    // Calculate transaction fee (gas_used * gas_price in ETH)
+    let tx_fee_eth = (gas_used as f64 * gas_price_gwei as f64) / 1_000_000_000.0;
+
+    TransactionInfo {
+        hash: hash.to_string(),
+        from: format!("{:#x}", tx.from),
+        to: tx.to.map(|a| format!("{:#x}", a)).unwrap_or_default(),
+        value: value_eth,
+        gas_price: gas_price_gwei,
+        gas_used,
+        status,
+        timestamp,
+        block_number,
+        transaction_fee: tx_fee_eth,
     }
 }
@@ -0,0 +1,172 @@
+//! Debounced validation of the search input
+//!
+//! Keystrokes only restart a timer; once typing pauses for
+//! `ui.debounce_delay_ms` the input is classified locally, without network
+//! calls, so the search bar can show what it will open. On the address lookup
+//! screen a longer pause also previews the input's ENS resolution.
+
+use super::core::App;
+use super::state::{AppState, EnsPreview, InputFeedback};
+use super::validation::{
+    is_address, is_block_number, is_ens_name, is_hex_prefix, is_transaction_hash,
+};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Pause in typing before the ENS preview is resolved; longer than the
+/// validation delay because it costs an RPC call
+const ENS_PREVIEW_DELAY_MS: u64 = 800;
+
+impl App {
+    /// Restart the debounce timer after the input changed
+    pub fn touch_debounce_timer(&mut self) {
+        self.debounce_timer = Some(Instant::now());
+        self.ens_preview_timer = Some(Instant::now());
+        self.ens_preview = None;
+        self.ens_preview_pending = None;
+    }
+
+    /// Drop any pending validation and its result
+    pub fn reset_input_feedback(&mut self) {
+        self.debounce_timer = None;
+        self.input_feedback = None;
+        self.ens_preview_timer = None;
+        self.ens_preview = None;
+        self.ens_preview_pending = None;
+    }
+
+    /// Validate the input once the debounce delay has passed and collect the
+    /// ENS preview; called on tick
+    pub fn poll_debounce_timer(&mut self) {
+        let delay = Duration::from_millis(self.config.ui.debounce_delay_ms);
+        if self
+            .debounce_timer
+            .is_some_and(|typed_at| typed_at.elapsed() >= delay)
+        {
+            self.debounce_timer = None;
+            self.on_debounced_input();
+        }
+
+        let ens_delay = Duration::from_millis(ENS_PREVIEW_DELAY_MS);
+        if self
+            .ens_preview_timer
+            .is_some_and(|typed_at| typed_at.elapsed() >= ens_delay)
+        {
+            self.ens_preview_timer = None;
+            self.request_ens_preview();
+        }
+        self.poll_ens_preview();
+    }
+
+    /// Resolve the address lookup input in the background if it is an ENS
+    /// name or an address; the result is picked up by `poll_ens_preview`
+    fn request_ens_preview(&mut self) {
+        if self.state != AppState::AddressLookup {
+            return;
+        }
+        let input = self.get_input().trim().to_string();
+        let is_name = is_ens_name(&input);
+        if !is_name && !is_address(&input) {
+            return;
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let service = self.blockchain_client.background_handle();
+        let task_input = input.clone();
+        tokio::spawn(async move {
+            let resolved = if is_name {
+                service.resolve_ens_address(&task_input).await
+            } else {
+                service.resolve_ens_name(&task_input).await
+            };
+            // Failures are not worth an error for a preview
+            let _ = sender.send(resolved.ok().flatten());
+        });
+        self.ens_preview_pending = Some((input, receiver));
+    }
+
+    /// Show a finished ENS preview, unless the input changed since it was requested
+    fn poll_ens_preview(&mut self) {
+        let Some((input, receiver)) = self.ens_preview_pending.as_mut() else {
+            return;
+        };
+        let resolved = match receiver.try_recv() {
+            Ok(resolved) => resolved,
+            Err(oneshot::error::TryRecvError::Empty) => return,
+            Err(oneshot::error::TryRecvError::Closed) => None,
+        };
+        let input = std::mem::take(input);
+        self.ens_preview_pending = None;
+
+        let Some(resolved) = resolved else {
+            return;
+        };
+        if self.state != AppState::AddressLookup || self.get_input().trim() != input {
+            return;
+        }
+        self.ens_preview = Some(if is_address(&input) {
+            EnsPreview::Name {
+                input,
+                name: resolved,
+            }
+        } else {
+            EnsPreview::Address {
+                input,
+                address: resolved,
+            }
+        });
+    }
+
+    /// Address an ENS name in the input resolves to, from the preview when it
+    /// is current and from the node otherwise
+    pub async fn resolve_ens_input(&self, input: &str) -> Option<String> {
+        if !is_ens_name(input) {
+            return None;
+        }
+        if let Some(EnsPreview::Address {
+            input: previewed,
+            address,
+        }) = &self.ens_preview
+        {
+            if previewed == input {
+                return Some(address.clone());
+            }
+        }
+        self.blockchain_client
+            .resolve_ens_address(input)
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// Classify the input typed so far
+    pub fn on_debounced_input(&mut self) {
+        let input = self.get_input().trim();
+        self.input_feedback = if input.is_empty() {
+            None
+        } else if is_address(input) {
+            Some(self.address_feedback(input))
+        } else if is_transaction_hash(input) {
+            Some(InputFeedback::TransactionHash)
+        } else if is_block_number(input) {
+            Some(InputFeedback::BlockNumber)
+        } else if is_hex_prefix(input) {
+            Some(InputFeedback::Partial)
+        } else {
+            Some(InputFeedback::Invalid)
+        };
+    }
+
+    /// EOA or contract when the address is already cached
+    fn address_feedback(&self, address: &str) -> InputFeedback {
+        let cached = self
+            .cache_manager
+            .get_address_info(address)
+            .or_else(|| self.cache_manager.get_address_info(&address.to_lowercase()));
+        match cached {
+            Some(info) if info.is_contract => InputFeedback::Contract,
+            Some(_) => InputFeedback::Eoa,
+            None => InputFeedback::Address,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Event monitor: past logs matching a filter, then live ones over WebSocket
+
+use super::block::parse_block_range;
+use super::core::App;
+use super::state::InputMode;
+use super::validation::is_address;
+use ethers::types::{Log, H256};
+use std::str::FromStr;
+
+/// Focusable rows of the filter panel (3 inputs + Search button)
+pub const EVENT_FILTER_FIELD_COUNT: usize = 4;
+
+/// Blocks searched back from the latest one when no range is given
+const DEFAULT_EVENT_RANGE_BLOCKS: u64 = 100;
+
+/// Logs kept on screen; the oldest are dropped as live ones arrive
+pub const EVENT_LOG_LIMIT: usize = 500;
+
+impl App {
+    /// Text of filter field `index`: address, topic0, block range
+    pub fn event_filter_field(&self, index: usize) -> String {
+        let filter = &self.event_filter;
+        match index {
+            0 => filter.contract_address.clone().unwrap_or_default(),
+            1 => filter
+                .topic0
+                .map(|topic| format!("{:#x}", topic))
+                .unwrap_or_default(),
+            2 if filter.block_range != (0, 0) => {
+                format!("{}-{}", filter.block_range.0, filter.block_range.1)
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Whether the Search button of the filter panel is focused
+    pub fn event_search_button_focused(&self) -> bool {
+        !self.event_table_focus && self.current_list_index == EVENT_FILTER_FIELD_COUNT - 1
+    }
+
+    /// Switch the focus between the filter panel and the log table
+    pub fn toggle_event_table_focus(&mut self) {
+        self.event_table_focus = !self.event_table_focus && self.event_logs.is_some();
+    }
+
+    /// Move the selection of the focused panel by one row
+    pub fn event_monitor_select(&mut self, forward: bool) {
+        let (index, rows) = if self.event_table_focus {
+            let rows = self.event_logs.as_ref().map_or(0, Vec::len);
+            (&mut self.event_log_index, rows)
+        } else {
+            (&mut self.current_list_index, EVENT_FILTER_FIELD_COUNT)
+        };
+        *index = if forward {
+            (*index + 1).min(rows.saturating_sub(1))
+        } else {
+            index.saturating_sub(1)
+        };
+    }
+
+    /// Start editing the focused filter field
+    pub fn edit_event_filter_field(&mut self) {
+        self.set_input(self.event_filter_field(self.current_list_index));
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Parse and store the edited filter field; an empty value clears it
+    pub fn commit_event_filter_field(&mut self, value: String) {
+        self.clear_input();
+        let value = value.trim();
+        let filter = &mut self.event_filter;
+        let error = match self.current_list_index {
+            0 if value.is_empty() => {
+                filter.contract_address = None;
+                None
+            }
+            0 if is_address(value) => {
+                filter.contract_address = Some(value.to_string());
+                None
+            }
+            0 => Some("Invalid contract address"),
+            1 if value.is_empty() => {
+                filter.topic0 = None;
+                None
+            }
+            1 => match H256::from_str(value) {
+                Ok(topic) => {
+                    filter.topic0 = Some(topic);
+                    None
+                }
+                Err(_) => Some("Topic must be a 32-byte 0x hash"),
+            },
+            2 if value.is_empty() => {
+                filter.block_range = (0, 0);
+                None
+            }
+            2 => match parse_block_range(value) {
+                Some(range) => {
+                    filter.block_range = range;
+                    None
+                }
+                None => Some("Enter the block range as start-end"),
+            },
+            _ => None,
+        };
+        if let Some(error) = error {
+            self.set_error(error.to_string());
+        }
+    }
+
+    /// Fetch past logs matching the filter, then stream new ones
+    ///
+    /// Without a block range the last 100 blocks are searched.
+    pub async fn search_event_logs(&mut self) {
+        self.clear_messages();
+        self.set_loading_with_message("event_logs", "Fetching logs...".to_string());
+        if self.event_filter.block_range == (0, 0) {
+            match self.blockchain_client.get_block_number().await {
+                Ok(latest) => {
+                    self.event_filter.block_range = (
+                        latest.saturating_sub(DEFAULT_EVENT_RANGE_BLOCKS - 1),
+                        latest,
+                    )
+                }
+                Err(e) => {
+                    self.set_loading("event_logs", false);
+                    self.report_error("Failed to get the latest block", &e);
+                    return;
+                }
+            }
+        }
+
+        match self.blockchain_client.get_logs(&self.event_filter).await {
+            Ok(mut logs) => {
+                logs.reverse();
+                logs.truncate(EVENT_LOG_LIMIT);
+                self.event_logs = Some(logs);
+                self.event_log_index = 0;
+            }
+            Err(e) => self.report_error("Failed to get logs", &e),
+        }
+        self.set_loading("event_logs", false);
+
+        // Restart the live stream with the new filter
+        self.stop_current_subscriptions().await;
+        if let Err(e) = self.start_subscriptions().await {
+            tracing::warn!(target: "warpscan", "Failed to start log subscription: {}", e);
+        }
+    }
+
+    /// Add a streamed log to the top of the table
+    pub fn push_event_log(&mut self, log: Log) {
+        let Some(ref mut logs) = self.event_logs else {
+            return;
+        };
+        // Keep a selected row on the same log as new ones arrive above it
+        if self.event_log_index > 0 {
+            self.event_log_index = (self.event_log_index + 1).min(EVENT_LOG_LIMIT - 1);
+        }
+        logs.insert(0, log);
+        logs.truncate(EVENT_LOG_LIMIT);
+    }
+
+    /// Transaction that emitted the selected log
+    pub fn selected_event_log_tx(&self) -> Option<String> {
+        self.event_logs
+            .as_ref()?
+            .get(self.event_log_index)?
+            .transaction_hash
+            .map(|hash| format!("{:#x}", hash))
+    }
+}
@@ -3,17 +3,284 @@
 use super::super::models::AddressTab;
 use super::core::App;
 use super::state::{AppState, DataMode, InputMode, ModeSelectionState};
+use super::wallet::WALLET_GENERATE_TAB;
 use crate::error::Result;
-use crossterm::event::KeyCode;
+use crate::ui::events::KeyEventUtils;
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// A screen section of the keybinding reference: its name and `(key, action)`
+/// pairs
+pub type KeyBindingSection = (&'static str, &'static [(&'static str, &'static str)]);
+
+/// Keybinding reference shown on the help screen and the `?` overlay
+pub struct KeyBindings;
+
+impl KeyBindings {
+    /// Sections in display order; keep in step with the handlers below
+    pub const SECTIONS: &'static [KeyBindingSection] = &[
+        (
+            "Global",
+            &[
+                ("q", "Quit"),
+                ("Esc / h / ←", "Go back (Esc on Home quits)"),
+                ("Alt+← / Alt+→", "History back / forward"),
+                ("?", "Toggle this help"),
+                ("0", "Home"),
+                ("b", "Block explorer"),
+                ("t", "Transaction viewer"),
+                ("a", "Address lookup"),
+                ("g", "Gas tracker"),
+                ("w", "Wallet manager"),
+                ("c", "Settings"),
+                ("m", "Mempool monitor"),
+                ("L", "Log viewer (labels on Address Lookup)"),
+                ("p", "CREATE2 calculator"),
+                ("/ or s", "Edit the search input"),
+                ("y", "Copy the focused item"),
+                ("S", "Copy a deep link to the current view"),
+                ("Ctrl+T", "Tracked transactions overlay"),
+            ],
+        ),
+        (
+            "Lists and Input",
+            &[
+                ("↑ / ↓", "Move the selection"),
+                ("Tab / →", "Next tab or panel"),
+                ("Enter", "Open the selection or edit the field"),
+                ("↑ / ↓ (typing)", "Recall recent searches"),
+                ("Esc (typing)", "Cancel the input"),
+            ],
+        ),
+        (
+            "Block Explorer",
+            &[
+                ("< / >", "Previous / next block"),
+                ("r", "Toggle block range mode"),
+                ("Enter", "Open the selected transaction"),
+            ],
+        ),
+        (
+            "Transaction Viewer",
+            &[
+                ("Tab", "Switch tab"),
+                ("R", "Refresh, bypassing the cache"),
+                ("i", "Expand input data"),
+                ("a", "Expand the access list"),
+                ("d", "Toggle the flow diagram"),
+            ],
+        ),
+        (
+            "Address Lookup",
+            &[
+                ("← / → / Tab", "Switch tab"),
+                ("Enter", "Open the transaction or NFT metadata"),
+                ("f", "Transaction filter bar"),
+                ("n / p", "Next / previous transaction page"),
+                ("R", "Refresh, bypassing the cache"),
+                ("g", "NFT gallery (Tokens tab)"),
+                ("i", "Token info (Tokens tab)"),
+                ("r", "Copy revoke data (Approvals tab)"),
+                ("e", "Export transactions as CSV"),
+                ("E", "Export a report"),
+                ("L", "Label the address"),
+            ],
+        ),
+        (
+            "Gas Tracker",
+            &[("r", "Refresh"), ("e", "Export gas history")],
+        ),
+        (
+            "Contract Interaction",
+            &[
+                ("Enter", "Open the function form / call"),
+                ("l", "Load an ABI file"),
+                ("s", "Simulate the call"),
+            ],
+        ),
+        (
+            "Contract Verification",
+            &[
+                ("Tab", "Switch source file"),
+                ("PgUp / PgDn", "Scroll the source"),
+            ],
+        ),
+        (
+            "CREATE2 Calculator",
+            &[("d", "Pick a known deployer"), ("+", "Save the deployer")],
+        ),
+        (
+            "Event Monitor",
+            &[
+                ("Tab", "Switch between filter and logs"),
+                ("Enter", "Edit the filter / search / open the transaction"),
+            ],
+        ),
+        (
+            "Mempool Monitor",
+            &[("/", "Filter by address"), ("x", "Clear the filter")],
+        ),
+        (
+            "Log Viewer",
+            &[
+                ("/", "Search"),
+                ("x", "Clear the search"),
+                ("1-5", "Toggle error / warn / info / debug / trace"),
+                ("c", "Copy the selected line"),
+                ("s", "Save the filtered logs"),
+            ],
+        ),
+        (
+            "Settings",
+            &[
+                ("← / →", "Change the theme or cache TTL"),
+                ("Tab", "Switch between theme and cache TTL"),
+                ("n", "Switch network"),
+                ("e", "Edit the config file (Enter: edit field, s: save)"),
+                ("i", "Cache inspector"),
+                ("Ctrl+Del", "Clear the cache"),
+                ("Ctrl+E", "Clear expired cache entries"),
+            ],
+        ),
+        ("Multisig Wallet", &[("Tab", "Next tab")]),
+    ];
+
+    /// Rows of the reference table: one per section heading and binding
+    pub fn row_count() -> usize {
+        Self::SECTIONS
+            .iter()
+            .map(|(_, bindings)| bindings.len() + 1)
+            .sum()
+    }
+}
+
+/// Handle keys for the help overlay, returning whether the key was consumed;
+/// `?` opens it from any screen and every key is swallowed while it is shown
+pub fn handle_help_overlay_keys(app: &mut App, key_event: &KeyEvent) -> bool {
+    if app.input_mode == InputMode::Editing || app.setup_wizard.is_some() {
+        return false;
+    }
+
+    if !app.show_help_overlay {
+        if key_event.code != KeyCode::Char('?') {
+            return false;
+        }
+        app.show_help_overlay = true;
+        app.help_scroll = 0;
+        return true;
+    }
+
+    match key_event.code {
+        KeyCode::Char('?') | KeyCode::Esc => app.show_help_overlay = false,
+        KeyCode::Up => scroll_help(app, false),
+        KeyCode::Down => scroll_help(app, true),
+        _ => {}
+    }
+    true
+}
+
+/// Scroll the keybinding reference by one row
+fn scroll_help(app: &mut App, forward: bool) {
+    app.help_scroll = if forward {
+        (app.help_scroll + 1).min(KeyBindings::row_count() - 1)
+    } else {
+        app.help_scroll.saturating_sub(1)
+    };
+}
 
 /// Handle key events based on input mode
 pub async fn handle_key_event(app: &mut App, key_code: KeyCode) -> Result<bool> {
+    if app.setup_wizard.is_some() {
+        handle_setup_wizard_keys(app, key_code).await;
+        return Ok(false);
+    }
+
     match app.input_mode {
         InputMode::Normal => handle_normal_mode_keys(app, key_code).await,
         InputMode::Editing => handle_editing_mode_keys(app, key_code).await,
     }
 }
 
+/// Handle Alt+Left / Alt+Right history navigation, returning whether the key
+/// was consumed
+pub async fn handle_history_keys(app: &mut App, key_event: &KeyEvent) -> bool {
+    if app.setup_wizard.is_some() || app.mode_selection_state == ModeSelectionState::Selecting {
+        return false;
+    }
+
+    if KeyEventUtils::is_alt_left(key_event) {
+        app.go_back().await;
+    } else if KeyEventUtils::is_alt_right(key_event) {
+        app.navigate_forward().await;
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Handle keys while the error popup is open, returning whether the key was
+/// consumed; every key is swallowed so the screen behind stays untouched
+pub fn handle_error_popup_keys(app: &mut App, key_event: &KeyEvent) -> bool {
+    if !app.error_popup_open || app.current_error.is_none() {
+        return false;
+    }
+
+    match key_event.code {
+        KeyCode::Char('y') => app.copy_current_error(),
+        KeyCode::Char('d') => app.error_show_debug = !app.error_show_debug,
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => app.dismiss_error_popup(),
+        _ => {}
+    }
+    true
+}
+
+/// Toggle the tracked transactions overlay on Ctrl+T, returning whether the
+/// key was consumed
+pub fn handle_tracked_overlay_keys(app: &mut App, key_event: &KeyEvent) -> bool {
+    if !KeyEventUtils::is_ctrl_t(key_event) {
+        return false;
+    }
+    app.toggle_tracked_overlay();
+    true
+}
+
+/// Handle the settings screen's cache shortcuts (Ctrl+Del, Ctrl+E) and the
+/// clear-all confirmation, returning whether the key was consumed
+pub fn handle_cache_keys(app: &mut App, key_event: &KeyEvent) -> bool {
+    if app.state != AppState::Settings
+        || app.input_mode == InputMode::Editing
+        || app.settings_editor.open
+    {
+        return false;
+    }
+
+    if app.cache_clear_confirm {
+        let confirmed = matches!(key_event.code, KeyCode::Char('y') | KeyCode::Enter);
+        app.confirm_clear_all_cache(confirmed);
+    } else if KeyEventUtils::is_ctrl_delete(key_event) {
+        app.request_clear_all_cache();
+    } else if KeyEventUtils::is_ctrl_e(key_event) {
+        app.clear_expired_cache();
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Handle key events while the first-run setup wizard is shown
+async fn handle_setup_wizard_keys(app: &mut App, key_code: KeyCode) {
+    match key_code {
+        KeyCode::Enter | KeyCode::Tab => app.advance_setup_wizard().await,
+        KeyCode::Esc => app.cancel_setup_wizard(),
+        KeyCode::Up => app.setup_wizard_select(false),
+        KeyCode::Down => app.setup_wizard_select(true),
+        KeyCode::Char(c) => app.add_char(c),
+        KeyCode::Backspace => app.remove_char(),
+        KeyCode::Left => app.move_cursor_left(),
+        KeyCode::Right => app.move_cursor_right(),
+        _ => {}
+    }
+}
+
 /// Handle key events in normal (non-editing) mode
 async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<bool> {
     // Handle mode selection first
@@ -42,15 +309,19 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                     if let Err(e) = app.blockchain_client.switch_to_local_node().await {
                         tracing::error!(
                             target: "warpscan",
-                            "Failed to switch to local node: {}. Continuing anyway...",
+                            "Failed to switch to local node: {}. Staying on the current network",
                             e
                         );
-                        // Continue anyway - user can still try
+                        app.report_error("Failed to switch to local node", &e);
                     } else {
+                        // Entries loaded for the previous chain don't apply here
+                        if app.blockchain_client.chain_id() != app.config.network.chain_id {
+                            app.cache_manager.clear_all();
+                        }
                         // Update App's config to match (for UI display)
                         app.config.network.rpc_url = "http://127.0.0.1:8545".to_string();
                         app.config.network.node_type = Some("anvil".to_string());
-                        app.config.network.chain_id = 31337;
+                        app.config.network.chain_id = app.blockchain_client.chain_id();
                         app.config.network.name = "Anvil Local".to_string();
                     }
                 }
@@ -82,14 +353,87 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                 if let Err(e) = app.start_subscriptions().await {
                     tracing::warn!(target: "warpscan", "Failed to start subscriptions: {}", e);
                 }
+
+                // Jump to the entity requested with --open
+                if let Some(link) = app.pending_deep_link.take() {
+                    app.open_deep_link(link).await;
+                }
             }
             _ => {}
         }
         return Ok(false);
     }
 
+    // The network switcher is modal while open
+    if app.state == AppState::Settings && app.network_picker_open {
+        match key_code {
+            KeyCode::Up => app.network_picker_select(false),
+            KeyCode::Down => app.network_picker_select(true),
+            KeyCode::Enter => app.switch_to_selected_network().await,
+            KeyCode::Esc => app.network_picker_open = false,
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // So is the config editor
+    if app.state == AppState::Settings && app.settings_editor.open {
+        match key_code {
+            KeyCode::Up => app.settings_editor_select(false),
+            KeyCode::Down => app.settings_editor_select(true),
+            KeyCode::Enter => app.edit_settings_field(),
+            KeyCode::Char('s') => app.save_settings_editor().await,
+            KeyCode::Esc => app.close_settings_editor(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // The NFT token ID picker is modal while open
+    if app.state == AppState::AddressLookup && app.nft_token_picker.is_some() {
+        match key_code {
+            KeyCode::Up => app.nft_token_picker_select(false),
+            KeyCode::Down => app.nft_token_picker_select(true),
+            KeyCode::Enter => app.open_selected_nft_token().await,
+            KeyCode::Esc => app.nft_token_picker = None,
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // The NFT metadata popup is modal while open
+    if app.state == AppState::AddressLookup && app.nft_detail_popup.is_some() {
+        if matches!(key_code, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+            app.nft_detail_popup = None;
+        }
+        return Ok(false);
+    }
+
+    // The Safe deployment confirmation is modal while open
+    if app.state == AppState::MultisigWallet && app.multisig_state.pending_deployment.is_some() {
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Enter => app.confirm_multisig_deployment(true),
+            KeyCode::Char('n') | KeyCode::Esc => app.confirm_multisig_deployment(false),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
     match key_code {
         KeyCode::Char('q') => return Ok(true), // Quit
+        KeyCode::Esc if app.transaction_filter_bar_open() => {
+            // Hide the filter bar; the filters themselves stay applied
+            app.toggle_transaction_filter_bar();
+        }
+        KeyCode::Esc if app.state == AppState::Settings && app.cache_inspector_open => {
+            app.close_cache_inspector();
+        }
+        KeyCode::Esc
+            if app.state == AppState::ContractInteraction
+                && app.contract_interaction_state.form_open =>
+        {
+            app.close_contract_function_form();
+        }
         KeyCode::Esc => {
             // Escape key: go back to previous screen, or go to Home if already on Home
             if app.state == AppState::Home {
@@ -119,6 +463,21 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                     // Navigate within address data tables
                     app.address_select_previous_item();
                 }
+                AppState::BlockExplorer => app.block_select_transaction(false),
+                AppState::ContractVerification => app.contract_source_select_file(false),
+                AppState::MultisigWallet => app.multisig_select(false),
+                AppState::WalletManager if app.current_tab == WALLET_GENERATE_TAB => {
+                    app.wallet_generate_select(false)
+                }
+                AppState::ContractInteraction if app.contract_interaction_state.form_open => {
+                    app.contract_function_select_field(false)
+                }
+                AppState::ContractInteraction => app.contract_interaction_select_function(false),
+                AppState::NftGallery => app.nft_gallery_select_previous().await,
+                AppState::MempoolMonitor => app.mempool_select(false),
+                AppState::EventMonitor => app.event_monitor_select(false),
+                AppState::Help => scroll_help(app, false),
+                AppState::Settings if app.settings_ttl_focus => app.settings_select_ttl(false),
                 _ => app.previous_item(),
             }
         }
@@ -147,9 +506,55 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                     // Navigate within address data tables
                     app.address_select_next_item();
                 }
+                AppState::BlockExplorer => app.block_select_transaction(true),
+                AppState::ContractVerification => app.contract_source_select_file(true),
+                AppState::MultisigWallet => app.multisig_select(true),
+                AppState::WalletManager if app.current_tab == WALLET_GENERATE_TAB => {
+                    app.wallet_generate_select(true)
+                }
+                AppState::ContractInteraction if app.contract_interaction_state.form_open => {
+                    app.contract_function_select_field(true)
+                }
+                AppState::ContractInteraction => app.contract_interaction_select_function(true),
+                AppState::Simulation => app.simulation_next_field(),
+                AppState::Create2Calculator => app.create2_next_field(),
+                AppState::NftGallery => app.nft_gallery_select_next().await,
+                AppState::LogViewer => app.log_viewer_select_next(),
+                AppState::MempoolMonitor => app.mempool_select(true),
+                AppState::EventMonitor => app.event_monitor_select(true),
+                AppState::Help => scroll_help(app, true),
+                AppState::Settings if app.settings_ttl_focus => app.settings_select_ttl(true),
                 _ => app.next_item(),
             }
         }
+        KeyCode::Tab if app.transaction_filter_bar_open() => {
+            app.transaction_filter_next_field();
+        }
+        KeyCode::Left | KeyCode::Right | KeyCode::Tab if app.state == AppState::TokenInfo => {
+            app.toggle_token_info_tab().await;
+        }
+        KeyCode::Tab if app.state == AppState::TransactionViewer => {
+            app.transaction_tab = app.transaction_tab.toggle();
+            app.current_list_index = 0;
+        }
+        KeyCode::Tab if app.state == AppState::MultisigWallet => app.multisig_next_tab(),
+        KeyCode::Tab if app.state == AppState::EventMonitor => app.toggle_event_table_focus(),
+        KeyCode::Tab if app.state == AppState::ContractVerification => {
+            app.toggle_contract_source_tab();
+        }
+        KeyCode::PageUp | KeyCode::PageDown if app.state == AppState::ContractVerification => {
+            app.contract_source_scroll_page(key_code == KeyCode::PageDown);
+        }
+        KeyCode::Tab if app.state == AppState::Settings => {
+            app.settings_ttl_focus = !app.settings_ttl_focus;
+        }
+        KeyCode::Left | KeyCode::Right if app.state == AppState::Settings => {
+            if app.settings_ttl_focus {
+                app.adjust_cache_ttl(key_code == KeyCode::Right);
+            } else {
+                app.cycle_theme(key_code == KeyCode::Right);
+            }
+        }
         KeyCode::Right | KeyCode::Tab => {
             match app.state {
                 AppState::Home => {
@@ -159,15 +564,7 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                 }
                 AppState::AddressLookup => {
                     // Switch to next address tab
-                    if let Some(current) = app.get_current_address_tab() {
-                        let next = match current {
-                            AddressTab::Details => AddressTab::Transactions,
-                            AddressTab::Transactions => AddressTab::AccountHistory,
-                            AddressTab::AccountHistory => AddressTab::TokenTransfers,
-                            AddressTab::TokenTransfers => AddressTab::Tokens,
-                            AddressTab::Tokens => AddressTab::InternalTxns,
-                            AddressTab::InternalTxns => AddressTab::Details,
-                        };
+                    if let Some(next) = app.address_data.as_ref().map(|d| d.adjacent_tab(true)) {
                         app.switch_address_tab(next);
                     }
                 }
@@ -187,15 +584,7 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                 }
                 AppState::AddressLookup => {
                     // Switch to previous address tab
-                    if let Some(current) = app.get_current_address_tab() {
-                        let prev = match current {
-                            AddressTab::Details => AddressTab::InternalTxns,
-                            AddressTab::Transactions => AddressTab::Details,
-                            AddressTab::AccountHistory => AddressTab::Transactions,
-                            AddressTab::TokenTransfers => AddressTab::AccountHistory,
-                            AddressTab::Tokens => AddressTab::TokenTransfers,
-                            AddressTab::InternalTxns => AddressTab::Tokens,
-                        };
+                    if let Some(prev) = app.address_data.as_ref().map(|d| d.adjacent_tab(false)) {
                         app.switch_address_tab(prev);
                     }
                 }
@@ -208,7 +597,16 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                     // For the new dashboard, Enter activates search or navigates to detailed view
                     if app.current_tab == 0 {
                         // In blocks section - navigate to block explorer with selected block
+                        let selected_block = app
+                            .dashboard_data
+                            .latest_blocks
+                            .get(app.current_list_index)
+                            .map(|b| b.number);
                         app.navigate_to(AppState::BlockExplorer).await;
+                        if let Some(block_number) = selected_block {
+                            app.set_input(block_number.to_string());
+                            app.lookup_block(&block_number.to_string()).await;
+                        }
                     } else if app.current_tab == 1 {
                         // In transactions section - navigate to transaction viewer with selected tx
                         app.navigate_to(AppState::TransactionViewer).await;
@@ -223,11 +621,22 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                         return Ok(false);
                     }
 
+                    // With the filter bar open, Enter edits the focused filter
+                    if app.transaction_filter_bar_open() {
+                        app.activate_transaction_filter_field();
+                        return Ok(false);
+                    }
+
+                    // Enter on an ERC-721 collection shows its token metadata
+                    if app.open_nft_detail_popup().await {
+                        return Ok(false);
+                    }
+
                     // On address lookup, Enter on selected row navigates based on tab
                     let navigation_data = app.address_data.as_ref().and_then(|address_data| {
                         match address_data.current_tab {
                             AddressTab::Transactions => address_data
-                                .transactions
+                                .filtered_transactions()
                                 .get(address_data.selected_transaction_index)
                                 .map(|tx| ("tx", tx.tx_hash.clone())),
                             AddressTab::AccountHistory => address_data
@@ -252,16 +661,122 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                     // Otherwise, enter editing mode for address input
                     app.input_mode = InputMode::Editing;
                 }
+                AppState::BlockExplorer if !app.block_range_mode => {
+                    // Open the selected transaction, or edit the block input
+                    let selected = app
+                        .block_data
+                        .as_ref()
+                        .and_then(|block| block.selected_transaction())
+                        .map(|tx| tx.hash.clone());
+                    match (selected, app.selected_range_block()) {
+                        (Some(tx_hash), _) => app.navigate_to_transaction(&tx_hash).await,
+                        // Open the selected row of a listed block range
+                        (None, Some(number)) if app.block_data.is_none() => {
+                            app.set_input(number.to_string());
+                            app.lookup_block(&number.to_string()).await;
+                        }
+                        _ => app.input_mode = InputMode::Editing,
+                    }
+                }
                 AppState::BlockExplorer | AppState::TransactionViewer => {
                     // Enter editing mode for input
                     app.input_mode = InputMode::Editing;
                 }
+                AppState::Simulation => {
+                    if app.simulation_button_focused() {
+                        app.run_simulation().await;
+                    } else {
+                        app.edit_simulation_field();
+                    }
+                }
+                AppState::Create2Calculator => {
+                    if app.create2_button_focused() {
+                        app.check_create2_deployment().await;
+                    } else {
+                        app.edit_create2_field();
+                    }
+                }
+                AppState::ContractInteraction if app.contract_interaction_state.form_open => {
+                    if app.contract_function_button_focused() {
+                        app.submit_contract_function().await;
+                    } else {
+                        app.edit_contract_function_field();
+                    }
+                }
+                AppState::ContractInteraction => app.open_contract_function_form().await,
+                AppState::EventMonitor if app.event_table_focus => {
+                    if let Some(tx_hash) = app.selected_event_log_tx() {
+                        app.navigate_to_transaction(&tx_hash).await;
+                    }
+                }
+                AppState::EventMonitor => {
+                    if app.event_search_button_focused() {
+                        app.search_event_logs().await;
+                    } else {
+                        app.edit_event_filter_field();
+                    }
+                }
+                AppState::Settings if !app.settings_ttl_focus => app.cycle_theme(true),
+                AppState::MultisigWallet => app.multisig_activate().await,
+                AppState::WalletManager if app.current_tab == WALLET_GENERATE_TAB => {
+                    app.wallet_generate_activate()
+                }
                 _ => {}
             }
         }
+        KeyCode::Char('r') if app.state == AppState::BlockExplorer => {
+            app.toggle_block_range_mode();
+        }
+        KeyCode::Char('<') if app.state == AppState::BlockExplorer && !app.block_range_mode => {
+            app.step_block(false).await
+        }
+        KeyCode::Char('>') if app.state == AppState::BlockExplorer && !app.block_range_mode => {
+            app.step_block(true).await
+        }
+        KeyCode::Char('e') if app.state == AppState::GasTracker => app.export_gas_history(),
+        KeyCode::Char('e') if app.state == AppState::AddressLookup => app.export_address_csv(),
+        KeyCode::Char('r')
+            if app.state == AppState::AddressLookup
+                && app.get_current_address_tab() == Some(AddressTab::Approvals) =>
+        {
+            app.copy_selected_revoke_data();
+        }
+        KeyCode::Char('r') if app.state == AppState::GasTracker => {
+            app.refresh_gas_tracker().await;
+        }
         KeyCode::Char('r') => {
             // Refresh current screen - placeholder for future implementation
         }
+        KeyCode::Char('R') if app.state == AppState::AddressLookup => app.refresh_address().await,
+        KeyCode::Char('R') if app.state == AppState::TransactionViewer => {
+            app.refresh_transaction().await;
+        }
+        KeyCode::Char('/') if app.state == AppState::LogViewer => app.open_log_search(),
+        KeyCode::Char('c') if app.state == AppState::LogViewer => app.copy_selected_log_line(),
+        KeyCode::Char('s') if app.state == AppState::LogViewer => app.save_filtered_logs(),
+        KeyCode::Char('x') if app.state == AppState::LogViewer => app.clear_log_search(),
+        KeyCode::Char(c @ '1'..='5') if app.state == AppState::LogViewer => {
+            let level = match c {
+                '1' => tracing::Level::ERROR,
+                '2' => tracing::Level::WARN,
+                '3' => tracing::Level::INFO,
+                '4' => tracing::Level::DEBUG,
+                _ => tracing::Level::TRACE,
+            };
+            app.toggle_log_level(level);
+        }
+        KeyCode::Char('L') if app.state == AppState::AddressLookup => app.open_label_prompt(),
+        KeyCode::Char('L') => app.navigate_to(AppState::LogViewer).await,
+        KeyCode::Char('/') if app.state == AppState::MempoolMonitor => app.open_mempool_filter(),
+        KeyCode::Char('x') if app.state == AppState::MempoolMonitor => app.clear_mempool_filter(),
+        KeyCode::Char('m') => app.navigate_to(AppState::MempoolMonitor).await,
+        KeyCode::Char('l') if app.state == AppState::ContractInteraction => {
+            app.start_abi_file_prompt();
+        }
+        KeyCode::Char('s') if app.state == AppState::ContractInteraction => {
+            // Open the simulation screen pre-filled with the current data
+            app.open_simulation().await;
+        }
         KeyCode::Char('/') | KeyCode::Char('s') => {
             // Quick access to search - enter editing mode
             match app.state {
@@ -269,7 +784,11 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                     app.current_tab = 2; // Focus on search bar
                     app.input_mode = InputMode::Editing;
                 }
-                AppState::AddressLookup | AppState::TransactionViewer | AppState::BlockExplorer => {
+                AppState::AddressLookup
+                | AppState::TransactionViewer
+                | AppState::BlockExplorer
+                | AppState::ContractVerification
+                | AppState::ContractInteraction => {
                     // Enter editing mode for input fields on these screens
                     app.input_mode = InputMode::Editing;
                 }
@@ -292,16 +811,61 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
                 app.navigate_to(AppState::TransactionViewer).await;
             }
         }
+        KeyCode::Char('a') if app.state == AppState::TransactionViewer => {
+            app.tx_access_list_expanded = !app.tx_access_list_expanded;
+        }
         KeyCode::Char('a') => app.navigate_to(AppState::AddressLookup).await,
+        KeyCode::Char('g')
+            if app.state == AppState::AddressLookup
+                && app.get_current_address_tab() == Some(AddressTab::Tokens) =>
+        {
+            app.open_nft_gallery().await;
+        }
         KeyCode::Char('g') => app.navigate_to(AppState::GasTracker).await,
+        KeyCode::Char('i')
+            if app.state == AppState::AddressLookup
+                && app.get_current_address_tab() == Some(AddressTab::Tokens) =>
+        {
+            app.open_token_info().await;
+        }
+        KeyCode::Char('f')
+            if app.state == AppState::AddressLookup
+                && app.get_current_address_tab() == Some(AddressTab::Transactions) =>
+        {
+            app.toggle_transaction_filter_bar();
+        }
+        KeyCode::Char(c @ ('n' | 'p'))
+            if app.state == AppState::AddressLookup
+                && app.get_current_address_tab() == Some(AddressTab::Transactions) =>
+        {
+            app.change_address_transactions_page(c == 'n').await;
+        }
+        KeyCode::Char('S') => app.copy_deep_link(),
+        KeyCode::Char('y') => app.copy_focused_item(),
+        KeyCode::Char('E') if app.state == AppState::AddressLookup => {
+            app.open_export_prompt();
+        }
+        KeyCode::Char('d') if app.state == AppState::Create2Calculator => {
+            app.create2_quick_select_deployer();
+        }
+        KeyCode::Char('+') if app.state == AppState::Create2Calculator => {
+            app.save_create2_deployer();
+        }
+        KeyCode::Char('p') if app.state != AppState::Create2Calculator => {
+            app.open_create2_calculator().await;
+        }
         KeyCode::Char('w') => app.navigate_to(AppState::WalletManager).await,
         KeyCode::Char('c') => app.navigate_to(AppState::Settings).await,
         KeyCode::Char('0') => app.navigate_to(AppState::Home).await,
-        KeyCode::Char('i') => {
+        KeyCode::Char('i') if app.state == AppState::Settings => app.open_cache_inspector(),
+        KeyCode::Char('n') if app.state == AppState::Settings => app.open_network_picker(),
+        KeyCode::Char('e') if app.state == AppState::Settings => app.open_settings_editor(),
+        KeyCode::Char('i') if app.state == AppState::TransactionViewer => {
             // Toggle input data expansion in transaction viewer
-            if app.state == AppState::TransactionViewer {
-                app.input_data_expanded = !app.input_data_expanded;
-            }
+            app.input_data_expanded = !app.input_data_expanded;
+        }
+        KeyCode::Char('d') if app.state == AppState::TransactionViewer => {
+            app.tx_diagram_mode = !app.tx_diagram_mode;
         }
         _ => {}
     }
@@ -310,22 +874,132 @@ async fn handle_normal_mode_keys(app: &mut App, key_code: KeyCode) -> Result<boo
 
 /// Handle key events in editing mode
 async fn handle_editing_mode_keys(app: &mut App, key_code: KeyCode) -> Result<bool> {
-    use super::validation::{is_address, is_block_number, is_transaction_hash};
+    use super::utils::format_address_display;
+    use super::validation::{is_address, is_ens_name, is_transaction_hash};
+
+    // The report export prompt reuses the input line for the file path
+    if app.state == AppState::AddressLookup && app.export_prompt_open {
+        match key_code {
+            KeyCode::Enter => {
+                app.confirm_export_prompt();
+                return Ok(false);
+            }
+            KeyCode::Esc => {
+                app.close_export_prompt();
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+
+    // So does the address label prompt, for the name and tags
+    if app.state == AppState::AddressLookup && app.label_prompt_address.is_some() {
+        match key_code {
+            KeyCode::Enter => {
+                app.confirm_label_prompt();
+                return Ok(false);
+            }
+            KeyCode::Esc => {
+                app.close_label_prompt();
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+
+    // Editing a config editor field reuses the input line
+    if app.state == AppState::Settings && app.settings_editor.editing {
+        match key_code {
+            KeyCode::Enter => {
+                app.commit_settings_field();
+                return Ok(false);
+            }
+            KeyCode::Esc => {
+                app.cancel_settings_field_edit();
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+
+    // The cache inspector query reuses the input line
+    if app.state == AppState::Settings && app.cache_inspector_open {
+        match key_code {
+            KeyCode::Enter => {
+                app.run_cache_inspector_query();
+                return Ok(false);
+            }
+            KeyCode::Esc => {
+                app.close_cache_inspector();
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+
+    // Editing a transaction filter field reuses the input line
+    if app.state == AppState::AddressLookup && app.transaction_filter_editing() {
+        match key_code {
+            KeyCode::Enter | KeyCode::Tab => {
+                let input = app.get_input().to_string();
+                app.commit_transaction_filter_field(input);
+                if key_code == KeyCode::Tab {
+                    app.transaction_filter_next_field();
+                }
+                return Ok(false);
+            }
+            KeyCode::Esc => {
+                app.cancel_transaction_filter_edit();
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+
+    // The log search applies on every keystroke; Enter and Esc just stop editing
+    if app.state == AppState::LogViewer {
+        match key_code {
+            KeyCode::Enter | KeyCode::Esc => app.input_mode = InputMode::Normal,
+            KeyCode::Char(c) => {
+                app.add_char(c);
+                app.update_log_search();
+            }
+            KeyCode::Backspace => {
+                app.remove_char();
+                app.update_log_search();
+            }
+            KeyCode::Left => app.move_cursor_left(),
+            KeyCode::Right => app.move_cursor_right(),
+            _ => {}
+        }
+        return Ok(false);
+    }
+
+    // Likewise for the mempool monitor's address filter
+    if app.state == AppState::MempoolMonitor {
+        match key_code {
+            KeyCode::Enter | KeyCode::Esc => app.input_mode = InputMode::Normal,
+            KeyCode::Char(c) => {
+                app.add_char(c);
+                app.update_mempool_filter();
+            }
+            KeyCode::Backspace => {
+                app.remove_char();
+                app.update_mempool_filter();
+            }
+            KeyCode::Left => app.move_cursor_left(),
+            KeyCode::Right => app.move_cursor_right(),
+            _ => {}
+        }
+        return Ok(false);
+    }
 
     // Handle Tab key to exit editing mode and switch tabs on AddressLookup screen
     if app.state == AppState::AddressLookup && key_code == KeyCode::Tab {
         // Exit editing mode and switch to next tab
         app.input_mode = InputMode::Normal;
         // Switch to next address tab
-        if let Some(current) = app.get_current_address_tab() {
-            let next = match current {
-                AddressTab::Details => AddressTab::Transactions,
-                AddressTab::Transactions => AddressTab::AccountHistory,
-                AddressTab::AccountHistory => AddressTab::TokenTransfers,
-                AddressTab::TokenTransfers => AddressTab::Tokens,
-                AddressTab::Tokens => AddressTab::InternalTxns,
-                AddressTab::InternalTxns => AddressTab::Details,
-            };
+        if let Some(next) = app.address_data.as_ref().map(|d| d.adjacent_tab(true)) {
             app.switch_address_tab(next);
         }
         return Ok(false);
@@ -334,12 +1008,69 @@ async fn handle_editing_mode_keys(app: &mut App, key_code: KeyCode) -> Result<bo
     match key_code {
         KeyCode::Enter => {
             // Process input based on current screen
-            let input = app.get_input().trim().to_string();
+            let mut input = app.get_input().trim().to_string();
+            // Swap an ENS name for the address it resolves to
+            let mut resolved_from = None;
+            if matches!(app.state, AppState::AddressLookup | AppState::Home) && is_ens_name(&input)
+            {
+                match app.resolve_ens_input(&input).await {
+                    Some(address) => {
+                        app.set_input(address.clone());
+                        resolved_from = Some(std::mem::replace(&mut input, address));
+                    }
+                    None => {
+                        app.input_mode = InputMode::Normal;
+                        app.reset_input_feedback();
+                        app.set_error(format!("ENS name {} does not resolve to an address", input));
+                        return Ok(false);
+                    }
+                }
+            }
             app.input_mode = InputMode::Normal;
+            app.history_index = None;
+            app.reset_input_feedback();
+
+            // Simulation fields may be left empty (e.g. no calldata)
+            if app.state == AppState::Simulation {
+                app.commit_simulation_field(input);
+                return Ok(false);
+            }
+            if app.state == AppState::Create2Calculator {
+                app.commit_create2_field(input);
+                return Ok(false);
+            }
+            // An empty event filter field matches anything
+            if app.state == AppState::EventMonitor {
+                app.commit_event_filter_field(input);
+                return Ok(false);
+            }
+            // Never recorded in the search history: the input may be a private key
+            if app.state == AppState::MultisigWallet {
+                app.commit_multisig_input(input);
+                return Ok(false);
+            }
+            // Likewise for the keystore password
+            if app.state == AppState::WalletManager && app.wallet_generate.password_prompt {
+                app.commit_keystore_password(input);
+                return Ok(false);
+            }
+            if app.state == AppState::ContractInteraction {
+                if app.contract_interaction_state.abi_path_prompt {
+                    app.commit_abi_file_path(input);
+                    return Ok(false);
+                }
+                if app.contract_interaction_state.form_open {
+                    app.commit_contract_function_field(input);
+                    return Ok(false);
+                }
+            }
 
             if input.is_empty() {
                 return Ok(false);
             }
+            if app.is_search_input() {
+                app.record_search(resolved_from.as_deref().unwrap_or(&input));
+            }
 
             match app.state {
                 AppState::AddressLookup => {
@@ -347,41 +1078,40 @@ async fn handle_editing_mode_keys(app: &mut App, key_code: KeyCode) -> Result<bo
                     if is_address(&input) {
                         // Call lookup_address - it now yields periodically to keep UI responsive
                         if let Err(e) = app.lookup_address(&input).await {
-                            app.set_error(format!("Failed to lookup address: {}", e));
+                            app.report_error("Failed to lookup address", &e);
                         }
                     } else {
-                        app.set_error("Invalid address format. Address must start with 0x and be 42 characters long.".to_string());
+                        app.set_error("Invalid address format. Enter an address (0x... 42 characters) or a resolvable ENS name.".to_string());
                     }
                 }
                 AppState::Home => {
                     // On home screen search bar, detect what type of input it is
+                    if !app.smart_navigate(&input).await {
+                        app.set_error("Invalid input. Please enter an address (0x...), ENS name, transaction hash, or block number.".to_string());
+                    }
+                }
+                AppState::BlockExplorer if app.block_range_mode => {
+                    app.analyze_block_range(&input).await;
+                }
+                AppState::BlockExplorer if input.contains('-') => {
+                    app.list_block_range(&input).await;
+                }
+                AppState::BlockExplorer => {
+                    // On block explorer, search by block number, block hash or transaction hash
+                    app.search_block_explorer(&input).await;
+                }
+                AppState::ContractVerification => {
                     if is_address(&input) {
-                        // Navigate to address lookup and search
-                        app.navigate_to(AppState::AddressLookup).await;
-                        app.set_input(input.clone());
-                        if let Err(e) = app.lookup_address(&input).await {
-                            app.set_error(format!("Failed to lookup address: {}", e));
-                        }
-                    } else if is_transaction_hash(&input) {
-                        // Navigate to transaction viewer
-                        app.navigate_to_transaction(&input).await;
-                    } else if is_block_number(&input) {
-                        // Navigate to block explorer
-                        app.navigate_to(AppState::BlockExplorer).await;
-                        app.set_input(input);
-                        // TODO: Implement block lookup
-                        app.set_error("Block lookup not yet implemented".to_string());
+                        app.lookup_contract_source(&input).await;
                     } else {
-                        app.set_error("Invalid input. Please enter an address (0x...), transaction hash, or block number.".to_string());
+                        app.set_error("Invalid address format. Address must start with 0x and be 42 characters long.".to_string());
                     }
                 }
-                AppState::BlockExplorer => {
-                    // On block explorer, search for block
-                    if is_block_number(&input) {
-                        // TODO: Implement block lookup
-                        app.set_error("Block lookup not yet implemented".to_string());
+                AppState::ContractInteraction => {
+                    if is_address(&input) {
+                        app.load_contract_abi_for_interaction(&input).await;
                     } else {
-                        app.set_error("Invalid block number format".to_string());
+                        app.set_error("Invalid address format. Address must start with 0x and be 42 characters long.".to_string());
                     }
                 }
                 AppState::TransactionViewer => {
@@ -397,17 +1127,39 @@ async fn handle_editing_mode_keys(app: &mut App, key_code: KeyCode) -> Result<bo
                     app.clear_input();
                 }
             }
+
+            if let Some(name) = resolved_from {
+                if app.error_message().is_none() {
+                    app.set_success(format!(
+                        "Resolved: {} from {}",
+                        format_address_display(&input, None, 0),
+                        name
+                    ));
+                }
+            }
         }
         KeyCode::Esc => {
             // Cancel editing and exit editing mode
             app.clear_input();
+            if app.state == AppState::ContractInteraction {
+                app.cancel_contract_interaction_edit();
+            }
+            app.wallet_generate.password_prompt = false;
             app.input_mode = InputMode::Normal;
+            app.history_index = None;
+            app.reset_input_feedback();
+        }
+        KeyCode::Up | KeyCode::Down if app.is_search_input() => {
+            app.recall_search(key_code == KeyCode::Up);
+            app.touch_debounce_timer();
         }
         KeyCode::Char(c) => {
             app.add_char(c);
+            app.touch_debounce_timer();
         }
         KeyCode::Backspace => {
             app.remove_char();
+            app.touch_debounce_timer();
         }
         KeyCode::Left => {
             app.move_cursor_left();
@@ -0,0 +1,219 @@
+//! Gas tracker handling
+
+use super::core::App;
+use super::state::AppState;
+use crate::blockchain::service::wei_to_gwei_round;
+use crate::blockchain::{BlockGasMetric, GasPrices};
+use crate::error::Result;
+use ethers::types::{Block, Transaction, U256};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Number of blocks kept in the gas history table
+const GAS_HISTORY_BLOCKS: usize = 100;
+
+/// Number of recent blocks summed for the burned ETH total
+pub const BURN_WINDOW_BLOCKS: u64 = 100;
+
+impl App {
+    /// Reload the EIP-1559 fee estimate and the ETH price for the gas tracker
+    pub async fn refresh_gas_tracker(&mut self) {
+        self.set_loading("gas_tracker", true);
+        match self.blockchain_client.estimate_eip1559_fees().await {
+            Ok(estimate) => self.gas_estimate = Some(estimate),
+            Err(e) => self.report_error("Failed to estimate gas fees", &e),
+        }
+        // The legacy panel is secondary to the estimate, so failures are only logged
+        match self.blockchain_client.get_gas_prices().await {
+            Ok(prices) => {
+                self.push_gas_price_sample(prices.clone());
+                self.gas_sampled_at = Some(Instant::now());
+                self.gas_prices = Some(prices);
+            }
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to fetch gas prices: {}", e),
+        }
+        // USD costs are optional, so a failed price lookup keeps the last price
+        match self.blockchain_client.get_eth_price_usd().await {
+            Ok(Some(price)) => self.eth_price_usd = Some(price),
+            Ok(None) => {}
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to fetch ETH price: {}", e),
+        }
+        match self
+            .blockchain_client
+            .get_gas_history(GAS_HISTORY_BLOCKS as u64)
+            .await
+        {
+            Ok(history) => self.gas_history_data = history.into(),
+            Err(e) => self.report_error("Failed to load gas history", &e),
+        }
+        match self.load_recent_burn().await {
+            Ok(burned) => self.gas_burned_recent = Some(burned),
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to sum burned ETH: {}", e),
+        }
+        self.set_loading("gas_tracker", false);
+    }
+
+    /// Sample gas prices for the sparklines while the gas tracker is open;
+    /// called on tick and throttled to the configured update interval
+    pub fn poll_gas_price_history(&mut self) {
+        if let Some(receiver) = self.gas_sample_pending.as_mut() {
+            match receiver.try_recv() {
+                Ok(result) => {
+                    self.gas_sample_pending = None;
+                    match result {
+                        Ok(prices) => {
+                            self.push_gas_price_sample(prices.clone());
+                            self.gas_prices = Some(prices);
+                        }
+                        Err(e) => {
+                            tracing::warn!(target: "warpscan", "Failed to sample gas prices: {}", e)
+                        }
+                    }
+                }
+                Err(oneshot::error::TryRecvError::Empty) => return,
+                Err(oneshot::error::TryRecvError::Closed) => self.gas_sample_pending = None,
+            }
+        }
+
+        let interval = Duration::from_secs(self.config.gas.update_interval_seconds.max(1));
+        if self.state != AppState::GasTracker
+            || self
+                .gas_sampled_at
+                .is_some_and(|sampled_at| sampled_at.elapsed() < interval)
+        {
+            return;
+        }
+        self.gas_sampled_at = Some(Instant::now());
+
+        let service = self.blockchain_client.background_handle();
+        let (sender, receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            let _ = sender.send(service.get_gas_prices().await);
+        });
+        self.gas_sample_pending = Some(receiver);
+    }
+
+    /// Append a gas price sample, dropping the oldest beyond `gas.history_samples`
+    fn push_gas_price_sample(&mut self, prices: GasPrices) {
+        self.gas_price_history.push_back(prices);
+        let limit = self.config.gas.history_samples.max(1);
+        while self.gas_price_history.len() > limit {
+            self.gas_price_history.pop_front();
+        }
+    }
+
+    /// ETH burned over the last `BURN_WINDOW_BLOCKS` blocks
+    async fn load_recent_burn(&self) -> Result<f64> {
+        let latest = self.blockchain_client.get_block_number().await?;
+        let from_block = latest.saturating_sub(BURN_WINDOW_BLOCKS - 1);
+        self.blockchain_client
+            .get_total_eth_burned_since(from_block, latest)
+            .await
+    }
+
+    /// Add the base fees burned in a newly received block to the running total
+    pub fn record_block_burn(&mut self, block: &Block<Transaction>) {
+        let Some(block_number) = block.number.map(|n| n.as_u64()) else {
+            return;
+        };
+        if self
+            .last_burned_block
+            .is_some_and(|last| last >= block_number)
+        {
+            return;
+        }
+        self.last_burned_block = Some(block_number);
+
+        let base_fee = block.base_fee_per_gas.unwrap_or_default();
+        let burned_wei = base_fee.as_u128() as f64 * block.gas_used.as_u128() as f64;
+        self.dashboard_data.network_stats.eth_burned_since_start +=
+            burned_wei / 1_000_000_000_000_000_000.0;
+    }
+
+    /// Append the gas metrics of a newly received block to the history
+    pub fn record_block_gas_metric(&mut self, block: &Block<Transaction>) {
+        let Some(block_number) = block.number.map(|n| n.as_u64()) else {
+            return;
+        };
+        if self
+            .gas_history_data
+            .back()
+            .is_some_and(|last| last.block_number >= block_number)
+        {
+            return;
+        }
+
+        let base_fee = block.base_fee_per_gas.unwrap_or_default();
+        // Effective tip per transaction: capped by the max fee for EIP-1559
+        // transactions, the gas price above the base fee for legacy ones
+        let tips: Vec<U256> = block
+            .transactions
+            .iter()
+            .map(
+                |tx| match (tx.max_priority_fee_per_gas, tx.max_fee_per_gas) {
+                    (Some(priority), Some(max_fee)) => {
+                        priority.min(max_fee.saturating_sub(base_fee))
+                    }
+                    _ => tx.gas_price.unwrap_or_default().saturating_sub(base_fee),
+                },
+            )
+            .collect();
+        let avg_tip = if tips.is_empty() {
+            U256::zero()
+        } else {
+            tips.iter().fold(U256::zero(), |sum, tip| sum + tip) / tips.len()
+        };
+
+        let gas_limit = block.gas_limit.as_u64();
+        self.gas_history_data.push_back(BlockGasMetric {
+            block_number,
+            base_fee_gwei: wei_to_gwei_round(base_fee),
+            gas_used_ratio: if gas_limit == 0 {
+                0.0
+            } else {
+                block.gas_used.as_u64() as f64 / gas_limit as f64
+            },
+            avg_priority_fee_gwei: wei_to_gwei_round(avg_tip),
+        });
+        while self.gas_history_data.len() > GAS_HISTORY_BLOCKS {
+            self.gas_history_data.pop_front();
+        }
+    }
+
+    /// Export the gas history table to a CSV file in the working directory
+    pub fn export_gas_history(&mut self) {
+        if self.gas_history_data.is_empty() {
+            self.set_error("No gas history to export".to_string());
+            return;
+        }
+
+        let path = format!(
+            "warpscan-gas-history-{}.csv",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        );
+        match self.write_gas_history_csv(Path::new(&path)) {
+            Ok(()) => self.set_success(format!("Gas history saved to {}", path)),
+            Err(e) => self.report_error("Failed to export gas history", &e),
+        }
+    }
+
+    /// Write the gas history, newest block first, as CSV
+    fn write_gas_history_csv(&self, path: &Path) -> Result<()> {
+        let mut csv =
+            String::from("block_number,base_fee_gwei,gas_used_percent,avg_priority_fee_gwei\n");
+        for metric in self.gas_history_data.iter().rev() {
+            let _ = writeln!(
+                csv,
+                "{},{},{:.2},{}",
+                metric.block_number,
+                metric.base_fee_gwei,
+                metric.gas_used_ratio * 100.0,
+                metric.avg_priority_fee_gwei
+            );
+        }
+        std::fs::write(path, csv)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+//! Non-interactive mode: run one query and print it as JSON
+
+use super::core::App;
+use crate::cli::{Cli, QueryType};
+use crate::error::{Error, Result};
+
+impl App {
+    /// Run the query described by `cli` and print the result to stdout as JSON
+    pub async fn run_headless(&self, cli: &Cli) -> Result<()> {
+        // Same source selection as the TUI before a mode is picked:
+        // local nodes are queried over RPC, everything else via Etherscan
+        let is_local = self
+            .config
+            .network
+            .node_type
+            .as_ref()
+            .map(|t| t == "anvil" || t == "hardhat" || t == "local")
+            .unwrap_or(false);
+        let use_etherscan = !is_local;
+
+        let value = match cli.query_type() {
+            QueryType::Address => {
+                let address = cli
+                    .address
+                    .as_deref()
+                    .ok_or_else(|| Error::validation("--address is required for this query"))?;
+                let info = self
+                    .blockchain_client
+                    .get_address_info_with_mode(address, use_etherscan)
+                    .await?;
+                serde_json::to_value(info)?
+            }
+            QueryType::Transaction => {
+                let tx_hash = cli
+                    .transaction
+                    .as_deref()
+                    .ok_or_else(|| Error::validation("--transaction is required for this query"))?;
+                let details = self
+                    .blockchain_client
+                    .get_transaction_details_with_mode(tx_hash, use_etherscan)
+                    .await?;
+                serde_json::to_value(details)?
+            }
+            QueryType::Block => {
+                let block = match cli.block {
+                    Some(number) => self.blockchain_client.get_block_by_number(number).await?,
+                    None => self.blockchain_client.get_latest_block().await?,
+                };
+                serde_json::to_value(block)?
+            }
+            QueryType::Gas => {
+                let prices = self.blockchain_client.get_gas_prices().await?;
+                serde_json::to_value(prices)?
+            }
+        };
+
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use super::core::App;
-use super::state::InputMode;
+use super::state::{AppState, InputMode, ModeSelectionState};
 
 impl App {
     /// Enter input mode
@@ -15,37 +15,47 @@ impl App {
     /// Add character to input
     pub fn add_char(&mut self, c: char) {
         if self.input_mode == InputMode::Editing {
-            self.input.insert(self.cursor_position, c);
-            self.cursor_position += 1;
+            self.input.insert(self.cursor_byte_pos, c);
+            self.cursor_byte_pos += c.len_utf8();
+            self.cursor_char_pos += 1;
         }
     }
 
     /// Remove character from input
     pub fn remove_char(&mut self) {
-        if self.input_mode == InputMode::Editing && self.cursor_position > 0 {
-            self.cursor_position -= 1;
-            self.input.remove(self.cursor_position);
+        if self.input_mode == InputMode::Editing && self.cursor_byte_pos > 0 {
+            self.move_cursor_left();
+            self.input.remove(self.cursor_byte_pos);
         }
     }
 
     /// Move cursor left
     pub fn move_cursor_left(&mut self) {
-        if self.input_mode == InputMode::Editing && self.cursor_position > 0 {
-            self.cursor_position -= 1;
+        if self.input_mode != InputMode::Editing {
+            return;
+        }
+        if let Some(c) = self.input[..self.cursor_byte_pos].chars().next_back() {
+            self.cursor_byte_pos -= c.len_utf8();
+            self.cursor_char_pos -= 1;
         }
     }
 
     /// Move cursor right
     pub fn move_cursor_right(&mut self) {
-        if self.input_mode == InputMode::Editing && self.cursor_position < self.input.len() {
-            self.cursor_position += 1;
+        if self.input_mode != InputMode::Editing {
+            return;
+        }
+        if let Some(c) = self.input[self.cursor_byte_pos..].chars().next() {
+            self.cursor_byte_pos += c.len_utf8();
+            self.cursor_char_pos += 1;
         }
     }
 
     /// Clear input
     pub fn clear_input(&mut self) {
         self.input.clear();
-        self.cursor_position = 0;
+        self.cursor_byte_pos = 0;
+        self.cursor_char_pos = 0;
     }
 
     /// Get current input
@@ -60,9 +70,122 @@ impl App {
         input
     }
 
+    /// Handle text pasted in one go (bracketed paste)
+    ///
+    /// Form fields receive the text at the cursor. Anywhere else the pasted
+    /// text replaces the input and is opened straight away when it is an
+    /// address, transaction hash or block number; otherwise it is left in the
+    /// input line for editing.
+    pub async fn handle_paste(&mut self, text: &str) {
+        // Copied values often carry a trailing newline or surrounding spaces
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+
+        let in_form = matches!(
+            self.state,
+            AppState::Simulation | AppState::Create2Calculator
+        ) || self.setup_wizard.is_some()
+            || self.export_prompt_open
+            || self.label_prompt_address.is_some()
+            || self.settings_editor.editing
+            || self.transaction_filter_editing();
+        if in_form {
+            if self.input_mode == InputMode::Editing {
+                self.input.insert_str(self.cursor_byte_pos, text);
+                self.cursor_byte_pos += text.len();
+                self.cursor_char_pos += text.chars().count();
+            }
+            return;
+        }
+        if self.mode_selection_state == ModeSelectionState::Selecting {
+            return;
+        }
+
+        self.set_input(text.to_string());
+        if !self.smart_navigate(text).await {
+            self.input_mode = InputMode::Editing;
+            self.touch_debounce_timer();
+        }
+    }
+
     /// Set input text
     pub fn set_input(&mut self, text: String) {
         self.input = text;
-        self.cursor_position = self.input.len();
+        self.cursor_byte_pos = self.input.len();
+        self.cursor_char_pos = self.input.chars().count();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blockchain::BlockchainService;
+    use crate::cache::CacheManager;
+    use crate::config::Config;
+    use crate::ui::components::render_input_field;
+    use crate::ui::theme::Theme;
+    use ratatui::{backend::TestBackend, layout::Rect, Terminal};
+    use std::sync::Arc;
+
+    async fn test_app() -> App {
+        let mut config = Config::default();
+        // Nothing listens on port 1; the input tests never touch the network
+        config.network.rpc_url = "http://127.0.0.1:1".to_string();
+        config.etherscan_api_key = None;
+        let cache = CacheManager::new(config.clone()).unwrap();
+        let service = BlockchainService::new(config.clone(), Arc::new(cache.clone()))
+            .await
+            .unwrap();
+        App::new(config, service, cache)
+    }
+
+    #[tokio::test]
+    async fn test_cursor_moves_over_multibyte_chars() {
+        let mut app = test_app().await;
+        app.enter_input_mode();
+        for c in "a€b".chars() {
+            app.add_char(c);
+        }
+        assert_eq!((app.cursor_byte_pos, app.cursor_char_pos), (5, 3));
+
+        app.move_cursor_left();
+        app.move_cursor_left();
+        assert_eq!((app.cursor_byte_pos, app.cursor_char_pos), (1, 1));
+        app.move_cursor_right();
+        assert_eq!((app.cursor_byte_pos, app.cursor_char_pos), (4, 2));
+
+        app.remove_char();
+        assert_eq!(app.input, "ab");
+        assert_eq!((app.cursor_byte_pos, app.cursor_char_pos), (1, 1));
+
+        app.set_input("véndola.eth".to_string());
+        assert_eq!((app.cursor_byte_pos, app.cursor_char_pos), (12, 11));
+    }
+
+    #[test]
+    fn test_input_field_cursor_column_with_multibyte_chars() {
+        let mut terminal = Terminal::new(TestBackend::new(20, 3)).unwrap();
+        terminal
+            .draw(|frame| {
+                // Cursor after "€€", i.e. byte 6 but column 2
+                render_input_field(
+                    frame,
+                    Rect::new(0, 0, 20, 3),
+                    &Theme::default(),
+                    "",
+                    "€€x",
+                    6,
+                    2,
+                    true,
+                );
+            })
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        assert_eq!(buffer[(3, 1)].symbol(), "│");
+        assert_eq!(buffer[(4, 1)].symbol(), "x");
+        terminal.backend_mut().assert_cursor_position((3, 1));
     }
 }
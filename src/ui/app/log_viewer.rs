@@ -0,0 +1,81 @@
+//! Log viewer search, copy and export
+
+use super::core::App;
+use super::state::InputMode;
+use crate::logging::{log_buffer, LogEntry};
+use tracing::Level;
+
+impl App {
+    /// Start editing the search query; the view filters on every keystroke
+    pub fn open_log_search(&mut self) {
+        let query = log_buffer().search_query.clone().unwrap_or_default();
+        self.set_input(query);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Use the input line as the search query
+    pub fn update_log_search(&mut self) {
+        let query = self.get_input().to_string();
+        log_buffer().search_query = (!query.is_empty()).then_some(query);
+        self.current_list_index = 0;
+    }
+
+    /// Drop the search query and show every entry of the enabled levels
+    pub fn clear_log_search(&mut self) {
+        log_buffer().search_query = None;
+        self.clear_input();
+        self.current_list_index = 0;
+    }
+
+    /// Show or hide entries of a log level
+    pub fn toggle_log_level(&mut self, level: Level) {
+        log_buffer().toggle_level(level);
+        self.current_list_index = 0;
+    }
+
+    /// Select the next visible log line
+    pub fn log_viewer_select_next(&mut self) {
+        let count = log_buffer().filtered_entries().count();
+        if self.current_list_index + 1 < count {
+            self.current_list_index += 1;
+        }
+    }
+
+    /// Copy the selected log line to the clipboard
+    pub fn copy_selected_log_line(&mut self) {
+        let line = log_buffer()
+            .filtered_entries()
+            .nth(self.current_list_index)
+            .map(LogEntry::line);
+        let Some(line) = line else {
+            self.set_error("No log line selected".to_string());
+            return;
+        };
+
+        match crate::ui::copy_to_clipboard(&line) {
+            Ok(()) => self.set_success("Copied log line".to_string()),
+            Err(e) => self.set_error(format!("{}", e)),
+        }
+    }
+
+    /// Save the currently visible log lines to a timestamped file
+    pub fn save_filtered_logs(&mut self) {
+        let lines: Vec<String> = log_buffer()
+            .filtered_entries()
+            .map(LogEntry::line)
+            .collect();
+        if lines.is_empty() {
+            self.set_error("No log lines to save".to_string());
+            return;
+        }
+
+        let path = format!(
+            "warpscan-logs-{}.log",
+            chrono::Utc::now().format("%Y%m%d-%H%M%S")
+        );
+        match std::fs::write(&path, lines.join("\n") + "\n") {
+            Ok(()) => self.set_success(format!("Saved {} log lines to {}", lines.len(), path)),
+            Err(e) => self.report_error("Failed to save logs", &e.into()),
+        }
+    }
+}
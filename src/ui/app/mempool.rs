@@ -0,0 +1,67 @@
+//! Mempool monitor: pending transactions pushed over the WebSocket provider
+
+use super::core::App;
+use super::state::InputMode;
+use crate::ui::models::TransactionInfo;
+use ethers::types::Transaction;
+
+/// Pending transactions kept on screen; older ones are dropped
+pub const MEMPOOL_LIMIT: usize = 200;
+
+impl App {
+    /// Add a pending transaction to the top of the monitor
+    pub fn push_pending_transaction(&mut self, transaction: &Transaction) {
+        let seen_at = chrono::Utc::now().timestamp() as u64;
+        let tx = TransactionInfo::from_block_transaction(transaction, 0, seen_at);
+        // Keep a selected row on the same transaction as new ones arrive above it
+        if self.current_list_index > 0 && self.mempool_filter_matches(&tx) {
+            self.current_list_index = (self.current_list_index + 1).min(MEMPOOL_LIMIT - 1);
+        }
+        self.mempool_txs.push_front(tx);
+        self.mempool_txs.truncate(MEMPOOL_LIMIT);
+        self.mempool_seen += 1;
+    }
+
+    /// Pending transactions whose from or to address contains the filter
+    pub fn filtered_mempool_txs(&self) -> Vec<&TransactionInfo> {
+        self.mempool_txs
+            .iter()
+            .filter(|tx| self.mempool_filter_matches(tx))
+            .collect()
+    }
+
+    /// Whether `tx` passes the address filter; addresses are stored lowercase
+    fn mempool_filter_matches(&self, tx: &TransactionInfo) -> bool {
+        let filter = self.mempool_filter.to_lowercase();
+        filter.is_empty() || tx.from.contains(&filter) || tx.to.contains(&filter)
+    }
+
+    /// Start editing the address filter; the table filters on every keystroke
+    pub fn open_mempool_filter(&mut self) {
+        self.set_input(self.mempool_filter.clone());
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Use the input line as the address filter
+    pub fn update_mempool_filter(&mut self) {
+        self.mempool_filter = self.get_input().trim().to_string();
+        self.current_list_index = 0;
+    }
+
+    /// Drop the address filter
+    pub fn clear_mempool_filter(&mut self) {
+        self.mempool_filter.clear();
+        self.clear_input();
+        self.current_list_index = 0;
+    }
+
+    /// Move the selected pending transaction by one
+    pub fn mempool_select(&mut self, forward: bool) {
+        let count = self.filtered_mempool_txs().len();
+        self.current_list_index = if forward {
+            (self.current_list_index + 1).min(count.saturating_sub(1))
+        } else {
+            self.current_list_index.saturating_sub(1)
+        };
+    }
+}
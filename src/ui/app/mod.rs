@@ -1,16 +1,46 @@
 // Application state and core functionality
 pub mod address;
+pub mod address_book;
+pub mod approvals;
+pub mod block;
+pub mod contract_interaction;
 pub mod core;
+pub mod create2;
 pub mod data;
+pub mod debounce;
+pub mod event_monitor;
 pub mod events;
+pub mod gas;
+pub mod headless;
 pub mod input;
+pub mod log_viewer;
+pub mod mempool;
 pub mod mouse;
+pub mod multisig;
 pub mod navigation;
+pub mod nft;
+pub mod notifications;
+pub mod prefetch;
+pub mod report;
+pub mod search_history;
+pub mod settings;
+pub mod setup_wizard;
+pub mod simulation;
 pub mod state;
 pub mod subscriptions;
+pub mod token_info;
+pub mod tracked;
+pub mod transaction_filter;
 pub mod ui_state;
+pub mod utils;
 pub mod validation;
+pub mod verification;
+pub mod wallet;
 
 // Re-export all public types and the main App struct for convenience
 pub use core::App;
-pub use state::{AppState, DataMode, InputMode, ModeSelectionState};
+pub use navigation::{generate_deep_link, parse_deep_link, DeepLink, NavigationEntry};
+pub use state::{
+    AppState, ClickAction, ClickableRegion, DataMode, EnsPreview, InputFeedback, InputMode,
+    ModeSelectionState,
+};
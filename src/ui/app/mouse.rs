@@ -2,7 +2,7 @@
 
 use super::super::models::AddressTab;
 use super::core::App;
-use super::state::AppState;
+use super::state::{AppState, ClickAction};
 use crate::error::Result;
 use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
 
@@ -13,6 +13,17 @@ pub async fn handle_mouse_event(app: &mut App, mouse_event: MouseEvent) -> Resul
             let x = mouse_event.column;
             let y = mouse_event.row;
 
+            // Values drawn as clickable take precedence over the layout guesses below
+            let action = app
+                .clickable_regions
+                .iter()
+                .find(|region| region.contains(x, y))
+                .map(|region| region.action.clone());
+            if let Some(action) = action {
+                execute_click_action(app, action).await;
+                return Ok(false);
+            }
+
             // Handle mouse clicks based on current screen and position
             match app.state {
                 AppState::Home => {
@@ -34,6 +45,10 @@ pub async fn handle_mouse_event(app: &mut App, mouse_event: MouseEvent) -> Resul
                 }
             }
         }
+        MouseEventKind::Moved => {
+            // Tracked for hover tooltips
+            app.mouse_position = Some((mouse_event.column, mouse_event.row));
+        }
         MouseEventKind::ScrollUp => {
             // Handle scroll up - move selection up
             app.previous_item();
@@ -49,6 +64,23 @@ pub async fn handle_mouse_event(app: &mut App, mouse_event: MouseEvent) -> Resul
     Ok(false)
 }
 
+/// Run the action of a clicked region
+async fn execute_click_action(app: &mut App, action: ClickAction) {
+    match action {
+        ClickAction::Navigate(AppState::TransactionViewer, hash) => {
+            app.navigate_to_transaction(&hash).await
+        }
+        ClickAction::Navigate(AppState::AddressLookup, address) => {
+            app.navigate_to_address(&address).await
+        }
+        ClickAction::Navigate(AppState::BlockExplorer, block) => {
+            app.navigate_to(AppState::BlockExplorer).await;
+            app.lookup_block(&block).await;
+        }
+        ClickAction::Navigate(state, _) => app.navigate_to(state).await,
+    }
+}
+
 /// Handle clicks on the home screen
 async fn handle_home_click(app: &mut App, _x: u16, y: u16) {
     // Calculate which menu item was clicked based on position
@@ -93,19 +125,15 @@ async fn handle_address_lookup_click(app: &mut App, x: u16, y: u16) -> Result<()
         // Calculate which tab was clicked based on x position
         // Each tab is approximately 15-20 characters wide
         let tab_width = 18;
-        let tab_index = (x as usize / tab_width).min(5); // Max 6 tabs (0-5)
-
-        let tab = match tab_index {
-            0 => AddressTab::Details,
-            1 => AddressTab::Transactions,
-            2 => AddressTab::AccountHistory,
-            3 => AddressTab::TokenTransfers,
-            4 => AddressTab::Tokens,
-            5 => AddressTab::InternalTxns,
-            _ => return Ok(()),
-        };
+        let tab_index = x as usize / tab_width;
 
-        app.switch_address_tab(tab);
+        let tab = app
+            .address_data
+            .as_ref()
+            .and_then(|data| data.visible_tabs().get(tab_index).cloned());
+        if let Some(tab) = tab {
+            app.switch_address_tab(tab);
+        }
         return Ok(());
     }
 
@@ -118,21 +146,7 @@ async fn handle_address_lookup_click(app: &mut App, x: u16, y: u16) -> Result<()
             if y >= 10 {
                 let row_index = y.saturating_sub(10) as usize;
                 match address_data.current_tab {
-                    AddressTab::Transactions => {
-                        if row_index < address_data.transactions.len() {
-                            address_data.transactions.get(row_index).map(|tx| {
-                                (
-                                    "transactions",
-                                    tx.tx_hash.clone(),
-                                    tx.from.clone(),
-                                    tx.to.clone(),
-                                    x,
-                                )
-                            })
-                        } else {
-                            None
-                        }
-                    }
+                    // Transactions register exact clickable regions when drawn
                     AddressTab::AccountHistory => {
                         if row_index < address_data.account_history.len() {
                             address_data.account_history.get(row_index).map(|entry| {
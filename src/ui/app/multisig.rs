@@ -0,0 +1,298 @@
+//! Multisig wallet handling: Safe creation, transaction proposals and owner
+//! signatures
+//!
+//! Nothing is broadcast from here. A new Safe's deployment calldata is copied
+//! for an owner to send, and proposals collect signatures until the threshold
+//! is met. Tracked wallets and proposals are kept in `session.json`.
+
+use super::core::App;
+use super::state::InputMode;
+use super::utils::format_address_display;
+use crate::blockchain::safe::{safe_tx_hash, SAFE_PROXY_FACTORY};
+use crate::blockchain::SafeDeployment;
+use crate::error::{Error, Result};
+use crate::models::{MultisigWallet, TransactionProposal};
+use crate::ui::models::MultisigTab;
+use crate::wallet::WalletManager;
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::{Address, Signature, H256, U256};
+use ethers::utils::to_checksum;
+use std::str::FromStr;
+
+impl App {
+    /// Switch to the next multisig tab
+    pub fn multisig_next_tab(&mut self) {
+        self.multisig_state.tab = self.multisig_state.tab.next();
+        self.multisig_state.signing = false;
+        self.current_list_index = 0;
+    }
+
+    /// Move the focused form row or pending proposal by one
+    pub fn multisig_select(&mut self, forward: bool) {
+        let count = match self.multisig_state.tab {
+            MultisigTab::Pending => self.multisig_state.proposals.len(),
+            tab => tab.field_count(),
+        };
+        self.current_list_index = if forward {
+            (self.current_list_index + 1).min(count.saturating_sub(1))
+        } else {
+            self.current_list_index.saturating_sub(1)
+        };
+    }
+
+    /// Edit the focused field, press the focused button, or start signing the
+    /// selected proposal
+    pub async fn multisig_activate(&mut self) {
+        let tab = self.multisig_state.tab;
+        if tab == MultisigTab::Pending {
+            if self.current_list_index < self.multisig_state.proposals.len() {
+                self.multisig_state.signing = true;
+                self.clear_input();
+                self.input_mode = InputMode::Editing;
+            }
+            return;
+        }
+
+        self.multisig_state.signing = false;
+        if self.current_list_index + 1 < tab.field_count() {
+            let value = self
+                .multisig_state
+                .field(self.current_list_index)
+                .to_string();
+            self.set_input(value);
+            self.input_mode = InputMode::Editing;
+        } else if tab == MultisigTab::Wallets {
+            self.submit_multisig_creation().await;
+        } else {
+            self.propose_multisig_tx();
+        }
+    }
+
+    /// Store the edited input into the focused field, or sign the selected
+    /// proposal with it when it is a private key
+    pub fn commit_multisig_input(&mut self, value: String) {
+        self.clear_input();
+        if !std::mem::take(&mut self.multisig_state.signing) {
+            self.multisig_state
+                .set_field(self.current_list_index, value);
+            return;
+        }
+
+        let Some(id) = self
+            .multisig_state
+            .proposals
+            .get(self.current_list_index)
+            .map(|proposal| proposal.id.clone())
+        else {
+            return;
+        };
+        match self.sign_multisig_tx(&id, &value) {
+            Ok(_) => self.set_success(format!("Signed {}", format_address_display(&id, None, 0))),
+            Err(e) => self.report_error("Failed to sign transaction", &e),
+        }
+    }
+
+    /// Create a wallet from the Wallets tab form
+    async fn submit_multisig_creation(&mut self) {
+        let owners: Vec<String> = self
+            .multisig_state
+            .owners
+            .split(',')
+            .map(|owner| owner.trim().to_string())
+            .filter(|owner| !owner.is_empty())
+            .collect();
+        let Ok(threshold) = self.multisig_state.threshold.trim().parse::<u32>() else {
+            let e = Error::validation(format!(
+                "Invalid threshold: {}",
+                self.multisig_state.threshold
+            ));
+            self.report_error("Failed to create multisig wallet", &e);
+            return;
+        };
+        if let Err(e) = self.create_multisig(owners, threshold).await {
+            self.report_error("Failed to create multisig wallet", &e);
+        }
+    }
+
+    /// Build the deployment of a new Safe and show it for confirmation
+    pub async fn create_multisig(&mut self, owners: Vec<String>, threshold: u32) -> Result<()> {
+        let mut addresses = Vec::with_capacity(owners.len());
+        for owner in &owners {
+            let address = WalletManager::validate_address(owner)?;
+            if addresses.contains(&address) {
+                return Err(Error::validation(format!("Duplicate owner {}", owner)));
+            }
+            addresses.push(address);
+        }
+        // A fresh salt so the same owners can deploy more than one Safe
+        let salt_nonce = U256::from(chrono::Utc::now().timestamp_millis().max(0) as u64);
+        let mut deployment = SafeDeployment::new(addresses, threshold, salt_nonce)?;
+
+        self.set_loading_with_message("multisig", "Predicting Safe address...".to_string());
+        let creation_code = self.blockchain_client.get_safe_proxy_creation_code().await;
+        self.set_loading("multisig", false);
+        deployment.predicted_address = Some(deployment.predict_address(&creation_code?));
+
+        self.multisig_state.pending_deployment = Some(deployment);
+        Ok(())
+    }
+
+    /// Close the deployment popup; on confirmation track the new wallet and
+    /// copy its deployment calldata for an owner to send
+    pub fn confirm_multisig_deployment(&mut self, confirmed: bool) {
+        let Some(deployment) = self.multisig_state.pending_deployment.take() else {
+            return;
+        };
+        if !confirmed {
+            return;
+        }
+
+        let address = deployment
+            .predicted_address
+            .map(|address| to_checksum(&address, None))
+            .unwrap_or_default();
+        self.multisig_state.wallets.push(MultisigWallet {
+            address: address.clone(),
+            owners: deployment
+                .owners
+                .iter()
+                .map(|owner| to_checksum(owner, None))
+                .collect(),
+            threshold: deployment.threshold,
+            created_at: chrono::Utc::now().timestamp() as u64,
+            name: None,
+        });
+        self.multisig_state.safe = address.clone();
+        self.save_session();
+
+        let calldata = format!("0x{}", hex::encode(&deployment.data));
+        match self.copy_to_clipboard(&calldata) {
+            Ok(()) => self.set_success(format!(
+                "Tracking {}; send the copied calldata to {} to deploy it",
+                format_address_display(&address, None, 0),
+                format_address_display(SAFE_PROXY_FACTORY, None, 0)
+            )),
+            Err(e) => self.report_error("Failed to copy deployment calldata", &e),
+        }
+    }
+
+    /// Add a transaction from the Propose tab form to the pending list
+    fn propose_multisig_tx(&mut self) {
+        match self.build_multisig_proposal() {
+            Ok(proposal) => {
+                let id = proposal.id.clone();
+                self.multisig_state.proposals.push(proposal);
+                self.save_session();
+                self.multisig_state.tab = MultisigTab::Pending;
+                self.current_list_index = self.multisig_state.proposals.len() - 1;
+                self.set_success(format!("Proposed {}", format_address_display(&id, None, 0)));
+            }
+            Err(e) => self.report_error("Failed to propose transaction", &e),
+        }
+    }
+
+    /// Parse the Propose tab form into a proposal keyed by its Safe transaction hash
+    fn build_multisig_proposal(&self) -> Result<TransactionProposal> {
+        let form = &self.multisig_state;
+        let safe = WalletManager::validate_address(form.safe.trim())?;
+        if form.threshold_of(form.safe.trim()).is_none() {
+            return Err(Error::validation(
+                "Create the wallet on the Wallets tab before proposing from it",
+            ));
+        }
+        let to = WalletManager::validate_address(form.to.trim())?;
+        let value = match form.value.trim() {
+            "" => U256::zero(),
+            value => ethers::utils::parse_ether(value)
+                .map_err(|e| Error::validation(format!("Invalid value: {}", e)))?,
+        };
+        let data = hex::decode(form.data.trim().trim_start_matches("0x"))
+            .map_err(|e| Error::validation(format!("Invalid data: {}", e)))?;
+        let nonce = match form.nonce.trim() {
+            "" => 0,
+            nonce => nonce
+                .parse()
+                .map_err(|_| Error::validation(format!("Invalid nonce: {}", nonce)))?,
+        };
+
+        let hash = safe_tx_hash(
+            self.blockchain_client.chain_id(),
+            safe,
+            to,
+            value,
+            &data,
+            nonce,
+        );
+        let id = format!("{:?}", hash);
+        if form.proposals.iter().any(|proposal| proposal.id == id) {
+            return Err(Error::validation("This transaction is already proposed"));
+        }
+
+        Ok(TransactionProposal {
+            id,
+            safe_address: to_checksum(&safe, None),
+            nonce,
+            to: to_checksum(&to, None),
+            value,
+            data,
+            signatures: Vec::new(),
+            executed: false,
+            created_at: chrono::Utc::now().timestamp() as u64,
+        })
+    }
+
+    /// Sign a proposed transaction as one of its wallet's owners
+    ///
+    /// The key is only used for this signature and is not stored.
+    pub fn sign_multisig_tx(&mut self, tx_hash: &str, private_key: &str) -> Result<Signature> {
+        let hash = H256::from_str(tx_hash)
+            .map_err(|e| Error::validation(format!("Invalid transaction hash: {}", e)))?;
+        let index = self
+            .multisig_state
+            .proposals
+            .iter()
+            .position(|proposal| proposal.id.eq_ignore_ascii_case(tx_hash))
+            .ok_or_else(|| Error::validation(format!("No proposal {}", tx_hash)))?;
+        let wallet = LocalWallet::from_str(private_key.trim())
+            .map_err(|e| Error::wallet(format!("Invalid private key: {}", e)))?;
+        let signer = wallet.address();
+
+        let proposal = &self.multisig_state.proposals[index];
+        let is_owner = self
+            .multisig_state
+            .wallets
+            .iter()
+            .find(|wallet| wallet.address.eq_ignore_ascii_case(&proposal.safe_address))
+            .is_some_and(|wallet| {
+                wallet
+                    .owners
+                    .iter()
+                    .any(|owner| Address::from_str(owner).ok() == Some(signer))
+            });
+        if !is_owner {
+            return Err(Error::validation(format!(
+                "{} is not an owner of {}",
+                to_checksum(&signer, None),
+                proposal.safe_address
+            )));
+        }
+        let already_signed = proposal.signatures.iter().any(|signature| {
+            Signature::from_str(signature)
+                .ok()
+                .and_then(|signature| signature.recover(hash).ok())
+                == Some(signer)
+        });
+        if already_signed {
+            return Err(Error::validation("This owner has already signed"));
+        }
+
+        let signature = wallet
+            .sign_hash(hash)
+            .map_err(|e| Error::wallet(format!("Failed to sign: {}", e)))?;
+        self.multisig_state.proposals[index]
+            .signatures
+            .push(format!("0x{}", signature));
+        self.save_session();
+        Ok(signature)
+    }
+}
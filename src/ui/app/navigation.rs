@@ -1,5 +1,169 @@
+use super::super::models::{AddressTab, TxDirection};
 use super::core::App;
 use super::state::{AppState, InputMode};
+use super::validation::{is_address, is_block_number, is_transaction_hash};
+use crate::error::{Error, Result};
+
+/// URI scheme of shareable deep links
+pub const DEEP_LINK_SCHEME: &str = "warpscan://";
+
+/// Screens remembered for back navigation
+const MAX_NAVIGATION_HISTORY: usize = 50;
+
+/// A visited screen and the input line it showed
+pub type NavigationEntry = (AppState, String);
+
+/// Record leaving `current` for `target` on the back stack
+///
+/// Navigating to the screen at the top of the forward stack reuses that entry
+/// and returns its input; anything else, including going Home, starts a new
+/// branch and drops the forward history.
+fn record_navigation(
+    back: &mut Vec<NavigationEntry>,
+    forward: &mut Vec<NavigationEntry>,
+    current: NavigationEntry,
+    target: &AppState,
+) -> Option<String> {
+    back.push(current);
+    if back.len() > MAX_NAVIGATION_HISTORY {
+        back.remove(0);
+    }
+
+    match forward.last() {
+        Some((state, _)) if state == target && *target != AppState::Home => {
+            forward.pop().map(|(_, input)| input)
+        }
+        _ => {
+            forward.clear();
+            None
+        }
+    }
+}
+
+/// Pop the entry to show next from `from`, saving `current` on `to`
+fn step_navigation(
+    from: &mut Vec<NavigationEntry>,
+    to: &mut Vec<NavigationEntry>,
+    current: NavigationEntry,
+) -> Option<NavigationEntry> {
+    let entry = from.pop()?;
+    to.push(current);
+    Some(entry)
+}
+
+/// A parsed `warpscan://` deep link
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeepLink {
+    Address {
+        address: String,
+        tab: Option<AddressTab>,
+        min_value_eth: Option<f64>,
+        method: Option<String>,
+        direction: Option<TxDirection>,
+    },
+    Transaction(String),
+    Block(u64),
+}
+
+/// Build the deep link for an entity shown on `state`
+///
+/// Screens without a shareable entity get a bare `warpscan://` link.
+pub fn generate_deep_link(state: AppState, data: &str) -> String {
+    let kind = match state {
+        AppState::AddressLookup => "address",
+        AppState::TransactionViewer => "tx",
+        AppState::BlockExplorer => "block",
+        _ => return DEEP_LINK_SCHEME.to_string(),
+    };
+    format!("{}{}/{}", DEEP_LINK_SCHEME, kind, data)
+}
+
+/// Parse a `warpscan://address|tx|block/...` URI
+pub fn parse_deep_link(uri: &str) -> Result<DeepLink> {
+    let rest = uri
+        .trim()
+        .strip_prefix(DEEP_LINK_SCHEME)
+        .ok_or_else(|| Error::parse(format!("Not a warpscan:// link: {}", uri)))?;
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let (kind, value) = path
+        .split_once('/')
+        .ok_or_else(|| Error::parse(format!("Incomplete deep link: {}", uri)))?;
+
+    match kind {
+        "address" if is_address(value) => {
+            let mut link = DeepLink::Address {
+                address: value.to_string(),
+                tab: None,
+                min_value_eth: None,
+                method: None,
+                direction: None,
+            };
+            if let DeepLink::Address {
+                tab,
+                min_value_eth,
+                method,
+                direction,
+                ..
+            } = &mut link
+            {
+                for (key, val) in query.split('&').filter_map(|pair| pair.split_once('=')) {
+                    let val = percent_decode(val);
+                    match key {
+                        "tab" => *tab = AddressTab::from_slug(&val),
+                        "min_value" => *min_value_eth = val.parse().ok(),
+                        "method" => *method = Some(val),
+                        "direction" => *direction = TxDirection::from_label(&val),
+                        _ => {}
+                    }
+                }
+            }
+            Ok(link)
+        }
+        "tx" if is_transaction_hash(value) => Ok(DeepLink::Transaction(value.to_string())),
+        "block" if is_block_number(value) => value
+            .parse()
+            .map(DeepLink::Block)
+            .map_err(|_| Error::parse(format!("Invalid block number: {}", value))),
+        _ => Err(Error::parse(format!("Unsupported deep link: {}", uri))),
+    }
+}
+
+/// Percent-encode a query string value
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Decode a percent-encoded query string value
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| value.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
 
 impl App {
     /// Navigate to a new state
@@ -8,11 +172,19 @@ impl App {
             // Stop subscriptions for current state
             self.stop_current_subscriptions().await;
 
-            self.navigation_history.push(self.state.clone());
+            let restored_input = record_navigation(
+                &mut self.navigation_history,
+                &mut self.nav_forward_stack,
+                (self.state.clone(), self.input.clone()),
+                &new_state,
+            );
             self.previous_state = Some(self.state.clone());
             let state_to_set = new_state.clone();
             self.state = new_state;
             self.reset_navigation_state();
+            if let Some(input) = restored_input {
+                self.set_input(input);
+            }
 
             // Auto-enter editing mode for input screens if no data exists
             match state_to_set {
@@ -27,10 +199,26 @@ impl App {
                         }
                     }
                 }
-                AppState::TransactionViewer => {
+                AppState::TransactionViewer if self.transaction_data.is_none() => {
                     // If no transaction data exists, automatically enter editing mode
-                    if self.transaction_data.is_none() {
-                        self.input_mode = crate::ui::app::state::InputMode::Editing;
+                    self.input_mode = crate::ui::app::state::InputMode::Editing;
+                }
+                AppState::GasTracker => {
+                    self.refresh_gas_tracker().await;
+                    if let Err(e) = self.start_subscriptions().await {
+                        tracing::warn!(target: "warpscan", "Failed to start gas tracker subscriptions: {}", e);
+                    }
+                }
+                AppState::EventMonitor if self.event_logs.is_some() => {
+                    if let Err(e) = self.start_subscriptions().await {
+                        tracing::warn!(target: "warpscan", "Failed to start log subscription: {}", e);
+                    }
+                }
+                AppState::MempoolMonitor => {
+                    self.mempool_txs.clear();
+                    self.mempool_seen = 0;
+                    if let Err(e) = self.start_subscriptions().await {
+                        tracing::warn!(target: "warpscan", "Failed to start mempool subscription: {}", e);
                     }
                 }
                 AppState::Home => {
@@ -44,20 +232,77 @@ impl App {
         }
     }
 
+    /// Open whatever `input` refers to: an address, a transaction hash or a block
+    ///
+    /// Returns false, leaving the screen unchanged, if the input is none of these.
+    pub async fn smart_navigate(&mut self, input: &str) -> bool {
+        use super::validation::{is_address, is_block_number, is_transaction_hash};
+
+        if is_address(input) {
+            self.navigate_to(AppState::AddressLookup).await;
+            self.set_input(input.to_string());
+            if let Err(e) = self.lookup_address(input).await {
+                self.report_error("Failed to lookup address", &e);
+            }
+        } else if is_transaction_hash(input) {
+            self.navigate_to_transaction(input).await;
+        } else if is_block_number(input) {
+            self.navigate_to(AppState::BlockExplorer).await;
+            self.set_input(input.to_string());
+            self.lookup_block(input).await;
+        } else {
+            return false;
+        }
+        true
+    }
+
     /// Go back to the previous state
     pub async fn go_back(&mut self) {
-        if let Some(previous) = self.navigation_history.pop() {
-            // Stop subscriptions for current state
-            self.stop_current_subscriptions().await;
+        let current = (self.state.clone(), self.input.clone());
+        if let Some(entry) = step_navigation(
+            &mut self.navigation_history,
+            &mut self.nav_forward_stack,
+            current,
+        ) {
+            self.show_navigation_entry(entry).await;
+        }
+    }
 
-            self.previous_state = Some(self.state.clone());
-            self.state = previous;
-            self.reset_navigation_state();
+    /// Return to the screen last left with `go_back`
+    pub async fn navigate_forward(&mut self) {
+        let current = (self.state.clone(), self.input.clone());
+        if let Some(entry) = step_navigation(
+            &mut self.nav_forward_stack,
+            &mut self.navigation_history,
+            current,
+        ) {
+            self.show_navigation_entry(entry).await;
+        }
+    }
 
-            // Start subscriptions for the state we're going back to
-            if let Err(e) = self.start_subscriptions().await {
-                tracing::warn!(target: "warpscan", "Failed to start subscriptions on go_back: {}", e);
-            }
+    /// Whether `go_back` has a screen to return to
+    pub fn can_go_back(&self) -> bool {
+        !self.navigation_history.is_empty()
+    }
+
+    /// Whether `navigate_forward` has a screen to return to
+    pub fn can_go_forward(&self) -> bool {
+        !self.nav_forward_stack.is_empty()
+    }
+
+    /// Switch to a screen from the history, restoring its input line
+    async fn show_navigation_entry(&mut self, (state, input): NavigationEntry) {
+        // Stop subscriptions for current state
+        self.stop_current_subscriptions().await;
+
+        self.previous_state = Some(self.state.clone());
+        self.state = state;
+        self.reset_navigation_state();
+        self.set_input(input);
+
+        // Start subscriptions for the state we're returning to
+        if let Err(e) = self.start_subscriptions().await {
+            tracing::warn!(target: "warpscan", "Failed to start subscriptions on history navigation: {}", e);
         }
     }
 
@@ -67,7 +312,8 @@ impl App {
         self.current_list_index = 0;
         self.scroll_offset = 0;
         self.input.clear();
-        self.cursor_position = 0;
+        self.cursor_byte_pos = 0;
+        self.cursor_char_pos = 0;
         self.input_mode = InputMode::Normal;
         self.clear_messages();
         // Reset address data selection indices when switching screens
@@ -77,12 +323,204 @@ impl App {
             address_data.selected_token_transfer_index = 0;
             address_data.selected_token_index = 0;
             address_data.selected_internal_txn_index = 0;
+            address_data.selected_withdrawal_index = 0;
         }
+        self.sync_address_scrollbar();
     }
 
     /// Clear all messages
     pub fn clear_messages(&mut self) {
-        self.error_message = None;
+        self.current_error = None;
+        self.error_popup_open = false;
         self.success_message = None;
     }
+
+    /// Deep link for what is currently shown, including address tab and filters
+    pub fn current_deep_link(&self) -> Option<String> {
+        match self.state {
+            AppState::AddressLookup => {
+                let data = self.address_data.as_ref()?;
+                let mut params = Vec::new();
+                if data.current_tab != AddressTab::Details {
+                    params.push(format!("tab={}", data.current_tab.slug()));
+                }
+                if let Some(min) = data.filter.min_value_eth {
+                    params.push(format!("min_value={}", min));
+                }
+                if let Some(ref method) = data.filter.method_filter {
+                    params.push(format!("method={}", percent_encode(method)));
+                }
+                if data.filter.direction != TxDirection::Both {
+                    params.push(format!(
+                        "direction={}",
+                        data.filter.direction.label().to_lowercase()
+                    ));
+                }
+
+                let link = generate_deep_link(AppState::AddressLookup, &data.details.address);
+                Some(if params.is_empty() {
+                    link
+                } else {
+                    format!("{}?{}", link, params.join("&"))
+                })
+            }
+            AppState::TransactionViewer => self
+                .transaction_data
+                .as_ref()
+                .map(|tx| generate_deep_link(AppState::TransactionViewer, &tx.hash)),
+            AppState::BlockExplorer => self.block_data.as_ref().map(|block| {
+                generate_deep_link(AppState::BlockExplorer, &block.details.number.to_string())
+            }),
+            _ => None,
+        }
+    }
+
+    /// Copy the deep link of the current view to the clipboard
+    pub fn copy_deep_link(&mut self) {
+        let Some(link) = self.current_deep_link() else {
+            self.set_error("Nothing to link to on this screen".to_string());
+            return;
+        };
+
+        match crate::ui::copy_to_clipboard(&link) {
+            Ok(()) => self.set_success(format!("Copied {}", link)),
+            Err(e) => self.set_error(format!("{}", e)),
+        }
+    }
+
+    /// Copy the address, transaction hash or block hash shown on the current
+    /// screen to the clipboard
+    pub fn copy_focused_item(&mut self) {
+        let text = match self.state {
+            AppState::AddressLookup => self
+                .address_data
+                .as_ref()
+                .map(|data| data.details.address.clone()),
+            AppState::TransactionViewer => self.transaction_data.as_ref().map(|tx| tx.hash.clone()),
+            AppState::BlockExplorer => self
+                .block_data
+                .as_ref()
+                .map(|block| block.details.hash.clone()),
+            AppState::MempoolMonitor => self
+                .filtered_mempool_txs()
+                .get(self.current_list_index)
+                .map(|tx| tx.hash.clone()),
+            _ => None,
+        };
+        let Some(text) = text else {
+            self.set_error("Nothing to copy on this screen".to_string());
+            return;
+        };
+        if let Err(e) = self.copy_to_clipboard(&text) {
+            self.report_error("Failed to copy to clipboard", &e);
+        }
+    }
+
+    /// Navigate to the entity a deep link points at
+    pub async fn open_deep_link(&mut self, link: DeepLink) {
+        match link {
+            DeepLink::Address {
+                address,
+                tab,
+                min_value_eth,
+                method,
+                direction,
+            } => {
+                self.navigate_to_address(&address).await;
+                if let Some(tab) = tab {
+                    self.switch_address_tab(tab);
+                }
+                if let Some(ref mut data) = self.address_data {
+                    data.filter.min_value_eth = min_value_eth;
+                    data.filter.method_filter = method;
+                    data.filter.direction = direction.unwrap_or_default();
+                }
+                self.sync_address_scrollbar();
+            }
+            DeepLink::Transaction(hash) => self.navigate_to_transaction(&hash).await,
+            DeepLink::Block(number) => {
+                self.navigate_to(AppState::BlockExplorer).await;
+                self.set_input(number.to_string());
+                self.lookup_block(&number.to_string()).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_deep_link_with_filters() {
+        let link = parse_deep_link(
+            "warpscan://address/0x00000000219ab540356cBB839Cbe05303d7705Fa?tab=transactions&min_value=1.5&method=swap%20exact&direction=incoming",
+        )
+        .unwrap();
+
+        assert_eq!(
+            link,
+            DeepLink::Address {
+                address: "0x00000000219ab540356cBB839Cbe05303d7705Fa".to_string(),
+                tab: Some(AddressTab::Transactions),
+                min_value_eth: Some(1.5),
+                method: Some("swap exact".to_string()),
+                direction: Some(TxDirection::Incoming),
+            }
+        );
+        assert_eq!(
+            parse_deep_link("warpscan://block/12345").unwrap(),
+            DeepLink::Block(12345)
+        );
+        assert!(parse_deep_link("https://etherscan.io/block/1").is_err());
+    }
+
+    #[test]
+    fn test_back_navigation_returns_home() {
+        let mut back = Vec::new();
+        let mut forward = Vec::new();
+        let address = "0x00000000219ab540356cBB839Cbe05303d7705Fa".to_string();
+
+        // Home -> Address -> Transaction
+        record_navigation(
+            &mut back,
+            &mut forward,
+            (AppState::Home, String::new()),
+            &AppState::AddressLookup,
+        );
+        record_navigation(
+            &mut back,
+            &mut forward,
+            (AppState::AddressLookup, address.clone()),
+            &AppState::TransactionViewer,
+        );
+
+        // Back -> Back
+        let entry = step_navigation(
+            &mut back,
+            &mut forward,
+            (AppState::TransactionViewer, "0xabc".to_string()),
+        );
+        assert_eq!(entry, Some((AppState::AddressLookup, address.clone())));
+        let entry = step_navigation(&mut back, &mut forward, entry.unwrap());
+        assert_eq!(entry, Some((AppState::Home, String::new())));
+        assert!(back.is_empty());
+        assert_eq!(forward.len(), 2);
+
+        // Revisiting the next forward screen reuses its entry; Home clears the rest
+        let restored = record_navigation(
+            &mut back,
+            &mut forward,
+            (AppState::Home, String::new()),
+            &AppState::AddressLookup,
+        );
+        assert_eq!(restored, Some(address));
+        record_navigation(
+            &mut back,
+            &mut forward,
+            (AppState::AddressLookup, String::new()),
+            &AppState::Home,
+        );
+        assert!(forward.is_empty());
+    }
 }
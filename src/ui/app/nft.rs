@@ -0,0 +1,287 @@
+//! NFT gallery selection and metadata loading
+
+use super::core::App;
+use crate::ui::events::{CustomEvent, Event};
+use crate::ui::models::{AddressTab, TokenInfo, TokenTransfer, TokenType};
+use ethers::types::U256;
+use std::collections::HashMap;
+
+impl App {
+    /// Token IDs of the gallery collection the address holds
+    pub fn nft_gallery_token_ids(&self) -> Vec<String> {
+        match self.nft_gallery_token {
+            Some(ref token) => self.nft_token_ids(token),
+            None => Vec::new(),
+        }
+    }
+
+    /// Token IDs of `token` the address holds, per its transfer history
+    fn nft_token_ids(&self, token: &TokenInfo) -> Vec<String> {
+        match self.address_data {
+            Some(ref data) => held_token_ids(
+                &data.token_transfers,
+                &token.contract_address,
+                &data.details.address,
+            ),
+            None => Vec::new(),
+        }
+    }
+
+    /// Fetch the floor prices of the looked-up address's NFT collections in
+    /// the background; they arrive as a `nft_floor_prices` `DataLoaded` event
+    pub async fn request_nft_floor_prices(&mut self) {
+        let Some(ref data) = self.address_data else {
+            return;
+        };
+        let address = data.details.address.clone();
+        let contracts: Vec<String> = data
+            .tokens
+            .iter()
+            .filter(|t| t.token_type.is_nft())
+            .map(|t| t.contract_address.clone())
+            .collect();
+        if contracts.is_empty() {
+            return;
+        }
+
+        let service = self.blockchain_client.background_handle();
+        let fetch = async move {
+            let prices =
+                futures::future::join_all(contracts.iter().map(|c| service.get_nft_floor_price(c)))
+                    .await;
+            let floor_prices: serde_json::Map<String, serde_json::Value> = contracts
+                .into_iter()
+                .zip(prices)
+                .filter_map(|(contract, price)| match price {
+                    Ok(price) => Some((contract, serde_json::json!(price))),
+                    Err(e) => {
+                        tracing::debug!(target: "warpscan", "Failed to fetch floor price for {}: {}", contract, e);
+                        None
+                    }
+                })
+                .collect();
+            serde_json::json!({ "address": address, "floor_prices": floor_prices })
+        };
+
+        match self.event_sender.clone() {
+            Some(sender) => {
+                tokio::spawn(async move {
+                    let data = fetch.await;
+                    let _ = sender.send(Event::Custom(CustomEvent::DataLoaded {
+                        operation: "nft_floor_prices".to_string(),
+                        data,
+                    }));
+                });
+            }
+            // Without an event loop, wait for the prices instead
+            None => {
+                let data = fetch.await;
+                self.apply_nft_floor_prices(&data);
+            }
+        }
+    }
+
+    /// Fill in floor prices fetched by `request_nft_floor_prices`, unless
+    /// another address was looked up in the meantime
+    pub fn apply_nft_floor_prices(&mut self, data: &serde_json::Value) {
+        let Some(floor_prices) = data["floor_prices"].as_object() else {
+            return;
+        };
+        let Some(ref mut address_data) = self.address_data else {
+            return;
+        };
+        if data["address"].as_str() != Some(address_data.details.address.as_str()) {
+            return;
+        }
+        let tokens = address_data
+            .tokens
+            .iter_mut()
+            .chain(self.nft_gallery_token.as_mut());
+        for token in tokens {
+            if let Some(price) = floor_prices.get(&token.contract_address) {
+                token.floor_price = price.as_f64();
+            }
+        }
+    }
+
+    /// Select the previous NFT card and load its metadata
+    pub async fn nft_gallery_select_previous(&mut self) {
+        if self.current_list_index > 0 {
+            self.current_list_index -= 1;
+            self.load_selected_nft_metadata().await;
+        }
+    }
+
+    /// Select the next NFT card and load its metadata
+    pub async fn nft_gallery_select_next(&mut self) {
+        if self.current_list_index + 1 < self.nft_gallery_token_ids().len() {
+            self.current_list_index += 1;
+            self.load_selected_nft_metadata().await;
+        }
+    }
+
+    /// Fetch the `tokenURI` metadata of the selected NFT card
+    pub async fn load_selected_nft_metadata(&mut self) {
+        self.nft_metadata = None;
+        let Some(contract) = self
+            .nft_gallery_token
+            .as_ref()
+            .map(|t| t.contract_address.clone())
+        else {
+            return;
+        };
+        let Some(token_id) = self
+            .nft_gallery_token_ids()
+            .get(self.current_list_index)
+            .cloned()
+        else {
+            return;
+        };
+
+        self.set_loading("nft_metadata", true);
+        match self
+            .blockchain_client
+            .get_nft_metadata(&contract, &token_id)
+            .await
+        {
+            Ok(metadata) => self.nft_metadata = Some(metadata),
+            Err(e) => self.report_error("Failed to load NFT metadata", &e),
+        }
+        self.set_loading("nft_metadata", false);
+    }
+
+    /// Open the metadata popup for the ERC-721 collection selected in the
+    /// Tokens tab; when the address holds several of its tokens, a picker of
+    /// their IDs is shown first
+    ///
+    /// Returns whether the selected row is an ERC-721 collection.
+    pub async fn open_nft_detail_popup(&mut self) -> bool {
+        let Some(token) = self.address_data.as_ref().and_then(|data| {
+            (data.current_tab == AddressTab::Tokens)
+                .then(|| data.tokens.get(data.selected_token_index))
+                .flatten()
+                .filter(|t| matches!(t.token_type, TokenType::ERC721))
+                .cloned()
+        }) else {
+            return false;
+        };
+        let mut token_ids = self.nft_token_ids(&token);
+        match token_ids.len() {
+            0 => self.set_error(format!(
+                "No held {} token found in this address's transfers",
+                token.symbol
+            )),
+            1 => {
+                let token_id = token_ids.remove(0);
+                self.load_nft_detail(&token, token_id).await;
+            }
+            _ => {
+                self.nft_token_picker_index = 0;
+                self.nft_token_picker = Some((token, token_ids));
+            }
+        }
+        true
+    }
+
+    /// Move the NFT token ID picker selection up or down
+    pub fn nft_token_picker_select(&mut self, next: bool) {
+        let Some((_, ref token_ids)) = self.nft_token_picker else {
+            return;
+        };
+        let last = token_ids.len().saturating_sub(1);
+        self.nft_token_picker_index = if next {
+            (self.nft_token_picker_index + 1).min(last)
+        } else {
+            self.nft_token_picker_index.saturating_sub(1)
+        };
+    }
+
+    /// Close the NFT token ID picker and open the metadata of the selected ID
+    pub async fn open_selected_nft_token(&mut self) {
+        let Some((token, mut token_ids)) = self.nft_token_picker.take() else {
+            return;
+        };
+        if self.nft_token_picker_index < token_ids.len() {
+            let token_id = token_ids.swap_remove(self.nft_token_picker_index);
+            self.load_nft_detail(&token, token_id).await;
+        }
+    }
+
+    /// Load the metadata of one token of `token` into the detail popup
+    async fn load_nft_detail(&mut self, token: &TokenInfo, token_id: String) {
+        self.set_loading_with_message(
+            "nft_metadata",
+            format!("Loading {} #{}...", token.symbol, token_id),
+        );
+        match self
+            .blockchain_client
+            .get_nft_metadata(&token.contract_address, &token_id)
+            .await
+        {
+            Ok(metadata) => self.nft_detail_popup = Some((token_id, metadata)),
+            Err(e) => self.report_error("Failed to load NFT metadata", &e),
+        }
+        self.set_loading("nft_metadata", false);
+    }
+}
+
+/// Token IDs of `contract` whose latest transfer went to `owner`, in
+/// numeric order
+///
+/// `transfers` are newest first, as Etherscan returns them.
+fn held_token_ids(transfers: &[TokenTransfer], contract: &str, owner: &str) -> Vec<String> {
+    let mut latest: HashMap<&str, &TokenTransfer> = HashMap::new();
+    for transfer in transfers
+        .iter()
+        .filter(|t| t.contract_address.eq_ignore_ascii_case(contract))
+    {
+        if let Some(token_id) = transfer.token_id.as_deref() {
+            latest
+                .entry(token_id)
+                .and_modify(|seen| {
+                    if transfer.timestamp > seen.timestamp {
+                        *seen = transfer;
+                    }
+                })
+                .or_insert(transfer);
+        }
+    }
+
+    let mut token_ids: Vec<String> = latest
+        .into_iter()
+        .filter(|(_, transfer)| transfer.to.eq_ignore_ascii_case(owner))
+        .map(|(token_id, _)| token_id.to_string())
+        .collect();
+    token_ids.sort_by_cached_key(|id| (U256::from_dec_str(id).ok(), id.clone()));
+    token_ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nft_transfer(contract: &str, token_id: &str, to: &str, timestamp: u64) -> TokenTransfer {
+        TokenTransfer {
+            token_id: Some(token_id.to_string()),
+            contract_address: contract.to_string(),
+            to: to.to_string(),
+            timestamp,
+            ..TokenTransfer::default()
+        }
+    }
+
+    #[test]
+    fn test_held_token_ids() {
+        let owner = "0xowner";
+        // Newest first: #3 was received and later sent away, and the other
+        // collection's #7 shares the name but not the contract
+        let transfers = vec![
+            nft_transfer("0xmayc", "3", "0xbuyer", 40),
+            nft_transfer("0xmayc", "10", owner, 30),
+            nft_transfer("0xfake", "7", owner, 25),
+            nft_transfer("0xMAYC", "9", owner, 20),
+            nft_transfer("0xmayc", "3", owner, 10),
+        ];
+        assert_eq!(held_token_ids(&transfers, "0xmayc", owner), vec!["9", "10"]);
+    }
+}
@@ -0,0 +1,28 @@
+//! Notification queue: balance changes and new blocks
+
+use super::core::App;
+use crate::ui::models::{Notification, NotificationLevel};
+use std::time::Instant;
+
+/// Notifications stacked on screen at once; older ones wait their turn
+pub const VISIBLE_NOTIFICATIONS: usize = 3;
+
+impl App {
+    /// Queue a notification, newest last
+    pub fn push_notification(&mut self, message: String, level: NotificationLevel) {
+        self.notifications
+            .push_back(Notification::new(message, level));
+    }
+
+    /// Drop the notifications past `expires_at`; called on tick
+    pub fn prune_notifications(&mut self) {
+        let now = Instant::now();
+        self.notifications
+            .retain(|notification| notification.expires_at > now);
+    }
+
+    /// Notifications to draw, newest first
+    pub fn visible_notifications(&self) -> impl Iterator<Item = &Notification> {
+        self.notifications.iter().rev().take(VISIBLE_NOTIFICATIONS)
+    }
+}
@@ -0,0 +1,55 @@
+//! Background prefetching of address info
+//!
+//! Addresses shown on screen are likely to be opened next, so their balance,
+//! transaction count and contract status are fetched in the background to
+//! warm the cache.
+
+use super::core::App;
+use super::state::DataMode;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Most address prefetches in flight at once, to spare the API quota
+pub const PREFETCH_CONCURRENCY: usize = 2;
+
+impl App {
+    /// Fetch address info for `addresses` in background tasks
+    ///
+    /// Failures only matter to the cache, so they are logged at trace level
+    /// and otherwise ignored. Does nothing when `ui.enable_prefetch` is off.
+    pub fn prefetch_address_data(&self, addresses: &[String]) {
+        if !self.config.ui.enable_prefetch {
+            return;
+        }
+
+        let mut seen = HashSet::new();
+        let addresses: Vec<String> = addresses
+            .iter()
+            .map(|address| address.to_lowercase())
+            .filter(|address| {
+                super::validation::is_address(address) && seen.insert(address.clone())
+            })
+            .collect();
+        if addresses.is_empty() {
+            return;
+        }
+
+        let use_etherscan = self.data_mode != Some(DataMode::LocalNode);
+        let service = Arc::new(self.blockchain_client.background_handle());
+        for address in addresses {
+            let service = service.clone();
+            let limiter = self.prefetch_limiter.clone();
+            tokio::spawn(async move {
+                let Ok(_permit) = limiter.acquire_owned().await else {
+                    return;
+                };
+                if let Err(e) = service
+                    .get_address_info_with_mode(&address, use_etherscan)
+                    .await
+                {
+                    tracing::trace!(target: "warpscan", "Prefetch failed for {}: {}", address, e);
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! Plain text address report export
+
+use super::super::models::{AddressTab, CompleteAddressData};
+use super::core::App;
+use super::state::InputMode;
+use crate::error::{Error, Result};
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Sections that can appear in an address report
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReportSection {
+    TransactionSummary,
+    TokenHoldings,
+    TransactionHistory,
+    InternalTransactions,
+    Approvals,
+    Withdrawals,
+}
+
+impl ReportSection {
+    /// Sections exported from a tab; the Details tab exports everything
+    fn for_tab(tab: &AddressTab) -> Vec<Self> {
+        match tab {
+            AddressTab::Details => vec![
+                Self::TransactionSummary,
+                Self::TokenHoldings,
+                Self::TransactionHistory,
+                Self::InternalTransactions,
+                Self::Approvals,
+                Self::Withdrawals,
+            ],
+            AddressTab::Transactions | AddressTab::AccountHistory => {
+                vec![Self::TransactionSummary, Self::TransactionHistory]
+            }
+            AddressTab::Tokens | AddressTab::TokenTransfers => vec![Self::TokenHoldings],
+            AddressTab::InternalTxns => vec![Self::InternalTransactions],
+            AddressTab::Approvals => vec![Self::Approvals],
+            AddressTab::Withdrawals => vec![Self::Withdrawals],
+        }
+    }
+}
+
+impl App {
+    /// Open the export prompt with a default file name in the input line
+    pub fn open_export_prompt(&mut self) {
+        let Some(ref data) = self.address_data else {
+            self.set_error("Look up an address before exporting a report".to_string());
+            return;
+        };
+
+        let default_path = format!("warpscan-report-{}.txt", data.details.address);
+        self.export_prompt_open = true;
+        self.set_input(default_path);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Close the export prompt and restore the address in the input line
+    pub fn close_export_prompt(&mut self) {
+        self.export_prompt_open = false;
+        self.input_mode = InputMode::Normal;
+        if let Some(ref data) = self.address_data {
+            let address = data.details.address.clone();
+            self.set_input(address);
+        }
+    }
+
+    /// Export the report to the path entered in the prompt
+    pub fn confirm_export_prompt(&mut self) {
+        let path = self.get_input().trim().to_string();
+        self.close_export_prompt();
+        if path.is_empty() {
+            return;
+        }
+
+        match self.export_address_report(Path::new(&path)) {
+            Ok(()) => self.set_success(format!("Report saved to {}", path)),
+            Err(e) => self.report_error("Failed to export report", &e),
+        }
+    }
+
+    /// Write a text summary of the loaded address to `path`
+    ///
+    /// Only data already held in memory is used, so this works offline. The
+    /// sections included follow the currently selected tab.
+    pub fn export_address_report(&self, path: &Path) -> Result<()> {
+        let data = self
+            .address_data
+            .as_ref()
+            .ok_or_else(|| Error::app("No address data to export"))?;
+
+        let report = build_report(
+            data,
+            &self.blockchain_client.get_network_name(),
+            &ReportSection::for_tab(&data.current_tab),
+        );
+        std::fs::write(path, report)?;
+
+        tracing::info!(target: "warpscan", "Exported address report to {}", path.display());
+        Ok(())
+    }
+}
+
+/// Render the report text for the given sections
+fn build_report(data: &CompleteAddressData, network: &str, sections: &[ReportSection]) -> String {
+    let details = &data.details;
+    let mut out = String::new();
+
+    let _ = writeln!(out, "WarpScan Address Report");
+    let _ = writeln!(
+        out,
+        "Generated: {}",
+        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    let _ = writeln!(out, "Network:   {}", network);
+
+    section_heading(&mut out, "Address Info");
+    let mut info = vec![
+        vec!["Address".to_string(), details.address.clone()],
+        vec!["Type".to_string(), format!("{:?}", details.address_type)],
+        vec!["Balance".to_string(), format!("{:.6} ETH", details.balance)],
+    ];
+    if let Some(ref ens) = details.ens_name {
+        info.push(vec!["ENS Name".to_string(), ens.clone()]);
+    }
+    if !details.tags.is_empty() {
+        let labels: Vec<&str> = details.tags.iter().map(|t| t.label.as_str()).collect();
+        info.push(vec!["Tags".to_string(), labels.join(", ")]);
+    }
+    out.push_str(&box_table(&["Field", "Value"], &info));
+
+    for section in sections {
+        match section {
+            ReportSection::TransactionSummary => {
+                section_heading(&mut out, "Transaction Summary");
+                let address = &details.address;
+                let sent = data
+                    .transactions
+                    .iter()
+                    .filter(|tx| tx.from.eq_ignore_ascii_case(address));
+                let total_fees: f64 = sent.clone().map(|tx| tx.fee).sum();
+                let total_sent: f64 = sent.map(|tx| tx.value).sum();
+                let total_received: f64 = data
+                    .transactions
+                    .iter()
+                    .filter(|tx| tx.to.eq_ignore_ascii_case(address))
+                    .map(|tx| tx.value)
+                    .sum();
+                let rows = vec![
+                    vec![
+                        "Total Transactions".to_string(),
+                        details.total_transactions.to_string(),
+                    ],
+                    vec![
+                        "Loaded Transactions".to_string(),
+                        data.transactions.len().to_string(),
+                    ],
+                    vec!["ETH Sent".to_string(), format!("{:.6}", total_sent)],
+                    vec!["ETH Received".to_string(), format!("{:.6}", total_received)],
+                    vec!["Fees Paid (ETH)".to_string(), format!("{:.6}", total_fees)],
+                ];
+                out.push_str(&box_table(&["Metric", "Value"], &rows));
+            }
+            ReportSection::TokenHoldings => {
+                section_heading(&mut out, "Token Holdings");
+                let rows: Vec<Vec<String>> = data
+                    .tokens
+                    .iter()
+                    .map(|t| {
+                        vec![
+                            t.symbol.clone(),
+                            t.name.clone(),
+                            format!("{:.4}", t.balance),
+                            t.contract_address.clone(),
+                        ]
+                    })
+                    .collect();
+                out.push_str(&box_table(
+                    &["Symbol", "Name", "Balance", "Contract"],
+                    &rows,
+                ));
+            }
+            ReportSection::TransactionHistory => {
+                section_heading(&mut out, "Transaction History");
+                let rows: Vec<Vec<String>> = data
+                    .transactions
+                    .iter()
+                    .map(|tx| {
+                        vec![
+                            format_timestamp(tx.timestamp),
+                            tx.block.to_string(),
+                            tx.method.clone(),
+                            tx.from.clone(),
+                            tx.to.clone(),
+                            format!("{:.6}", tx.value),
+                            tx.tx_hash.clone(),
+                        ]
+                    })
+                    .collect();
+                out.push_str(&box_table(
+                    &[
+                        "Date",
+                        "Block",
+                        "Method",
+                        "From",
+                        "To",
+                        "Value (ETH)",
+                        "Hash",
+                    ],
+                    &rows,
+                ));
+            }
+            ReportSection::InternalTransactions => {
+                section_heading(&mut out, "Internal Transactions");
+                let rows: Vec<Vec<String>> = data
+                    .internal_transactions
+                    .iter()
+                    .map(|tx| {
+                        vec![
+                            format_timestamp(tx.timestamp),
+                            tx.block.to_string(),
+                            tx.tx_type.clone(),
+                            tx.from.clone(),
+                            tx.to.clone(),
+                            format!("{:.6}", tx.value),
+                            tx.parent_tx_hash.clone(),
+                        ]
+                    })
+                    .collect();
+                out.push_str(&box_table(
+                    &[
+                        "Date",
+                        "Block",
+                        "Type",
+                        "From",
+                        "To",
+                        "Value (ETH)",
+                        "Parent Tx",
+                    ],
+                    &rows,
+                ));
+            }
+            ReportSection::Approvals if data.approvals.is_empty() => {}
+            ReportSection::Approvals => {
+                section_heading(&mut out, "Token Approvals");
+                let rows: Vec<Vec<String>> = data
+                    .approvals
+                    .iter()
+                    .map(|approval| {
+                        vec![
+                            approval.block_number.to_string(),
+                            approval.token_address.clone(),
+                            approval.spender.clone(),
+                            if approval.is_unlimited() {
+                                "Unlimited".to_string()
+                            } else {
+                                approval.value.to_string()
+                            },
+                        ]
+                    })
+                    .collect();
+                out.push_str(&box_table(
+                    &["Block", "Token", "Spender", "Allowance (raw)"],
+                    &rows,
+                ));
+            }
+            // Most addresses never receive withdrawals, so skip the empty table
+            ReportSection::Withdrawals if data.withdrawals.is_empty() => {}
+            ReportSection::Withdrawals => {
+                section_heading(&mut out, "Beacon Chain Withdrawals");
+                let rows: Vec<Vec<String>> = data
+                    .withdrawals
+                    .iter()
+                    .map(|w| {
+                        vec![
+                            format_timestamp(w.timestamp),
+                            w.block_number.to_string(),
+                            w.validator_index.to_string(),
+                            w.withdrawal_index.to_string(),
+                            format!("{:.6}", w.amount_eth()),
+                        ]
+                    })
+                    .collect();
+                out.push_str(&box_table(
+                    &["Date", "Block", "Validator", "Index", "Amount (ETH)"],
+                    &rows,
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+/// Append a section heading underlined with a double rule
+fn section_heading(out: &mut String, title: &str) {
+    let _ = writeln!(out);
+    let _ = writeln!(out, "{}", title);
+    let _ = writeln!(out, "{}", "═".repeat(title.chars().count()));
+}
+
+/// Format a unix timestamp as a UTC date and time
+fn format_timestamp(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
+}
+
+/// Draw a table with box-drawing borders, sized to its widest cells
+fn box_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let rule = |left: &str, mid: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        format!("{}{}{}\n", left, segments.join(mid), right)
+    };
+    let line = |cells: Vec<&str>| {
+        let padded: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!(" {}{} ", cell, " ".repeat(width - cell.chars().count())))
+            .collect();
+        format!("│{}│\n", padded.join("│"))
+    };
+
+    let mut table = rule("┌", "┬", "┐");
+    table.push_str(&line(headers.to_vec()));
+    table.push_str(&rule("├", "┼", "┤"));
+    if rows.is_empty() {
+        let inner = widths.iter().map(|w| w + 3).sum::<usize>() - 1;
+        let empty = "(none)";
+        table.push_str(&format!("│ {:<width$} │\n", empty, width = inner - 2));
+    }
+    for row in rows {
+        table.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    table.push_str(&rule("└", "┴", "┘"));
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_table_aligns_columns() {
+        let table = box_table(
+            &["Field", "Value"],
+            &[vec!["Balance".to_string(), "1.5 ETH".to_string()]],
+        );
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], "┌─────────┬─────────┐");
+        assert_eq!(lines[1], "│ Field   │ Value   │");
+        assert_eq!(lines[3], "│ Balance │ 1.5 ETH │");
+        assert_eq!(lines[4], "└─────────┴─────────┘");
+        assert!(lines
+            .iter()
+            .all(|l| l.chars().count() == lines[0].chars().count()));
+    }
+}
@@ -0,0 +1,138 @@
+//! Recall of previous searches in the input line
+//!
+//! Entries are kept newest first, deduplicated and capped at
+//! `SEARCH_HISTORY_LIMIT`. The list is written to `search_history.json` in
+//! the cache directory on shutdown and read back on startup.
+
+use super::core::App;
+use super::state::AppState;
+use crate::config::Config;
+use crate::error::{Error, Result};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+/// Most searches kept in the history
+pub const SEARCH_HISTORY_LIMIT: usize = 50;
+
+impl App {
+    /// Whether the input line holds a search, as opposed to a password, key
+    /// or form value that must never be recalled or remembered
+    pub fn is_search_input(&self) -> bool {
+        matches!(
+            self.state,
+            AppState::Home
+                | AppState::AddressLookup
+                | AppState::BlockExplorer
+                | AppState::TransactionViewer
+                | AppState::ContractVerification
+        )
+    }
+
+    /// Remember a submitted search
+    pub fn record_search(&mut self, input: &str) {
+        push_search(&mut self.search_history, input);
+        self.history_index = None;
+    }
+
+    /// Replace the input with an older (`Up`) or newer (`Down`) search;
+    /// moving past the newest entry clears the input
+    pub fn recall_search(&mut self, older: bool) {
+        if self.search_history.is_empty() {
+            return;
+        }
+        self.history_index = match (self.history_index, older) {
+            (None, true) => Some(0),
+            (None, false) => return,
+            (Some(index), true) => Some((index + 1).min(self.search_history.len() - 1)),
+            (Some(0), false) => None,
+            (Some(index), false) => Some(index - 1),
+        };
+        let text = self
+            .history_index
+            .and_then(|index| self.search_history.get(index))
+            .cloned()
+            .unwrap_or_default();
+        self.set_input(text);
+    }
+
+    /// Restore the search history from the previous session
+    pub fn load_search_history(&mut self) {
+        match read_search_history() {
+            Ok(history) => self.search_history = history,
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to load search history: {}", e),
+        }
+    }
+
+    /// Persist the search history for the next session
+    pub fn save_search_history(&self) {
+        if let Err(e) = write_search_history(&self.search_history) {
+            tracing::warn!(target: "warpscan", "Failed to save search history: {}", e);
+        }
+    }
+}
+
+/// Move `input` to the front of the history, dropping the oldest entries
+/// beyond `SEARCH_HISTORY_LIMIT`
+fn push_search(history: &mut VecDeque<String>, input: &str) {
+    let input = input.trim();
+    if input.is_empty() {
+        return;
+    }
+    history.retain(|entry| entry != input);
+    history.push_front(input.to_string());
+    history.truncate(SEARCH_HISTORY_LIMIT);
+}
+
+fn search_history_path() -> Result<PathBuf> {
+    Ok(Config::cache_dir()?.join("search_history.json"))
+}
+
+/// Read `search_history.json`, returning an empty history if it does not exist yet
+fn read_search_history() -> Result<VecDeque<String>> {
+    let path = search_history_path()?;
+    if !path.exists() {
+        return Ok(VecDeque::new());
+    }
+    let contents = std::fs::read_to_string(&path)?;
+    let mut history: VecDeque<String> = serde_json::from_str(&contents).map_err(|e| {
+        Error::parse(format!(
+            "Invalid search history file {}: {}",
+            path.display(),
+            e
+        ))
+    })?;
+    history.truncate(SEARCH_HISTORY_LIMIT);
+    Ok(history)
+}
+
+/// Write `search_history.json`, creating the cache directory if needed
+fn write_search_history(history: &VecDeque<String>) -> Result<()> {
+    let path = search_history_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(history)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_search_dedups_and_caps() {
+        let mut history = VecDeque::new();
+        push_search(&mut history, "0xabc");
+        push_search(&mut history, "vitalik.eth");
+        push_search(&mut history, " 0xabc ");
+        push_search(&mut history, "");
+        assert_eq!(history, ["0xabc", "vitalik.eth"]);
+
+        for block in 0..SEARCH_HISTORY_LIMIT {
+            push_search(&mut history, &block.to_string());
+        }
+        assert_eq!(history.len(), SEARCH_HISTORY_LIMIT);
+        assert_eq!(history.front().map(String::as_str), Some("49"));
+        assert!(!history.contains(&"vitalik.eth".to_string()));
+    }
+}
@@ -0,0 +1,299 @@
+//! Settings screen handling
+
+use super::core::App;
+use super::state::InputMode;
+use crate::cache::CacheType;
+use crate::config::{Config, Network, MAX_CACHE_TTL_SECONDS, MIN_CACHE_TTL_SECONDS};
+use crate::ui::models::SettingsEditorState;
+
+impl App {
+    /// Switch to the next (or previous) theme and persist the choice
+    pub fn cycle_theme(&mut self, forward: bool) {
+        let name = self.theme_manager.cycle_theme(forward);
+        self.config.ui.theme = name.clone();
+
+        match Config::update_file(|config| config.ui.theme = name.clone()) {
+            Ok(()) => self.set_success(format!("Theme set to {}", name)),
+            Err(e) => self.set_error(format!("Theme applied but not saved: {}", e)),
+        }
+    }
+
+    /// Move the cache TTL selection up or down
+    pub fn settings_select_ttl(&mut self, next: bool) {
+        let last = CacheType::ALL.len() - 1;
+        self.settings_ttl_index = if next {
+            (self.settings_ttl_index + 1).min(last)
+        } else {
+            self.settings_ttl_index.saturating_sub(1)
+        };
+    }
+
+    /// Raise or lower the TTL of the selected cache and persist it
+    ///
+    /// Steps are 10s below a minute, a minute below an hour and an hour above.
+    pub fn adjust_cache_ttl(&mut self, increase: bool) {
+        let cache = CacheType::ALL[self.settings_ttl_index];
+        let current = self.cache_manager.ttl(cache);
+        let step = match (current, increase) {
+            (0..=59, true) | (0..=60, false) => 10,
+            (60..=3_599, true) | (61..=3_600, false) => 60,
+            _ => 3_600,
+        };
+        let seconds = if increase {
+            current.saturating_add(step)
+        } else {
+            current.saturating_sub(step)
+        }
+        .clamp(MIN_CACHE_TTL_SECONDS, MAX_CACHE_TTL_SECONDS);
+
+        self.cache_manager.set_ttl(cache, seconds);
+        *cache.config_ttl_mut(&mut self.config.cache) = seconds;
+
+        match Config::update_file(|config| *cache.config_ttl_mut(&mut config.cache) = seconds) {
+            Ok(()) => self.set_success(format!(
+                "{} cache TTL set to {}",
+                cache.label(),
+                format_ttl(seconds)
+            )),
+            Err(e) => self.set_error(format!("TTL applied but not saved: {}", e)),
+        }
+    }
+
+    /// Open the network switcher on the active network
+    pub fn open_network_picker(&mut self) {
+        self.network_picker_index = Network::PRESET_CHAINS
+            .iter()
+            .position(|chain| chain.chain_id() == self.config.network.chain_id)
+            .unwrap_or(0);
+        self.network_picker_open = true;
+    }
+
+    /// Move the network switcher selection up or down
+    pub fn network_picker_select(&mut self, next: bool) {
+        let last = Network::PRESET_CHAINS.len() - 1;
+        self.network_picker_index = if next {
+            (self.network_picker_index + 1).min(last)
+        } else {
+            self.network_picker_index.saturating_sub(1)
+        };
+    }
+
+    /// Switch to the selected preset network and remember it in the config
+    ///
+    /// Cached data and loaded lookups belong to the old chain, so they are
+    /// dropped and the dashboard is refreshed.
+    pub async fn switch_to_selected_network(&mut self) {
+        self.network_picker_open = false;
+        let network = Network::preset(Network::PRESET_CHAINS[self.network_picker_index]);
+        if network.chain_id == self.config.network.chain_id {
+            return;
+        }
+
+        self.stop_all_subscriptions().await;
+        if let Err(e) = self.blockchain_client.switch_network(network.clone()).await {
+            self.report_error("Failed to switch network", &e);
+            return;
+        }
+        self.config.network = network.clone();
+        self.config.ws_rpc_url = None;
+        self.reset_chain_data().await;
+
+        let name = network.name.clone();
+        match Config::update_file(|config| {
+            config.network = network;
+            config.ws_rpc_url = None;
+        }) {
+            Ok(()) => self.set_success(format!("Switched to {}", name)),
+            Err(e) => self.set_error(format!("Switched to {} but not saved: {}", name, e)),
+        }
+    }
+
+    /// Drop cached data and loaded lookups of the previous chain and refresh
+    /// the dashboard
+    async fn reset_chain_data(&mut self) {
+        self.cache_manager.clear_all();
+        self.address_data = None;
+        self.transaction_data = None;
+        self.block_data = None;
+        self.dashboard_data.latest_blocks.clear();
+        self.dashboard_data.latest_transactions.clear();
+        self.refresh_dashboard().await;
+    }
+
+    /// Open the config editor on the live configuration
+    pub fn open_settings_editor(&mut self) {
+        match SettingsEditorState::open(&self.config) {
+            Ok(editor) => self.settings_editor = editor,
+            Err(e) => self.report_error("Failed to open the config editor", &e),
+        }
+    }
+
+    /// Close the config editor, dropping unsaved edits
+    pub fn close_settings_editor(&mut self) {
+        self.settings_editor = SettingsEditorState::default();
+    }
+
+    /// Move the config editor selection up or down
+    pub fn settings_editor_select(&mut self, next: bool) {
+        let editor = &mut self.settings_editor;
+        let last = editor.fields.len().saturating_sub(1);
+        editor.selected = if next {
+            (editor.selected + 1).min(last)
+        } else {
+            editor.selected.saturating_sub(1)
+        };
+    }
+
+    /// Edit the selected field in the input line
+    pub fn edit_settings_field(&mut self) {
+        let Some(value) = self
+            .settings_editor
+            .selected_field()
+            .map(|field| field.current_value.clone())
+        else {
+            return;
+        };
+        self.settings_editor.editing = true;
+        self.settings_editor.error = None;
+        self.set_input(value);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Accept the edited value if it fits the field type; otherwise keep
+    /// editing and show why it was rejected
+    pub fn commit_settings_field(&mut self) {
+        let input = self.get_input().to_string();
+        let editor = &mut self.settings_editor;
+        let Some(field) = editor.fields.get_mut(editor.selected) else {
+            return;
+        };
+        match field.validate(&input) {
+            Ok(value) => {
+                if value != field.current_value {
+                    field.current_value = value;
+                    editor.dirty = true;
+                }
+                editor.editing = false;
+                editor.error = None;
+                self.clear_input();
+                self.input_mode = InputMode::Normal;
+            }
+            Err(message) => editor.error = Some(message),
+        }
+    }
+
+    /// Stop editing the selected field, keeping its previous value
+    pub fn cancel_settings_field_edit(&mut self) {
+        self.settings_editor.editing = false;
+        self.settings_editor.error = None;
+        self.clear_input();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Write the edited config to disk and apply it
+    ///
+    /// A changed `rpc_url` or `chain_id` is connected to first; if the node
+    /// cannot be reached or reports another chain, nothing is saved. Data of
+    /// the previous chain is dropped once the chain changes.
+    pub async fn save_settings_editor(&mut self) {
+        let config = match self
+            .settings_editor
+            .apply(&self.config)
+            .and_then(|mut config| {
+                config.validate()?;
+                Ok(config)
+            }) {
+            Ok(config) => config,
+            Err(e) => {
+                self.settings_editor.error = Some(e.to_string());
+                return;
+            }
+        };
+
+        let chain_changed = config.network.chain_id != self.config.network.chain_id;
+        if chain_changed {
+            self.stop_all_subscriptions().await;
+        }
+        if chain_changed || config.network.rpc_url != self.config.network.rpc_url {
+            if let Err(e) = self.blockchain_client.reconnect(&config.network).await {
+                self.settings_editor.error = Some(e.to_string());
+                return;
+            }
+        }
+
+        if let Err(e) = config.save() {
+            self.settings_editor.error = Some(format!("Failed to save config: {}", e));
+            return;
+        }
+        self.config = config;
+        if chain_changed {
+            self.reset_chain_data().await;
+        }
+        self.settings_editor.dirty = false;
+        self.settings_editor.error = None;
+        self.set_success("Config saved; some settings take effect after a restart".to_string());
+    }
+
+    /// Open the cache inspector with an empty key query (debug mode only)
+    pub fn open_cache_inspector(&mut self) {
+        if !self.config.debug_mode {
+            self.set_error(
+                "Set debug_mode = true in the config to use the cache inspector".to_string(),
+            );
+            return;
+        }
+
+        self.cache_inspector_open = true;
+        self.clear_input();
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Close the cache inspector and forget its results
+    pub fn close_cache_inspector(&mut self) {
+        self.cache_inspector_open = false;
+        self.cache_inspector_entries = None;
+        self.clear_input();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Look up the cache entries matching the query in the input line
+    pub fn run_cache_inspector_query(&mut self) {
+        let query = self.get_input().trim().to_string();
+        self.input_mode = InputMode::Normal;
+        if query.is_empty() {
+            return;
+        }
+
+        self.cache_inspector_entries =
+            Some(self.cache_manager.find_cache_entries_debug_info(&query));
+    }
+
+    /// Ask for confirmation before clearing the whole cache
+    pub fn request_clear_all_cache(&mut self) {
+        self.cache_clear_confirm = true;
+    }
+
+    /// Clear the whole cache after confirmation, or back out
+    pub fn confirm_clear_all_cache(&mut self, confirmed: bool) {
+        self.cache_clear_confirm = false;
+        if confirmed {
+            self.cache_manager.clear_all();
+            self.set_success("Cleared all cached data".to_string());
+        }
+    }
+
+    /// Remove expired entries from every cache
+    pub fn clear_expired_cache(&mut self) {
+        let removed = self.cache_manager.clear_expired();
+        self.set_success(format!("Removed {} expired cache entries", removed));
+    }
+}
+
+/// Format a TTL as seconds, minutes or hours, e.g. `90s`, `5m` or `2h`
+pub fn format_ttl(seconds: u64) -> String {
+    match seconds {
+        s if s % 3_600 == 0 => format!("{}h", s / 3_600),
+        s if s >= 60 && s % 60 == 0 => format!("{}m", s / 60),
+        s => format!("{}s", s),
+    }
+}
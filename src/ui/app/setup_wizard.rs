@@ -0,0 +1,110 @@
+//! First-run setup wizard handling
+
+use super::super::models::{SetupWizard, SetupWizardStep, PUBLIC_RPC_ENDPOINTS, WIZARD_NETWORKS};
+use super::core::App;
+use super::state::InputMode;
+
+impl App {
+    /// Show the setup wizard at its first step
+    pub fn start_setup_wizard(&mut self) {
+        self.setup_wizard = Some(SetupWizard::default());
+        self.clear_input();
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Move the highlight in the endpoint or network list
+    ///
+    /// Picking an endpoint fills the RPC URL input with it.
+    pub fn setup_wizard_select(&mut self, forward: bool) {
+        let Some(ref mut wizard) = self.setup_wizard else {
+            return;
+        };
+        let len = match wizard.step {
+            SetupWizardStep::RpcUrl => PUBLIC_RPC_ENDPOINTS.len(),
+            SetupWizardStep::Network => WIZARD_NETWORKS.len(),
+            SetupWizardStep::EtherscanApiKey => return,
+        };
+
+        let next = match (wizard.list_index, forward) {
+            (None, true) => 0,
+            (None, false) => len - 1,
+            (Some(i), true) => (i + 1) % len,
+            (Some(i), false) => (i + len - 1) % len,
+        };
+        wizard.list_index = Some(next);
+
+        if wizard.step == SetupWizardStep::RpcUrl {
+            self.set_input(PUBLIC_RPC_ENDPOINTS[next].1.to_string());
+        }
+    }
+
+    /// Record the current step (blank means skipped) and move on, saving
+    /// the config after the last step
+    pub async fn advance_setup_wizard(&mut self) {
+        let value = self.get_input().trim().to_string();
+        let Some(ref mut wizard) = self.setup_wizard else {
+            return;
+        };
+        let answer = (!value.is_empty()).then_some(value);
+
+        match wizard.step {
+            SetupWizardStep::RpcUrl => {
+                wizard.rpc_url = answer;
+                wizard.step = SetupWizardStep::EtherscanApiKey;
+            }
+            SetupWizardStep::EtherscanApiKey => {
+                wizard.etherscan_api_key = answer;
+                wizard.step = SetupWizardStep::Network;
+            }
+            SetupWizardStep::Network => {
+                wizard.network = wizard.list_index;
+                self.finish_setup_wizard().await;
+                return;
+            }
+        }
+        wizard.list_index = None;
+        self.clear_input();
+    }
+
+    /// Close the wizard without writing a config; it is shown again next run
+    pub fn cancel_setup_wizard(&mut self) {
+        self.setup_wizard = None;
+        self.clear_input();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Apply the collected answers, write the config file and reconnect
+    async fn finish_setup_wizard(&mut self) {
+        let Some(wizard) = self.setup_wizard.take() else {
+            return;
+        };
+        self.clear_input();
+        self.input_mode = InputMode::Normal;
+
+        let mut config = self.config.clone();
+        if let Some((name, chain_id, default_rpc)) = wizard.network.map(|i| WIZARD_NETWORKS[i]) {
+            config.network.name = name.to_string();
+            config.network.chain_id = chain_id;
+            config.network.rpc_url = default_rpc.to_string();
+            // Marks the node as local so lookups use RPC instead of Etherscan
+            config.network.node_type = (default_rpc == "http://127.0.0.1:8545")
+                .then(|| "local".to_string())
+                .or(config.network.node_type);
+        }
+        if let Some(rpc_url) = wizard.rpc_url {
+            config.network.rpc_url = rpc_url;
+        }
+        if wizard.etherscan_api_key.is_some() {
+            config.etherscan_api_key = wizard.etherscan_api_key;
+        }
+
+        match config.save_to_disk() {
+            Ok(path) => self.set_success(format!("Configuration saved to {}", path.display())),
+            Err(e) => self.report_error("Failed to save configuration", &e),
+        }
+        if let Err(e) = self.blockchain_client.reconfigure(config.clone()).await {
+            self.report_error("Failed to apply configuration", &e);
+        }
+        self.config = config;
+    }
+}
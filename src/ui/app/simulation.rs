@@ -0,0 +1,150 @@
+//! Transaction simulation (dry-run `eth_call`) handling
+
+use super::super::models::{SimulationForm, SIMULATION_FIELD_COUNT};
+use super::contract_interaction::format_token;
+use super::core::App;
+use super::state::{AppState, InputMode};
+use crate::blockchain::SimulationResult;
+use crate::error::{Error, Result};
+
+impl App {
+    /// Open the simulation screen, pre-filled from the currently loaded data
+    pub async fn open_simulation(&mut self) {
+        let mut form = SimulationForm::default();
+
+        let interaction = &self.contract_interaction_state;
+        if let (AppState::ContractInteraction, Some(address)) =
+            (&self.state, &interaction.contract_address)
+        {
+            // Call the selected function; arguments are appended by hand
+            form.to = address.clone();
+            if let Some(function) = interaction.functions.get(interaction.selected_function) {
+                form.data = format!("0x{}", hex::encode(function.selector()));
+                form.function = self.loaded_abi_function(function);
+            }
+        } else if let (AppState::TransactionViewer, Some(tx)) =
+            (&self.state, &self.transaction_data)
+        {
+            form.from = tx.from.clone();
+            form.to = tx.to.clone().unwrap_or_default();
+            form.data = tx.input_data.clone();
+            form.value = tx.value.to_string();
+        } else if let (AppState::AddressLookup, Some(address_data)) =
+            (&self.state, &self.address_data)
+        {
+            form.to = address_data.details.address.clone();
+        }
+
+        self.navigate_to(AppState::Simulation).await;
+        self.simulation_form = form;
+    }
+
+    /// Whether the Simulate button is focused (rather than an input field)
+    pub fn simulation_button_focused(&self) -> bool {
+        self.current_list_index == SIMULATION_FIELD_COUNT - 1
+    }
+
+    /// Move focus to the next simulation field
+    pub fn simulation_next_field(&mut self) {
+        if self.current_list_index < SIMULATION_FIELD_COUNT - 1 {
+            self.current_list_index += 1;
+        }
+    }
+
+    /// Start editing the focused simulation field
+    pub fn edit_simulation_field(&mut self) {
+        let value = self
+            .simulation_form
+            .field(self.current_list_index)
+            .to_string();
+        self.set_input(value);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Store the edited input into the focused simulation field
+    pub fn commit_simulation_field(&mut self, value: String) {
+        self.simulation_form
+            .set_field(self.current_list_index, value);
+        self.clear_input();
+    }
+
+    /// Estimate gas and dry-run a transaction on the latest block
+    ///
+    /// Uses the `[simulation]` API when configured, `eth_call` otherwise.
+    pub async fn simulate_transaction(
+        &self,
+        from: &str,
+        to: &str,
+        value_eth: f64,
+        data_hex: &str,
+    ) -> Result<SimulationResult> {
+        let value = ethers::utils::parse_ether(value_eth)
+            .map_err(|e| Error::validation(format!("Invalid value: {}", e)))?;
+        self.blockchain_client
+            .simulate_transaction(from, to, data_hex, value, None)
+            .await
+    }
+
+    /// Run the simulation with the current form values
+    pub async fn run_simulation(&mut self) {
+        self.clear_messages();
+        self.simulation_form.result = None;
+        self.simulation_form.decoded_output = None;
+
+        let value = self.simulation_form.value.trim();
+        let value_eth = if value.is_empty() {
+            0.0
+        } else {
+            match value.parse::<f64>() {
+                Ok(value) if value >= 0.0 => value,
+                _ => {
+                    self.set_error(format!("Invalid value: {}", value));
+                    return;
+                }
+            }
+        };
+
+        self.set_loading("simulation", true);
+        let result = self
+            .simulate_transaction(
+                self.simulation_form.from.trim(),
+                self.simulation_form.to.trim(),
+                value_eth,
+                self.simulation_form.data.trim(),
+            )
+            .await;
+        self.set_loading("simulation", false);
+
+        match result {
+            Ok(result) => {
+                if result.success {
+                    self.simulation_form.decoded_output = self.decode_simulation_output(&result);
+                }
+                self.simulation_form.result = Some(result);
+            }
+            Err(e) => self.report_error("Simulation failed", &e),
+        }
+    }
+
+    /// Return values of a successful run, when the form still calls the
+    /// function it was opened with
+    fn decode_simulation_output(&self, result: &SimulationResult) -> Option<Vec<(String, String)>> {
+        let form = &self.simulation_form;
+        let function = form.function.as_ref()?;
+        let selector = hex::encode(function.short_signature());
+        let data = form.data.trim().trim_start_matches("0x").to_lowercase();
+        if !data.starts_with(&selector) {
+            return None;
+        }
+        let output = hex::decode(result.return_data.trim_start_matches("0x")).ok()?;
+        let values = function.decode_output(&output).ok()?;
+        Some(
+            function
+                .outputs
+                .iter()
+                .zip(values)
+                .map(|(param, value)| (param.kind.to_string(), format_token(&value)))
+                .collect(),
+        )
+    }
+}
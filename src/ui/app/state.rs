@@ -7,12 +7,17 @@ pub enum AppState {
     AddressLookup,
     ContractSearch,
     TokenInfo,
+    NftGallery,
     GasTracker,
     ContractInteraction,
+    Simulation,
+    Create2Calculator,
     ContractVerification,
     WalletManager,
     MultisigWallet,
     EventMonitor,
+    MempoolMonitor,
+    LogViewer,
     Settings,
     Help,
     Quit,
@@ -39,6 +44,86 @@ pub enum ModeSelectionState {
     Selected,  // Mode has been selected
 }
 
+/// Live validation result for the search input, shown while typing
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputFeedback {
+    /// Address known from the cache to hold no code
+    Eoa,
+    /// Address known from the cache to be a contract
+    Contract,
+    /// Well-formed address not seen before
+    Address,
+    TransactionHash,
+    BlockNumber,
+    /// `0x` followed by hex digits, not yet a full address or hash
+    Partial,
+    Invalid,
+}
+
+impl InputFeedback {
+    /// Badge text, if the result is worth showing
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            InputFeedback::Eoa => Some("EOA"),
+            InputFeedback::Contract => Some("Contract"),
+            InputFeedback::Address => Some("Address"),
+            InputFeedback::TransactionHash => Some("Transaction"),
+            InputFeedback::BlockNumber => Some("Block"),
+            InputFeedback::Partial => None,
+            InputFeedback::Invalid => Some("Invalid"),
+        }
+    }
+}
+
+/// ENS resolution of the address lookup input, previewed while typing
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnsPreview {
+    /// A `.eth` name and the address it resolves to
+    Address { input: String, address: String },
+    /// An address and its primary ENS name
+    Name { input: String, name: String },
+}
+
+impl EnsPreview {
+    /// The input this preview was resolved for
+    pub fn input(&self) -> &str {
+        match self {
+            EnsPreview::Address { input, .. } | EnsPreview::Name { input, .. } => input,
+        }
+    }
+
+    /// Suggestion shown below the input
+    pub fn label(&self) -> String {
+        match self {
+            EnsPreview::Address { address, .. } => format!("→ Resolves to: {}", address),
+            EnsPreview::Name { name, .. } => format!("→ ENS: {}", name),
+        }
+    }
+}
+
+/// What clicking a rendered value does
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClickAction {
+    /// Open a screen for a value, e.g. the transaction viewer for a hash
+    Navigate(AppState, String),
+}
+
+/// Single-row screen area that runs an action when clicked; rebuilt every frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClickableRegion {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub action: ClickAction,
+}
+
+impl ClickableRegion {
+    /// Whether a click at `(column, row)` lands in this region
+    pub fn contains(&self, column: u16, row: u16) -> bool {
+        row == self.y && column >= self.x && column < self.x.saturating_add(self.width)
+    }
+}
+
 impl AppState {
     /// Get the current state as a string for display
     pub fn title(&self) -> &'static str {
@@ -49,12 +134,17 @@ impl AppState {
             AppState::AddressLookup => "Address Lookup",
             AppState::ContractSearch => "Contract Search",
             AppState::TokenInfo => "Token Information",
+            AppState::NftGallery => "NFT Gallery",
             AppState::GasTracker => "Gas Tracker",
             AppState::ContractInteraction => "Contract Interaction",
+            AppState::Simulation => "Transaction Simulation",
+            AppState::Create2Calculator => "CREATE2 Address Calculator",
             AppState::ContractVerification => "Contract Verification",
             AppState::WalletManager => "Wallet Manager",
             AppState::MultisigWallet => "Multi-Signature Wallet",
             AppState::EventMonitor => "Event Monitor",
+            AppState::MempoolMonitor => "Mempool Monitor",
+            AppState::LogViewer => "Log Viewer",
             AppState::Settings => "Settings",
             AppState::Help => "Help",
             AppState::Quit => "Quit",
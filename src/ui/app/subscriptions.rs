@@ -1,10 +1,16 @@
 //! Subscription management for real-time updates
 
 use super::core::App;
+use super::utils::format_address_display;
 use crate::blockchain::SubscriptionEvent;
 use crate::error::Result;
+use crate::ui::models::NotificationLevel;
+use ethers::types::H256;
 use hex;
 
+/// Deepest re-org whose blocks are invalidated; deeper ones are only logged
+const MAX_REORG_DEPTH: u64 = 6;
+
 impl App {
     /// Start subscriptions based on current state
     pub async fn start_subscriptions(&mut self) -> Result<()> {
@@ -27,8 +33,50 @@ impl App {
                 manager
                     .subscribe_to_blocks("homepage_blocks".to_string())
                     .await?;
+                manager
+                    .subscribe_to_finality("finality_heads".to_string())
+                    .await?;
                 tracing::info!(target: "warpscan", "Started block subscription for homepage");
             }
+            crate::ui::app::state::AppState::TransactionViewer
+                if self.transaction_data.is_some() =>
+            {
+                // Watch the finality heads for the confirmations label
+                manager
+                    .subscribe_to_finality("finality_heads".to_string())
+                    .await?;
+                tracing::info!(target: "warpscan", "Started finality subscription for transaction");
+            }
+            crate::ui::app::state::AppState::GasTracker => {
+                // Subscribe to new blocks to extend the gas history
+                manager
+                    .subscribe_to_blocks("gas_tracker_blocks".to_string())
+                    .await?;
+                tracing::info!(target: "warpscan", "Started block subscription for gas tracker");
+            }
+            crate::ui::app::state::AppState::MempoolMonitor => {
+                // Pending transactions are only pushed over WebSocket
+                self.mempool_ws_missing = !manager.has_websocket();
+                if !self.mempool_ws_missing {
+                    manager
+                        .subscribe_to_pending_transactions("mempool".to_string())
+                        .await?;
+                    tracing::info!(target: "warpscan", "Started pending transaction subscription");
+                }
+            }
+            crate::ui::app::state::AppState::EventMonitor if self.event_logs.is_some() => {
+                // Logs are only pushed over WebSocket; otherwise just the search results show
+                self.event_stream_live = manager.has_websocket();
+                if self.event_stream_live {
+                    manager
+                        .subscribe_to_logs(
+                            "event_logs".to_string(),
+                            self.event_filter.to_filter(false),
+                        )
+                        .await?;
+                    tracing::info!(target: "warpscan", "Started log subscription");
+                }
+            }
             crate::ui::app::state::AppState::AddressLookup => {
                 // Subscribe to address transactions if address data exists
                 if let Some(ref address_data) = self.address_data {
@@ -78,6 +126,19 @@ impl App {
         match self.state {
             crate::ui::app::state::AppState::Home => {
                 manager.unsubscribe("homepage_blocks");
+                manager.unsubscribe("finality_heads");
+            }
+            crate::ui::app::state::AppState::TransactionViewer => {
+                manager.unsubscribe("finality_heads");
+            }
+            crate::ui::app::state::AppState::GasTracker => {
+                manager.unsubscribe("gas_tracker_blocks");
+            }
+            crate::ui::app::state::AppState::MempoolMonitor => {
+                manager.unsubscribe("mempool");
+            }
+            crate::ui::app::state::AppState::EventMonitor => {
+                manager.unsubscribe("event_logs");
             }
             crate::ui::app::state::AppState::AddressLookup => {
                 if let Some(ref address_data) = self.address_data {
@@ -91,12 +152,17 @@ impl App {
 
     /// Handle real-time subscription event
     pub async fn handle_subscription_event(&mut self, event: SubscriptionEvent) {
-        tracing::info!(
-            target: "warpscan",
-            "🎯 handle_subscription_event called: {:?}, current_state={:?}",
+        if !matches!(
             event,
-            self.state
-        );
+            SubscriptionEvent::PendingTransaction { .. } | SubscriptionEvent::NewLog { .. }
+        ) {
+            tracing::info!(
+                target: "warpscan",
+                "🎯 handle_subscription_event called: {:?}, current_state={:?}",
+                event,
+                self.state
+            );
+        }
         match event {
             SubscriptionEvent::NewBlock {
                 block_number,
@@ -106,6 +172,12 @@ impl App {
                 // This keeps the Home dashboard in sync even when the user is viewing other screens.
                 self.handle_new_block(block_number, block_hash).await;
             }
+            SubscriptionEvent::SafeHead { block_number } => {
+                self.handle_finality_head(Some(block_number), None);
+            }
+            SubscriptionEvent::FinalizedHead { block_number } => {
+                self.handle_finality_head(None, Some(block_number));
+            }
             SubscriptionEvent::NewAddressTransaction {
                 address,
                 transaction,
@@ -122,16 +194,14 @@ impl App {
                 }
             }
             SubscriptionEvent::PendingTransaction { transaction } => {
-                // Handle pending transaction (could show notification)
-                tracing::debug!(
-                    target: "warpscan",
-                    "Pending transaction: {:?}",
-                    transaction.hash
-                );
+                if self.state == crate::ui::app::state::AppState::MempoolMonitor {
+                    self.push_pending_transaction(&transaction);
+                }
             }
             SubscriptionEvent::NewLog { log } => {
-                // Handle new log/event
-                tracing::debug!(target: "warpscan", "New log: {:?}", log);
+                if self.state == crate::ui::app::state::AppState::EventMonitor {
+                    self.push_event_log(log);
+                }
             }
             SubscriptionEvent::Error {
                 subscription_id,
@@ -160,13 +230,39 @@ impl App {
             "🔄 handle_new_block called: block_number={}",
             block_number
         );
-        // Fetch the new block and add to dashboard
+        // Fetch the new block with its transactions in one call and add to dashboard
         if let Ok(Some(block)) = self
             .blockchain_client
-            .get_block_by_number(block_number)
+            .get_block_with_transactions(block_number)
             .await
         {
-            use crate::ui::models::{BlockInfo, TransactionInfo, TransactionStatus};
+            use crate::ui::models::{BlockInfo, TransactionInfo};
+
+            if self.config.cache.re_org_detection {
+                let depth = self.reorg_depth(block_number, block.parent_hash).await;
+                if depth > MAX_REORG_DEPTH {
+                    tracing::warn!(
+                        target: "warpscan",
+                        "Chain re-org deeper than {} blocks below block {}, cache left as is",
+                        MAX_REORG_DEPTH,
+                        block_number
+                    );
+                } else if depth > 0 {
+                    tracing::warn!(target: "warpscan", "Chain re-org detected at depth {}", depth);
+                    self.cache_manager
+                        .invalidate_block_range(block_number - depth, block_number);
+                    self.dashboard_data.latest_blocks.clear();
+                    self.refresh_dashboard().await;
+                    self.push_notification(
+                        format!("Chain re-org detected at depth {} — refreshing data", depth),
+                        NotificationLevel::Warning,
+                    );
+                    return;
+                }
+            }
+            // Keep the header so the next block can be checked against it
+            self.cache_manager
+                .store_block(block_number, block.clone().into());
 
             if let Some(num) = block.number {
                 let block_num = num.as_u64();
@@ -190,6 +286,11 @@ impl App {
                     reward: 0.0,
                 };
 
+                self.record_block_tx_count(block_info.transaction_count, block_timestamp);
+                self.record_block_gas_metric(&block);
+                self.record_block_burn(&block);
+
+                let miner = block_info.miner.clone();
                 // Prepend new block to the list
                 self.dashboard_data.latest_blocks.insert(0, block_info);
                 // Keep only last 5 blocks
@@ -197,6 +298,13 @@ impl App {
                     self.dashboard_data.latest_blocks.truncate(5);
                 }
 
+                if self.state == crate::ui::app::state::AppState::Home {
+                    self.push_notification(
+                        format!("New block #{}", block_num),
+                        NotificationLevel::Info,
+                    );
+                }
+
                 // Update network stats
                 self.dashboard_data.network_stats.latest_block = block_num;
                 let now = std::time::SystemTime::now()
@@ -218,91 +326,40 @@ impl App {
                     .map(|t| t.hash.clone())
                     .collect();
 
-                for tx_hash in &block.transactions {
-                    let tx_hash_str = format!("{:#x}", tx_hash);
-
+                for tx in &block.transactions {
                     // Skip if we already know about this transaction
-                    if existing_hashes.contains(&tx_hash_str) {
+                    if existing_hashes.contains(&format!("{:#x}", tx.hash)) {
                         continue;
                     }
-
-                    // Fetch transaction details
-                    if let Ok(Some(tx)) = self
-                        .blockchain_client
-                        .get_transaction_by_hash(&tx_hash_str)
-                        .await
-                    {
-                        // Fetch transaction receipt for gas_used and status
-                        let receipt_result = self
-                            .blockchain_client
-                            .get_transaction_receipt(&tx_hash_str)
-                            .await;
-
-                        let (gas_used, status, gas_price_gwei) =
-                            if let Ok(Some(receipt)) = receipt_result {
-                                let gas_used_val =
-                                    receipt.gas_used.map(|g| g.as_u128() as u64).unwrap_or(0);
-                                let status_val = if receipt.status == Some(1.into()) {
-                                    TransactionStatus::Success
-                                } else if receipt.status == Some(0.into()) {
-                                    TransactionStatus::Failed
-                                } else {
-                                    TransactionStatus::Pending
-                                };
-                                let gas_price_val = receipt
-                                    .effective_gas_price
-                                    .map(|p| p.as_u64() / 1_000_000_000) // Convert to gwei
-                                    .unwrap_or_else(|| {
-                                        // Fallback to transaction gas_price if effective_gas_price not available
-                                        tx.gas_price
-                                            .map(|p| p.as_u64() / 1_000_000_000)
-                                            .unwrap_or(0)
-                                    });
-                                (gas_used_val, status_val, gas_price_val)
-                            } else {
-                                // Fallback if receipt not available (pending transaction)
-                                let gas_price_val = tx
-                                    .gas_price
-                                    .map(|p| p.as_u64() / 1_000_000_000)
-                                    .unwrap_or(0);
-                                (0, TransactionStatus::Pending, gas_price_val)
-                            };
-
-                        // Convert value from wei to ETH
-                        const WEI_TO_ETH: f64 = 1_000_000_000_000_000_000.0;
-                        let value_eth = tx.value.as_u128() as f64 / WEI_TO_ETH;
-
-                        // Calculate transaction fee (gas_used * gas_price in ETH)
-                        let tx_fee_eth =
-                            (gas_used as f64 * gas_price_gwei as f64) / 1_000_000_000.0;
-
-                        let tx_info = TransactionInfo {
-                            hash: tx_hash_str.clone(),
-                            from: format!("{:#x}", tx.from),
-                            to: tx.to.map(|a| format!("{:#x}", a)).unwrap_or_default(),
-                            value: value_eth,
-                            gas_price: gas_price_gwei,
-                            gas_used,
-                            status,
-                            timestamp: block_timestamp,
-                            block_number: block_num,
-                            transaction_fee: tx_fee_eth,
-                        };
-
-                        new_txs.push(tx_info);
-                    }
+                    new_txs.push(TransactionInfo::from_block_transaction(
+                        tx,
+                        block_num,
+                        block_timestamp,
+                    ));
                 }
 
+                // Warm the cache for the miner and the parties to the largest
+                // transfers, the addresses most likely to be opened next
+                let mut by_value: Vec<&TransactionInfo> = new_txs.iter().collect();
+                by_value.sort_by(|a, b| b.value.total_cmp(&a.value));
+                let mut prefetch = vec![miner];
+                prefetch.extend(
+                    by_value
+                        .iter()
+                        .flat_map(|tx| [tx.from.clone(), tx.to.clone()])
+                        .filter(|address| !address.is_empty())
+                        .take(3),
+                );
+                self.prefetch_address_data(&prefetch);
+
                 if !new_txs.is_empty() {
                     // Prepend new transactions and keep a small, recent window
                     // (we keep them sorted by block_number desc)
-                    self.dashboard_data
-                        .latest_transactions
-                        .extend(new_txs.into_iter());
+                    self.dashboard_data.latest_transactions.extend(new_txs);
 
                     self.dashboard_data
                         .latest_transactions
-                        .sort_by(|a, b| b.block_number.cmp(&a.block_number));
+                        .sort_by_key(|tx| std::cmp::Reverse(tx.block_number));
 
                     // Keep only the latest N transactions (e.g. 20 for a richer view)
                     const MAX_LATEST_TXS: usize = 20;
@@ -322,6 +379,76 @@ impl App {
         }
     }
 
+    /// Number of cached blocks below `block_number` that are no longer on the
+    /// chain ending in `parent_hash`
+    ///
+    /// Walks back while the cached block differs from the canonical one,
+    /// stopping at the first match or uncached block, or once the depth
+    /// exceeds `MAX_REORG_DEPTH`.
+    async fn reorg_depth(&self, block_number: u64, parent_hash: H256) -> u64 {
+        let mut depth = 0;
+        let mut canonical = parent_hash;
+        while let Some(cached) = block_number
+            .checked_sub(depth + 1)
+            .and_then(|number| self.cache_manager.get_block(number))
+        {
+            if cached.hash == Some(canonical) {
+                break;
+            }
+            depth += 1;
+            if depth > MAX_REORG_DEPTH {
+                break;
+            }
+            match self
+                .blockchain_client
+                .get_block_by_hash(&format!("{:#x}", canonical))
+                .await
+            {
+                Ok(Some(block)) => canonical = block.parent_hash,
+                _ => break,
+            }
+        }
+        depth
+    }
+
+    /// Handle a move of the safe or finalized head
+    ///
+    /// Toasts when the transaction on screen becomes finalized.
+    fn handle_finality_head(&mut self, safe: Option<u64>, finalized: Option<u64>) {
+        let latest = self.dashboard_data.network_stats.latest_block;
+        let previous = self.block_finality;
+        let mut finality = previous.unwrap_or(crate::blockchain::BlockFinalityInfo {
+            latest,
+            safe: 0,
+            finalized: 0,
+        });
+        finality.latest = finality.latest.max(latest);
+        if let Some(block_number) = safe {
+            finality.safe = block_number;
+        }
+        if let Some(block_number) = finalized {
+            finality.finalized = block_number;
+        }
+        self.block_finality = Some(finality);
+
+        if self.state != crate::ui::app::state::AppState::TransactionViewer {
+            return;
+        }
+        let Some((hash, block)) = self
+            .transaction_data
+            .as_ref()
+            .filter(|tx| tx.block_number > 0)
+            .map(|tx| (tx.hash.clone(), tx.block_number))
+        else {
+            return;
+        };
+        // Without earlier heads we can't tell whether it just became final
+        let was_finalized = previous.is_none_or(|f| block <= f.finalized);
+        if !was_finalized && block <= finality.finalized {
+            self.set_success(format!("Transaction {} is now finalized", hash));
+        }
+    }
+
     /// Handle new address transaction event
     ///
     /// This incrementally updates:
@@ -435,6 +562,28 @@ impl App {
             "Activity"
         };
 
+        // Self-transfers leave the balance as is, apart from the fee
+        let balance_change = match action {
+            _ if value_eth == 0.0 || from_lower == to_lower => None,
+            "Sent" => Some((
+                format!(
+                    "Sent {:.4} ETH to {}",
+                    value_eth,
+                    format_address_display(&address_tx.to, None, 0)
+                ),
+                NotificationLevel::Info,
+            )),
+            "Received" => Some((
+                format!(
+                    "Received {:.4} ETH from {}",
+                    value_eth,
+                    format_address_display(&address_tx.from, None, 0)
+                ),
+                NotificationLevel::Success,
+            )),
+            _ => None,
+        };
+
         let age = "0s ago".to_string();
 
         let history_entry = AccountHistoryEntry {
@@ -463,5 +612,10 @@ impl App {
             address_data.details.address,
             block_number
         );
+
+        if let Some((message, level)) = balance_change {
+            self.push_notification(message, level);
+        }
+        self.sync_address_scrollbar();
     }
 }
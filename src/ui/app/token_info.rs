@@ -0,0 +1,68 @@
+//! Token information screen handling
+
+use super::super::models::{AddressTab, TokenInfoTab};
+use super::core::App;
+use super::state::AppState;
+use crate::error::Error;
+
+impl App {
+    /// Open the token information screen for the ERC-20 selected in the Tokens tab
+    pub async fn open_token_info(&mut self) {
+        let token = self.address_data.as_ref().and_then(|data| {
+            if data.current_tab != AddressTab::Tokens {
+                return None;
+            }
+            data.tokens
+                .get(data.selected_token_index)
+                .filter(|t| !t.token_type.is_nft())
+                .cloned()
+        });
+
+        match token {
+            Some(token) => {
+                self.navigate_to(AppState::TokenInfo).await;
+                self.token_info_token = Some(token);
+                self.token_info_tab = TokenInfoTab::Overview;
+                self.token_holders = None;
+                self.token_holders_error = None;
+            }
+            None => self.set_error("Select a fungible token to view its details".to_string()),
+        }
+    }
+
+    /// Switch between the Overview and Token Holders tabs
+    ///
+    /// Holders are fetched the first time their tab is shown, since the
+    /// endpoint is expensive and only available on paid Etherscan plans.
+    pub async fn toggle_token_info_tab(&mut self) {
+        self.token_info_tab = self.token_info_tab.toggle();
+        if self.token_info_tab == TokenInfoTab::Holders
+            && self.token_holders.is_none()
+            && self.token_holders_error.is_none()
+        {
+            self.load_token_holders().await;
+        }
+    }
+
+    /// Fetch the top holders of the token shown on the screen
+    pub async fn load_token_holders(&mut self) {
+        let Some(contract) = self
+            .token_info_token
+            .as_ref()
+            .map(|t| t.contract_address.clone())
+        else {
+            return;
+        };
+
+        self.set_loading("token_holders", true);
+        match self.blockchain_client.get_token_holders(&contract).await {
+            Ok(holders) => self.token_holders = Some(holders),
+            Err(Error::PremiumRequired(_)) => {
+                self.token_holders_error =
+                    Some("This feature requires an Etherscan Pro API key".to_string());
+            }
+            Err(e) => self.token_holders_error = Some(format!("Failed to load holders: {}", e)),
+        }
+        self.set_loading("token_holders", false);
+    }
+}
@@ -0,0 +1,192 @@
+//! Confirmation tracking for transactions the user submitted
+//!
+//! Tracked transactions are polled in the background every
+//! `TRACKED_POLL_INTERVAL_SECS` until their block is finalized, and dropped
+//! once they are `MAX_TRACKED_CONFIRMATIONS` deep. The list survives restarts
+//! in `session.json` next to the config file.
+
+use super::core::App;
+use super::utils::format_address_display;
+use crate::blockchain::{BlockFinalityInfo, TxConfirmation};
+use crate::config::Config;
+use crate::error::{Error, Result};
+use crate::ui::models::{SessionData, TrackedTx};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Seconds between confirmation polls
+const TRACKED_POLL_INTERVAL_SECS: u64 = 30;
+
+/// Confirmations after which a transaction is no longer tracked
+const MAX_TRACKED_CONFIRMATIONS: u64 = 5000;
+
+/// Result of one background poll of the tracked transactions
+#[derive(Debug)]
+pub struct TrackedPoll {
+    finality: Option<BlockFinalityInfo>,
+    updates: Vec<(String, Result<TxConfirmation>)>,
+}
+
+impl App {
+    /// Start following the confirmations of a submitted transaction
+    pub fn track_transaction(&mut self, hash: &str) {
+        if self.tracked_transactions.iter().any(|tx| tx.hash == hash) {
+            return;
+        }
+        self.tracked_transactions
+            .push(TrackedTx::new(hash.to_string()));
+        // Poll on the next tick instead of waiting a full interval
+        self.tracked_polled_at = None;
+        self.save_session();
+    }
+
+    /// Show or hide the tracked transactions overlay
+    pub fn toggle_tracked_overlay(&mut self) {
+        self.show_tracked_overlay = !self.show_tracked_overlay;
+    }
+
+    /// Apply a finished poll and start the next one when due; called on tick
+    pub fn poll_pending_tx_status(&mut self) {
+        if let Some(receiver) = self.tracked_poll_pending.as_mut() {
+            match receiver.try_recv() {
+                Ok(poll) => {
+                    self.tracked_poll_pending = None;
+                    self.apply_tracked_poll(poll);
+                }
+                Err(oneshot::error::TryRecvError::Empty) => return,
+                Err(oneshot::error::TryRecvError::Closed) => self.tracked_poll_pending = None,
+            }
+        }
+
+        if self.tracked_transactions.is_empty()
+            || self.tracked_polled_at.is_some_and(|polled_at| {
+                polled_at.elapsed() < Duration::from_secs(TRACKED_POLL_INTERVAL_SECS)
+            })
+        {
+            return;
+        }
+        self.tracked_polled_at = Some(Instant::now());
+
+        let hashes: Vec<String> = self
+            .tracked_transactions
+            .iter()
+            .filter(|tx| !tx.finalized)
+            .map(|tx| tx.hash.clone())
+            .collect();
+        let service = self.blockchain_client.background_handle();
+        let (sender, receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            let finality = service.get_block_finality_labels().await.ok();
+            let mut updates = Vec::with_capacity(hashes.len());
+            for hash in hashes {
+                let confirmation = service.get_confirmation_count(&hash).await;
+                updates.push((hash, confirmation));
+            }
+            let _ = sender.send(TrackedPoll { finality, updates });
+        });
+        self.tracked_poll_pending = Some(receiver);
+    }
+
+    /// Record new statuses, notify on changes and drop deeply confirmed transactions
+    fn apply_tracked_poll(&mut self, poll: TrackedPoll) {
+        let mut notifications = Vec::new();
+        for (hash, confirmation) in poll.updates {
+            let confirmation = match confirmation {
+                Ok(confirmation) => confirmation,
+                Err(e) => {
+                    tracing::warn!(target: "warpscan", "Failed to poll transaction {}: {}", hash, e);
+                    continue;
+                }
+            };
+            let Some(tx) = self
+                .tracked_transactions
+                .iter_mut()
+                .find(|tx| tx.hash == hash)
+            else {
+                continue;
+            };
+            if confirmation.status != tx.last_status {
+                notifications.push(format!(
+                    "{}: {}",
+                    format_address_display(&hash, None, 0),
+                    TrackedTx::transition_label(
+                        tx.last_status,
+                        confirmation.status,
+                        confirmation.confirmations
+                    )
+                ));
+            }
+            tx.last_status = confirmation.status;
+            tx.block_number = confirmation.block_number;
+            tx.confirmations = confirmation.confirmations;
+        }
+
+        // Finalized transactions are no longer polled; their depth follows the head
+        if let Some(finality) = poll.finality {
+            for tx in &mut self.tracked_transactions {
+                let Some(block_number) = tx.block_number else {
+                    continue;
+                };
+                tx.finalized = block_number <= finality.finalized;
+                if tx.finalized {
+                    tx.confirmations = tx
+                        .confirmations
+                        .max(finality.latest.saturating_sub(block_number) + 1);
+                }
+            }
+        }
+        self.tracked_transactions
+            .retain(|tx| tx.confirmations < MAX_TRACKED_CONFIRMATIONS);
+
+        for message in notifications {
+            tracing::info!(target: "warpscan", "Tracked transaction {}", message);
+            self.set_success(message);
+        }
+        self.save_session();
+    }
+
+    /// Restore the tracked transactions and multisig wallets from the previous session
+    pub fn load_session(&mut self) {
+        match read_session() {
+            Ok(session) => {
+                self.tracked_transactions = session.tracked_transactions;
+                self.multisig_state.wallets = session.multisig_wallets;
+                self.multisig_state.proposals = session.multisig_proposals;
+            }
+            Err(e) => tracing::warn!(target: "warpscan", "Failed to load session: {}", e),
+        }
+    }
+
+    /// Persist the tracked transactions and multisig wallets for the next session
+    pub fn save_session(&self) {
+        let session = SessionData {
+            tracked_transactions: self.tracked_transactions.clone(),
+            multisig_wallets: self.multisig_state.wallets.clone(),
+            multisig_proposals: self.multisig_state.proposals.clone(),
+        };
+        if let Err(e) = write_session(&session) {
+            tracing::warn!(target: "warpscan", "Failed to save session: {}", e);
+        }
+    }
+}
+
+/// Read `session.json`, returning an empty session if it does not exist yet
+fn read_session() -> Result<SessionData> {
+    let path = Config::session_path()?;
+    if !path.exists() {
+        return Ok(SessionData::default());
+    }
+    let contents = std::fs::read_to_string(&path)?;
+    serde_json::from_str(&contents)
+        .map_err(|e| Error::parse(format!("Invalid session file {}: {}", path.display(), e)))
+}
+
+/// Write `session.json`, creating the config directory if needed
+fn write_session(session: &SessionData) -> Result<()> {
+    let path = Config::session_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(session)?)?;
+    Ok(())
+}
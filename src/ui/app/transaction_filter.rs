@@ -0,0 +1,105 @@
+//! Transaction filter bar handling for the address Transactions tab
+
+use super::super::models::{AddressTab, AddressTransaction, TX_FILTER_FIELD_COUNT};
+use super::core::App;
+use super::state::{AppState, InputMode};
+
+impl App {
+    /// Transactions of the loaded address that pass the active filters
+    pub fn apply_transaction_filters(&self) -> Vec<&AddressTransaction> {
+        self.address_data
+            .as_ref()
+            .map(|data| data.filtered_transactions())
+            .unwrap_or_default()
+    }
+
+    /// Whether the filter bar is open on the Transactions tab
+    pub fn transaction_filter_bar_open(&self) -> bool {
+        self.state == AppState::AddressLookup
+            && self.address_data.as_ref().is_some_and(|data| {
+                data.current_tab == AddressTab::Transactions && data.filter.bar_open
+            })
+    }
+
+    /// Whether a filter field is being edited through the input line
+    pub fn transaction_filter_editing(&self) -> bool {
+        self.address_data
+            .as_ref()
+            .is_some_and(|data| data.filter.editing)
+    }
+
+    /// Show or hide the filter bar (filters stay applied when hidden)
+    pub fn toggle_transaction_filter_bar(&mut self) {
+        if let Some(ref mut data) = self.address_data {
+            data.filter.bar_open = !data.filter.bar_open;
+            data.filter.focused_field = 0;
+        }
+    }
+
+    /// Move focus to the next filter bar field
+    pub fn transaction_filter_next_field(&mut self) {
+        if let Some(ref mut data) = self.address_data {
+            data.filter.focused_field = (data.filter.focused_field + 1) % TX_FILTER_FIELD_COUNT;
+        }
+    }
+
+    /// Edit the focused field, or cycle the direction when it is focused
+    pub fn activate_transaction_filter_field(&mut self) {
+        let Some(ref mut data) = self.address_data else {
+            return;
+        };
+
+        if data.filter.focused_field == TX_FILTER_FIELD_COUNT - 1 {
+            data.filter.direction = data.filter.direction.next();
+            data.selected_transaction_index = 0;
+            self.sync_address_scrollbar();
+            return;
+        }
+
+        let value = data.filter.field_text(data.filter.focused_field);
+        data.filter.editing = true;
+        self.set_input(value);
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Store the edited input into the focused filter field
+    pub fn commit_transaction_filter_field(&mut self, value: String) {
+        let Some(ref mut data) = self.address_data else {
+            return;
+        };
+
+        let value = value.trim();
+        let mut error = None;
+        match data.filter.focused_field {
+            0 if value.is_empty() => data.filter.min_value_eth = None,
+            0 => match value.parse::<f64>() {
+                Ok(min) if min >= 0.0 => data.filter.min_value_eth = Some(min),
+                _ => error = Some(format!("Invalid minimum value: {}", value)),
+            },
+            1 if value.is_empty() => data.filter.method_filter = None,
+            1 => data.filter.method_filter = Some(value.to_string()),
+            _ => {}
+        }
+
+        data.filter.editing = false;
+        data.selected_transaction_index = 0;
+        let address = data.details.address.clone();
+
+        self.input_mode = InputMode::Normal;
+        self.set_input(address);
+        self.sync_address_scrollbar();
+        if let Some(error) = error {
+            self.set_error(error);
+        }
+    }
+
+    /// Abandon editing a filter field without changing it
+    pub fn cancel_transaction_filter_edit(&mut self) {
+        if let Some(ref mut data) = self.address_data {
+            data.filter.editing = false;
+            let address = data.details.address.clone();
+            self.set_input(address);
+        }
+        self.input_mode = InputMode::Normal;
+    }
+}
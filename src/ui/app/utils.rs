@@ -0,0 +1,99 @@
+//! Display helpers shared by the screens
+
+use super::super::models::AddressDetails;
+
+/// Compact address text for table cells
+///
+/// With a name this is `"{name} (0x…{last6})"`, or just the name when that does
+/// not fit in `max_len`; without one it is `"0x{first6}…{last6}"`.
+pub fn format_address_display(address: &str, ens_name: Option<&str>, max_len: usize) -> String {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    let last6 = hex.get(hex.len().saturating_sub(6)..).unwrap_or(hex);
+
+    match ens_name {
+        Some(name) => {
+            let with_suffix = format!("{} (0x…{})", name, last6);
+            if with_suffix.chars().count() <= max_len {
+                with_suffix
+            } else {
+                name.to_string()
+            }
+        }
+        None if hex.len() > 12 => format!("0x{}…{}", &hex[..6], last6),
+        None => address.to_string(),
+    }
+}
+
+/// Name shown for the looked-up address: its name tag label, else its ENS name
+pub fn address_display_name(details: &AddressDetails) -> Option<&str> {
+    details
+        .tags
+        .first()
+        .map(|tag| tag.label.as_str())
+        .or(details.ens_name.as_deref())
+}
+
+/// ETH amount with precision adapted to its size
+///
+/// Two decimals from 1000 ETH, four from 1 ETH, six from 0.0001 ETH, and
+/// scientific notation below that so dust does not round to zero.
+pub fn format_eth_value(eth: f64) -> String {
+    let magnitude = eth.abs();
+    if magnitude == 0.0 {
+        "0 ETH".to_string()
+    } else if magnitude >= 1_000.0 {
+        format!("{:.2} ETH", eth)
+    } else if magnitude >= 1.0 {
+        format!("{:.4} ETH", eth)
+    } else if magnitude >= 0.0001 {
+        format!("{:.6} ETH", eth)
+    } else {
+        format!("{:.2e} ETH", eth)
+    }
+}
+
+/// Gas price in gwei with thousands separators, e.g. `"1,000 gwei"`
+pub fn format_gwei(gwei: u64) -> String {
+    let digits = gwei.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    format!("{} gwei", grouped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_eth_value_and_gwei() {
+        assert_eq!(format_eth_value(1234.5678), "1234.57 ETH");
+        assert_eq!(format_eth_value(1.5), "1.5000 ETH");
+        assert_eq!(format_eth_value(0.0005), "0.000500 ETH");
+        assert_eq!(format_eth_value(0.000001), "1.00e-6 ETH");
+        assert_eq!(format_eth_value(0.0), "0 ETH");
+
+        assert_eq!(format_gwei(25), "25 gwei");
+        assert_eq!(format_gwei(1_000), "1,000 gwei");
+        assert_eq!(format_gwei(1_234_567), "1,234,567 gwei");
+    }
+
+    #[test]
+    fn test_format_address_display() {
+        let address = "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045";
+
+        assert_eq!(format_address_display(address, None, 20), "0xd8dA6B…A96045");
+        assert_eq!(
+            format_address_display(address, Some("vitalik.eth"), 30),
+            "vitalik.eth (0x…A96045)"
+        );
+        assert_eq!(
+            format_address_display(address, Some("vitalik.eth"), 15),
+            "vitalik.eth"
+        );
+    }
+}
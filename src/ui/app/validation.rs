@@ -14,8 +14,41 @@ pub fn is_transaction_hash(input: &str) -> bool {
         && input[2..].chars().all(|c| c.is_ascii_hexdigit())
 }
 
+/// Check if input looks like a block hash
+///
+/// Block and transaction hashes share the same 32-byte format, so a match
+/// only says the input may be a block; the block explorer asks the node
+/// for a block first and falls back to a transaction.
+pub fn is_block_hash(input: &str) -> bool {
+    is_transaction_hash(input)
+}
+
+/// Check if input looks like an ENS name such as `vitalik.eth` or `nick.xyz`
+///
+/// Needs at least two dot-separated labels and an alphabetic top-level
+/// label, so decimal numbers like `1.5` are not mistaken for names.
+pub fn is_ens_name(input: &str) -> bool {
+    let labels: Vec<&str> = input.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
 /// Check if input looks like a block number
 pub fn is_block_number(input: &str) -> bool {
     input.chars().all(|c| c.is_ascii_digit())
 }
 
+/// Check if input could still become an address or transaction hash
+pub fn is_hex_prefix(input: &str) -> bool {
+    input.starts_with("0x")
+        && input.len() <= 66
+        && input[2..].chars().all(|c| c.is_ascii_hexdigit())
+}
@@ -0,0 +1,72 @@
+//! Contract verification screen handling
+
+use super::core::App;
+use crate::ui::models::ContractSourceTab;
+
+/// Lines moved by one PgUp/PgDn in the source and ABI views
+const SOURCE_PAGE_LINES: u16 = 20;
+
+impl App {
+    /// Look up the verified source of a contract on Etherscan, then Sourcify
+    pub async fn lookup_contract_source(&mut self, address: &str) {
+        self.set_loading_with_message("contract_source", "Fetching verified source...".to_string());
+        self.current_list_index = 0;
+        self.contract_source_scroll = 0;
+        match self
+            .blockchain_client
+            .get_contract_source_code(address)
+            .await
+        {
+            Ok(source) => {
+                self.contract_source_address = Some(address.to_string());
+                self.contract_source = source;
+            }
+            Err(e) => self.report_error("Failed to fetch contract source", &e),
+        }
+        self.set_loading("contract_source", false);
+    }
+
+    /// Move the source file selection by one, staying within the file list
+    pub fn contract_source_select_file(&mut self, forward: bool) {
+        let file_count = self
+            .contract_source
+            .as_ref()
+            .map_or(0, |source| source.source_files.len());
+        self.current_list_index = if forward {
+            (self.current_list_index + 1).min(file_count.saturating_sub(1))
+        } else {
+            self.current_list_index.saturating_sub(1)
+        };
+        self.contract_source_scroll = 0;
+    }
+
+    /// Switch between the source code and ABI tabs
+    pub fn toggle_contract_source_tab(&mut self) {
+        self.contract_source_tab = self.contract_source_tab.toggle();
+        self.contract_source_scroll = 0;
+    }
+
+    /// Scroll the source or ABI view by a page, stopping at its last line
+    pub fn contract_source_scroll_page(&mut self, forward: bool) {
+        let Some(ref source) = self.contract_source else {
+            return;
+        };
+        let line_count = match self.contract_source_tab {
+            ContractSourceTab::Source => source
+                .source_files
+                .values()
+                .nth(self.current_list_index)
+                .map_or(0, |content| content.lines().count()),
+            ContractSourceTab::Abi => source.pretty_abi().lines().count(),
+        };
+        let last_line = u16::try_from(line_count.saturating_sub(1)).unwrap_or(u16::MAX);
+        self.contract_source_scroll = if forward {
+            self.contract_source_scroll
+                .saturating_add(SOURCE_PAGE_LINES)
+                .min(last_line)
+        } else {
+            self.contract_source_scroll
+                .saturating_sub(SOURCE_PAGE_LINES)
+        };
+    }
+}
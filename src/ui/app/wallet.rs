@@ -0,0 +1,78 @@
+//! Wallet manager screen handling
+
+use super::core::App;
+use super::state::InputMode;
+use crate::ui::models::MNEMONIC_WORD_COUNTS;
+use crate::wallet::WalletManager;
+
+/// Tab index of the Generate tab
+pub const WALLET_GENERATE_TAB: usize = 1;
+
+impl App {
+    /// Move the Generate tab focus by one row
+    pub fn wallet_generate_select(&mut self, forward: bool) {
+        let rows = self.wallet_generate.field_count();
+        self.current_list_index = if forward {
+            (self.current_list_index + 1).min(rows - 1)
+        } else {
+            self.current_list_index.saturating_sub(1)
+        };
+    }
+
+    /// Act on the focused Generate tab row: toggle the word count, generate
+    /// a wallet or ask for the keystore password
+    pub fn wallet_generate_activate(&mut self) {
+        match self.current_list_index {
+            0 => {
+                let state = &mut self.wallet_generate;
+                state.word_count = MNEMONIC_WORD_COUNTS
+                    .into_iter()
+                    .find(|&count| count != state.word_count)
+                    .unwrap_or(state.word_count);
+            }
+            1 => self.generate_hd_wallet(),
+            _ => {
+                self.wallet_generate.password_prompt = true;
+                self.clear_input();
+                self.input_mode = InputMode::Editing;
+            }
+        }
+    }
+
+    /// Generate a new mnemonic and derive its first accounts
+    fn generate_hd_wallet(&mut self) {
+        match WalletManager::generate_hd_wallet(self.wallet_generate.word_count) {
+            Ok((mnemonic, addresses)) => {
+                let state = &mut self.wallet_generate;
+                state.mnemonic = Some(mnemonic.to_phrase());
+                state.addresses = addresses;
+                state.keystore_path = None;
+            }
+            Err(e) => self.report_error("Failed to generate wallet", &e),
+        }
+    }
+
+    /// Encrypt the first derived account with the entered password and
+    /// save its keystore
+    pub fn commit_keystore_password(&mut self, password: String) {
+        self.wallet_generate.password_prompt = false;
+        self.clear_input();
+        let Some(phrase) = self.wallet_generate.mnemonic.clone() else {
+            return;
+        };
+        if password.is_empty() {
+            self.set_error("Enter a password to encrypt the keystore".to_string());
+            return;
+        }
+
+        let saved = WalletManager::derive_hd_account(&phrase, 0)
+            .and_then(|wallet| WalletManager::save_encrypted_keystore(&wallet, &password));
+        match saved {
+            Ok(path) => {
+                self.set_success(format!("Keystore saved to {}", path.display()));
+                self.wallet_generate.keystore_path = Some(path);
+            }
+            Err(e) => self.report_error("Failed to save keystore", &e),
+        }
+    }
+}
@@ -1,33 +1,91 @@
 //! Error component for WarpScan
 //!
-//! This module contains the error message component.
+//! This module contains the error popup component.
 
+use crate::ui::models::AppError;
 use crate::ui::theme::Theme;
 use ratatui::{
     layout::{Alignment, Rect},
+    style::Modifier,
     text::{Line, Span, Text},
     widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
-/// Render an error message
-pub fn render_error(frame: &mut Frame, area: Rect, theme: &Theme, message: &str) {
+/// Widest the popup gets, in columns
+const MAX_POPUP_WIDTH: u16 = 80;
+
+/// Render an error popup centered over `area`
+pub fn render_error(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    error: &AppError,
+    show_debug: bool,
+) {
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("✗ {}", error.message),
+            theme.error().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    if let Some(detail) = &error.detail {
+        for line in detail.lines() {
+            lines.push(Line::from(Span::styled(line.to_string(), theme.muted())));
+        }
+        lines.push(Line::from(""));
+    }
+
+    if let Some(suggestion) = &error.action_suggestion {
+        lines.push(Line::from(vec![
+            Span::styled("Try: ", theme.label()),
+            Span::styled(suggestion.clone(), theme.info()),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    if show_debug {
+        if let Some(debug) = &error.debug {
+            for line in debug.lines() {
+                lines.push(Line::from(Span::styled(line.to_string(), theme.muted())));
+            }
+            lines.push(Line::from(""));
+        }
+    }
+
+    lines.push(Line::from(Span::styled(
+        "y: copy  d: debug  Esc: close",
+        theme.muted(),
+    )));
+
+    let width = area.width.min(MAX_POPUP_WIDTH);
+    let inner_width = width.saturating_sub(2).max(1) as usize;
+    // Estimate wrapped height so short errors get a small popup
+    let wrapped_lines: usize = lines
+        .iter()
+        .map(|line| line.width().max(1).div_ceil(inner_width))
+        .sum();
+    let height = ((wrapped_lines + 2) as u16).min(area.height);
+    let popup_area = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
     let block = Block::default()
         .title("Error")
         .borders(Borders::ALL)
         .border_style(theme.error());
 
-    let error_text = Text::from(vec![
-        Line::from(vec![Span::styled("✗ ", theme.error())]),
-        Line::from(message),
-    ]);
-
-    let paragraph = Paragraph::new(error_text)
+    let paragraph = Paragraph::new(Text::from(lines))
         .block(block)
-        .style(theme.error())
-        .alignment(Alignment::Center)
-        .wrap(Wrap { trim: true });
+        .style(theme.normal())
+        .alignment(Alignment::Left)
+        .wrap(Wrap { trim: false });
 
-    frame.render_widget(Clear, area);
-    frame.render_widget(paragraph, area);
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(paragraph, popup_area);
 }
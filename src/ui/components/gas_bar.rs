@@ -0,0 +1,66 @@
+//! Gas usage bar component for WarpScan
+//!
+//! This module contains the block gas utilization bar component.
+
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    widgets::{Gauge, Paragraph},
+    Frame,
+};
+
+/// Minimum width before the `gas_used / gas_limit` annotation is shown
+const MIN_WIDTH_FOR_FRACTION: u16 = 40;
+
+/// Render a gas utilization bar for a block
+///
+/// The bar is green below 50%, yellow from 50% to 80% and red above 80%.
+pub fn render_gas_bar(frame: &mut Frame, area: Rect, gas_used: u64, gas_limit: u64, theme: &Theme) {
+    let ratio = if gas_limit > 0 {
+        (gas_used as f64 / gas_limit as f64).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let color = if ratio < 0.5 {
+        theme.success
+    } else if ratio <= 0.8 {
+        theme.warning
+    } else {
+        theme.error
+    };
+
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(color).bg(theme.background))
+        .ratio(ratio)
+        .label(format!("{:.1}%", ratio * 100.0));
+
+    if area.width < MIN_WIDTH_FOR_FRACTION {
+        frame.render_widget(gauge, area);
+        return;
+    }
+
+    let fraction = format!(" {} / {}", format_gas(gas_used), format_gas(gas_limit));
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(fraction.chars().count() as u16),
+        ])
+        .split(area);
+
+    frame.render_widget(gauge, chunks[0]);
+    frame.render_widget(Paragraph::new(fraction).style(theme.muted()), chunks[1]);
+}
+
+/// Format a gas amount compactly (e.g. `15.2M`)
+fn format_gas(gas: u64) -> String {
+    if gas >= 1_000_000 {
+        format!("{:.1}M", gas as f64 / 1_000_000.0)
+    } else if gas >= 1_000 {
+        format!("{:.1}K", gas as f64 / 1_000.0)
+    } else {
+        gas.to_string()
+    }
+}
@@ -19,6 +19,7 @@ pub fn render_help_popup(frame: &mut Frame, area: Rect, theme: &Theme) {
         "  Tab/Shift+Tab - Switch tabs",
         "  Enter         - Select/Confirm",
         "  Esc           - Go back/Cancel",
+        "  Alt+←/Alt+→   - History back/forward",
         "  q             - Quit",
         "",
         "Shortcuts:",
@@ -26,6 +27,8 @@ pub fn render_help_popup(frame: &mut Frame, area: Rect, theme: &Theme) {
         "  Ctrl+R        - Refresh",
         "  Ctrl+L        - Clear cache",
         "  Ctrl+S        - Save",
+        "  Ctrl+T        - Tracked transactions",
+        "  L             - Log viewer",
         "  ?             - Show this help",
         "",
         "Press any key to close",
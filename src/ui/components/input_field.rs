@@ -10,13 +10,19 @@ use ratatui::{
 };
 
 /// Render input field
+///
+/// The cursor is given both as a byte index into `input`, where the cursor
+/// mark is inserted, and as a character count, which is its column. While the
+/// field is active the terminal cursor is placed there too.
+#[allow(clippy::too_many_arguments)]
 pub fn render_input_field(
     frame: &mut Frame,
     area: Rect,
     theme: &Theme,
     title: &str,
     input: &str,
-    cursor_position: usize,
+    cursor_byte_pos: usize,
+    cursor_char_pos: usize,
     is_active: bool,
 ) {
     let style = if is_active {
@@ -39,8 +45,8 @@ pub fn render_input_field(
     let input_text = if is_active {
         // Show cursor
         let mut text = input.to_string();
-        if cursor_position <= text.len() {
-            text.insert(cursor_position, '│');
+        if text.is_char_boundary(cursor_byte_pos) {
+            text.insert(cursor_byte_pos, '│');
         }
         text
     } else {
@@ -53,4 +59,8 @@ pub fn render_input_field(
         .wrap(Wrap { trim: false });
 
     frame.render_widget(paragraph, area);
+
+    if is_active {
+        frame.set_cursor_position((area.x + cursor_char_pos as u16 + 1, area.y + 1));
+    }
 }
@@ -10,17 +10,40 @@ use ratatui::{
     Frame,
 };
 
-/// Render a loading spinner
-pub fn render_loading(frame: &mut Frame, area: Rect, theme: &Theme, message: &str) {
+/// Braille spinner frames
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Spinner frames for terminals without braille glyphs
+const SPINNER_ASCII: [char; 4] = ['-', '\\', '|', '/'];
+
+/// Spinner frame to draw for a tick count
+pub fn spinner_char(spinner_frame: usize) -> char {
+    // The Linux virtual console font has no braille block
+    let frames: &[char] = if std::env::var("TERM").is_ok_and(|term| term == "linux") {
+        &SPINNER_ASCII
+    } else {
+        &SPINNER
+    };
+    frames[spinner_frame % frames.len()]
+}
+
+/// Render a loading spinner, advanced by `spinner_frame` on every tick
+pub fn render_loading(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    message: &str,
+    spinner_frame: usize,
+) {
     let block = Block::default()
         .title("Loading")
         .borders(Borders::ALL)
         .border_style(theme.border());
 
-    let loading_text = Text::from(vec![
-        Line::from(vec![Span::styled("⠋ ", theme.loading())]),
-        Line::from(message),
-    ]);
+    let loading_text = Text::from(vec![Line::from(vec![
+        Span::styled(format!("{} ", spinner_char(spinner_frame)), theme.loading()),
+        Span::raw(message),
+    ])]);
 
     let paragraph = Paragraph::new(loading_text)
         .block(block)
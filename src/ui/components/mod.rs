@@ -3,18 +3,26 @@
 //! This module contains reusable UI components for the terminal interface.
 
 pub mod error;
+pub mod gas_bar;
 pub mod help_popup;
 pub mod input_field;
 pub mod loading;
+pub mod notifications;
 pub mod progress;
+pub mod sparkline;
 pub mod status_bar;
 pub mod success;
+pub mod tracked_overlay;
 
 // Re-export all component functions for convenience
 pub use error::render_error;
+pub use gas_bar::render_gas_bar;
 pub use help_popup::render_help_popup;
 pub use input_field::render_input_field;
 pub use loading::render_loading;
+pub use notifications::render_notifications;
 pub use progress::render_progress;
+pub use sparkline::render_sparkline;
 pub use status_bar::render_status_bar;
 pub use success::render_success;
+pub use tracked_overlay::render_tracked_overlay;
@@ -0,0 +1,62 @@
+//! Notification stack component for WarpScan
+//!
+//! Draws queued notifications as small popups in the top-right corner.
+
+use crate::ui::models::{Notification, NotificationLevel};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::Modifier,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::time::{Duration, Instant};
+
+/// Width of a notification popup
+const NOTIFICATION_WIDTH: u16 = 44;
+
+/// Height of a notification popup: one line of text inside the border
+const NOTIFICATION_HEIGHT: u16 = 3;
+
+/// Time before expiry at which a notification starts fading out
+const FADE_OUT: Duration = Duration::from_secs(1);
+
+/// Render notifications stacked down from the top-right corner of `area`
+pub fn render_notifications<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    notifications: impl Iterator<Item = &'a Notification>,
+) {
+    let width = NOTIFICATION_WIDTH.min(area.width);
+    let now = Instant::now();
+
+    for (index, notification) in notifications.enumerate() {
+        let y = area.y + 1 + index as u16 * NOTIFICATION_HEIGHT;
+        if y + NOTIFICATION_HEIGHT > area.y + area.height {
+            break;
+        }
+        let popup = Rect {
+            x: area.x + area.width - width,
+            y,
+            width,
+            height: NOTIFICATION_HEIGHT,
+        };
+
+        let mut style = match notification.level {
+            NotificationLevel::Info => theme.info(),
+            NotificationLevel::Success => theme.success(),
+            NotificationLevel::Warning => theme.warning(),
+            NotificationLevel::Error => theme.error(),
+        };
+        if notification.expires_at.saturating_duration_since(now) < FADE_OUT {
+            style = style.add_modifier(Modifier::DIM);
+        }
+
+        let paragraph = Paragraph::new(notification.message.as_str())
+            .style(style)
+            .block(Block::default().borders(Borders::ALL).border_style(style));
+        frame.render_widget(Clear, popup);
+        frame.render_widget(paragraph, popup);
+    }
+}
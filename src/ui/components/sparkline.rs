@@ -0,0 +1,13 @@
+//! Sparkline component for WarpScan
+//!
+//! This module contains a compact sparkline for small data series.
+
+use crate::ui::theme::Theme;
+use ratatui::{layout::Rect, widgets::Sparkline, Frame};
+
+/// Render a borderless sparkline of the given data points
+pub fn render_sparkline(frame: &mut Frame, area: Rect, theme: &Theme, data: &[u64]) {
+    let sparkline = Sparkline::default().data(data).style(theme.primary());
+
+    frame.render_widget(sparkline, area);
+}
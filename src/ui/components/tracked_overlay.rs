@@ -0,0 +1,76 @@
+//! Tracked transactions overlay for WarpScan
+//!
+//! This module contains the small panel listing submitted transactions and
+//! their confirmation status.
+
+use crate::blockchain::TransactionStatus;
+use crate::ui::app::utils::format_address_display;
+use crate::ui::models::TrackedTx;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Width of the overlay, in columns
+const OVERLAY_WIDTH: u16 = 60;
+
+/// Render the tracked transactions in the top-right corner of `area`
+pub fn render_tracked_overlay(frame: &mut Frame, area: Rect, theme: &Theme, tracked: &[TrackedTx]) {
+    let lines: Vec<Line> = if tracked.is_empty() {
+        vec![Line::from(Span::styled(
+            "No transactions tracked",
+            theme.muted(),
+        ))]
+    } else {
+        tracked
+            .iter()
+            .map(|tx| {
+                let (status, style) = match tx.last_status {
+                    TransactionStatus::Success => ("Success", theme.success()),
+                    TransactionStatus::Failed => ("Failed", theme.error()),
+                    TransactionStatus::Pending => ("Pending", theme.warning()),
+                    TransactionStatus::Unknown => ("Unknown", theme.muted()),
+                };
+                let depth = if tx.finalized {
+                    format!("{} conf, finalized", tx.confirmations)
+                } else if tx.confirmations > 0 {
+                    format!("{} conf", tx.confirmations)
+                } else {
+                    format!("{}s", tx.submitted_at.elapsed().as_secs())
+                };
+                Line::from(vec![
+                    Span::styled(format_address_display(&tx.hash, None, 0), theme.normal()),
+                    Span::raw("  "),
+                    Span::styled(status, style),
+                    Span::raw("  "),
+                    Span::styled(depth, theme.muted()),
+                ])
+            })
+            .collect()
+    };
+
+    let width = area.width.min(OVERLAY_WIDTH);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let overlay_area = Rect {
+        x: area.x + area.width - width,
+        y: area.y,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .title("Tracked Transactions (Ctrl+T)")
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    frame.render_widget(Clear, overlay_area);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines))
+            .block(block)
+            .style(theme.normal()),
+        overlay_area,
+    );
+}
@@ -30,23 +30,15 @@ impl EventHandler {
                         .unwrap_or_else(|| Duration::from_secs(0));
 
                     if event::poll(timeout).unwrap_or(false) {
-                        match event::read() {
-                            Ok(CrosstermEvent::Key(key)) => {
-                                if sender.send(Event::Key(key)).is_err() {
-                                    break;
-                                }
-                            }
-                            Ok(CrosstermEvent::Mouse(mouse)) => {
-                                if sender.send(Event::Mouse(mouse)).is_err() {
-                                    break;
-                                }
-                            }
-                            Ok(CrosstermEvent::Resize(w, h)) => {
-                                if sender.send(Event::Resize(w, h)).is_err() {
-                                    break;
-                                }
-                            }
-                            _ => {}
+                        let sent = match event::read() {
+                            Ok(CrosstermEvent::Key(key)) => sender.send(Event::Key(key)),
+                            Ok(CrosstermEvent::Mouse(mouse)) => sender.send(Event::Mouse(mouse)),
+                            Ok(CrosstermEvent::Resize(w, h)) => sender.send(Event::Resize(w, h)),
+                            Ok(CrosstermEvent::Paste(text)) => sender.send(Event::Paste(text)),
+                            _ => Ok(()),
+                        };
+                        if sent.is_err() {
+                            break;
                         }
                     }
 
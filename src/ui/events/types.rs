@@ -11,6 +11,8 @@ pub enum Event {
     Mouse(MouseEvent),
     /// Resize event
     Resize(u16, u16),
+    /// Text pasted while bracketed paste mode is enabled
+    Paste(String),
     /// Tick event for periodic updates
     Tick,
     /// Custom application events
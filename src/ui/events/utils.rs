@@ -31,6 +31,31 @@ impl KeyEventUtils {
         key_event.code == KeyCode::Char('s') && key_event.modifiers.contains(KeyModifiers::CONTROL)
     }
 
+    /// Check if the key event is Ctrl+E
+    pub fn is_ctrl_e(key_event: &KeyEvent) -> bool {
+        key_event.code == KeyCode::Char('e') && key_event.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Check if the key event is Ctrl+T
+    pub fn is_ctrl_t(key_event: &KeyEvent) -> bool {
+        key_event.code == KeyCode::Char('t') && key_event.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Check if the key event is Ctrl+Delete
+    pub fn is_ctrl_delete(key_event: &KeyEvent) -> bool {
+        key_event.code == KeyCode::Delete && key_event.modifiers.contains(KeyModifiers::CONTROL)
+    }
+
+    /// Check if the key event is Alt+Left
+    pub fn is_alt_left(key_event: &KeyEvent) -> bool {
+        key_event.code == KeyCode::Left && key_event.modifiers.contains(KeyModifiers::ALT)
+    }
+
+    /// Check if the key event is Alt+Right
+    pub fn is_alt_right(key_event: &KeyEvent) -> bool {
+        key_event.code == KeyCode::Right && key_event.modifiers.contains(KeyModifiers::ALT)
+    }
+
     /// Check if the key event is Enter
     pub fn is_enter(key_event: &KeyEvent) -> bool {
         key_event.code == KeyCode::Enter
@@ -16,7 +16,7 @@ pub use theme::Theme;
 
 use crate::error::{Error, Result};
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -28,8 +28,13 @@ pub type Tui = Terminal<CrosstermBackend<io::Stdout>>;
 
 /// Initialize the terminal
 pub fn init() -> Result<Tui> {
-    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)
-        .map_err(|e| Error::ui(format!("Failed to enter alternate screen: {}", e)))?;
+    execute!(
+        io::stdout(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )
+    .map_err(|e| Error::ui(format!("Failed to enter alternate screen: {}", e)))?;
 
     enable_raw_mode().map_err(|e| Error::ui(format!("Failed to enable raw mode: {}", e)))?;
 
@@ -41,8 +46,28 @@ pub fn init() -> Result<Tui> {
 pub fn restore() -> Result<()> {
     disable_raw_mode().map_err(|e| Error::ui(format!("Failed to disable raw mode: {}", e)))?;
 
-    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)
-        .map_err(|e| Error::ui(format!("Failed to leave alternate screen: {}", e)))?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )
+    .map_err(|e| Error::ui(format!("Failed to leave alternate screen: {}", e)))?;
 
     Ok(())
 }
+
+/// Copy text to the system clipboard with the OSC 52 terminal escape sequence
+///
+/// Supported by most modern terminals (and tmux with `set-clipboard on`), and
+/// also works over SSH since the terminal, not the host, owns the clipboard.
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    use base64::Engine;
+    use std::io::Write;
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", encoded)
+        .and_then(|_| stdout.flush())
+        .map_err(|e| Error::ui(format!("Failed to copy to clipboard: {}", e)))
+}
@@ -33,6 +33,7 @@ pub struct AddressDetails {
     pub token_count: u32,
     pub estimated_net_worth: f64,
     pub total_transactions: u64,
+    pub pending_balance: f64,
     pub outgoing_transfers: u64,
     pub total_gas_used: u64,
     pub contract_name: Option<String>,
@@ -40,6 +41,10 @@ pub struct AddressDetails {
     pub creation_tx_hash: Option<String>,
     pub last_activity: u64,
     pub ens_name: Option<String>, // ENS name for EOA addresses
+    pub nonce_analysis: Option<crate::blockchain::NonceAnalysis>, // EOA addresses only
+    pub self_destruct: Option<crate::blockchain::SelfDestructInfo>, // Set once a contract is gone
+    pub tags: Vec<crate::blockchain::AddressTag>, // Etherscan name tags
+    pub validator: Option<crate::blockchain::ValidatorInfo>, // Set for beacon chain depositors
 }
 
 /// Address type enumeration
@@ -62,6 +67,7 @@ impl Default for AddressDetails {
             token_count: 25,
             estimated_net_worth: 18_450.50,
             total_transactions: 1_234,
+            pending_balance: 15.75,
             outgoing_transfers: 567,
             total_gas_used: 2_500_000,
             contract_name: None,
@@ -69,6 +75,10 @@ impl Default for AddressDetails {
             creation_tx_hash: None,
             last_activity: 1640995200,
             ens_name: None,
+            nonce_analysis: None,
+            self_destruct: None,
+            tags: Vec::new(),
+            validator: None,
         }
     }
 }
@@ -132,7 +142,7 @@ impl Default for AccountHistoryEntry {
 }
 
 /// Complete address data containing all tabs information
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct CompleteAddressData {
     pub details: AddressDetails,
     pub transactions: Vec<AddressTransaction>,
@@ -140,16 +150,170 @@ pub struct CompleteAddressData {
     pub token_transfers: Vec<super::TokenTransfer>,
     pub tokens: Vec<super::TokenInfo>,
     pub internal_transactions: Vec<super::InternalTransaction>,
+    pub withdrawals: Vec<crate::blockchain::BeaconWithdrawal>,
+    /// ERC-20 allowances the address still grants
+    pub approvals: Vec<crate::blockchain::TokenApproval>,
     pub current_tab: AddressTab,
     pub selected_transaction_index: usize,
     pub selected_history_index: usize,
     pub selected_token_transfer_index: usize,
     pub selected_token_index: usize,
     pub selected_internal_txn_index: usize,
+    pub selected_withdrawal_index: usize,
+    pub selected_approval_index: usize,
+    pub filter: AddressFilterState,
+    /// Page of the Transactions tab, starting at 1
+    pub current_page: u64,
+    /// Known page count; grows while full pages keep coming back
+    pub total_pages: u64,
+}
+
+impl CompleteAddressData {
+    /// Tabs shown for this address; Withdrawals only for staking addresses
+    pub fn visible_tabs(&self) -> Vec<AddressTab> {
+        AddressTab::ALL
+            .into_iter()
+            .filter(|tab| *tab != AddressTab::Withdrawals || !self.withdrawals.is_empty())
+            .collect()
+    }
+
+    /// Held token with this contract address, for its symbol and decimals
+    pub fn held_token(&self, contract_address: &str) -> Option<&super::TokenInfo> {
+        self.tokens.iter().find(|token| {
+            token
+                .contract_address
+                .eq_ignore_ascii_case(contract_address)
+        })
+    }
+
+    /// The visible tab after (or before) the current one, wrapping around
+    pub fn adjacent_tab(&self, forward: bool) -> AddressTab {
+        let tabs = self.visible_tabs();
+        let index = tabs
+            .iter()
+            .position(|tab| *tab == self.current_tab)
+            .unwrap_or(0);
+        let next = if forward {
+            (index + 1) % tabs.len()
+        } else {
+            (index + tabs.len() - 1) % tabs.len()
+        };
+        tabs[next].clone()
+    }
+
+    /// Transactions that pass the active filters, in display order
+    pub fn filtered_transactions(&self) -> Vec<&AddressTransaction> {
+        self.transactions
+            .iter()
+            .filter(|tx| self.filter.matches(tx, &self.details.address))
+            .collect()
+    }
+}
+
+/// Number of editable fields in the transaction filter bar
+pub const TX_FILTER_FIELD_COUNT: usize = 3;
+
+/// Transaction direction relative to the looked-up address
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub enum TxDirection {
+    Incoming,
+    Outgoing,
+    #[default]
+    Both,
+}
+
+impl TxDirection {
+    /// Display label for the filter bar
+    pub fn label(&self) -> &'static str {
+        match self {
+            TxDirection::Incoming => "Incoming",
+            TxDirection::Outgoing => "Outgoing",
+            TxDirection::Both => "Both",
+        }
+    }
+
+    /// Parse a label case-insensitively (used by deep links)
+    pub fn from_label(label: &str) -> Option<Self> {
+        [
+            TxDirection::Incoming,
+            TxDirection::Outgoing,
+            TxDirection::Both,
+        ]
+        .into_iter()
+        .find(|d| d.label().eq_ignore_ascii_case(label))
+    }
+
+    /// Cycle to the next direction
+    pub fn next(self) -> Self {
+        match self {
+            TxDirection::Both => TxDirection::Incoming,
+            TxDirection::Incoming => TxDirection::Outgoing,
+            TxDirection::Outgoing => TxDirection::Both,
+        }
+    }
+}
+
+/// Filters for the Transactions tab (whale-watching and method search)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AddressFilterState {
+    pub min_value_eth: Option<f64>,
+    pub method_filter: Option<String>,
+    pub direction: TxDirection,
+    /// Whether the inline filter bar is shown
+    pub bar_open: bool,
+    /// Focused filter bar field (0 = value, 1 = method, 2 = direction)
+    pub focused_field: usize,
+    /// Whether the focused field is being edited through the input line
+    pub editing: bool,
+}
+
+impl AddressFilterState {
+    /// Whether any filter is narrowing the transaction list
+    pub fn is_active(&self) -> bool {
+        self.min_value_eth.is_some()
+            || self.method_filter.is_some()
+            || self.direction != TxDirection::Both
+    }
+
+    /// Whether a transaction of `address` passes all active filters
+    pub fn matches(&self, tx: &AddressTransaction, address: &str) -> bool {
+        if let Some(min) = self.min_value_eth {
+            if tx.value < min {
+                return false;
+            }
+        }
+
+        if let Some(ref method) = self.method_filter {
+            let method = method.to_lowercase();
+            if !tx.method.to_lowercase().contains(&method)
+                && !tx.tx_type.to_lowercase().contains(&method)
+            {
+                return false;
+            }
+        }
+
+        match self.direction {
+            TxDirection::Incoming => tx.to.eq_ignore_ascii_case(address),
+            TxDirection::Outgoing => tx.from.eq_ignore_ascii_case(address),
+            TxDirection::Both => true,
+        }
+    }
+
+    /// Text of an editable field, as shown in the input line
+    pub fn field_text(&self, index: usize) -> String {
+        match index {
+            0 => self
+                .min_value_eth
+                .map(|v| v.to_string())
+                .unwrap_or_default(),
+            1 => self.method_filter.clone().unwrap_or_default(),
+            _ => self.direction.label().to_string(),
+        }
+    }
 }
 
 /// Address detail tabs
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub enum AddressTab {
     #[default]
     Details,
@@ -158,4 +322,55 @@ pub enum AddressTab {
     TokenTransfers,
     Tokens,
     InternalTxns,
+    /// ERC-20 allowances granted by the address
+    Approvals,
+    /// Beacon chain withdrawals, only shown for addresses that received any
+    Withdrawals,
+}
+
+impl AddressTab {
+    /// Every tab, in display order
+    pub const ALL: [AddressTab; 8] = [
+        AddressTab::Details,
+        AddressTab::Transactions,
+        AddressTab::AccountHistory,
+        AddressTab::TokenTransfers,
+        AddressTab::Tokens,
+        AddressTab::InternalTxns,
+        AddressTab::Approvals,
+        AddressTab::Withdrawals,
+    ];
+
+    /// Title shown in the tab bar
+    pub fn title(&self) -> &'static str {
+        match self {
+            AddressTab::Details => "Details",
+            AddressTab::Transactions => "Transactions",
+            AddressTab::AccountHistory => "Account History",
+            AddressTab::TokenTransfers => "Token Transfers",
+            AddressTab::Tokens => "Tokens",
+            AddressTab::InternalTxns => "Internal Txns",
+            AddressTab::Approvals => "Approvals",
+            AddressTab::Withdrawals => "Withdrawals",
+        }
+    }
+
+    /// Short name used in deep link query strings
+    pub fn slug(&self) -> &'static str {
+        match self {
+            AddressTab::Details => "details",
+            AddressTab::Transactions => "transactions",
+            AddressTab::AccountHistory => "history",
+            AddressTab::TokenTransfers => "transfers",
+            AddressTab::Tokens => "tokens",
+            AddressTab::InternalTxns => "internal",
+            AddressTab::Approvals => "approvals",
+            AddressTab::Withdrawals => "withdrawals",
+        }
+    }
+
+    /// Parse a deep link tab name
+    pub fn from_slug(slug: &str) -> Option<Self> {
+        AddressTab::ALL.into_iter().find(|tab| tab.slug() == slug)
+    }
 }
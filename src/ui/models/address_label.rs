@@ -0,0 +1,38 @@
+//! User labels for known addresses, kept in the address book
+
+use serde::{Deserialize, Serialize};
+
+/// Name and tags the user gave an address
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AddressLabel {
+    pub name: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Colour name or `#rrggbb` used instead of the theme's info colour
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+impl AddressLabel {
+    /// Parse the label prompt text `"name, tag, tag"`, or `None` when no name is given
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut parts = input.split(',').map(str::trim);
+        let name = parts.next().filter(|name| !name.is_empty())?;
+        Some(Self {
+            name: name.to_string(),
+            tags: parts
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+            color: None,
+        })
+    }
+
+    /// Text for the label prompt, the inverse of `parse`
+    pub fn prompt_text(&self) -> String {
+        std::iter::once(self.name.as_str())
+            .chain(self.tags.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
@@ -0,0 +1,166 @@
+//! Error shown to the user, with context for the error popup
+
+use serde::Serialize;
+
+/// Broad category of an error, used to suggest a fix
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum ErrorType {
+    Network,
+    Blockchain,
+    Config,
+    Validation,
+    PremiumRequired,
+    RateLimited,
+    NotFound,
+    Io,
+    Parse,
+    Other,
+}
+
+impl ErrorType {
+    /// Category of an [`crate::error::Error`]
+    pub fn from_error(error: &crate::error::Error) -> Self {
+        match error.kind() {
+            "network" | "timeout" => ErrorType::Network,
+            "blockchain" | "contract" => ErrorType::Blockchain,
+            "config" => ErrorType::Config,
+            "validation" => ErrorType::Validation,
+            "premium_required" => ErrorType::PremiumRequired,
+            "rate_limit" => ErrorType::RateLimited,
+            "not_found" => ErrorType::NotFound,
+            "io" => ErrorType::Io,
+            "parse" | "serialization" => ErrorType::Parse,
+            _ => ErrorType::Other,
+        }
+    }
+
+    /// Guess the category of a plain message from the error text it embeds
+    ///
+    /// Missing resources are only recognised from the error variant in
+    /// [`ErrorType::from_error`]; "not found" also appears in unrelated node
+    /// errors such as "method not found".
+    pub fn from_message(message: &str) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("network error") || lower.contains("timed out") {
+            ErrorType::Network
+        } else if lower.contains("premium api required") {
+            ErrorType::PremiumRequired
+        } else if lower.contains("rate limited") || lower.contains("rate limit reached") {
+            ErrorType::RateLimited
+        } else if lower.contains("blockchain error") || lower.contains("contract error") {
+            ErrorType::Blockchain
+        } else if lower.contains("configuration error") {
+            ErrorType::Config
+        } else if lower.contains("invalid") || lower.contains("validation error") {
+            ErrorType::Validation
+        } else if lower.contains("io error") {
+            ErrorType::Io
+        } else if lower.contains("parse error") {
+            ErrorType::Parse
+        } else {
+            ErrorType::Other
+        }
+    }
+
+    /// What the user can try next
+    pub fn action_suggestion(&self) -> Option<&'static str> {
+        match self {
+            ErrorType::Network => {
+                Some("Check your RPC URL and Etherscan API key in config.toml, then retry")
+            }
+            ErrorType::Blockchain => {
+                Some("The node may not have this data; try again or switch data mode")
+            }
+            ErrorType::Config => Some("Fix the setting in config.toml and restart WarpScan"),
+            ErrorType::Validation => Some("Check the input format and try again"),
+            ErrorType::PremiumRequired => {
+                Some("This feature needs a paid Etherscan plan; set a Pro API key in config.toml")
+            }
+            ErrorType::RateLimited => {
+                Some("Wait a moment and retry, or lower etherscan_rate_limit in config.toml")
+            }
+            ErrorType::NotFound => {
+                Some("Check the hash or address, and that the right network is selected")
+            }
+            ErrorType::Io => Some("Check that the path exists and is writable"),
+            ErrorType::Parse | ErrorType::Other => None,
+        }
+    }
+}
+
+/// Error shown to the user
+#[derive(Debug, Clone, Serialize)]
+pub struct AppError {
+    /// One-line summary shown inline and as the popup heading
+    pub message: String,
+    /// The error and each of its causes, one per line
+    pub detail: Option<String>,
+    /// `{:?}` of the error, toggled in the popup
+    pub debug: Option<String>,
+    pub error_type: ErrorType,
+    /// Unix time the error occurred
+    pub timestamp: u64,
+    pub action_suggestion: Option<String>,
+}
+
+impl AppError {
+    /// Error from a plain message, categorized by its text
+    pub fn from_message(message: String) -> Self {
+        let error_type = ErrorType::from_message(&message);
+        Self::new(message, None, None, error_type)
+    }
+
+    /// Error from a failed operation, keeping its `source()` chain
+    pub fn from_error(context: &str, error: &crate::error::Error) -> Self {
+        let mut chain = vec![error.to_string()];
+        let mut source = std::error::Error::source(error);
+        while let Some(cause) = source {
+            chain.push(format!("Caused by: {}", cause));
+            source = cause.source();
+        }
+
+        // Say when the API will take requests again rather than just failing;
+        // the client has already waited out part of the delay
+        let message = match error.retry_after_secs() {
+            Some(0) => "API rate limit reached; the request can be retried now".to_string(),
+            Some(secs) => format!("API rate limit reached; try again in {} seconds", secs),
+            None => format!("{}: {}", context, error),
+        };
+
+        Self::new(
+            message,
+            Some(chain.join("\n")),
+            Some(format!("{:#?}", error)),
+            ErrorType::from_error(error),
+        )
+    }
+
+    fn new(
+        message: String,
+        detail: Option<String>,
+        debug: Option<String>,
+        error_type: ErrorType,
+    ) -> Self {
+        Self {
+            message,
+            detail,
+            debug,
+            error_type,
+            timestamp: chrono::Utc::now().timestamp() as u64,
+            action_suggestion: error_type.action_suggestion().map(str::to_string),
+        }
+    }
+
+    /// Everything known about the error, for copying
+    pub fn full_text(&self) -> String {
+        let mut text = self.message.clone();
+        for extra in [&self.detail, &self.action_suggestion, &self.debug]
+            .into_iter()
+            .flatten()
+        {
+            text.push_str("\n\n");
+            text.push_str(extra);
+        }
+        text
+    }
+}
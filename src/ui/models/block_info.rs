@@ -14,6 +14,29 @@ pub struct BlockInfo {
     pub reward: f64,
 }
 
+impl BlockInfo {
+    /// Row for a block header; size and reward are not known from it
+    pub fn from_block<T>(block: &ethers::types::Block<T>) -> Self {
+        Self {
+            number: block.number.map(|n| n.as_u64()).unwrap_or(0),
+            hash: block
+                .hash
+                .map(|h| format!("{:#x}", h))
+                .unwrap_or_else(|| "0x0".to_string()),
+            timestamp: block.timestamp.as_u64(),
+            miner: block
+                .author
+                .map(|a| format!("{:#x}", a))
+                .unwrap_or_else(|| "0x0".to_string()),
+            transaction_count: block.transactions.len() as u32,
+            gas_used: block.gas_used.as_u64(),
+            gas_limit: block.gas_limit.as_u64(),
+            size: 0,
+            reward: 0.0,
+        }
+    }
+}
+
 impl Default for BlockInfo {
     fn default() -> Self {
         Self {
@@ -29,3 +52,59 @@ impl Default for BlockInfo {
         }
     }
 }
+
+/// Full header of a block and its transactions
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BlockDetails {
+    pub number: u64,
+    pub hash: String,
+    pub parent_hash: String,
+    pub state_root: String,
+    pub transactions_root: String,
+    pub receipts_root: String,
+    pub miner: String,
+    /// Decimal; zero after the Merge
+    pub difficulty: String,
+    /// Decimal; None when the node no longer reports it
+    pub total_difficulty: Option<String>,
+    pub size: u64,
+    pub gas_limit: u64,
+    pub gas_used: u64,
+    pub timestamp: u64,
+    /// Hex-encoded `extraData`
+    pub extra_data: String,
+    /// In wei; None for blocks before London (EIP-1559)
+    pub base_fee_per_gas: Option<u64>,
+    pub uncle_count: usize,
+    pub transactions: Vec<super::TransactionInfo>,
+}
+
+impl BlockDetails {
+    /// Base fee in gwei, if the block has one
+    pub fn base_fee_gwei(&self) -> Option<f64> {
+        self.base_fee_per_gas.map(|fee| fee as f64 / 1e9)
+    }
+
+    /// Base fee times gas used, in ETH
+    pub fn burned_eth(&self) -> f64 {
+        self.base_fee_per_gas
+            .map(|fee| fee as f64 * self.gas_used as f64 / 1e18)
+            .unwrap_or_default()
+    }
+}
+
+/// A looked-up block with the block explorer's transaction cursor
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CompleteBlockData {
+    pub details: BlockDetails,
+    pub selected_transaction_index: usize,
+}
+
+impl CompleteBlockData {
+    /// The transaction under the cursor, if the block has any
+    pub fn selected_transaction(&self) -> Option<&super::TransactionInfo> {
+        self.details
+            .transactions
+            .get(self.selected_transaction_index)
+    }
+}
@@ -0,0 +1,114 @@
+use ethers::abi::{Function, StateMutability};
+use serde::Serialize;
+use std::cmp::Ordering;
+
+/// A function of a loaded contract ABI
+#[derive(Debug, Clone, Serialize)]
+pub struct AbiFunction {
+    pub name: String,
+    /// Parameter names and Solidity types, e.g. `("amountIn", "uint256")`
+    pub inputs: Vec<(String, String)>,
+    /// Solidity types of the return values
+    pub outputs: Vec<String>,
+    /// Whether the function is `view` or `pure` and can be called without a transaction
+    pub is_view: bool,
+}
+
+impl From<&Function> for AbiFunction {
+    fn from(function: &Function) -> Self {
+        #[allow(deprecated)]
+        let is_view = matches!(
+            function.state_mutability,
+            StateMutability::View | StateMutability::Pure
+        ) || function.constant == Some(true);
+        Self {
+            name: function.name.clone(),
+            inputs: function
+                .inputs
+                .iter()
+                .map(|param| (param.name.clone(), param.kind.to_string()))
+                .collect(),
+            outputs: function
+                .outputs
+                .iter()
+                .map(|param| param.kind.to_string())
+                .collect(),
+            is_view,
+        }
+    }
+}
+
+impl AbiFunction {
+    /// List order: view functions first, then write functions, each by name
+    pub fn display_order(a: &Self, b: &Self) -> Ordering {
+        b.is_view.cmp(&a.is_view).then_with(|| a.name.cmp(&b.name))
+    }
+
+    /// 4-byte selector, from the canonical signature without spaces
+    pub fn selector(&self) -> [u8; 4] {
+        let types: Vec<&str> = self.inputs.iter().map(|(_, kind)| kind.as_str()).collect();
+        let hash = ethers::utils::id(format!("{}({})", self.name, types.join(",")));
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    /// Signature such as `transfer(address, uint256)`
+    pub fn signature(&self) -> String {
+        let types: Vec<&str> = self.inputs.iter().map(|(_, kind)| kind.as_str()).collect();
+        format!("{}({})", self.name, types.join(", "))
+    }
+}
+
+/// Result of submitting a function form
+#[derive(Debug, Clone, Serialize)]
+pub enum CallOutcome {
+    /// Decoded return values of a read call, as `(type, value)`
+    Read(Vec<(String, String)>),
+    /// Calldata of a write call and its gas estimate, or why estimation failed
+    Write {
+        calldata: String,
+        gas: std::result::Result<u64, String>,
+    },
+}
+
+/// State of the contract interaction screen
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ContractInteractionState {
+    /// Contract whose ABI is loaded
+    pub contract_address: Option<String>,
+    /// Functions of the loaded ABI, view functions first
+    pub functions: Vec<AbiFunction>,
+    /// Index of the highlighted function
+    pub selected_function: usize,
+    /// Whether the argument form of the selected function is shown
+    pub form_open: bool,
+    /// Argument values of the open form, one per function input
+    pub args: Vec<String>,
+    /// Outcome of the last form submission
+    pub outcome: Option<CallOutcome>,
+    /// Whether the input line takes the path of an ABI JSON file
+    pub abi_path_prompt: bool,
+}
+
+impl ContractInteractionState {
+    /// The highlighted function
+    pub fn function(&self) -> Option<&AbiFunction> {
+        self.functions.get(self.selected_function)
+    }
+
+    /// Rows of the open form: one per argument, then the Call button
+    pub fn form_rows(&self) -> usize {
+        self.args.len() + 1
+    }
+
+    /// Value of argument `index`
+    pub fn field(&self, index: usize) -> &str {
+        self.args.get(index).map(String::as_str).unwrap_or_default()
+    }
+
+    /// Replace the value of argument `index`
+    pub fn set_field(&mut self, index: usize, value: String) {
+        if let Some(arg) = self.args.get_mut(index) {
+            *arg = value;
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/// Tabs of the contract verification screen
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ContractSourceTab {
+    #[default]
+    Source,
+    Abi,
+}
+
+impl ContractSourceTab {
+    /// Tab title shown in the tab bar
+    pub fn title(&self) -> &'static str {
+        match self {
+            ContractSourceTab::Source => "Source Code",
+            ContractSourceTab::Abi => "ABI",
+        }
+    }
+
+    /// The other tab (the screen only has two)
+    pub fn toggle(&self) -> Self {
+        match self {
+            ContractSourceTab::Source => ContractSourceTab::Abi,
+            ContractSourceTab::Abi => ContractSourceTab::Source,
+        }
+    }
+}
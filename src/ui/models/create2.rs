@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+/// Number of focusable rows on the CREATE2 screen (3 inputs + Check button)
+pub const CREATE2_FIELD_COUNT: usize = 4;
+
+/// Well-known CREATE2 deployers offered in the quick-select list
+pub const KNOWN_CREATE2_DEPLOYERS: [(&str, &str); 3] = [
+    (
+        "Deterministic Deployment Proxy",
+        "0x4e59b44847b379578588920cA78FbF26c0B4956C",
+    ),
+    (
+        "Safe Proxy Factory 1.3.0",
+        "0xa6B71E26C5e0845f74c812102Ca7114b6a896AB2",
+    ),
+    ("CreateX", "0xba5Ed099633D3B313e4D5F7bdc1305d3c28ba5Ed"),
+];
+
+/// Form state for the CREATE2 address calculator screen
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Create2Form {
+    pub deployer: String,
+    /// Hex-encoded salt (left-padded to 32 bytes)
+    pub salt: String,
+    /// Hex-encoded keccak256 of the init code
+    pub init_code_hash: String,
+    /// Predicted deployment address, once all inputs are valid
+    pub predicted: Option<String>,
+    /// Whether code exists at the predicted address (after a check)
+    pub deployed: Option<bool>,
+    /// Position in the deployer quick-select list
+    pub quick_select_index: Option<usize>,
+}
+
+impl Create2Form {
+    /// Labels for the input fields, in display order
+    pub const FIELD_LABELS: [&'static str; 3] = ["Deployer", "Salt (hex)", "Init Code Hash (hex)"];
+
+    /// Get the value of the field at `index`
+    pub fn field(&self, index: usize) -> &str {
+        match index {
+            0 => &self.deployer,
+            1 => &self.salt,
+            2 => &self.init_code_hash,
+            _ => "",
+        }
+    }
+
+    /// Set the value of the field at `index`
+    pub fn set_field(&mut self, index: usize, value: String) {
+        match index {
+            0 => self.deployer = value,
+            1 => self.salt = value,
+            2 => self.init_code_hash = value,
+            _ => {}
+        }
+    }
+}
@@ -1,7 +1,9 @@
+use serde::Serialize;
+
 use super::{BlockInfo, DailyTransactionData, NetworkStats, SearchResult, TransactionInfo};
 
 /// Dashboard data containing all information for the main screen
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct DashboardData {
     pub network_stats: NetworkStats,
     pub latest_blocks: Vec<BlockInfo>,
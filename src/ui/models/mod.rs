@@ -1,24 +1,54 @@
 // Network and blockchain data models
 pub mod address;
+pub mod address_label;
+pub mod app_error;
 pub mod block_info;
+pub mod contract_interaction;
+pub mod contract_verification;
+pub mod create2;
 pub mod daily_transaction_data;
 pub mod dashboard_data;
 pub mod internal_transaction;
+pub mod multisig;
 pub mod network_stats;
+pub mod notification;
 pub mod search_result;
+pub mod settings_editor;
+pub mod setup_wizard;
+pub mod simulation;
 pub mod token;
+pub mod tracked_tx;
 pub mod transaction;
+pub mod wallet;
 
 // Re-export all public types for convenience
 pub use address::{
-    AccountHistoryEntry, AddressDetails, AddressInfo, AddressTab, AddressTransaction, AddressType,
-    CompleteAddressData,
+    AccountHistoryEntry, AddressDetails, AddressFilterState, AddressInfo, AddressTab,
+    AddressTransaction, AddressType, CompleteAddressData, TxDirection, TX_FILTER_FIELD_COUNT,
 };
-pub use block_info::BlockInfo;
+pub use address_label::AddressLabel;
+pub use app_error::{AppError, ErrorType};
+pub use block_info::{BlockDetails, BlockInfo, CompleteBlockData};
+pub use contract_interaction::{AbiFunction, CallOutcome, ContractInteractionState};
+pub use contract_verification::ContractSourceTab;
+pub use create2::{Create2Form, CREATE2_FIELD_COUNT, KNOWN_CREATE2_DEPLOYERS};
 pub use daily_transaction_data::DailyTransactionData;
 pub use dashboard_data::DashboardData;
 pub use internal_transaction::InternalTransaction;
+pub use multisig::{
+    MultisigState, MultisigTab, MULTISIG_CREATE_FIELD_COUNT, MULTISIG_PROPOSE_FIELD_COUNT,
+};
 pub use network_stats::NetworkStats;
+pub use notification::{Notification, NotificationLevel, NOTIFICATION_DURATION};
 pub use search_result::SearchResult;
-pub use token::{TokenInfo, TokenTransfer, TokenType};
-pub use transaction::{TransactionDetails, TransactionInfo, TransactionStatus};
+pub use settings_editor::{FieldType, SettingsEditorState, SettingsField};
+pub use setup_wizard::{
+    SetupWizard, SetupWizardStep, ETHERSCAN_SIGNUP_URL, PUBLIC_RPC_ENDPOINTS, WIZARD_NETWORKS,
+};
+pub use simulation::{SimulationForm, SIMULATION_FIELD_COUNT};
+pub use token::{TokenInfo, TokenInfoTab, TokenTransfer, TokenType};
+pub use tracked_tx::{SessionData, TrackedTx};
+pub use transaction::{
+    AccessEntry, TransactionDetails, TransactionInfo, TransactionStatus, TransactionTab,
+};
+pub use wallet::{WalletGenerateState, MNEMONIC_WORD_COUNTS};
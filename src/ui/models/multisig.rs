@@ -0,0 +1,123 @@
+use crate::blockchain::SafeDeployment;
+use crate::models::{MultisigWallet, TransactionProposal};
+
+/// Focusable rows of the Wallets tab (2 inputs + Create button)
+pub const MULTISIG_CREATE_FIELD_COUNT: usize = 3;
+
+/// Focusable rows of the Propose tab (5 inputs + Propose button)
+pub const MULTISIG_PROPOSE_FIELD_COUNT: usize = 6;
+
+/// Tabs of the multisig wallet screen
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MultisigTab {
+    #[default]
+    Wallets,
+    Propose,
+    Pending,
+}
+
+impl MultisigTab {
+    /// All tabs, in display order
+    pub const ALL: [MultisigTab; 3] = [
+        MultisigTab::Wallets,
+        MultisigTab::Propose,
+        MultisigTab::Pending,
+    ];
+
+    /// Tab title shown in the tab bar
+    pub fn title(&self) -> &'static str {
+        match self {
+            MultisigTab::Wallets => "Wallets",
+            MultisigTab::Propose => "Propose",
+            MultisigTab::Pending => "Pending",
+        }
+    }
+
+    /// The tab to the right, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            MultisigTab::Wallets => MultisigTab::Propose,
+            MultisigTab::Propose => MultisigTab::Pending,
+            MultisigTab::Pending => MultisigTab::Wallets,
+        }
+    }
+
+    /// Focusable rows of the tab's form, 0 for the pending list
+    pub fn field_count(&self) -> usize {
+        match self {
+            MultisigTab::Wallets => MULTISIG_CREATE_FIELD_COUNT,
+            MultisigTab::Propose => MULTISIG_PROPOSE_FIELD_COUNT,
+            MultisigTab::Pending => 0,
+        }
+    }
+}
+
+/// State of the multisig wallet screen
+#[derive(Debug, Clone, Default)]
+pub struct MultisigState {
+    pub tab: MultisigTab,
+    /// Comma separated owner addresses for a new wallet
+    pub owners: String,
+    pub threshold: String,
+    /// Wallet the proposed transaction is sent from
+    pub safe: String,
+    pub to: String,
+    /// Value in ETH
+    pub value: String,
+    /// Hex-encoded calldata
+    pub data: String,
+    pub nonce: String,
+    /// Tracked multisig wallets
+    pub wallets: Vec<MultisigWallet>,
+    /// Proposed transactions, oldest first
+    pub proposals: Vec<TransactionProposal>,
+    /// Deployment awaiting confirmation in the popup
+    pub pending_deployment: Option<SafeDeployment>,
+    /// Whether the input line holds a private key for signing the selected proposal
+    pub signing: bool,
+}
+
+impl MultisigState {
+    /// Labels of the Wallets tab inputs, in display order
+    pub const CREATE_LABELS: [&'static str; 2] = ["Owners (comma separated)", "Threshold"];
+
+    /// Labels of the Propose tab inputs, in display order
+    pub const PROPOSE_LABELS: [&'static str; 5] =
+        ["Wallet", "To", "Value (ETH)", "Data (hex)", "Nonce"];
+
+    /// Get the value of input `index` on the current tab
+    pub fn field(&self, index: usize) -> &str {
+        match (self.tab, index) {
+            (MultisigTab::Wallets, 0) => &self.owners,
+            (MultisigTab::Wallets, 1) => &self.threshold,
+            (MultisigTab::Propose, 0) => &self.safe,
+            (MultisigTab::Propose, 1) => &self.to,
+            (MultisigTab::Propose, 2) => &self.value,
+            (MultisigTab::Propose, 3) => &self.data,
+            (MultisigTab::Propose, 4) => &self.nonce,
+            _ => "",
+        }
+    }
+
+    /// Set the value of input `index` on the current tab
+    pub fn set_field(&mut self, index: usize, value: String) {
+        match (self.tab, index) {
+            (MultisigTab::Wallets, 0) => self.owners = value,
+            (MultisigTab::Wallets, 1) => self.threshold = value,
+            (MultisigTab::Propose, 0) => self.safe = value,
+            (MultisigTab::Propose, 1) => self.to = value,
+            (MultisigTab::Propose, 2) => self.value = value,
+            (MultisigTab::Propose, 3) => self.data = value,
+            (MultisigTab::Propose, 4) => self.nonce = value,
+            _ => {}
+        }
+    }
+
+    /// Threshold of the tracked wallet at `address`
+    pub fn threshold_of(&self, address: &str) -> Option<u32> {
+        self.wallets
+            .iter()
+            .find(|wallet| wallet.address.eq_ignore_ascii_case(address))
+            .map(|wallet| wallet.threshold)
+    }
+}
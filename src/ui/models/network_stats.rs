@@ -4,24 +4,30 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NetworkStats {
     pub ethereum_price: f64,
+    /// 24h price change in percent, when the price source reports it
+    pub price_change_24h: Option<f64>,
     pub market_cap: f64,
     pub latest_block: u64,
     pub transactions_count: u64,
     pub gas_price: u64,
     pub network_utilization: f64,
     pub block_time: String,
+    /// ETH burned by base fees in the blocks received since startup
+    pub eth_burned_since_start: f64,
 }
 
 impl Default for NetworkStats {
     fn default() -> Self {
         Self {
             ethereum_price: 2048.75,
+            price_change_24h: None,
             market_cap: 246_000_000_000.0,
             latest_block: 21_234_567,
             transactions_count: 1_234_567_890,
             gas_price: 25,
             network_utilization: 0.75,
             block_time: "Loading...".to_string(),
+            eth_burned_since_start: 0.0,
         }
     }
 }
@@ -0,0 +1,34 @@
+//! Short-lived notifications stacked in the corner of the screen
+
+use std::time::{Duration, Instant};
+
+/// How long a notification stays on screen
+pub const NOTIFICATION_DURATION: Duration = Duration::from_secs(5);
+
+/// Severity of a notification, which picks its colour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// A message shown until `expires_at`
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub message: String,
+    pub level: NotificationLevel,
+    pub expires_at: Instant,
+}
+
+impl Notification {
+    /// A notification expiring `NOTIFICATION_DURATION` from now
+    pub fn new(message: String, level: NotificationLevel) -> Self {
+        Self {
+            message,
+            level,
+            expires_at: Instant::now() + NOTIFICATION_DURATION,
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Editable view of the configuration file on the settings screen
+//!
+//! Fields are found by serializing `Config` to TOML and flattening its scalar
+//! values into dotted keys such as `network.rpc_url`, so new config options
+//! show up without changes here. Saving writes the edited values back into
+//! the TOML and deserializes it, which catches anything the per-field checks
+//! miss (an out-of-range port, for example).
+
+use crate::config::Config;
+use crate::error::{Error, Result};
+
+/// Optional settings that are left out of the TOML while unset
+const OPTIONAL_KEYS: [&str; 4] = [
+    "etherscan_api_key",
+    "metrics_port",
+    "network.node_type",
+    "ws_rpc_url",
+];
+
+/// Sections not offered in the editor; the simulation provider is a tagged
+/// table whose shape depends on the provider
+const SKIPPED_SECTIONS: [&str; 1] = ["simulation"];
+
+/// Help text for known keys
+const DESCRIPTIONS: &[(&str, &str)] = &[
+    (
+        "debug_mode",
+        "Enable developer tooling such as the cache inspector",
+    ),
+    ("etherscan_api_key", "Etherscan API key (empty: none)"),
+    (
+        "ipfs_gateway",
+        "HTTP gateway for ipfs:// NFT metadata, ending in /ipfs/",
+    ),
+    (
+        "metrics_port",
+        "Port of the Prometheus metrics endpoint (empty: off)",
+    ),
+    (
+        "ws_rpc_url",
+        "WebSocket endpoint for new blocks (empty: poll over HTTP)",
+    ),
+    ("network.name", "Network name"),
+    ("network.rpc_url", "RPC URL of the Ethereum node"),
+    ("network.chain_id", "Chain ID (1 mainnet, 11155111 sepolia)"),
+    ("network.timeout_seconds", "Request timeout in seconds"),
+    (
+        "network.node_type",
+        "Node type: anvil, hardhat, infura, alchemy, custom",
+    ),
+    (
+        "network.max_retries",
+        "Times a failed RPC request is retried",
+    ),
+    (
+        "etherscan_rate_limit.requests_per_second",
+        "Etherscan requests per second (0: unlimited)",
+    ),
+    ("cache.enabled", "Whether caching is enabled"),
+    ("cache.max_size_mb", "Maximum cache size in MB"),
+    (
+        "cache.cleanup_interval_secs",
+        "How often expired entries are swept, in seconds",
+    ),
+    (
+        "cache.re_org_detection",
+        "Drop cached data of re-orged blocks",
+    ),
+    (
+        "cache.persist_to_disk",
+        "Keep cache entries across restarts",
+    ),
+    (
+        "ui.theme",
+        "Theme: dark, light, high_contrast, dracula, ethereum",
+    ),
+    ("ui.refresh_interval_ms", "Refresh interval in milliseconds"),
+    ("ui.max_results_per_page", "Maximum results per page"),
+    ("ui.log_level", "Log level: error, warn, info, debug, trace"),
+    (
+        "ui.debounce_delay_ms",
+        "Pause after typing before input is validated, in ms",
+    ),
+    (
+        "ui.enable_prefetch",
+        "Warm the address cache for addresses on screen",
+    ),
+    (
+        "gas.update_interval_seconds",
+        "Gas price update interval in seconds",
+    ),
+    ("gas.history_days", "Days of gas price history kept"),
+    (
+        "gas.max_range_blocks",
+        "Largest block range summed for burned fees",
+    ),
+    (
+        "gas.history_samples",
+        "Gas price samples kept for the sparklines",
+    ),
+];
+
+/// Kind of value a settings field holds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldType {
+    /// `http(s)://` or `ws(s)://` URL
+    Url,
+    /// Non-negative integer
+    U64,
+    /// `true` or `false`
+    Bool,
+    /// Free text
+    Text,
+}
+
+impl FieldType {
+    /// Short name shown next to the value
+    pub fn label(&self) -> &'static str {
+        match self {
+            FieldType::Url => "url",
+            FieldType::U64 => "integer",
+            FieldType::Bool => "bool",
+            FieldType::Text => "text",
+        }
+    }
+}
+
+/// One editable config value
+#[derive(Debug, Clone, PartialEq)]
+pub struct SettingsField {
+    /// Dotted TOML key, e.g. `network.rpc_url`
+    pub key: String,
+    /// Value as shown and edited; empty for an unset optional value
+    pub current_value: String,
+    pub description: String,
+    pub field_type: FieldType,
+}
+
+impl SettingsField {
+    /// Whether the value may be left empty to unset it
+    pub fn optional(&self) -> bool {
+        OPTIONAL_KEYS.contains(&self.key.as_str())
+    }
+
+    /// Check `input` against the field type, returning the value to store
+    pub fn validate(&self, input: &str) -> std::result::Result<String, String> {
+        let input = input.trim();
+        if input.is_empty() {
+            return if self.optional() || self.field_type == FieldType::Text {
+                Ok(String::new())
+            } else {
+                Err(format!("{} cannot be empty", self.key))
+            };
+        }
+
+        match self.field_type {
+            FieldType::Url => match reqwest::Url::parse(input) {
+                Ok(url) if matches!(url.scheme(), "http" | "https" | "ws" | "wss") => {
+                    Ok(input.to_string())
+                }
+                Ok(url) => Err(format!("Unsupported URL scheme {}", url.scheme())),
+                Err(e) => Err(format!("Invalid URL: {}", e)),
+            },
+            FieldType::U64 => input
+                .parse::<u64>()
+                .map(|value| value.to_string())
+                .map_err(|_| format!("{} must be a non-negative integer", self.key)),
+            FieldType::Bool => input
+                .parse::<bool>()
+                .map(|value| value.to_string())
+                .map_err(|_| format!("{} must be true or false", self.key)),
+            FieldType::Text => Ok(input.to_string()),
+        }
+    }
+
+    /// TOML value of the field, `None` when an optional value is unset
+    fn toml_value(&self) -> Option<toml::Value> {
+        if self.current_value.is_empty() && self.optional() {
+            return None;
+        }
+        Some(match self.field_type {
+            FieldType::U64 => self
+                .current_value
+                .parse::<i64>()
+                .map(toml::Value::Integer)
+                .unwrap_or_else(|_| toml::Value::String(self.current_value.clone())),
+            FieldType::Bool => toml::Value::Boolean(self.current_value == "true"),
+            FieldType::Url | FieldType::Text => toml::Value::String(self.current_value.clone()),
+        })
+    }
+}
+
+/// State of the config editor panel on the settings screen
+#[derive(Debug, Clone, Default)]
+pub struct SettingsEditorState {
+    pub open: bool,
+    pub fields: Vec<SettingsField>,
+    pub selected: usize,
+    /// Whether the selected field is being edited in the input line
+    pub editing: bool,
+    /// Why the last edit or save was rejected
+    pub error: Option<String>,
+    /// Whether there are edits not yet saved
+    pub dirty: bool,
+}
+
+impl SettingsEditorState {
+    /// Open the editor on the values of `config`
+    pub fn open(config: &Config) -> Result<Self> {
+        Ok(Self {
+            open: true,
+            fields: settings_fields(config)?,
+            ..Default::default()
+        })
+    }
+
+    /// Selected field, if any
+    pub fn selected_field(&self) -> Option<&SettingsField> {
+        self.fields.get(self.selected)
+    }
+
+    /// `base` with the edited values applied
+    pub fn apply(&self, base: &Config) -> Result<Config> {
+        let mut root = toml::Value::try_from(base)
+            .map_err(|e| Error::parse(format!("Failed to serialize config: {}", e)))?;
+        for field in &self.fields {
+            set_path(&mut root, &field.key, field.toml_value());
+        }
+        root.try_into()
+            .map_err(|e| Error::validation(format!("Invalid config: {}", e)))
+    }
+}
+
+/// Editable scalar values of `config`, top-level keys first
+pub fn settings_fields(config: &Config) -> Result<Vec<SettingsField>> {
+    let root = toml::Value::try_from(config)
+        .map_err(|e| Error::parse(format!("Failed to serialize config: {}", e)))?;
+
+    let mut leaves = Vec::new();
+    if let toml::Value::Table(ref table) = root {
+        flatten("", table, &mut leaves);
+    }
+    for key in OPTIONAL_KEYS {
+        if !leaves.iter().any(|(existing, _)| existing == key) {
+            leaves.push((key.to_string(), toml::Value::String(String::new())));
+        }
+    }
+    leaves.sort_by(|(a, _), (b, _)| (a.contains('.'), a).cmp(&(b.contains('.'), b)));
+
+    Ok(leaves
+        .into_iter()
+        .map(|(key, value)| {
+            let field_type = match value {
+                toml::Value::Boolean(_) => FieldType::Bool,
+                toml::Value::Integer(_) => FieldType::U64,
+                _ if key.ends_with("url") || key.ends_with("gateway") => FieldType::Url,
+                _ if key == "metrics_port" => FieldType::U64,
+                _ => FieldType::Text,
+            };
+            let current_value = match value {
+                toml::Value::String(text) => text,
+                other => other.to_string(),
+            };
+            let description = DESCRIPTIONS
+                .iter()
+                .find(|(known, _)| *known == key)
+                .map(|(_, description)| description.to_string())
+                .unwrap_or_default();
+            SettingsField {
+                key,
+                current_value,
+                description,
+                field_type,
+            }
+        })
+        .collect())
+}
+
+/// Collect the scalar values below `table` as `(dotted key, value)`
+fn flatten(
+    prefix: &str,
+    table: &toml::map::Map<String, toml::Value>,
+    out: &mut Vec<(String, toml::Value)>,
+) {
+    for (name, value) in table {
+        let key = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        match value {
+            toml::Value::Table(_) if SKIPPED_SECTIONS.contains(&key.as_str()) => {}
+            toml::Value::Table(inner) => flatten(&key, inner, out),
+            toml::Value::String(_) | toml::Value::Integer(_) | toml::Value::Boolean(_) => {
+                out.push((key, value.clone()))
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Set or, for `None`, remove the value at a dotted key
+fn set_path(root: &mut toml::Value, key: &str, value: Option<toml::Value>) {
+    let mut parts: Vec<&str> = key.split('.').collect();
+    let Some(last) = parts.pop() else {
+        return;
+    };
+    let mut table = root;
+    for part in parts {
+        let Some(inner) = table.as_table_mut() else {
+            return;
+        };
+        table = inner
+            .entry(part)
+            .or_insert_with(|| toml::Value::Table(Default::default()));
+    }
+    if let Some(table) = table.as_table_mut() {
+        match value {
+            Some(value) => {
+                table.insert(last.to_string(), value);
+            }
+            None => {
+                table.remove(last);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_fields_round_trip() {
+        let config = Config::default();
+        let mut editor = SettingsEditorState::open(&config).unwrap();
+
+        let rpc_url = editor
+            .fields
+            .iter()
+            .position(|field| field.key == "network.rpc_url")
+            .unwrap();
+        assert_eq!(editor.fields[rpc_url].field_type, FieldType::Url);
+        assert!(editor.fields[rpc_url].validate("not a url").is_err());
+        assert!(editor.fields[rpc_url].validate("ftp://node").is_err());
+        editor.fields[rpc_url].current_value = editor.fields[rpc_url]
+            .validate(" http://127.0.0.1:8545 ")
+            .unwrap();
+
+        let port = editor
+            .fields
+            .iter()
+            .position(|field| field.key == "metrics_port")
+            .unwrap();
+        assert_eq!(editor.fields[port].validate(""), Ok(String::new()));
+        assert!(editor.fields[port].validate("-1").is_err());
+        editor.fields[port].current_value = "9100".to_string();
+
+        let updated = editor.apply(&config).unwrap();
+        assert_eq!(updated.network.rpc_url, "http://127.0.0.1:8545");
+        assert_eq!(updated.metrics_port, Some(9100));
+
+        // Out of range for the u16 port, caught when the config is rebuilt
+        editor.fields[port].current_value = "70000".to_string();
+        assert!(editor.apply(&config).is_err());
+    }
+}
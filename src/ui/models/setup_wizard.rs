@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+/// Popular public RPC endpoints offered on the first wizard step
+pub const PUBLIC_RPC_ENDPOINTS: [(&str, &str); 5] = [
+    (
+        "PublicNode (Mainnet)",
+        "https://ethereum-rpc.publicnode.com",
+    ),
+    ("LlamaRPC (Mainnet)", "https://eth.llamarpc.com"),
+    ("Ankr (Mainnet)", "https://rpc.ankr.com/eth"),
+    (
+        "PublicNode (Sepolia)",
+        "https://ethereum-sepolia-rpc.publicnode.com",
+    ),
+    ("Local Node", "http://127.0.0.1:8545"),
+];
+
+/// Networks offered on the last wizard step: (name, chain ID, default RPC URL)
+pub const WIZARD_NETWORKS: [(&str, u64, &str); 3] = [
+    ("Ethereum Mainnet", 1, "https://ethereum-rpc.publicnode.com"),
+    (
+        "Sepolia",
+        11155111,
+        "https://ethereum-sepolia-rpc.publicnode.com",
+    ),
+    ("Local Node", 31337, "http://127.0.0.1:8545"),
+];
+
+/// Where to sign up for an Etherscan API key
+pub const ETHERSCAN_SIGNUP_URL: &str = "https://etherscan.io/register";
+
+/// Steps of the first-run setup wizard
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub enum SetupWizardStep {
+    #[default]
+    RpcUrl,
+    EtherscanApiKey,
+    Network,
+}
+
+impl SetupWizardStep {
+    /// 1-based position shown in the wizard header
+    pub fn number(&self) -> usize {
+        match self {
+            SetupWizardStep::RpcUrl => 1,
+            SetupWizardStep::EtherscanApiKey => 2,
+            SetupWizardStep::Network => 3,
+        }
+    }
+
+    /// Step heading
+    pub fn title(&self) -> &'static str {
+        match self {
+            SetupWizardStep::RpcUrl => "RPC Endpoint",
+            SetupWizardStep::EtherscanApiKey => "Etherscan API Key (optional)",
+            SetupWizardStep::Network => "Network",
+        }
+    }
+}
+
+/// Answers collected by the setup wizard; `None` means the step was skipped
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SetupWizard {
+    pub step: SetupWizardStep,
+    pub rpc_url: Option<String>,
+    pub etherscan_api_key: Option<String>,
+    /// Index into `WIZARD_NETWORKS`
+    pub network: Option<usize>,
+    /// Highlighted entry of the endpoint or network list
+    pub list_index: Option<usize>,
+}
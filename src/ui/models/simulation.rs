@@ -0,0 +1,49 @@
+use crate::blockchain::SimulationResult;
+use serde::Serialize;
+
+/// Number of focusable rows on the simulation screen (4 inputs + Simulate button)
+pub const SIMULATION_FIELD_COUNT: usize = 5;
+
+/// Form state for the transaction simulation screen
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SimulationForm {
+    pub from: String,
+    pub to: String,
+    /// Hex-encoded calldata
+    pub data: String,
+    /// Value in ETH
+    pub value: String,
+    /// Result of the last simulation run
+    pub result: Option<SimulationResult>,
+    /// Function being called, when opened from Contract Interaction
+    pub function: Option<ethers::abi::Function>,
+    /// Return values of the last run as `(type, value)`, decoded with `function`
+    pub decoded_output: Option<Vec<(String, String)>>,
+}
+
+impl SimulationForm {
+    /// Labels for the input fields, in display order
+    pub const FIELD_LABELS: [&'static str; 4] = ["From", "To", "Data (hex)", "Value (ETH)"];
+
+    /// Get the value of the field at `index`
+    pub fn field(&self, index: usize) -> &str {
+        match index {
+            0 => &self.from,
+            1 => &self.to,
+            2 => &self.data,
+            3 => &self.value,
+            _ => "",
+        }
+    }
+
+    /// Set the value of the field at `index`
+    pub fn set_field(&mut self, index: usize, value: String) {
+        match index {
+            0 => self.from = value,
+            1 => self.to = value,
+            2 => self.data = value,
+            3 => self.value = value,
+            _ => {}
+        }
+    }
+}
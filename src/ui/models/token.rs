@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenTransfer {
     pub token_id: Option<String>,
+    /// Lowercase address of the token contract
+    pub contract_address: String,
     pub txn_hash: String,
     pub from: String,
     pub to: String,
@@ -17,6 +19,7 @@ impl Default for TokenTransfer {
     fn default() -> Self {
         Self {
             token_id: None,
+            contract_address: "0xdac17f958d2ee523a2206206994597c13d831ec7".to_string(),
             txn_hash: "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890ab"
                 .to_string(),
             from: "0x1111111111111111111111111111111111111111".to_string(),
@@ -39,6 +42,8 @@ pub struct TokenInfo {
     pub balance: f64,
     pub value_usd: f64,
     pub decimals: u8,
+    /// Collection floor price in ETH (NFTs only)
+    pub floor_price: Option<f64>,
 }
 
 /// Token type enumeration
@@ -60,6 +65,50 @@ impl Default for TokenInfo {
             balance: 1000.0,
             value_usd: 1000.0,
             decimals: 6,
+            floor_price: None,
+        }
+    }
+}
+
+impl TokenType {
+    /// Parse a token standard string such as "ERC-721" or "ERC1155"
+    pub fn from_standard(standard: &str) -> Self {
+        match standard.to_uppercase().replace('-', "").as_str() {
+            "ERC20" => TokenType::ERC20,
+            "ERC721" => TokenType::ERC721,
+            "ERC1155" => TokenType::ERC1155,
+            other => TokenType::Other(other.to_string()),
+        }
+    }
+
+    /// Whether this token type represents non-fungible tokens
+    pub fn is_nft(&self) -> bool {
+        matches!(self, TokenType::ERC721 | TokenType::ERC1155)
+    }
+}
+
+/// Tabs of the token information screen
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum TokenInfoTab {
+    #[default]
+    Overview,
+    Holders,
+}
+
+impl TokenInfoTab {
+    /// Tab title shown in the tab bar
+    pub fn title(&self) -> &'static str {
+        match self {
+            TokenInfoTab::Overview => "Overview",
+            TokenInfoTab::Holders => "Token Holders",
+        }
+    }
+
+    /// The other tab (the screen only has two)
+    pub fn toggle(&self) -> Self {
+        match self {
+            TokenInfoTab::Overview => TokenInfoTab::Holders,
+            TokenInfoTab::Holders => TokenInfoTab::Overview,
         }
     }
 }
@@ -0,0 +1,61 @@
+//! Transactions the user submitted and is waiting on
+
+use crate::blockchain::TransactionStatus;
+use crate::models::{MultisigWallet, TransactionProposal};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+/// A submitted transaction whose confirmations are being followed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackedTx {
+    pub hash: String,
+    /// Not persisted; restarts when the session is loaded
+    #[serde(skip, default = "Instant::now")]
+    pub submitted_at: Instant,
+    pub last_status: TransactionStatus,
+    /// Block the transaction was included in, once known
+    #[serde(default)]
+    pub block_number: Option<u64>,
+    pub confirmations: u64,
+    /// Whether the inclusion block is finalized, after which it is no longer polled
+    #[serde(default)]
+    pub finalized: bool,
+}
+
+impl TrackedTx {
+    /// Start tracking a freshly submitted transaction
+    pub fn new(hash: String) -> Self {
+        Self {
+            hash,
+            submitted_at: Instant::now(),
+            last_status: TransactionStatus::Pending,
+            block_number: None,
+            confirmations: 0,
+            finalized: false,
+        }
+    }
+
+    /// Status change message such as `Pending → Success (1 conf)`
+    pub fn transition_label(from: TransactionStatus, to: TransactionStatus, confs: u64) -> String {
+        match to {
+            TransactionStatus::Success => format!(
+                "{:?} → Success ({} conf{})",
+                from,
+                confs,
+                if confs == 1 { "" } else { "s" }
+            ),
+            _ => format!("{:?} → {:?}", from, to),
+        }
+    }
+}
+
+/// Session state persisted between runs in `session.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionData {
+    #[serde(default)]
+    pub tracked_transactions: Vec<TrackedTx>,
+    #[serde(default)]
+    pub multisig_wallets: Vec<MultisigWallet>,
+    #[serde(default)]
+    pub multisig_proposals: Vec<TransactionProposal>,
+}
@@ -1,3 +1,6 @@
+use crate::blockchain::{DecodedLog, MevInfo};
+use ethers::types::transaction::eip2930::AccessListItem;
+use ethers::types::Transaction;
 use serde::{Deserialize, Serialize};
 
 /// Transaction information for the latest transactions section
@@ -15,6 +18,36 @@ pub struct TransactionInfo {
     pub transaction_fee: f64,
 }
 
+impl TransactionInfo {
+    /// Row for a transaction taken from a block, without fetching its receipt
+    ///
+    /// Status stays Pending (contract creations are assumed to succeed) until
+    /// the receipt is known.
+    pub fn from_block_transaction(tx: &Transaction, block_number: u64, timestamp: u64) -> Self {
+        let status = if tx.to.is_none() {
+            TransactionStatus::Success
+        } else {
+            TransactionStatus::Pending
+        };
+
+        Self {
+            hash: format!("{:#x}", tx.hash),
+            from: format!("{:#x}", tx.from),
+            to: tx.to.map(|a| format!("{:#x}", a)).unwrap_or_default(),
+            value: tx.value.as_u128() as f64 / 1e18,
+            gas_price: tx
+                .gas_price
+                .map(|p| p.as_u64() / 1_000_000_000)
+                .unwrap_or(0),
+            gas_used: 0,
+            status,
+            timestamp,
+            block_number,
+            transaction_fee: 0.0,
+        }
+    }
+}
+
 /// Transfer entry for a transaction (ETH or token)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransactionTransfer {
@@ -27,6 +60,20 @@ pub struct TransactionTransfer {
     pub token_address: Option<String>, // None for ETH transfers
 }
 
+impl TransactionTransfer {
+    /// Key identifying the asset moved: `ETH`, or the lowercase token address
+    pub fn asset_key(&self) -> String {
+        match self.transfer_type {
+            TransferType::ETH | TransferType::Internal => "ETH".to_string(),
+            TransferType::Token => self
+                .token_address
+                .clone()
+                .unwrap_or_default()
+                .to_lowercase(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransferType {
     ETH,
@@ -55,6 +102,85 @@ pub struct TransactionDetails {
     pub contract_address: Option<String>, // If this is a contract creation
     pub confirmations: u64,
     pub transfers: Vec<TransactionTransfer>, // All transfers in this transaction
+    /// Event logs emitted by the transaction, decoded where the event is known
+    #[serde(default)]
+    pub logs: Vec<DecodedLog>,
+    /// EIP-2718 envelope type: 0 legacy, 1 EIP-2930, 2 EIP-1559, ...
+    #[serde(default)]
+    pub tx_type: u8,
+    /// Fee cap in gwei (EIP-1559 transactions)
+    #[serde(default)]
+    pub max_fee_per_gas: Option<u64>,
+    /// Priority fee cap in gwei (EIP-1559 transactions)
+    #[serde(default)]
+    pub max_priority_fee_per_gas: Option<u64>,
+    /// Addresses and storage slots declared up front (EIP-2930)
+    #[serde(default)]
+    pub access_list: Vec<AccessEntry>,
+    /// Flashbots bundle the transaction landed in (mainnet only)
+    #[serde(default)]
+    pub mev_bundle: Option<MevInfo>,
+}
+
+impl TransactionDetails {
+    /// Envelope type such as `Type 2 (EIP-1559)`
+    pub fn tx_type_label(&self) -> String {
+        let standard = match self.tx_type {
+            0 => "Legacy",
+            1 => "EIP-2930",
+            2 => "EIP-1559",
+            3 => "EIP-4844",
+            4 => "EIP-7702",
+            _ => return format!("Type {}", self.tx_type),
+        };
+        format!("Type {} ({})", self.tx_type, standard)
+    }
+}
+
+/// An access list address with the storage slots it declares
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessEntry {
+    pub address: String,
+    pub storage_keys: Vec<String>,
+}
+
+impl From<&AccessListItem> for AccessEntry {
+    fn from(item: &AccessListItem) -> Self {
+        Self {
+            address: format!("{:#x}", item.address),
+            storage_keys: item
+                .storage_keys
+                .iter()
+                .map(|key| format!("{:#x}", key))
+                .collect(),
+        }
+    }
+}
+
+/// Tabs of the transaction viewer
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub enum TransactionTab {
+    #[default]
+    Overview,
+    Logs,
+}
+
+impl TransactionTab {
+    /// Tab title shown in the tab bar
+    pub fn title(&self) -> &'static str {
+        match self {
+            TransactionTab::Overview => "Overview",
+            TransactionTab::Logs => "Logs",
+        }
+    }
+
+    /// The other tab (the viewer only has two)
+    pub fn toggle(&self) -> Self {
+        match self {
+            TransactionTab::Overview => TransactionTab::Logs,
+            TransactionTab::Logs => TransactionTab::Overview,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -103,6 +229,12 @@ impl Default for TransactionDetails {
             contract_address: None,
             confirmations: 1234,
             transfers: Vec::new(),
+            logs: Vec::new(),
+            tx_type: 2,
+            max_fee_per_gas: Some(30),
+            max_priority_fee_per_gas: Some(2),
+            access_list: Vec::new(),
+            mev_bundle: None,
         }
     }
 }
@@ -0,0 +1,43 @@
+use crate::wallet::DerivedAddress;
+use std::path::PathBuf;
+
+/// Mnemonic lengths offered on the Generate tab
+pub const MNEMONIC_WORD_COUNTS: [usize; 2] = [12, 24];
+
+/// State of the wallet manager's Generate tab
+#[derive(Debug, Clone)]
+pub struct WalletGenerateState {
+    /// Words in the mnemonic to generate
+    pub word_count: usize,
+    /// Phrase of the last generated wallet
+    pub mnemonic: Option<String>,
+    /// First accounts derived from `mnemonic`
+    pub addresses: Vec<DerivedAddress>,
+    /// Whether the input line takes the keystore password
+    pub password_prompt: bool,
+    /// Keystore written for the first derived account
+    pub keystore_path: Option<PathBuf>,
+}
+
+impl Default for WalletGenerateState {
+    fn default() -> Self {
+        Self {
+            word_count: MNEMONIC_WORD_COUNTS[0],
+            mnemonic: None,
+            addresses: Vec::new(),
+            password_prompt: false,
+            keystore_path: None,
+        }
+    }
+}
+
+impl WalletGenerateState {
+    /// Focusable rows: word count, Generate, and Save once a wallet exists
+    pub fn field_count(&self) -> usize {
+        if self.mnemonic.is_some() {
+            3
+        } else {
+            2
+        }
+    }
+}
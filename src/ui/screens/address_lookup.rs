@@ -1,17 +1,28 @@
+use crate::blockchain::KnownAddresses;
+use crate::ui::app::utils::{address_display_name, format_address_display, format_eth_value};
+use crate::ui::app::{AppState, ClickAction, ClickableRegion};
+use crate::ui::models::AddressLabel;
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout, Margin},
-    style::{Modifier, Style},
+    layout::{Alignment, Constraint, Direction, Flex, Layout, Margin, Rect},
+    style::{Color, Modifier, Style},
     text::{Line, Span, Text},
     widgets::{
-        Block, BorderType, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation,
-        ScrollbarState, Table, TableState, Tabs, Wrap,
+        Block, BorderType, Borders, Cell, List, ListItem, ListState, Paragraph, Row, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Table, TableState, Tabs, Wrap,
     },
     Frame,
 };
+use std::collections::HashMap;
+
+/// Marker drawn in front of the selected table row
+const TABLE_HIGHLIGHT_SYMBOL: &str = " █ ";
+
+/// Orange used for the exchange badge, independent of the theme palette
+const EXCHANGE_BADGE_COLOR: Color = Color::Rgb(255, 165, 0);
 
 /// Render the address lookup screen
-pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
+pub fn render_address_lookup(frame: &mut Frame, app: &mut App, theme: &Theme) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -32,23 +43,47 @@ pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
         );
     frame.render_widget(title, chunks[0]);
 
+    // While a transaction filter or the export path is edited the input line
+    // belongs to that widget
+    let input_redirected = app.transaction_filter_editing()
+        || app.export_prompt_open
+        || app.label_prompt_address.is_some();
+    let address_input = match app.address_data {
+        Some(ref data) if input_redirected => data.details.address.as_str(),
+        _ => app.input.as_str(),
+    };
+
     // Input field
     crate::ui::components::render_input_field(
         frame,
         chunks[1],
         theme,
         "Enter address:",
-        &app.input,
-        app.cursor_position,
-        app.input_mode == crate::ui::InputMode::Editing,
+        address_input,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
+        app.input_mode == crate::ui::InputMode::Editing && !input_redirected,
     );
 
-    // Show cursor when in editing mode
-    if app.input_mode == crate::ui::InputMode::Editing {
-        frame.set_cursor_position((
-            chunks[1].x + app.cursor_position as u16 + 1,
-            chunks[1].y + 1,
-        ));
+    // Faint ENS preview on the input's bottom border
+    if let Some(preview) = app
+        .ens_preview
+        .as_ref()
+        .filter(|preview| !input_redirected && preview.input() == app.input.trim())
+    {
+        let preview_area = Rect {
+            x: chunks[1].x + 2,
+            y: chunks[1].y + chunks[1].height.saturating_sub(1),
+            width: chunks[1].width.saturating_sub(4),
+            height: 1,
+        };
+        frame.render_widget(
+            Paragraph::new(Span::styled(
+                format!(" {} ", preview.label()),
+                theme.muted(),
+            )),
+            preview_area,
+        );
     }
 
     // Content area
@@ -66,19 +101,44 @@ pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
         render_address_type_indicator(frame, content_chunks[0], &address_data.details, theme);
 
         // Tabs
-        render_address_tabs(frame, content_chunks[1], &address_data.current_tab, theme);
+        render_address_tabs(
+            frame,
+            content_chunks[1],
+            &address_data.visible_tabs(),
+            &address_data.current_tab,
+            theme,
+        );
 
         // Tab content
         match address_data.current_tab {
             crate::ui::models::AddressTab::Details => {
-                render_address_details_tab(frame, content_chunks[2], &address_data.details, theme);
+                render_address_details_tab(
+                    frame,
+                    content_chunks[2],
+                    &address_data.details,
+                    &mut app.clickable_regions,
+                    theme,
+                );
             }
             crate::ui::models::AddressTab::Transactions => {
+                let filter_input = app
+                    .transaction_filter_editing()
+                    .then_some((app.input.as_str(), app.cursor_char_pos));
                 render_address_transactions_tab(
                     frame,
                     content_chunks[2],
-                    &address_data.transactions,
+                    &address_data.details,
+                    &app.address_book,
+                    &address_data.filtered_transactions(),
+                    address_data.transactions.len(),
+                    (address_data.current_page, address_data.total_pages),
+                    &address_data.filter,
+                    filter_input,
                     address_data.selected_transaction_index,
+                    app.scrollbar_states
+                        .entry("address_txs".to_string())
+                        .or_default(),
+                    &mut app.clickable_regions,
                     theme,
                 );
             }
@@ -86,8 +146,13 @@ pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
                 render_address_history_tab(
                     frame,
                     content_chunks[2],
+                    &address_data.details,
+                    &app.address_book,
                     &address_data.account_history,
                     address_data.selected_history_index,
+                    app.scrollbar_states
+                        .entry("address_history".to_string())
+                        .or_default(),
                     theme,
                 );
             }
@@ -95,8 +160,13 @@ pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
                 render_token_transfers_tab(
                     frame,
                     content_chunks[2],
+                    &address_data.details,
+                    &app.address_book,
                     &address_data.token_transfers,
                     address_data.selected_token_transfer_index,
+                    app.scrollbar_states
+                        .entry("token_transfers".to_string())
+                        .or_default(),
                     theme,
                 );
             }
@@ -106,6 +176,9 @@ pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
                     content_chunks[2],
                     &address_data.tokens,
                     address_data.selected_token_index,
+                    app.scrollbar_states
+                        .entry("tokens".to_string())
+                        .or_default(),
                     theme,
                 );
             }
@@ -113,20 +186,50 @@ pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
                 render_internal_txns_tab(
                     frame,
                     content_chunks[2],
+                    &address_data.details,
+                    &app.address_book,
                     &address_data.internal_transactions,
                     address_data.selected_internal_txn_index,
+                    app.scrollbar_states
+                        .entry("internal_txns".to_string())
+                        .or_default(),
+                    theme,
+                );
+            }
+            crate::ui::models::AddressTab::Approvals => {
+                render_approvals_tab(
+                    frame,
+                    content_chunks[2],
+                    address_data,
+                    &app.address_book,
+                    app.scrollbar_states
+                        .entry("approvals".to_string())
+                        .or_default(),
+                    theme,
+                );
+            }
+            crate::ui::models::AddressTab::Withdrawals => {
+                render_withdrawals_tab(
+                    frame,
+                    content_chunks[2],
+                    &address_data.withdrawals,
+                    address_data.selected_withdrawal_index,
+                    app.scrollbar_states
+                        .entry("withdrawals".to_string())
+                        .or_default(),
                     theme,
                 );
             }
         }
     } else if !app.input.is_empty() {
-        let message = if let Some(ref error) = app.error_message {
-            error.clone()
+        let message = if let Some(error) = app.error_message() {
+            error
         } else {
-            "Loading address data...".to_string()
+            app.loading_message("address_search")
+                .unwrap_or("Loading address data...")
         };
 
-        crate::ui::components::render_loading(frame, chunks[2], theme, &message);
+        crate::ui::components::render_loading(frame, chunks[2], theme, message, app.spinner_frame);
     } else {
         let prompt = ratatui::widgets::Paragraph::new("Enter an Ethereum address to view details")
             .style(theme.muted())
@@ -138,6 +241,201 @@ pub fn render_address_lookup(frame: &mut Frame, app: &App, theme: &Theme) {
             );
         frame.render_widget(prompt, chunks[2]);
     }
+
+    if let Some((ref token, ref token_ids)) = app.nft_token_picker {
+        render_nft_token_picker(frame, token, token_ids, app.nft_token_picker_index, theme);
+    }
+
+    if let Some((ref token_id, ref metadata)) = app.nft_detail_popup {
+        render_nft_detail_popup(frame, token_id, metadata, theme);
+    }
+
+    if app.export_prompt_open {
+        render_export_prompt(frame, app, theme);
+    } else if let Some(ref address) = app.label_prompt_address {
+        render_label_prompt(frame, app, address, theme);
+    } else if let Some(ref message) = app.success_message {
+        let area = frame.area();
+        let width = (message.chars().count() as u16 + 4).clamp(30, area.width);
+        let toast = ratatui::layout::Rect {
+            x: area.x + area.width - width,
+            y: area.y + area.height.saturating_sub(5),
+            width,
+            height: 5.min(area.height),
+        };
+        crate::ui::components::render_success(frame, toast, theme, message);
+    }
+}
+
+/// Unicode-art stand-in for an NFT image, which the terminal cannot draw
+const NFT_IMAGE_PLACEHOLDER: [&str; 7] = [
+    "╭──────────────────╮",
+    "│░░░░░░░░░░░░░░░░░░│",
+    "│░░░░░░▄▄░░░░░░░░░░│",
+    "│░░░░░▐██▌░░░▄▄░░░░│",
+    "│░░▄▄████████████▄░│",
+    "│▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓▓│",
+    "╰──────────────────╯",
+];
+
+/// Render the metadata of an ERC-721 token opened from the Tokens tab
+/// Render the list of held token IDs of an ERC-721 collection
+fn render_nft_token_picker(
+    frame: &mut Frame,
+    token: &crate::ui::models::TokenInfo,
+    token_ids: &[String],
+    selected: usize,
+    theme: &Theme,
+) {
+    let items: Vec<ListItem> = token_ids
+        .iter()
+        .map(|token_id| {
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", token.symbol), theme.muted()),
+                Span::styled(format!("#{}", token_id), theme.normal()),
+            ]))
+        })
+        .collect();
+
+    let screen = frame.area();
+    let width = screen.width.min(44);
+    let height = (items.len() as u16 + 2).min(screen.height.saturating_sub(4));
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+
+    let mut state = ListState::default().with_selected(Some(selected));
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    "{} held (Enter: open, Esc: close)",
+                    token_ids.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        )
+        .highlight_style(theme.selected());
+    frame.render_widget(ratatui::widgets::Clear, area);
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn render_nft_detail_popup(
+    frame: &mut Frame,
+    token_id: &str,
+    metadata: &crate::blockchain::NftMetadata,
+    theme: &Theme,
+) {
+    let area = frame.area();
+    let width = area.width.saturating_sub(8).min(70);
+    let height = area.height.saturating_sub(4).min(30);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let mut lines: Vec<Line> = NFT_IMAGE_PLACEHOLDER
+        .iter()
+        .map(|row| Line::from(Span::styled(*row, theme.muted())).centered())
+        .collect();
+    if let Some(ref image_url) = metadata.image_url {
+        lines.push(Line::from(Span::styled(image_url.clone(), theme.info())).centered());
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Name: ", theme.label()),
+        Span::styled(
+            metadata.name.clone(),
+            theme.primary().add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("Token ID: ", theme.label()),
+        Span::styled(token_id.to_string(), theme.normal()),
+    ]));
+    if let Some(ref description) = metadata.description {
+        lines.push(Line::from(vec![
+            Span::styled("Description: ", theme.label()),
+            Span::styled(description.clone(), theme.normal()),
+        ]));
+    }
+    if !metadata.attributes.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled("Attributes", theme.label())));
+        for attribute in &metadata.attributes {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}: ", attribute.trait_type), theme.muted()),
+                Span::styled(attribute.value.clone(), theme.normal()),
+            ]));
+        }
+    }
+
+    frame.render_widget(ratatui::widgets::Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .title("NFT Metadata (Esc: close)")
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        ),
+        popup,
+    );
+}
+
+/// Render the file path prompt for the report export
+fn render_export_prompt(frame: &mut Frame, app: &App, theme: &Theme) {
+    let area = frame.area();
+    let width = area.width.saturating_sub(8).min(80);
+    let popup = ratatui::layout::Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height.saturating_sub(3) / 2,
+        width,
+        height: 3.min(area.height),
+    };
+
+    frame.render_widget(ratatui::widgets::Clear, popup);
+    crate::ui::components::render_input_field(
+        frame,
+        popup,
+        theme,
+        "Export report to (Enter: save, Esc: cancel)",
+        &app.input,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
+        true,
+    );
+}
+
+/// Render the label prompt for the looked-up address
+fn render_label_prompt(frame: &mut Frame, app: &App, address: &str, theme: &Theme) {
+    let area = frame.area();
+    let width = area.width.saturating_sub(8).min(80);
+    let popup = ratatui::layout::Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height.saturating_sub(3) / 2,
+        width,
+        height: 3.min(area.height),
+    };
+
+    frame.render_widget(ratatui::widgets::Clear, popup);
+    crate::ui::components::render_input_field(
+        frame,
+        popup,
+        theme,
+        &format!(
+            "Label {} as name, tags (Enter: save, empty: remove, Esc: cancel)",
+            format_address_display(address, None, 0)
+        ),
+        &app.input,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
+        true,
+    );
 }
 
 /// Render address type indicator
@@ -158,17 +456,78 @@ fn render_address_type_indicator(
         AddressType::Unknown => ("Unknown", theme.muted()),
     };
 
-    // Build the indicator line with type, address, and optionally ENS name
-    let mut spans = vec![
-        Span::styled("Type: ", theme.label()),
-        Span::styled(type_text, type_style),
+    use crate::blockchain::address_utils::{checksum_status, to_checksum_address, ChecksumStatus};
+
+    // Show the EIP-55 checksummed form, flagging how the entered address compares
+    let checksummed =
+        to_checksum_address(&details.address).unwrap_or_else(|| details.address.clone());
+    let (checksum_text, checksum_style) = match checksum_status(&details.address) {
+        Some(ChecksumStatus::Valid) => (" ✓ EIP-55", theme.success()),
+        Some(ChecksumStatus::Missing) => (" (checksummed)", theme.muted()),
+        Some(ChecksumStatus::Invalid) => (" ✗ bad checksum", theme.error()),
+        None => ("", theme.muted()),
+    };
+
+    // Build the indicator line with type, address, and optionally ENS name.
+    // A detected self-destruct replaces the normal type display.
+    let mut spans = match &details.self_destruct {
+        Some(info) => vec![Span::styled(
+            format!(
+                " ⚠ This contract was self-destructed at block {} ",
+                info.block_number
+            ),
+            theme
+                .error()
+                .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+        )],
+        None if matches!(details.address_type, AddressType::Exchange) => {
+            let badge = match details.contract_name {
+                Some(ref name) => format!(" Exchange: {} ", name),
+                None => " Exchange ".to_string(),
+            };
+            vec![
+                Span::styled("Type: ", theme.label()),
+                Span::styled(
+                    badge,
+                    Style::default()
+                        .fg(EXCHANGE_BADGE_COLOR)
+                        .add_modifier(Modifier::BOLD | Modifier::REVERSED),
+                ),
+            ]
+        }
+        None => vec![
+            Span::styled("Type: ", theme.label()),
+            Span::styled(type_text, type_style),
+        ],
+    };
+    spans.extend([
         Span::raw(" | "),
         Span::styled("Address: ", theme.label()),
-        Span::styled(&details.address, theme.normal()),
-    ];
+        Span::styled(checksummed, theme.normal()),
+        Span::styled(checksum_text, checksum_style),
+    ]);
+
+    // Name tag chips
+    for tag in &details.tags {
+        use crate::blockchain::TagType;
+
+        let (text, style) = match tag.tag_type {
+            TagType::Exchange => ("Exchange", theme.warning()),
+            TagType::Hack => ("⚠ Hack", theme.error()),
+            TagType::Token => ("Token", theme.primary()),
+            TagType::Defi => ("DeFi", theme.info()),
+            TagType::Whale => ("Whale", theme.accent()),
+            TagType::Team => ("Team", theme.success()),
+        };
+        spans.push(Span::raw(" "));
+        spans.push(Span::styled(
+            format!(" {} ", text),
+            style.add_modifier(Modifier::REVERSED | Modifier::BOLD),
+        ));
+    }
 
     // Add ENS name for EOA addresses if it exists
-    if let AddressType::EOA = details.address_type {
+    if let (AddressType::EOA, None) = (&details.address_type, &details.self_destruct) {
         if let Some(ens_name) = &details.ens_name {
             spans.push(Span::raw(" | "));
             spans.push(Span::styled("ENS: ", theme.label()));
@@ -189,28 +548,12 @@ fn render_address_type_indicator(
 fn render_address_tabs(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
+    tabs: &[crate::ui::models::AddressTab],
     current_tab: &crate::ui::models::AddressTab,
     theme: &Theme,
 ) {
-    use crate::ui::models::AddressTab;
-
-    let tab_titles = vec![
-        "Details",
-        "Transactions",
-        "Account History",
-        "Token Transfers",
-        "Tokens",
-        "Internal Txns",
-    ];
-
-    let selected_index = match current_tab {
-        AddressTab::Details => 0,
-        AddressTab::Transactions => 1,
-        AddressTab::AccountHistory => 2,
-        AddressTab::TokenTransfers => 3,
-        AddressTab::Tokens => 4,
-        AddressTab::InternalTxns => 5,
-    };
+    let tab_titles: Vec<&str> = tabs.iter().map(|tab| tab.title()).collect();
+    let selected_index = tabs.iter().position(|tab| tab == current_tab).unwrap_or(0);
 
     let tabs = Tabs::new(tab_titles)
         .block(
@@ -230,6 +573,7 @@ fn render_address_details_tab(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
     details: &crate::ui::models::AddressDetails,
+    clickable_regions: &mut Vec<ClickableRegion>,
     theme: &Theme,
 ) {
     let chunks = Layout::default()
@@ -237,39 +581,47 @@ fn render_address_details_tab(
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
         .split(area);
 
+    let is_eoa = matches!(details.address_type, crate::ui::models::AddressType::EOA);
+
+    // Vanity badge for EOAs (local computation only)
+    let mut address_type_spans = vec![
+        Span::styled("Address Type: ", theme.label()),
+        Span::styled(
+            match details.address_type {
+                crate::ui::models::AddressType::EOA => "EOA (Wallet)",
+                crate::ui::models::AddressType::Contract => "Contract",
+                crate::ui::models::AddressType::Token => "Token Contract",
+                crate::ui::models::AddressType::MultiSig => "Multi-Sig Wallet",
+                crate::ui::models::AddressType::Exchange => "Exchange",
+                crate::ui::models::AddressType::Unknown => "Unknown",
+            },
+            theme.primary(),
+        ),
+    ];
+    if is_eoa && crate::blockchain::address_utils::is_vanity_address(&details.address) {
+        address_type_spans.push(Span::raw(" "));
+        address_type_spans.push(Span::styled(
+            " Vanity Address ",
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
     // Left column - Basic info
-    let left_content = Text::from(vec![
-        Line::from(vec![
-            Span::styled("Balance: ", theme.label()),
-            Span::styled(
-                {
-                    // Format balance with appropriate precision
-                    let balance_value = details.balance;
-                    if balance_value >= 1_000.0 {
-                        format!("{:.2} ETH", balance_value)
-                    } else if balance_value >= 1.0 {
-                        format!("{:.4} ETH", balance_value)
-                    } else {
-                        format!("{:.6} ETH", balance_value)
-                    }
-                },
-                theme.success(),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("Address Type: ", theme.label()),
-            Span::styled(
-                match details.address_type {
-                    crate::ui::models::AddressType::EOA => "EOA (Wallet)",
-                    crate::ui::models::AddressType::Contract => "Contract",
-                    crate::ui::models::AddressType::Token => "Token Contract",
-                    crate::ui::models::AddressType::MultiSig => "Multi-Sig Wallet",
-                    crate::ui::models::AddressType::Exchange => "Exchange",
-                    crate::ui::models::AddressType::Unknown => "Unknown",
-                },
-                theme.primary(),
-            ),
-        ]),
+    let mut left_lines = vec![Line::from(vec![
+        Span::styled("Balance: ", theme.label()),
+        Span::styled(format_eth_value(details.balance), theme.success()),
+    ])];
+    if is_eoa {
+        left_lines.push(Line::from(vec![
+            Span::styled("Pending Balance: ", theme.label()),
+            Span::styled(format_eth_value(details.pending_balance), theme.warning()),
+        ]));
+    }
+    left_lines.extend([
+        Line::from(address_type_spans),
         Line::from(vec![
             Span::styled("Token Count: ", theme.label()),
             Span::styled(details.token_count.to_string(), theme.normal()),
@@ -295,6 +647,7 @@ fn render_address_details_tab(
             Span::styled(format!("{}", details.total_gas_used), theme.normal()),
         ]),
     ]);
+    let left_content = Text::from(left_lines);
 
     let left_paragraph = Paragraph::new(left_content)
         .block(
@@ -321,18 +674,114 @@ fn render_address_details_tab(
         Line::from(""),
     ];
 
-    if let Some(creator) = &details.contract_creator {
+    if let Some(nonce) = &details.nonce_analysis {
+        let mut spans = vec![
+            Span::styled("Nonce: ", theme.label()),
+            Span::styled(
+                format!(
+                    "{} (pending: {}, gap: {})",
+                    nonce.current_nonce, nonce.pending_nonce, nonce.gap_count
+                ),
+                theme.normal(),
+            ),
+        ];
+        if nonce.has_gap {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(
+                " NONCE GAP ",
+                Style::default()
+                    .fg(ratatui::style::Color::Black)
+                    .bg(ratatui::style::Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        right_lines.push(Line::from(spans));
+    }
+
+    // Creator and creation tx are links to the address and transaction views
+    let links = [
+        (
+            "Contract Creator: ",
+            details.contract_creator.as_ref().map(|creator| {
+                (
+                    format_address_display(creator, KnownAddresses::lookup(creator), 0),
+                    AppState::AddressLookup,
+                    creator,
+                )
+            }),
+        ),
+        (
+            "Creation Tx: ",
+            details.creation_tx_hash.as_ref().map(|hash| {
+                (
+                    format_address_display(hash, None, 0),
+                    AppState::TransactionViewer,
+                    hash,
+                )
+            }),
+        ),
+    ];
+    let inner_width = chunks[1].width.saturating_sub(2);
+    for (label, link) in links {
+        let Some((text, target, value)) = link else {
+            continue;
+        };
+        // Rows are only known while nothing above has wrapped
+        if right_lines
+            .iter()
+            .all(|line| line.width() <= inner_width as usize)
+        {
+            let label_width = label.chars().count() as u16;
+            clickable_regions.push(ClickableRegion {
+                x: chunks[1].x + 1 + label_width,
+                y: chunks[1].y + 1 + right_lines.len() as u16,
+                width: (text.chars().count() as u16).min(inner_width.saturating_sub(label_width)),
+                action: ClickAction::Navigate(target, value.clone()),
+            });
+        }
         right_lines.push(Line::from(vec![
-            Span::styled("Contract Creator: ", theme.label()),
-            Span::styled(creator.clone(), theme.normal()),
+            Span::styled(label, theme.label()),
+            Span::styled(text, theme.address().add_modifier(Modifier::UNDERLINED)),
         ]));
     }
 
-    if let Some(creation_tx) = &details.creation_tx_hash {
+    if let Some(validator) = &details.validator {
+        let gwei_to_eth = |gwei: u64| gwei as f64 / 1e9;
+        let status_style = match validator.status {
+            crate::blockchain::ValidatorStatus::Active => theme.success(),
+            crate::blockchain::ValidatorStatus::Slashed => theme.error(),
+            _ => theme.warning(),
+        };
+        right_lines.push(Line::from(""));
+        right_lines.push(Line::from(Span::styled("Validator", theme.title())));
         right_lines.push(Line::from(vec![
-            Span::styled("Creation Tx: ", theme.label()),
-            Span::styled(creation_tx.clone(), theme.normal()),
+            Span::styled("Index: ", theme.label()),
+            Span::styled(validator.index.to_string(), theme.normal()),
+            Span::raw(" | "),
+            Span::styled("Status: ", theme.label()),
+            Span::styled(validator.status.label(), status_style),
         ]));
+        right_lines.push(Line::from(vec![
+            Span::styled("Balance: ", theme.label()),
+            Span::styled(
+                format!(
+                    "{:.4} ETH (effective {:.0} ETH)",
+                    gwei_to_eth(validator.balance_gwei),
+                    gwei_to_eth(validator.effective_balance_gwei)
+                ),
+                theme.success(),
+            ),
+        ]));
+        right_lines.push(Line::from(vec![
+            Span::styled("Activation Epoch: ", theme.label()),
+            Span::styled(validator.activation_epoch.to_string(), theme.normal()),
+        ]));
+        if validator.slashed {
+            right_lines.push(Line::from(Span::styled(
+                "⚠ This validator has been slashed",
+                theme.error(),
+            )));
+        }
     }
 
     let right_content = Text::from(right_lines);
@@ -349,14 +798,150 @@ fn render_address_details_tab(
     frame.render_widget(right_paragraph, chunks[1]);
 }
 
+/// Shortened transaction hash for table cells
+fn tx_hash_display(tx_hash: &str) -> String {
+    format!("{:.10}...", tx_hash)
+}
+
+/// Compact address cell text, preferring the user's label, then the name of
+/// the looked-up address and then a known address name
+fn table_address(
+    address: &str,
+    owner: &crate::ui::models::AddressDetails,
+    address_book: &HashMap<String, AddressLabel>,
+    max_len: usize,
+) -> String {
+    let name = address_book
+        .get(&address.to_lowercase())
+        .map(|label| label.name.as_str())
+        .or_else(|| {
+            address_display_name(owner).filter(|_| address.eq_ignore_ascii_case(&owner.address))
+        })
+        .or_else(|| KnownAddresses::lookup(address));
+    format_address_display(address, name, max_len)
+}
+
+/// Bold labelled addresses, in the label's own colour when it has one
+fn address_cell_style(
+    address: &str,
+    address_book: &HashMap<String, AddressLabel>,
+    style: Style,
+    is_selected: bool,
+) -> Style {
+    match address_book.get(&address.to_lowercase()) {
+        Some(label) if !is_selected => {
+            let style = style.add_modifier(Modifier::BOLD);
+            match label
+                .color
+                .as_deref()
+                .and_then(|color| color.parse::<Color>().ok())
+            {
+                Some(color) => style.fg(color),
+                None => style,
+            }
+        }
+        _ => style,
+    }
+}
+
+/// Render the inline filter bar above the Transactions table
+fn render_transaction_filter_bar(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    filter: &crate::ui::models::AddressFilterState,
+    filter_input: Option<(&str, usize)>,
+    theme: &Theme,
+) {
+    let value_text = filter
+        .min_value_eth
+        .map(|v| format!("{} ETH", v))
+        .unwrap_or_else(|| "any".to_string());
+    let method_text = filter
+        .method_filter
+        .clone()
+        .unwrap_or_else(|| "any".to_string());
+    let fields = [
+        ("Value ≥ ", value_text),
+        ("Method: ", method_text),
+        ("Direction: ", filter.direction.label().to_string()),
+    ];
+
+    let mut spans = Vec::new();
+    let mut cursor_x = None;
+    let mut offset = 0u16;
+    for (idx, (label, value)) in fields.into_iter().enumerate() {
+        if idx > 0 {
+            spans.push(Span::styled(" | ", theme.muted()));
+            offset += 3;
+        }
+        let focused = idx == filter.focused_field;
+        let value = match filter_input {
+            Some((input, cursor)) if focused => {
+                cursor_x = Some(offset + label.chars().count() as u16 + cursor as u16);
+                input.to_string()
+            }
+            _ => value,
+        };
+        offset += (label.chars().count() + value.chars().count()) as u16;
+        spans.push(Span::styled(label, theme.label()));
+        spans.push(Span::styled(
+            value,
+            if focused {
+                theme.selected()
+            } else {
+                theme.normal()
+            },
+        ));
+    }
+
+    let bar = Paragraph::new(Line::from(spans)).block(
+        Block::default()
+            .title("Filters (Tab: next field, Enter: edit, Esc: hide)")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(bar, area);
+
+    if let Some(x) = cursor_x {
+        frame.set_cursor_position((area.x + 1 + x, area.y + 1));
+    }
+}
+
 /// Render the Transactions tab
+#[allow(clippy::too_many_arguments)]
 fn render_address_transactions_tab(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
-    transactions: &[crate::ui::models::AddressTransaction],
+    owner: &crate::ui::models::AddressDetails,
+    address_book: &HashMap<String, AddressLabel>,
+    transactions: &[&crate::ui::models::AddressTransaction],
+    total_count: usize,
+    (page, total_pages): (u64, u64),
+    filter: &crate::ui::models::AddressFilterState,
+    filter_input: Option<(&str, usize)>,
     selected_index: usize,
+    scrollbar_state: &mut ScrollbarState,
+    clickable_regions: &mut Vec<ClickableRegion>,
     theme: &Theme,
 ) {
+    let area = if filter.bar_open {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+        render_transaction_filter_bar(frame, chunks[0], filter, filter_input, theme);
+        chunks[1]
+    } else {
+        area
+    };
+    let title = format!(
+        "Transactions ({}/{}) | Page {}/{} (n/p: page)",
+        transactions.len(),
+        total_count,
+        page,
+        total_pages
+    );
+
     // Header
     let header = Row::new(vec![
         Cell::from(Span::styled(
@@ -392,12 +977,17 @@ fn render_address_transactions_tab(
 
     // Show empty state if no transactions
     if transactions.is_empty() {
-        let empty_message = Paragraph::new("No transactions found")
+        let empty_text = if filter.is_active() {
+            "No transactions match the active filters"
+        } else {
+            "No transactions found"
+        };
+        let empty_message = Paragraph::new(empty_text)
             .style(theme.muted())
             .alignment(Alignment::Center)
             .block(
                 Block::default()
-                    .title("Transactions")
+                    .title(title)
                     .borders(Borders::ALL)
                     .border_type(BorderType::Double)
                     .border_style(theme.border()),
@@ -407,6 +997,8 @@ fn render_address_transactions_tab(
     }
 
     // Rows
+    let address_width = area.width as usize * 20 / 100;
+
     let rows: Vec<Row> = transactions
         .iter()
         .enumerate()
@@ -422,21 +1014,7 @@ fn render_address_transactions_tab(
                 }
             };
 
-            let age = chrono::DateTime::from_timestamp(tx.timestamp as i64, 0)
-                .map(|dt| {
-                    let now = chrono::Utc::now();
-                    let dur = now.signed_duration_since(dt);
-                    if dur.num_days() > 0 {
-                        format!("{}d ago", dur.num_days())
-                    } else if dur.num_hours() > 0 {
-                        format!("{}h ago", dur.num_hours())
-                    } else if dur.num_minutes() > 0 {
-                        format!("{}m ago", dur.num_minutes())
-                    } else {
-                        format!("{}s ago", dur.num_seconds())
-                    }
-                })
-                .unwrap_or_else(|| "—".to_string());
+            let age = format_age(tx.timestamp);
 
             // Simplify method to only the function name
             let method_display = if tx.method.is_empty() {
@@ -461,72 +1039,136 @@ fn render_address_transactions_tab(
             };
 
             Row::new(vec![
-                Cell::from(Span::styled(format!("{:.10}...", tx.tx_hash), hash_style)),
+                Cell::from(Span::styled(tx_hash_display(&tx.tx_hash), hash_style)),
                 Cell::from(Span::styled(method_display, row_style)),
                 Cell::from(Span::styled(tx.block.to_string(), row_style)),
                 Cell::from(Span::styled(age, theme.muted())),
-                Cell::from(Span::styled(format!("{:.10}...", tx.from), address_style)),
-                Cell::from(Span::styled(format!("{:.10}...", tx.to), address_style)),
                 Cell::from(Span::styled(
-                    format!("{:.4} ETH", tx.value),
-                    theme.warning(),
+                    table_address(&tx.from, owner, address_book, address_width),
+                    address_cell_style(&tx.from, address_book, address_style, is_selected),
                 )),
+                Cell::from(Span::styled(
+                    table_address(&tx.to, owner, address_book, address_width),
+                    address_cell_style(&tx.to, address_book, address_style, is_selected),
+                )),
+                Cell::from(Span::styled(format_eth_value(tx.value), theme.warning())),
             ])
             .style(row_style)
         })
         .collect();
 
-    let table = Table::new(
-        rows,
-        [
-            Constraint::Length(18),     // hash
-            Constraint::Length(14),     // method
-            Constraint::Length(10),     // block
-            Constraint::Length(10),     // age
-            Constraint::Percentage(20), // from
-            Constraint::Percentage(20), // to
-            Constraint::Length(14),     // amount
-        ],
-    )
-    .header(header)
-    .block(
-        Block::default()
-            .title("Transactions (Press Enter on row to view details, click addresses to navigate)")
-            .borders(Borders::ALL)
-            .border_type(BorderType::Double)
-            .border_style(theme.border()),
-    )
-    .column_spacing(1)
-    .highlight_style(theme.selected())
-    .highlight_symbol(" █ ");
+    let widths = [
+        Constraint::Length(18),     // hash
+        Constraint::Length(14),     // method
+        Constraint::Length(10),     // block
+        Constraint::Length(10),     // age
+        Constraint::Percentage(20), // from
+        Constraint::Percentage(20), // to
+        Constraint::Length(14),     // amount
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(format!(
+                "{} (Press Enter on row to view details, click addresses to navigate, f: filter)",
+                title
+            ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Double)
+                .border_style(theme.border()),
+        )
+        .column_spacing(1)
+        .highlight_style(theme.selected())
+        .highlight_symbol(TABLE_HIGHLIGHT_SYMBOL);
 
     let mut state = TableState::default();
     state.select(Some(selected_index));
     frame.render_stateful_widget(table, area, &mut state);
 
+    // Register the hash and address cells of the visible rows as clickable
+    let inner = area.inner(Margin {
+        vertical: 1,
+        horizontal: 1,
+    });
+    let symbol_width = TABLE_HIGHLIGHT_SYMBOL.chars().count() as u16;
+    let columns = Layout::horizontal(widths)
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(Rect {
+            x: inner.x + symbol_width,
+            width: inner.width.saturating_sub(symbol_width),
+            ..inner
+        });
+    let visible_rows = inner.height.saturating_sub(1) as usize;
+    for (row, tx) in transactions
+        .iter()
+        .skip(state.offset())
+        .take(visible_rows)
+        .enumerate()
+    {
+        let y = inner.y + 1 + row as u16;
+        let address_text_width = |address: &str| {
+            table_address(address, owner, address_book, address_width)
+                .chars()
+                .count()
+        };
+        let cells = [
+            (
+                0,
+                tx_hash_display(&tx.tx_hash).chars().count(),
+                AppState::TransactionViewer,
+                &tx.tx_hash,
+            ),
+            (
+                4,
+                address_text_width(&tx.from),
+                AppState::AddressLookup,
+                &tx.from,
+            ),
+            (
+                5,
+                address_text_width(&tx.to),
+                AppState::AddressLookup,
+                &tx.to,
+            ),
+        ];
+        for (column, text_width, target, value) in cells {
+            let cell = columns[column];
+            clickable_regions.push(ClickableRegion {
+                x: cell.x,
+                y,
+                width: cell.width.min(text_width as u16),
+                action: ClickAction::Navigate(target, value.clone()),
+            });
+        }
+    }
+
     // Render scrollbar
     let scrollbar = Scrollbar::default()
         .orientation(ScrollbarOrientation::VerticalRight)
         .begin_symbol(None)
         .end_symbol(None);
-    let mut scrollbar_state =
-        ScrollbarState::new(transactions.len().saturating_sub(1)).position(selected_index);
     frame.render_stateful_widget(
         scrollbar,
         area.inner(Margin {
             vertical: 1,
             horizontal: 1,
         }),
-        &mut scrollbar_state,
+        scrollbar_state,
     );
 }
 
 /// Render the Account History tab
+#[allow(clippy::too_many_arguments)]
 fn render_address_history_tab(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
+    owner: &crate::ui::models::AddressDetails,
+    address_book: &HashMap<String, AddressLabel>,
     history: &[crate::ui::models::AccountHistoryEntry],
     selected_index: usize,
+    scrollbar_state: &mut ScrollbarState,
     theme: &Theme,
 ) {
     // Show empty state if no history
@@ -569,6 +1211,8 @@ fn render_address_history_tab(
     ])
     .style(Style::default().add_modifier(Modifier::BOLD));
 
+    let address_width = area.width as usize * 25 / 100;
+
     let rows: Vec<Row> = history
         .iter()
         .enumerate()
@@ -597,10 +1241,13 @@ fn render_address_history_tab(
                 Cell::from(Span::styled(&entry.age, theme.muted())),
                 Cell::from(Span::styled(&entry.action, row_style)),
                 Cell::from(Span::styled(
-                    format!("{:.10}...", entry.from),
-                    address_style,
+                    table_address(&entry.from, owner, address_book, address_width),
+                    address_cell_style(&entry.from, address_book, address_style, is_selected),
+                )),
+                Cell::from(Span::styled(
+                    table_address(&entry.to, owner, address_book, address_width),
+                    address_cell_style(&entry.to, address_book, address_style, is_selected),
                 )),
-                Cell::from(Span::styled(format!("{:.10}...", entry.to), address_style)),
                 Cell::from(Span::styled(
                     format!("{:.10}...", entry.tx_hash),
                     hash_style,
@@ -641,24 +1288,26 @@ fn render_address_history_tab(
         .orientation(ScrollbarOrientation::VerticalRight)
         .begin_symbol(None)
         .end_symbol(None);
-    let mut scrollbar_state =
-        ScrollbarState::new(history.len().saturating_sub(1)).position(selected_index);
     frame.render_stateful_widget(
         scrollbar,
         area.inner(Margin {
             vertical: 1,
             horizontal: 1,
         }),
-        &mut scrollbar_state,
+        scrollbar_state,
     );
 }
 
 /// Render the Token Transfers tab
+#[allow(clippy::too_many_arguments)]
 fn render_token_transfers_tab(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
+    owner: &crate::ui::models::AddressDetails,
+    address_book: &HashMap<String, AddressLabel>,
     transfers: &[crate::ui::models::TokenTransfer],
     selected_index: usize,
+    scrollbar_state: &mut ScrollbarState,
     theme: &Theme,
 ) {
     // Show empty state if no transfers
@@ -705,6 +1354,8 @@ fn render_token_transfers_tab(
     ])
     .style(Style::default().add_modifier(Modifier::BOLD));
 
+    let address_width = area.width as usize * 20 / 100;
+
     let rows: Vec<Row> = transfers
         .iter()
         .enumerate()
@@ -742,12 +1393,12 @@ fn render_token_transfers_tab(
                     hash_style,
                 )),
                 Cell::from(Span::styled(
-                    format!("{:.10}...", transfer.from),
-                    address_style,
+                    table_address(&transfer.from, owner, address_book, address_width),
+                    address_cell_style(&transfer.from, address_book, address_style, is_selected),
                 )),
                 Cell::from(Span::styled(
-                    format!("{:.10}...", transfer.to),
-                    address_style,
+                    table_address(&transfer.to, owner, address_book, address_width),
+                    address_cell_style(&transfer.to, address_book, address_style, is_selected),
                 )),
                 Cell::from(Span::styled(&transfer.token_symbol, row_style)),
                 Cell::from(Span::styled(
@@ -791,15 +1442,13 @@ fn render_token_transfers_tab(
         .orientation(ScrollbarOrientation::VerticalRight)
         .begin_symbol(None)
         .end_symbol(None);
-    let mut scrollbar_state =
-        ScrollbarState::new(transfers.len().saturating_sub(1)).position(selected_index);
     frame.render_stateful_widget(
         scrollbar,
         area.inner(Margin {
             vertical: 1,
             horizontal: 1,
         }),
-        &mut scrollbar_state,
+        scrollbar_state,
     );
 }
 
@@ -809,6 +1458,7 @@ fn render_tokens_tab(
     area: ratatui::layout::Rect,
     tokens: &[crate::ui::models::TokenInfo],
     selected_index: usize,
+    scrollbar_state: &mut ScrollbarState,
     theme: &Theme,
 ) {
     // Show empty state if no tokens
@@ -827,6 +1477,19 @@ fn render_tokens_tab(
         return;
     }
 
+    // NFT summary row when the address holds any ERC-721/ERC-1155 tokens
+    let has_nfts = tokens.iter().any(|t| t.token_type.is_nft());
+    let area = if has_nfts {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+        render_nft_summary(frame, chunks[0], tokens, theme);
+        chunks[1]
+    } else {
+        area
+    };
+
     let header = Row::new(vec![
         Cell::from(Span::styled(
             "Symbol",
@@ -852,6 +1515,10 @@ fn render_tokens_tab(
             "Value USD",
             theme.label().add_modifier(Modifier::BOLD),
         )),
+        Cell::from(Span::styled(
+            "Floor Price",
+            theme.label().add_modifier(Modifier::BOLD),
+        )),
     ])
     .style(Style::default().add_modifier(Modifier::BOLD));
 
@@ -907,12 +1574,18 @@ fn render_tokens_tab(
                 theme.success()
             };
 
+            let floor_price_text = match token.floor_price {
+                Some(price) => format!("{:.4} ETH", price),
+                None if token.token_type.is_nft() => "—".to_string(),
+                None => String::new(),
+            };
+
             Row::new(vec![
                 Cell::from(Span::styled(&token.symbol, symbol_style)),
                 Cell::from(Span::styled(&token.name, name_style)),
                 Cell::from(Span::styled(token_type_text, type_style)),
                 Cell::from(Span::styled(
-                    format!("{:.10}...", token.contract_address),
+                    format_address_display(&token.contract_address, None, 18),
                     address_style,
                 )),
                 Cell::from(Span::styled(format!("{:.4}", token.balance), balance_style)),
@@ -920,6 +1593,7 @@ fn render_tokens_tab(
                     format!("${:.2}", token.value_usd),
                     value_style,
                 )),
+                Cell::from(Span::styled(floor_price_text, value_style)),
             ])
             .style(row_style)
         })
@@ -934,12 +1608,16 @@ fn render_tokens_tab(
             Constraint::Length(18), // contract
             Constraint::Length(15), // balance
             Constraint::Length(12), // value
+            Constraint::Length(14), // floor price
         ],
     )
     .header(header)
     .block(
         Block::default()
-            .title("Tokens (Click contract address to view details)")
+            .title(format!(
+                "Tokens - ${:.2} total (i: token info, g on an NFT for gallery, Enter on ERC-721: metadata)",
+                tokens.iter().map(|t| t.value_usd).sum::<f64>()
+            ))
             .borders(Borders::ALL)
             .border_type(BorderType::Double)
             .border_style(theme.border()),
@@ -957,24 +1635,65 @@ fn render_tokens_tab(
         .orientation(ScrollbarOrientation::VerticalRight)
         .begin_symbol(None)
         .end_symbol(None);
-    let mut scrollbar_state =
-        ScrollbarState::new(tokens.len().saturating_sub(1)).position(selected_index);
     frame.render_stateful_widget(
         scrollbar,
         area.inner(Margin {
             vertical: 1,
             horizontal: 1,
         }),
-        &mut scrollbar_state,
+        scrollbar_state,
     );
 }
 
+/// Render the NFT summary row for the Tokens tab
+fn render_nft_summary(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    tokens: &[crate::ui::models::TokenInfo],
+    theme: &Theme,
+) {
+    use crate::ui::models::TokenType;
+
+    let erc721: Vec<_> = tokens
+        .iter()
+        .filter(|t| matches!(t.token_type, TokenType::ERC721))
+        .collect();
+    let erc721_count: f64 = erc721.iter().map(|t| t.balance).sum();
+    let erc1155_count: f64 = tokens
+        .iter()
+        .filter(|t| matches!(t.token_type, TokenType::ERC1155))
+        .map(|t| t.balance)
+        .sum();
+
+    let summary = Line::from(vec![
+        Span::styled(format!("{}", erc721_count as u64), theme.primary()),
+        Span::styled(" ERC-721 NFTs across ", theme.label()),
+        Span::styled(format!("{}", erc721.len()), theme.primary()),
+        Span::styled(" collections", theme.label()),
+        Span::raw(" | "),
+        Span::styled(format!("{}", erc1155_count as u64), theme.primary()),
+        Span::styled(" ERC-1155 items", theme.label()),
+    ]);
+
+    let paragraph = Paragraph::new(summary).block(
+        Block::default()
+            .title("NFT Summary")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(paragraph, area);
+}
+
 /// Render the Internal Transactions tab
+#[allow(clippy::too_many_arguments)]
 fn render_internal_txns_tab(
     frame: &mut Frame,
     area: ratatui::layout::Rect,
+    owner: &crate::ui::models::AddressDetails,
+    address_book: &HashMap<String, AddressLabel>,
     internal_txns: &[crate::ui::models::InternalTransaction],
     selected_index: usize,
+    scrollbar_state: &mut ScrollbarState,
     theme: &Theme,
 ) {
     // Show empty state if no internal transactions
@@ -1021,6 +1740,8 @@ fn render_internal_txns_tab(
     ])
     .style(Style::default().add_modifier(Modifier::BOLD));
 
+    let address_width = area.width as usize * 25 / 100;
+
     let rows: Vec<Row> = internal_txns
         .iter()
         .enumerate()
@@ -1052,9 +1773,15 @@ fn render_internal_txns_tab(
                 )),
                 Cell::from(Span::styled(&tx.tx_type, row_style)),
                 Cell::from(Span::styled(tx.block.to_string(), row_style)),
-                Cell::from(Span::styled(format!("{:.10}...", tx.from), address_style)),
-                Cell::from(Span::styled(format!("{:.10}...", tx.to), address_style)),
-                Cell::from(Span::styled(format!("{:.4} ETH", tx.value), row_style)),
+                Cell::from(Span::styled(
+                    table_address(&tx.from, owner, address_book, address_width),
+                    address_cell_style(&tx.from, address_book, address_style, is_selected),
+                )),
+                Cell::from(Span::styled(
+                    table_address(&tx.to, owner, address_book, address_width),
+                    address_cell_style(&tx.to, address_book, address_style, is_selected),
+                )),
+                Cell::from(Span::styled(format_eth_value(tx.value), row_style)),
             ])
             .style(row_style)
         })
@@ -1092,14 +1819,215 @@ fn render_internal_txns_tab(
         .orientation(ScrollbarOrientation::VerticalRight)
         .begin_symbol(None)
         .end_symbol(None);
-    let mut scrollbar_state =
-        ScrollbarState::new(internal_txns.len().saturating_sub(1)).position(selected_index);
     frame.render_stateful_widget(
         scrollbar,
         area.inner(Margin {
             vertical: 1,
             horizontal: 1,
         }),
-        &mut scrollbar_state,
+        scrollbar_state,
+    );
+}
+
+/// Render the ERC-20 allowances tab
+fn render_approvals_tab(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    data: &crate::ui::models::CompleteAddressData,
+    address_book: &HashMap<String, AddressLabel>,
+    scrollbar_state: &mut ScrollbarState,
+    theme: &Theme,
+) {
+    let selected_index = data.selected_approval_index;
+    let header = Row::new(
+        ["Token", "Spender", "Allowance", "Block"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+
+    let rows: Vec<Row> = data
+        .approvals
+        .iter()
+        .enumerate()
+        .map(|(idx, approval)| {
+            let is_selected = idx == selected_index;
+            let row_style = if is_selected {
+                theme.selected()
+            } else {
+                Style::default().fg(theme.foreground)
+            };
+            let token = data.held_token(&approval.token_address);
+            let token_text = match token {
+                Some(token) => token.symbol.clone(),
+                None => format_address_display(&approval.token_address, None, 0),
+            };
+            let allowance = if approval.is_unlimited() {
+                Span::styled("Unlimited", theme.warning())
+            } else {
+                let text = match token {
+                    Some(token) => {
+                        ethers::utils::format_units(approval.value, token.decimals as u32)
+                            .unwrap_or_else(|_| approval.value.to_string())
+                    }
+                    None => format!("{} (raw)", approval.value),
+                };
+                Span::raw(text)
+            };
+            Row::new(vec![
+                Cell::from(token_text),
+                Cell::from(table_address(
+                    &approval.spender,
+                    &data.details,
+                    address_book,
+                    24,
+                ))
+                .style(address_cell_style(
+                    &approval.spender,
+                    address_book,
+                    row_style,
+                    is_selected,
+                )),
+                Cell::from(allowance),
+                Cell::from(approval.block_number.to_string()),
+            ])
+            .style(row_style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(14),
+            Constraint::Length(26),
+            Constraint::Min(18),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(format!(
+                "Token Approvals ({}) - r: copy revoke data",
+                data.approvals.len()
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(theme.border()),
+    )
+    .column_spacing(1)
+    .highlight_style(theme.selected())
+    .highlight_symbol(TABLE_HIGHLIGHT_SYMBOL);
+
+    let mut state = TableState::default();
+    state.select(Some(selected_index));
+    frame.render_stateful_widget(table, area, &mut state);
+
+    let scrollbar = Scrollbar::default()
+        .orientation(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None);
+    frame.render_stateful_widget(
+        scrollbar,
+        area.inner(ratatui::layout::Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+        scrollbar_state,
+    );
+}
+
+/// Render the beacon chain withdrawals tab
+fn render_withdrawals_tab(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    withdrawals: &[crate::blockchain::BeaconWithdrawal],
+    selected_index: usize,
+    scrollbar_state: &mut ScrollbarState,
+    theme: &Theme,
+) {
+    let header = Row::new(
+        ["Block", "Age", "Validator Index", "Amount"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+
+    let rows: Vec<Row> = withdrawals
+        .iter()
+        .enumerate()
+        .map(|(idx, withdrawal)| {
+            let row_style = if idx == selected_index {
+                theme.selected()
+            } else {
+                Style::default().fg(theme.foreground)
+            };
+            Row::new(vec![
+                Cell::from(withdrawal.block_number.to_string()),
+                Cell::from(format_age(withdrawal.timestamp)),
+                Cell::from(withdrawal.validator_index.to_string()),
+                Cell::from(format!("{:.6} ETH", withdrawal.amount_eth())),
+            ])
+            .style(row_style)
+        })
+        .collect();
+
+    let total: f64 = withdrawals.iter().map(|w| w.amount_eth()).sum();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(16),
+            Constraint::Min(18),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(format!(
+                "Beacon Chain Withdrawals ({} totalling {:.4} ETH)",
+                withdrawals.len(),
+                total
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Double)
+            .border_style(theme.border()),
+    )
+    .column_spacing(1)
+    .highlight_style(theme.selected())
+    .highlight_symbol(" █ ");
+
+    let mut state = TableState::default();
+    state.select(Some(selected_index));
+    frame.render_stateful_widget(table, area, &mut state);
+
+    let scrollbar = Scrollbar::default()
+        .orientation(ScrollbarOrientation::VerticalRight)
+        .begin_symbol(None)
+        .end_symbol(None);
+    frame.render_stateful_widget(
+        scrollbar,
+        area.inner(Margin {
+            vertical: 1,
+            horizontal: 1,
+        }),
+        scrollbar_state,
     );
 }
+
+/// Time since a unix timestamp, e.g. `3h ago`
+fn format_age(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| {
+            let dur = chrono::Utc::now().signed_duration_since(dt);
+            if dur.num_days() > 0 {
+                format!("{}d ago", dur.num_days())
+            } else if dur.num_hours() > 0 {
+                format!("{}h ago", dur.num_hours())
+            } else if dur.num_minutes() > 0 {
+                format!("{}m ago", dur.num_minutes())
+            } else {
+                format!("{}s ago", dur.num_seconds())
+            }
+        })
+        .unwrap_or_else(|| "—".to_string())
+}
@@ -2,11 +2,12 @@
 //!
 //! This module contains the block explorer screen implementation.
 
+use crate::ui::models::{BlockDetails, CompleteBlockData};
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
     Frame,
 };
 
@@ -32,27 +33,46 @@ pub fn render_block_explorer(frame: &mut Frame, app: &App, theme: &Theme) {
         );
     frame.render_widget(title, chunks[0]);
 
-    // Input field for block number
+    // Input field for block number, or a start-end range in range analysis mode
+    let input_label = if app.block_range_mode {
+        "Range Analysis: Enter Start-End Block Numbers (r: single block)"
+    } else {
+        "Enter Block Number, Hash or Start-End to list blocks (r: range analysis)"
+    };
     crate::ui::components::render_input_field(
         frame,
         chunks[1],
         theme,
-        "Enter Block Number or Hash",
+        input_label,
         app.get_input(),
-        app.cursor_position,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
         app.input_mode == crate::ui::InputMode::Editing,
     );
 
     // Content area
+    if app.block_range_mode {
+        render_range_analysis(frame, chunks[2], app, theme);
+        return;
+    }
+
+    if let (Some(block), false) = (&app.block_data, app.is_loading("block_search")) {
+        render_block_details(frame, chunks[2], block, app.block_rewards.as_ref(), theme);
+        return;
+    }
+
     let content = if app.is_loading("block_search") {
         Text::from("Loading block information...")
-    } else if let Some(error) = &app.error_message {
+    } else if let Some(error) = app.error_message() {
         Text::from(vec![
             Line::from(Span::styled("Error: ", theme.error())),
-            Line::from(error.clone()),
+            Line::from(error),
         ])
+    } else if !app.block_range_data.is_empty() {
+        render_block_list(frame, chunks[2], app, theme);
+        return;
     } else {
-        Text::from("Enter a block number or hash to search")
+        Text::from("Enter a block number or hash to search, or a range such as 19000000-19000049")
     };
 
     let content_paragraph = Paragraph::new(content)
@@ -66,3 +86,405 @@ pub fn render_block_explorer(frame: &mut Frame, app: &App, theme: &Theme) {
 
     frame.render_widget(content_paragraph, chunks[2]);
 }
+
+/// Render the details of a looked-up block: header, stats and transactions
+fn render_block_details(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    block: &CompleteBlockData,
+    rewards: Option<&crate::blockchain::BlockRewardInfo>,
+    theme: &Theme,
+) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(12), Constraint::Min(0)])
+        .split(area);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(rows[0]);
+
+    render_block_header(frame, top[0], &block.details, theme);
+    render_block_stats(frame, top[1], block, rewards, theme);
+    render_block_transactions(frame, rows[1], block, theme);
+}
+
+/// Render the block header fields in two columns, with the gas usage below
+fn render_block_header(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    block: &BlockDetails,
+    theme: &Theme,
+) {
+    let outer = Block::default()
+        .title(format!("Block #{} (</>: previous/next)", block.number))
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+    let inner = outer.inner(area);
+    frame.render_widget(outer, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7), // Header fields
+            Constraint::Length(1), // Gas used
+            Constraint::Length(1), // Gas bar
+            Constraint::Min(0),
+        ])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(rows[0]);
+
+    let timestamp = chrono::DateTime::from_timestamp(block.timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "Unknown".to_string());
+    let field = |label: &'static str, value: String, style| {
+        Line::from(vec![
+            Span::styled(label, theme.label()),
+            Span::styled(value, style),
+        ])
+    };
+
+    let hashes = Text::from(vec![
+        field("Block Height: ", block.number.to_string(), theme.primary()),
+        field("Hash: ", block.hash.clone(), theme.normal()),
+        field("Parent Hash: ", block.parent_hash.clone(), theme.normal()),
+        field("State Root: ", block.state_root.clone(), theme.normal()),
+        field(
+            "Transactions Root: ",
+            block.transactions_root.clone(),
+            theme.normal(),
+        ),
+        field(
+            "Receipts Root: ",
+            block.receipts_root.clone(),
+            theme.normal(),
+        ),
+        field("Extra Data: ", block.extra_data.clone(), theme.muted()),
+    ]);
+    frame.render_widget(Paragraph::new(hashes), columns[0]);
+
+    let properties = Text::from(vec![
+        field("Timestamp: ", timestamp, theme.normal()),
+        field("Fee Recipient: ", block.miner.clone(), theme.info()),
+        field("Difficulty: ", block.difficulty.clone(), theme.normal()),
+        field(
+            "Total Difficulty: ",
+            block
+                .total_difficulty
+                .clone()
+                .unwrap_or_else(|| "N/A".to_string()),
+            theme.normal(),
+        ),
+        field("Size: ", format!("{} bytes", block.size), theme.normal()),
+        field("Gas Limit: ", block.gas_limit.to_string(), theme.normal()),
+    ]);
+    frame.render_widget(Paragraph::new(properties), columns[1]);
+
+    let gas_used = Line::from(vec![
+        Span::styled("Gas Used: ", theme.label()),
+        Span::styled(block.gas_used.to_string(), theme.normal()),
+    ]);
+    frame.render_widget(Paragraph::new(gas_used), rows[1]);
+
+    crate::ui::components::render_gas_bar(frame, rows[2], block.gas_used, block.gas_limit, theme);
+}
+
+/// Render transaction, uncle and fee statistics, followed by the rewards
+fn render_block_stats(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    block: &CompleteBlockData,
+    rewards: Option<&crate::blockchain::BlockRewardInfo>,
+    theme: &Theme,
+) {
+    let outer = Block::default()
+        .title("Stats")
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+    let inner = outer.inner(area);
+    frame.render_widget(outer, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(0)])
+        .split(inner);
+
+    let stat = |label: &'static str, value: String, style| {
+        Line::from(vec![
+            Span::styled(label, theme.label()),
+            Span::styled(value, style),
+        ])
+    };
+    let stats = Text::from(vec![
+        stat(
+            "Transactions: ",
+            block.details.transactions.len().to_string(),
+            theme.normal(),
+        ),
+        stat(
+            "Uncles: ",
+            block.details.uncle_count.to_string(),
+            theme.normal(),
+        ),
+        stat(
+            "Base Fee: ",
+            block
+                .details
+                .base_fee_gwei()
+                .map(|fee| format!("{:.2} gwei", fee))
+                .unwrap_or_else(|| "N/A (pre-London)".to_string()),
+            theme.normal(),
+        ),
+        stat(
+            "Burned: ",
+            format!("{:.6} ETH", block.details.burned_eth()),
+            theme.warning(),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(stats), rows[0]);
+
+    render_block_rewards(frame, rows[1], block.details.number, rewards, theme);
+}
+
+/// Render the block's transactions with the selected one highlighted
+fn render_block_transactions(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    block: &CompleteBlockData,
+    theme: &Theme,
+) {
+    let header = Row::new(vec!["Hash", "From", "To", "Value", "Gas Used"])
+        .style(theme.header())
+        .height(1);
+
+    let rows: Vec<Row> = block
+        .details
+        .transactions
+        .iter()
+        .map(|tx| {
+            let to = if tx.to.is_empty() {
+                "Contract Creation".to_string()
+            } else {
+                tx.to.clone()
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(format!("{:.18}...", tx.hash), theme.primary())),
+                Cell::from(Span::styled(tx.from.clone(), theme.info())),
+                Cell::from(Span::styled(to, theme.info())),
+                Cell::from(Span::styled(
+                    format!("{:.4} ETH", tx.value),
+                    theme.warning(),
+                )),
+                Cell::from(Span::styled(
+                    if tx.gas_used > 0 {
+                        tx.gas_used.to_string()
+                    } else {
+                        "-".to_string()
+                    },
+                    theme.normal(),
+                )),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(21),     // hash
+            Constraint::Percentage(28), // from
+            Constraint::Percentage(28), // to
+            Constraint::Length(14),     // value
+            Constraint::Length(10),     // gas used
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title(format!(
+                "Transactions ({}) (Enter: view transaction)",
+                block.details.transactions.len()
+            ))
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    )
+    .column_spacing(1)
+    .highlight_style(theme.selected())
+    .highlight_symbol(" █ ");
+
+    let mut state = TableState::default();
+    if !block.details.transactions.is_empty() {
+        state.select(Some(block.selected_transaction_index));
+    }
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+/// Render the miner and uncle rewards below the block fields
+fn render_block_rewards(
+    frame: &mut Frame,
+    area: ratatui::layout::Rect,
+    block_number: u64,
+    rewards: Option<&crate::blockchain::BlockRewardInfo>,
+    theme: &Theme,
+) {
+    let mut lines = vec![Line::from(Span::styled("Block Rewards", theme.title()))];
+
+    match rewards {
+        _ if block_number > crate::blockchain::service::MERGE_BLOCK => {
+            lines.push(Line::from(Span::styled("N/A (PoS block)", theme.muted())));
+        }
+        Some(rewards) => {
+            lines.push(Line::from(vec![
+                Span::styled("Miner: ", theme.label()),
+                Span::styled(&rewards.block_miner, theme.info()),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Total Reward: ", theme.label()),
+                Span::styled(wei_to_eth_text(&rewards.block_reward), theme.warning()),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("Uncle Inclusion Rewards: ", theme.label()),
+                Span::styled(
+                    wei_to_eth_text(&rewards.uncle_inclusion_rewards),
+                    theme.warning(),
+                ),
+            ]));
+            for (miner, reward) in rewards.uncle_miners.iter().zip(&rewards.uncle_rewards) {
+                lines.push(Line::from(vec![
+                    Span::styled("  Uncle ", theme.muted()),
+                    Span::styled(miner.as_str(), theme.normal()),
+                    Span::raw(" "),
+                    Span::styled(wei_to_eth_text(reward), theme.warning()),
+                ]));
+            }
+        }
+        None => {
+            lines.push(Line::from(Span::styled(
+                "Unavailable (requires an Etherscan API key)",
+                theme.muted(),
+            )));
+        }
+    }
+
+    frame.render_widget(Paragraph::new(Text::from(lines)), area);
+}
+
+/// Format a decimal wei amount as ETH
+fn wei_to_eth_text(wei: &str) -> String {
+    match ethers::types::U256::from_dec_str(wei) {
+        Ok(value) => format!("{:.6} ETH", value.as_u128() as f64 / 1e18),
+        Err(_) => format!("{} wei", wei),
+    }
+}
+
+/// Render the blocks of a `start-end` lookup
+fn render_block_list(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, theme: &Theme) {
+    let header =
+        Row::new(["Block", "Hash", "Txns", "Gas Used", "Miner", "Time (UTC)"]).style(theme.label());
+    let rows: Vec<Row> = app
+        .block_range_data
+        .iter()
+        .map(|block| {
+            let gas_percent = if block.gas_limit > 0 {
+                block.gas_used as f64 / block.gas_limit as f64 * 100.0
+            } else {
+                0.0
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(block.number.to_string(), theme.block_number())),
+                Cell::from(Span::styled(
+                    format!("{:.12}...", block.hash),
+                    theme.transaction_hash(),
+                )),
+                Cell::from(block.transaction_count.to_string()),
+                Cell::from(format!("{:.1}%", gas_percent)),
+                Cell::from(Span::styled(
+                    format!("{:.12}...", block.miner),
+                    theme.address(),
+                )),
+                Cell::from(
+                    chrono::DateTime::from_timestamp(block.timestamp as i64, 0)
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                        .unwrap_or_default(),
+                ),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(16),
+            Constraint::Length(6),
+            Constraint::Length(9),
+            Constraint::Length(16),
+            Constraint::Min(19),
+        ],
+    )
+    .header(header)
+    .highlight_style(theme.selected())
+    .block(
+        Block::default()
+            .title(format!(
+                "Blocks ({}) (Enter: view block)",
+                app.block_range_data.len()
+            ))
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    let mut state = TableState::default().with_selected(Some(app.block_range_index));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+/// Render the statistics of an analysed block range
+fn render_range_analysis(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, theme: &Theme) {
+    let content = if app.is_loading("block_range") {
+        Text::from("Fetching blocks in range...")
+    } else if let Some(error) = app.error_message() {
+        Text::from(vec![
+            Line::from(Span::styled("Error: ", theme.error())),
+            Line::from(error),
+        ])
+    } else if let Some(summary) = &app.block_range_summary {
+        let gwei = |wei: u64| format!("{:.2} gwei", wei as f64 / 1e9);
+        let stat = |label: &'static str, value: String| {
+            Line::from(vec![
+                Span::styled(label, theme.label()),
+                Span::styled(value, theme.normal()),
+            ])
+        };
+        Text::from(vec![
+            stat("Blocks: ", summary.block_count.to_string()),
+            stat(
+                "Total Transactions: ",
+                summary.total_transactions.to_string(),
+            ),
+            stat("Average Gas Used: ", format!("{:.0}", summary.avg_gas_used)),
+            stat(
+                "Average Block Time: ",
+                format!("{:.2}s", summary.avg_block_time_secs),
+            ),
+            stat("Min Base Fee: ", gwei(summary.min_base_fee)),
+            stat("Max Base Fee: ", gwei(summary.max_base_fee)),
+            stat(
+                "Total ETH Burned: ",
+                format!("{:.6} ETH", summary.total_eth_burned),
+            ),
+        ])
+    } else {
+        Text::from("Enter a block range such as 19000000-19000099 (up to 100 blocks)")
+    };
+
+    let paragraph = Paragraph::new(content)
+        .block(
+            Block::default()
+                .title("Range Analysis")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, area);
+}
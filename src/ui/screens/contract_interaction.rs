@@ -2,19 +2,22 @@
 //!
 //! This module contains the contract interaction screen implementation.
 
+use crate::ui::models::{AbiFunction, CallOutcome, ContractInteractionState};
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
 /// Render the contract interaction screen
-pub fn render_contract_interaction(frame: &mut Frame, _app: &App, theme: &Theme) {
+pub fn render_contract_interaction(frame: &mut Frame, app: &App, theme: &Theme) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
+            Constraint::Length(3), // Input
             Constraint::Min(0),    // Content area
         ])
         .split(frame.area());
@@ -30,14 +33,268 @@ pub fn render_contract_interaction(frame: &mut Frame, _app: &App, theme: &Theme)
         );
     frame.render_widget(title, main_chunks[0]);
 
-    // Content area
-    let content = Paragraph::new("Contract Interaction screen - Coming soon")
-        .style(theme.normal())
+    let state = &app.contract_interaction_state;
+    let editing_arg = state
+        .function()
+        .filter(|_| state.form_open && app.input_mode == crate::ui::InputMode::Editing)
+        .and_then(|function| function.inputs.get(app.current_list_index));
+    let label = match editing_arg {
+        _ if state.abi_path_prompt => "Enter ABI JSON file path:".to_string(),
+        Some((name, kind)) => format!("Enter {} ({}):", name, kind),
+        _ => "Enter contract address (/ to edit, l: load ABI file):".to_string(),
+    };
+    crate::ui::components::render_input_field(
+        frame,
+        main_chunks[1],
+        theme,
+        &label,
+        &app.input,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
+        app.input_mode == crate::ui::InputMode::Editing,
+    );
+
+    if let Some(message) = app
+        .loading_message("contract_abi")
+        .or_else(|| app.loading_message("contract_call"))
+    {
+        crate::ui::components::render_loading(
+            frame,
+            main_chunks[2],
+            theme,
+            message,
+            app.spinner_frame,
+        );
+        return;
+    }
+
+    let Some(ref address) = state.contract_address else {
+        let content = Paragraph::new(
+            "Load a verified contract to list its functions\n\nPress 'l' to load an ABI file, 's' to simulate a transaction",
+        )
+        .style(theme.muted())
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(theme.secondary()),
         );
-    frame.render_widget(content, main_chunks[1]);
+        frame.render_widget(content, main_chunks[2]);
+        return;
+    };
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(main_chunks[2]);
+
+    // Left panel - function list
+    let selected = state
+        .selected_function
+        .min(state.functions.len().saturating_sub(1));
+    let items: Vec<ListItem> = state
+        .functions
+        .iter()
+        .map(|function| {
+            let (kind, style) = if function.is_view {
+                ("read  ", theme.info())
+            } else {
+                ("write ", theme.warning())
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(kind, style),
+                Span::styled(function.name.clone(), theme.normal()),
+            ]))
+        })
+        .collect();
+    let mut list_state = ListState::default().with_selected(Some(selected));
+    let functions = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("Functions ({}) ↑/↓", state.functions.len()))
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .highlight_style(theme.selected());
+    frame.render_stateful_widget(functions, body[0], &mut list_state);
+
+    // Right panel - selected function, or its argument form
+    let (title, details) = match state.functions.get(selected) {
+        Some(function) if state.form_open => (
+            "Call (↑/↓ select, Enter edit/submit, Esc close)",
+            function_form(function, state, app.current_list_index, theme),
+        ),
+        Some(function) => (
+            "Function (Enter: call)",
+            function_details(function, address, theme),
+        ),
+        None => (
+            "Function",
+            Text::from(Span::styled("The ABI has no functions", theme.muted())),
+        ),
+    };
+    let details = Paragraph::new(details).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(details, body[1]);
+
+    // Signature tooltip for the hovered function
+    if let Some((column, row)) = app.mouse_position {
+        let list_inner = body[0].inner(ratatui::layout::Margin::new(1, 1));
+        let hovered = list_inner
+            .contains((column, row).into())
+            .then(|| list_state.offset() + (row - list_inner.y) as usize)
+            .and_then(|index| state.functions.get(index));
+        if let Some(function) = hovered {
+            render_signature_tooltip(frame, &function.signature(), column, row, theme);
+        }
+    }
+}
+
+/// Inputs, outputs and call kind of a function
+fn function_details<'a>(function: &AbiFunction, address: &str, theme: &Theme) -> Text<'a> {
+    let mut lines = vec![
+        Line::from(Span::styled(function.signature(), theme.title())),
+        Line::from(vec![
+            Span::styled("Contract: ", theme.label()),
+            Span::styled(address.to_string(), theme.address()),
+        ]),
+        Line::from(vec![
+            Span::styled("Kind: ", theme.label()),
+            if function.is_view {
+                Span::styled("Read (view)", theme.info())
+            } else {
+                Span::styled("Write (transaction)", theme.warning())
+            },
+        ]),
+        Line::from(""),
+        Line::from(Span::styled("Inputs", theme.label())),
+    ];
+    if function.inputs.is_empty() {
+        lines.push(Line::from(Span::styled("  none", theme.muted())));
+    }
+    for (name, kind) in &function.inputs {
+        let name = if name.is_empty() { "_" } else { name.as_str() };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", kind), theme.info()),
+            Span::styled(name.to_string(), theme.normal()),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("Outputs", theme.label())));
+    lines.push(Line::from(Span::styled(
+        if function.outputs.is_empty() {
+            "  none".to_string()
+        } else {
+            format!("  ({})", function.outputs.join(", "))
+        },
+        theme.normal(),
+    )));
+    Text::from(lines)
+}
+
+/// Argument fields, submit button and the outcome of the last submission
+fn function_form<'a>(
+    function: &AbiFunction,
+    state: &ContractInteractionState,
+    focused: usize,
+    theme: &Theme,
+) -> Text<'a> {
+    let mut lines = vec![
+        Line::from(Span::styled(function.signature(), theme.title())),
+        Line::from(""),
+    ];
+    for (index, (name, kind)) in function.inputs.iter().enumerate() {
+        let name = if name.is_empty() { "_" } else { name.as_str() };
+        let row_style = if index == focused {
+            theme.selected()
+        } else {
+            theme.normal()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{} ", name), row_style),
+            Span::styled(format!("({}): ", kind), theme.info()),
+            Span::styled(state.field(index).to_string(), theme.normal()),
+        ]));
+    }
+    let button = if function.is_view {
+        "[ Call ]"
+    } else {
+        "[ Encode & estimate gas ]"
+    };
+    let button_style = if focused + 1 == state.form_rows() {
+        theme.selected()
+    } else {
+        theme.secondary()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(button, button_style)));
+
+    match &state.outcome {
+        Some(CallOutcome::Read(values)) => {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Returned", theme.label())));
+            if values.is_empty() {
+                lines.push(Line::from(Span::styled("  nothing", theme.muted())));
+            }
+            for (kind, value) in values {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {} ", kind), theme.info()),
+                    Span::styled(value.clone(), theme.success()),
+                ]));
+            }
+        }
+        Some(CallOutcome::Write { calldata, gas }) => {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Calldata", theme.label())));
+            lines.push(Line::from(Span::styled(calldata.clone(), theme.normal())));
+            lines.push(Line::from(vec![
+                Span::styled("Estimated gas: ", theme.label()),
+                match gas {
+                    Ok(gas) => Span::styled(gas.to_string(), theme.success()),
+                    Err(reason) => Span::styled(reason.clone(), theme.error()),
+                },
+            ]));
+        }
+        None => {}
+    }
+    Text::from(lines)
+}
+
+/// Draw the full signature in a small box just below the mouse
+fn render_signature_tooltip(
+    frame: &mut Frame,
+    signature: &str,
+    column: u16,
+    row: u16,
+    theme: &Theme,
+) {
+    let screen = frame.area();
+    let width = (signature.chars().count() as u16 + 2).min(screen.width);
+    let area = Rect {
+        x: column.min(screen.right().saturating_sub(width)),
+        y: if row + 4 <= screen.bottom() {
+            row + 1
+        } else {
+            row.saturating_sub(3)
+        },
+        width,
+        height: 3,
+    }
+    .intersection(screen);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(signature.to_string())
+            .style(theme.normal())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.primary()),
+            ),
+        area,
+    );
 }
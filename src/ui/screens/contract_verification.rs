@@ -2,19 +2,88 @@
 //!
 //! This module contains the contract verification screen implementation.
 
+use crate::blockchain::{ContractSource, VerificationSource};
+use crate::ui::models::ContractSourceTab;
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs},
     Frame,
 };
 
+/// Solidity keywords highlighted in the source view
+const SOLIDITY_KEYWORDS: &[&str] = &[
+    "pragma",
+    "import",
+    "contract",
+    "interface",
+    "library",
+    "abstract",
+    "is",
+    "function",
+    "modifier",
+    "event",
+    "error",
+    "struct",
+    "enum",
+    "constructor",
+    "fallback",
+    "receive",
+    "returns",
+    "return",
+    "if",
+    "else",
+    "for",
+    "while",
+    "do",
+    "break",
+    "continue",
+    "emit",
+    "revert",
+    "require",
+    "assert",
+    "new",
+    "delete",
+    "using",
+    "public",
+    "private",
+    "internal",
+    "external",
+    "view",
+    "pure",
+    "payable",
+    "virtual",
+    "override",
+    "memory",
+    "storage",
+    "calldata",
+    "constant",
+    "immutable",
+    "indexed",
+    "anonymous",
+    "unchecked",
+    "assembly",
+    "try",
+    "catch",
+    "true",
+    "false",
+];
+
+/// Solidity types without a size suffix; sized ones (`uint256`, `bytes32`) are
+/// matched by prefix
+const SOLIDITY_TYPES: &[&str] = &[
+    "address", "bool", "string", "bytes", "uint", "int", "mapping", "fixed", "ufixed",
+];
+
 /// Render the contract verification screen
-pub fn render_contract_verification(frame: &mut Frame, _app: &App, theme: &Theme) {
+pub fn render_contract_verification(frame: &mut Frame, app: &App, theme: &Theme) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
+            Constraint::Length(3), // Input
             Constraint::Min(0),    // Content area
         ])
         .split(frame.area());
@@ -30,14 +99,280 @@ pub fn render_contract_verification(frame: &mut Frame, _app: &App, theme: &Theme
         );
     frame.render_widget(title, main_chunks[0]);
 
-    // Content area
-    let content = Paragraph::new("Contract Verification screen - Coming soon")
+    crate::ui::components::render_input_field(
+        frame,
+        main_chunks[1],
+        theme,
+        "Enter contract address (/ to edit):",
+        &app.input,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
+        app.input_mode == crate::ui::InputMode::Editing,
+    );
+
+    if let Some(message) = app.loading_message("contract_source") {
+        crate::ui::components::render_loading(
+            frame,
+            main_chunks[2],
+            theme,
+            message,
+            app.spinner_frame,
+        );
+        return;
+    }
+
+    let Some(ref address) = app.contract_source_address else {
+        let empty = Paragraph::new("Look up a contract to see its verified source")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.secondary()),
+            );
+        frame.render_widget(empty, main_chunks[2]);
+        return;
+    };
+
+    match app.contract_source {
+        Some(ref source) => render_source(frame, main_chunks[2], app, address, source, theme),
+        None => {
+            let unverified = Paragraph::new(format!(
+                "{} is not verified on Etherscan or Sourcify",
+                address
+            ))
+            .style(theme.warning())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.secondary()),
+            );
+            frame.render_widget(unverified, main_chunks[2]);
+        }
+    }
+}
+
+/// Render the verification summary, the source files and the selected file
+fn render_source(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    address: &str,
+    source: &ContractSource,
+    theme: &Theme,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(7), // Summary
+            Constraint::Length(3), // Tabs
+            Constraint::Min(0),    // Source or ABI
+        ])
+        .split(area);
+
+    let badge = match source.verified_via {
+        VerificationSource::Etherscan => Span::styled("✓ Verified on Etherscan", theme.success()),
+        VerificationSource::Sourcify => Span::styled(
+            format!(
+                "✓ Verified via Sourcify ({} match)",
+                source.match_type.as_deref().unwrap_or("unknown")
+            ),
+            theme.info().add_modifier(Modifier::BOLD),
+        ),
+    };
+    let summary = Paragraph::new(Text::from(vec![
+        Line::from(badge),
+        Line::from(vec![
+            Span::styled("Contract: ", theme.label()),
+            Span::styled(source.contract_name.clone(), theme.primary()),
+            Span::raw("  "),
+            Span::styled(address.to_string(), theme.address()),
+        ]),
+        Line::from(vec![
+            Span::styled("Compiler: ", theme.label()),
+            Span::styled(source.compiler_version.clone(), theme.normal()),
+        ]),
+        Line::from(vec![
+            Span::styled("Optimization: ", theme.label()),
+            if source.optimization_used {
+                Span::styled(format!("Yes, {} runs", source.runs), theme.normal())
+            } else {
+                Span::styled("No", theme.normal())
+            },
+        ]),
+        Line::from(vec![
+            Span::styled("Files: ", theme.label()),
+            Span::styled(source.source_files.len().to_string(), theme.normal()),
+            Span::raw("  "),
+            Span::styled("ABI: ", theme.label()),
+            Span::styled(format!("{} bytes", source.abi.len()), theme.normal()),
+            Span::raw("  "),
+            Span::styled("Constructor Args: ", theme.label()),
+            Span::styled(
+                format!("{} bytes", source.constructor_arguments.len() / 2),
+                theme.normal(),
+            ),
+        ]),
+    ]))
+    .block(
+        Block::default()
+            .title("Verification")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(summary, chunks[0]);
+
+    let tabs = Tabs::new(vec![
+        ContractSourceTab::Source.title(),
+        ContractSourceTab::Abi.title(),
+    ])
+    .block(
+        Block::default()
+            .title("Tab to switch, PgUp/PgDn to scroll")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    )
+    .style(theme.normal())
+    .highlight_style(theme.selected())
+    .select(match app.contract_source_tab {
+        ContractSourceTab::Source => 0,
+        ContractSourceTab::Abi => 1,
+    });
+    frame.render_widget(tabs, chunks[1]);
+
+    match app.contract_source_tab {
+        ContractSourceTab::Source => render_source_files(frame, chunks[2], app, source, theme),
+        ContractSourceTab::Abi => {
+            let abi = Paragraph::new(source.pretty_abi())
+                .style(theme.normal())
+                .scroll((app.contract_source_scroll, 0))
+                .block(
+                    Block::default()
+                        .title("ABI")
+                        .borders(Borders::ALL)
+                        .border_style(theme.border()),
+                );
+            frame.render_widget(abi, chunks[2]);
+        }
+    }
+}
+
+/// Render the source file list and the selected file
+fn render_source_files(
+    frame: &mut Frame,
+    area: Rect,
+    app: &App,
+    source: &ContractSource,
+    theme: &Theme,
+) {
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(area);
+
+    let selected = app
+        .current_list_index
+        .min(source.source_files.len().saturating_sub(1));
+    let items: Vec<ListItem> = source
+        .source_files
+        .iter()
+        .map(|(path, content)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(path.clone(), theme.normal()),
+                Span::styled(
+                    format!(" ({} lines)", content.lines().count()),
+                    theme.muted(),
+                ),
+            ]))
+        })
+        .collect();
+    let mut state = ListState::default().with_selected(Some(selected));
+    let files = List::new(items)
+        .block(
+            Block::default()
+                .title("Source Files (↑/↓)")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .highlight_style(theme.selected());
+    frame.render_stateful_widget(files, body[0], &mut state);
+
+    let (path, content) = source
+        .source_files
+        .iter()
+        .nth(selected)
+        .map_or(("", ""), |(path, content)| {
+            (path.as_str(), content.as_str())
+        });
+    let lines: Vec<Line> = content
+        .lines()
+        .map(|line| highlight_solidity(line, theme))
+        .collect();
+    let preview = Paragraph::new(lines)
         .style(theme.normal())
-        .alignment(Alignment::Center)
+        .scroll((app.contract_source_scroll, 0))
         .block(
             Block::default()
+                .title(path)
                 .borders(Borders::ALL)
-                .border_style(theme.secondary()),
+                .border_style(theme.border()),
         );
-    frame.render_widget(content, main_chunks[1]);
+    frame.render_widget(preview, body[1]);
+}
+
+/// Colour keywords, types, string literals and `//` comments in a line of
+/// Solidity; block comments are not tracked across lines
+fn highlight_solidity<'a>(line: &'a str, theme: &Theme) -> Line<'a> {
+    let (code, comment) = match line.find("//") {
+        Some(start) if !line[..start].contains('"') => line.split_at(start),
+        _ => (line, ""),
+    };
+    let comment_style = theme.muted();
+    if code.trim_start().starts_with('*') || code.trim_start().starts_with("/*") {
+        return Line::from(Span::styled(line, comment_style));
+    }
+
+    let mut spans = Vec::new();
+    let mut rest = code;
+    while let Some(c) = rest.chars().next() {
+        let (token, style) = if c == '"' || c == '\'' {
+            let end = rest[1..].find(c).map_or(rest.len(), |end| end + 2);
+            (&rest[..end], theme.success())
+        } else if c.is_alphanumeric() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            (word, word_style(word, theme))
+        } else {
+            let end = rest
+                .find(|c: char| c.is_alphanumeric() || c == '_' || c == '"' || c == '\'')
+                .unwrap_or(rest.len());
+            (&rest[..end], theme.normal())
+        };
+        spans.push(Span::styled(token, style));
+        rest = &rest[token.len()..];
+    }
+    if !comment.is_empty() {
+        spans.push(Span::styled(comment, comment_style));
+    }
+    Line::from(spans)
+}
+
+/// Style of an identifier-like word in Solidity source
+fn word_style(word: &str, theme: &Theme) -> Style {
+    let is_type = SOLIDITY_TYPES.iter().any(|base| {
+        word.strip_prefix(base)
+            .is_some_and(|size| size.chars().all(|c| c.is_ascii_digit() || c == 'x'))
+    });
+    if SOLIDITY_KEYWORDS.contains(&word) {
+        theme.primary().add_modifier(Modifier::BOLD)
+    } else if is_type {
+        theme.info()
+    } else if word.starts_with(|c: char| c.is_ascii_digit()) {
+        theme.warning()
+    } else {
+        theme.normal()
+    }
 }
@@ -0,0 +1,165 @@
+//! CREATE2 Address Calculator screen for WarpScan
+//!
+//! This module contains the counterfactual deployment address screen implementation.
+
+use crate::ui::models::{Create2Form, CREATE2_FIELD_COUNT, KNOWN_CREATE2_DEPLOYERS};
+use crate::ui::{app::App, theme::Theme};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the CREATE2 address calculator screen
+pub fn render_create2_calculator(frame: &mut Frame, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Deployer
+            Constraint::Length(3), // Salt
+            Constraint::Length(3), // Init code hash
+            Constraint::Length(3), // Check button
+            Constraint::Min(0),    // Result
+        ])
+        .split(frame.area());
+
+    // Title
+    let title = Paragraph::new("CREATE2 Address Calculator")
+        .style(theme.title())
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        );
+    frame.render_widget(title, chunks[0]);
+
+    // Input fields
+    let editing = app.input_mode == crate::ui::InputMode::Editing;
+    for (index, label) in Create2Form::FIELD_LABELS.iter().enumerate() {
+        let area = chunks[index + 1];
+        let focused = app.current_list_index == index;
+        let is_editing = focused && editing;
+        let value = if is_editing {
+            app.input.as_str()
+        } else {
+            app.create2_form.field(index)
+        };
+
+        crate::ui::components::render_input_field(
+            frame,
+            area,
+            theme,
+            label,
+            value,
+            app.cursor_byte_pos,
+            app.cursor_char_pos,
+            is_editing,
+        );
+
+        if focused && !is_editing {
+            let marker = Block::default()
+                .title(*label)
+                .borders(Borders::ALL)
+                .border_style(theme.selected());
+            frame.render_widget(marker, area);
+        }
+    }
+
+    // Check button
+    let button_style = if app.current_list_index == CREATE2_FIELD_COUNT - 1 {
+        theme.selected()
+    } else {
+        theme.normal()
+    };
+    let button = Paragraph::new("[ Check if deployed ]")
+        .style(button_style)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        );
+    frame.render_widget(button, chunks[4]);
+
+    // Result
+    let mut lines = Vec::new();
+    match app.create2_form.predicted {
+        Some(ref predicted) => {
+            let mut spans = vec![
+                Span::styled("Predicted Address: ", theme.label()),
+                Span::styled(predicted.clone(), theme.address()),
+                Span::raw("  "),
+            ];
+            if app.is_loading("create2_check") {
+                spans.push(Span::styled("Checking...", theme.muted()));
+            } else {
+                match app.create2_form.deployed {
+                    Some(true) => spans.push(Span::styled(
+                        " Deployed ",
+                        theme
+                            .success()
+                            .add_modifier(Modifier::REVERSED | Modifier::BOLD),
+                    )),
+                    Some(false) => spans.push(Span::styled(
+                        " Not yet deployed ",
+                        theme.muted().add_modifier(Modifier::REVERSED),
+                    )),
+                    None => {}
+                }
+            }
+            lines.push(Line::from(spans));
+        }
+        None => lines.push(Line::from(Span::styled(
+            "Fill in the deployer, salt and init code hash to predict the address",
+            theme.muted(),
+        ))),
+    }
+
+    if let Some(error) = app.error_message() {
+        lines.push(Line::from(Span::styled(error, theme.error())));
+    } else if let Some(ref success) = app.success_message {
+        lines.push(Line::from(Span::styled(success.clone(), theme.success())));
+    }
+
+    // Quick-select list
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Quick-select deployers (d: next, +: save current):",
+        theme.label(),
+    )));
+    for (index, address) in app.create2_deployer_choices().iter().enumerate() {
+        let name = KNOWN_CREATE2_DEPLOYERS
+            .get(index)
+            .map(|(name, _)| *name)
+            .unwrap_or("Saved");
+        let style = if app.create2_form.quick_select_index == Some(index) {
+            theme.selected()
+        } else {
+            theme.normal()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {:<32}", name), theme.muted()),
+            Span::styled(address.clone(), style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Use ↑/↓ to select a field, Enter to edit, Enter on the button to check the chain",
+        theme.muted(),
+    )));
+
+    let result = Paragraph::new(Text::from(lines))
+        .block(
+            Block::default()
+                .title("Result")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(result, chunks[5]);
+}
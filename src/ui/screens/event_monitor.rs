@@ -1,25 +1,40 @@
 //! Event Monitor screen for WarpScan
 //!
-//! This module contains the event monitor screen implementation.
+//! Searches contract logs by address, topic and block range, and streams new
+//! matching logs when a WebSocket endpoint is available.
 
-use crate::ui::{app::App, theme::Theme};
+use crate::ui::app::event_monitor::EVENT_FILTER_FIELD_COUNT;
+use crate::ui::{app::App, theme::Theme, InputMode};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
     Frame,
 };
 
+/// Labels of the filter input fields
+const FILTER_LABELS: [&str; EVENT_FILTER_FIELD_COUNT - 1] = [
+    "Contract Address (empty: any)",
+    "Topic0 / event signature hash (empty: any)",
+    "Block Range start-end (empty: last 100 blocks)",
+];
+
 /// Render the event monitor screen
-pub fn render_event_monitor(frame: &mut Frame, _app: &App, theme: &Theme) {
-    let main_chunks = Layout::default()
+pub fn render_event_monitor(frame: &mut Frame, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
-            Constraint::Min(0),    // Content area
+            Constraint::Length(3), // Contract address
+            Constraint::Length(3), // Topic0
+            Constraint::Length(3), // Block range
+            Constraint::Length(3), // Search button
+            Constraint::Min(0),    // Logs
+            Constraint::Length(1), // Key hints or message
         ])
         .split(frame.area());
 
-    // Title
     let title = Paragraph::new("Event Monitor")
         .style(theme.title())
         .alignment(Alignment::Center)
@@ -28,16 +43,156 @@ pub fn render_event_monitor(frame: &mut Frame, _app: &App, theme: &Theme) {
                 .borders(Borders::ALL)
                 .border_style(theme.primary()),
         );
-    frame.render_widget(title, main_chunks[0]);
+    frame.render_widget(title, chunks[0]);
+
+    // Filter fields
+    let editing = app.input_mode == InputMode::Editing;
+    for (index, label) in FILTER_LABELS.iter().enumerate() {
+        let area = chunks[index + 1];
+        let focused = !app.event_table_focus && app.current_list_index == index;
+        let is_editing = focused && editing;
+        let value = if is_editing {
+            app.input.clone()
+        } else {
+            app.event_filter_field(index)
+        };
+
+        crate::ui::components::render_input_field(
+            frame,
+            area,
+            theme,
+            label,
+            &value,
+            app.cursor_byte_pos,
+            app.cursor_char_pos,
+            is_editing,
+        );
 
-    // Content area
-    let content = Paragraph::new("Event Monitor screen - Coming soon")
-        .style(theme.normal())
+        if focused && !is_editing {
+            let marker = Block::default()
+                .title(*label)
+                .borders(Borders::ALL)
+                .border_style(theme.selected());
+            frame.render_widget(marker, area);
+        }
+    }
+
+    let button_style = if app.event_search_button_focused() {
+        theme.selected()
+    } else {
+        theme.normal()
+    };
+    let button = Paragraph::new("[ Search ]")
+        .style(button_style)
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(theme.secondary()),
+                .border_style(theme.border()),
         );
-    frame.render_widget(content, main_chunks[1]);
+    frame.render_widget(button, chunks[4]);
+
+    if let Some(message) = app.loading_message("event_logs") {
+        let loading = Paragraph::new(message)
+            .style(theme.info())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.border()),
+            );
+        frame.render_widget(loading, chunks[5]);
+    } else {
+        render_log_table(frame, chunks[5], app, theme);
+    }
+
+    let footer = match (app.error_message(), &app.success_message) {
+        (Some(error), _) => Span::styled(error, theme.error()),
+        (None, Some(success)) => Span::styled(success.clone(), theme.success()),
+        (None, None) => Span::styled(
+            "↑/↓: select  Enter: edit / search / open tx  Tab: switch panel",
+            theme.muted(),
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(footer)).alignment(Alignment::Center),
+        chunks[6],
+    );
+}
+
+/// Matching logs, newest first
+fn render_log_table(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let border_style = if app.event_table_focus {
+        theme.selected()
+    } else {
+        theme.border()
+    };
+    let Some(ref logs) = app.event_logs else {
+        let hint = Paragraph::new("Set a filter and press Enter on [ Search ]")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title("Logs")
+                    .borders(Borders::ALL)
+                    .border_style(border_style),
+            );
+        frame.render_widget(hint, area);
+        return;
+    };
+
+    let header = Row::new(["Block", "Tx Hash", "Log Index", "Data"])
+        .style(theme.label().add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = logs
+        .iter()
+        .map(|log| {
+            let or_pending = |value: Option<String>| value.unwrap_or_else(|| "pending".into());
+            let tx_hash = log
+                .transaction_hash
+                .map(|hash| format!("{:#x}", hash)[..10].to_string());
+            Row::new(vec![
+                Cell::from(or_pending(log.block_number.map(|n| n.to_string()))),
+                Cell::from(Span::styled(or_pending(tx_hash), theme.transaction_hash())),
+                Cell::from(or_pending(log.log_index.map(|i| i.to_string()))),
+                Cell::from(format!("0x{}", hex::encode(&log.data))),
+            ])
+            .style(theme.normal())
+        })
+        .collect();
+
+    let (range_start, range_end) = app.event_filter.block_range;
+    let stream = if app.event_stream_live {
+        "Live"
+    } else {
+        "WebSocket required for live logs"
+    };
+    let title = format!(
+        "Logs ({}) | Blocks {}-{} | {}",
+        logs.len(),
+        range_start,
+        range_end,
+        stream
+    );
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Length(10),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .highlight_style(theme.selected())
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(border_style),
+    );
+
+    // Keeps the selected row in view as the list scrolls
+    let mut state = TableState::default()
+        .with_selected((app.event_table_focus && !logs.is_empty()).then_some(app.event_log_index));
+    frame.render_stateful_widget(table, area, &mut state);
 }
@@ -1,16 +1,38 @@
+use crate::blockchain::{BlockGasMetric, Eip1559Tier, GasPrices};
+use crate::ui::app::gas::BURN_WINDOW_BLOCKS;
 use crate::ui::{app::App, theme::Theme};
+use ethers::types::U256;
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table},
     Frame,
 };
 
+/// Gas used by a plain ETH transfer
+const TRANSFER_GAS: u64 = 21_000;
+
+/// Width of the gas used mini bar, in cells
+const USAGE_BAR_WIDTH: usize = 10;
+
+/// Width of the min/max labels left of each sparkline, in cells
+const SPARKLINE_LABEL_WIDTH: u16 = 16;
+
+/// Bar heights are scaled to this before being handed to the sparkline
+const SPARKLINE_RESOLUTION: f64 = 100.0;
+
 /// Render the gas tracker screen
 pub fn render_gas_tracker(frame: &mut Frame, app: &App, theme: &Theme) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .constraints([
+            Constraint::Length(3),  // Title
+            Constraint::Length(3),  // Base fee
+            Constraint::Length(7),  // Legacy prices and fee tiers
+            Constraint::Min(0),     // History
+            Constraint::Length(11), // Price sparklines
+        ])
         .split(frame.area());
 
     // Title
@@ -23,39 +45,345 @@ pub fn render_gas_tracker(frame: &mut Frame, app: &App, theme: &Theme) {
                 .border_style(theme.primary()),
         );
     frame.render_widget(title, chunks[0]);
+    render_gas_history(frame, chunks[3], app, theme);
+    render_price_sparklines(frame, chunks[4], app, theme);
 
-    // Gas information
-    let gas_info = if app.is_loading("gas_tracker") {
-        Text::from("Loading gas information...")
-    } else {
-        Text::from(vec![
-            Line::from("Current Gas Prices:"),
-            Line::from(""),
-            Line::from(vec![
-                Span::styled("Safe: ", theme.label()),
-                Span::styled("20 gwei", theme.success()),
-            ]),
-            Line::from(vec![
-                Span::styled("Standard: ", theme.label()),
-                Span::styled("25 gwei", theme.warning()),
-            ]),
+    let fee_panels = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(chunks[2]);
+    render_legacy_prices(frame, fee_panels[0], app.gas_prices.as_ref(), theme);
+
+    let tiers_block = Block::default()
+        .title("EIP-1559 maxFeePerGas / maxPriorityFeePerGas (r: refresh)")
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    let estimate = match app.gas_estimate {
+        Some(ref estimate) => estimate,
+        None => {
+            let message = if app.is_loading("gas_tracker") {
+                "Loading gas information..."
+            } else {
+                "No fee data yet. Press 'r' to refresh"
+            };
+            let empty = Paragraph::new(message)
+                .style(theme.muted())
+                .alignment(Alignment::Center)
+                .block(tiers_block);
+            frame.render_widget(empty, chunks[1].union(fee_panels[1]));
+            return;
+        }
+    };
+
+    // Base fee and ETH price
+    let price_text = app
+        .eth_price_usd
+        .map(|price| format!("${:.2}", price))
+        .unwrap_or_else(|| "N/A".to_string());
+    let burned_text = app
+        .gas_burned_recent
+        .map(|burned| format!("{:.4} ETH", burned))
+        .unwrap_or_else(|| "N/A".to_string());
+    let summary = Paragraph::new(Line::from(vec![
+        Span::styled("Next Base Fee: ", theme.label()),
+        Span::styled(format!("{} gwei", estimate.base_fee_gwei), theme.info()),
+        Span::raw("  |  "),
+        Span::styled("ETH Price: ", theme.label()),
+        Span::styled(price_text, theme.success()),
+        Span::raw("  |  "),
+        Span::styled(
+            format!("Total Burned (last {} blocks): ", BURN_WINDOW_BLOCKS),
+            theme.label(),
+        ),
+        Span::styled(burned_text, theme.warning()),
+        Span::raw("  |  "),
+        Span::styled("Tip Source: ", theme.label()),
+        Span::styled(estimate.priority_fee_source.label(), theme.muted()),
+    ]))
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(summary, chunks[1]);
+
+    // Fee tiers
+    let header = Row::new(
+        [
+            "Speed",
+            "Priority Fee",
+            "Max Fee",
+            "Est. Wait",
+            "Transfer Cost (USD)",
+        ]
+        .into_iter()
+        .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+
+    let tiers = [
+        ("Slow", &estimate.slow, theme.success()),
+        ("Standard", &estimate.standard, theme.warning()),
+        ("Fast", &estimate.fast, theme.error()),
+    ];
+    let rows: Vec<Row> = tiers
+        .into_iter()
+        .map(|(name, tier, style)| {
+            Row::new(vec![
+                Cell::from(Span::styled(name, style)),
+                Cell::from(format!("{} gwei", tier.max_priority_fee_gwei)),
+                Cell::from(format!("{} gwei", tier.max_fee_gwei)),
+                Cell::from(format!("~{} blocks", tier.estimated_wait_blocks)),
+                Cell::from(transfer_cost_text(
+                    estimate.base_fee_gwei,
+                    tier,
+                    app.eth_price_usd,
+                )),
+            ])
+            .style(Style::default().fg(theme.foreground))
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(14),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(tiers_block);
+    frame.render_widget(table, fee_panels[1]);
+}
+
+/// Render the legacy gas price tiers with the current and next base fee
+fn render_legacy_prices(frame: &mut Frame, area: Rect, prices: Option<&GasPrices>, theme: &Theme) {
+    let block = Block::default()
+        .title("Legacy Gas Price")
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+    let Some(prices) = prices else {
+        let empty = Paragraph::new("N/A")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    };
+
+    let line = |label: &'static str, wei: U256, style: Style| {
+        Line::from(vec![
+            Span::styled(label, theme.label()),
+            Span::styled(format!("{:.2} gwei", wei_to_gwei(wei)), style),
+        ])
+    };
+    let lines = vec![
+        line("Slow:     ", prices.slow, theme.success()),
+        line("Standard: ", prices.standard, theme.warning()),
+        line("Fast:     ", prices.fast, theme.error()),
+        line("Base Fee: ", prices.base_fee, theme.info()),
+        line("Next:     ", prices.next_base_fee, theme.info()),
+    ];
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// Wei as fractional gwei, for display
+fn wei_to_gwei(wei: U256) -> f64 {
+    wei.as_u128() as f64 / 1e9
+}
+
+/// Render the per-block gas history, newest block first
+fn render_gas_history(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let block = Block::default()
+        .title(format!(
+            "Gas History - last {} blocks (e: export CSV)",
+            app.gas_history_data.len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    if app.gas_history_data.is_empty() {
+        let empty = Paragraph::new("No gas history loaded")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(
+        [
+            "Block #",
+            "Base Fee (gwei)",
+            "Gas Used %",
+            "Avg Priority Fee",
+            "Usage",
+        ]
+        .into_iter()
+        .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+
+    let rows: Vec<Row> = app
+        .gas_history_data
+        .iter()
+        .rev()
+        .map(|metric| {
+            let usage_style = usage_style(metric, theme);
+            Row::new(vec![
+                Cell::from(metric.block_number.to_string()),
+                Cell::from(metric.base_fee_gwei.to_string()),
+                Cell::from(Span::styled(
+                    format!("{:.1}%", metric.gas_used_ratio * 100.0),
+                    usage_style,
+                )),
+                Cell::from(format!("{} gwei", metric.avg_priority_fee_gwei)),
+                Cell::from(Span::styled(usage_bar(metric.gas_used_ratio), usage_style)),
+            ])
+            .style(Style::default().fg(theme.foreground))
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(16),
+            Constraint::Length(12),
+            Constraint::Length(18),
+            Constraint::Length(USAGE_BAR_WIDTH as u16 + 2),
+        ],
+    )
+    .header(header)
+    .block(block);
+    frame.render_widget(table, area);
+}
+
+/// Picks one price tier out of a gas price sample
+type PriceTier = fn(&GasPrices) -> U256;
+
+/// Render slow, standard and fast gas prices over the sampled window,
+/// each scaled to its own visible min and max
+fn render_price_sparklines(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let block = Block::default()
+        .title(format!(
+            "Gas Price Trend - last {} samples, every {}s",
+            app.gas_price_history.len(),
+            app.config.gas.update_interval_seconds
+        ))
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    if app.gas_price_history.is_empty() {
+        let empty = Paragraph::new("Collecting gas price samples...")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Ratio(1, 3); 3])
+        .split(inner);
+    let visible = inner.width.saturating_sub(SPARKLINE_LABEL_WIDTH) as usize;
+    let skip = app.gas_price_history.len().saturating_sub(visible);
+    let series: [(&str, PriceTier, Style); 3] = [
+        ("Slow", |prices| prices.slow, theme.success()),
+        ("Standard", |prices| prices.standard, theme.warning()),
+        ("Fast", |prices| prices.fast, theme.error()),
+    ];
+
+    for ((name, price, style), row) in series.into_iter().zip(rows.iter()) {
+        let values: Vec<f64> = app
+            .gas_price_history
+            .iter()
+            .skip(skip)
+            .map(|prices| wei_to_gwei(price(prices)))
+            .collect();
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(SPARKLINE_LABEL_WIDTH),
+                Constraint::Min(0),
+            ])
+            .split(*row);
+        let labels = Paragraph::new(vec![
             Line::from(vec![
-                Span::styled("Fast: ", theme.label()),
-                Span::styled("30 gwei", theme.error()),
+                Span::styled(format!("{:<9}", name), style),
+                Span::styled(format!("{:.2}", max), theme.muted()),
             ]),
             Line::from(""),
-            Line::from("Press 'r' to refresh"),
-        ])
-    };
+            Line::from(Span::styled(format!("{:>9}{:.2}", "", min), theme.muted())),
+        ]);
+        frame.render_widget(labels, columns[0]);
 
-    let gas_paragraph = Paragraph::new(gas_info)
-        .block(
-            Block::default()
-                .title("Gas Information")
-                .borders(Borders::ALL)
-                .border_style(theme.border()),
-        )
-        .wrap(Wrap { trim: true });
+        let scaled = scale_to_range(&values, min, max);
+        let sparkline = Sparkline::default()
+            .data(&scaled)
+            .max(SPARKLINE_RESOLUTION as u64)
+            .style(style);
+        frame.render_widget(sparkline, columns[1]);
+    }
+}
+
+/// Map values onto `1..=SPARKLINE_RESOLUTION` between `min` and `max`, so
+/// small swings stay visible; a flat series is drawn at half height
+fn scale_to_range(values: &[f64], min: f64, max: f64) -> Vec<u64> {
+    let range = max - min;
+    values
+        .iter()
+        .map(|value| {
+            if range <= f64::EPSILON {
+                return (SPARKLINE_RESOLUTION / 2.0) as u64;
+            }
+            (1.0 + (value - min) / range * (SPARKLINE_RESOLUTION - 1.0)).round() as u64
+        })
+        .collect()
+}
+
+/// Red above 90% gas used, yellow from 70%, green below
+fn usage_style(metric: &BlockGasMetric, theme: &Theme) -> Style {
+    if metric.gas_used_ratio > 0.9 {
+        theme.error()
+    } else if metric.gas_used_ratio >= 0.7 {
+        theme.warning()
+    } else {
+        theme.success()
+    }
+}
+
+/// Horizontal bar of block fullness, e.g. `██████░░░░`
+fn usage_bar(ratio: f64) -> String {
+    let filled = ((ratio.clamp(0.0, 1.0) * USAGE_BAR_WIDTH as f64).round()) as usize;
+    format!(
+        "{}{}",
+        "█".repeat(filled),
+        "░".repeat(USAGE_BAR_WIDTH - filled)
+    )
+}
 
-    frame.render_widget(gas_paragraph, chunks[1]);
+/// Expected cost of a 21000 gas transfer; the base fee is paid in full but
+/// only the tip on top of it, not the whole max fee
+fn transfer_cost_text(
+    base_fee_gwei: u64,
+    tier: &Eip1559Tier,
+    eth_price_usd: Option<f64>,
+) -> String {
+    let fee_gwei = (base_fee_gwei + tier.max_priority_fee_gwei) * TRANSFER_GAS;
+    let fee_eth = fee_gwei as f64 / 1_000_000_000.0;
+    match eth_price_usd {
+        Some(price) => format!("${:.2} ({:.6} ETH)", fee_eth * price, fee_eth),
+        None => format!("{:.6} ETH", fee_eth),
+    }
 }
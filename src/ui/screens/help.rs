@@ -1,16 +1,23 @@
 //! Help screen for WarpScan
 //!
-//! This module contains the help screen implementation.
+//! Shows version and connection info, the keybinding reference and the
+//! recent searches. The same reference is shown over any screen with `?`.
 
+use crate::ui::app::events::KeyBindings;
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, Paragraph, Row, Table, TableState},
     Frame,
 };
 
+/// Searches listed in the recent searches panel
+const RECENT_SEARCHES_SHOWN: usize = 10;
+
 /// Render the help screen
-pub fn render_help(frame: &mut Frame, _app: &App, theme: &Theme) {
+pub fn render_help(frame: &mut Frame, app: &App, theme: &Theme) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -30,14 +37,109 @@ pub fn render_help(frame: &mut Frame, _app: &App, theme: &Theme) {
         );
     frame.render_widget(title, main_chunks[0]);
 
-    // Content area
-    let content = Paragraph::new("Help screen - Coming soon")
-        .style(theme.normal())
-        .alignment(Alignment::Center)
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(40)])
+        .split(main_chunks[1]);
+
+    render_help_content(frame, content_chunks[0], app, theme);
+    render_recent_searches(frame, content_chunks[1], app, theme);
+}
+
+/// Render the keybinding reference over the current screen
+pub fn render_help_overlay(frame: &mut Frame, app: &App, theme: &Theme) {
+    let area = frame.area();
+    let width = 80.min(area.width);
+    let height = (area.height * 4 / 5).max(12).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    frame.render_widget(Clear, popup);
+    render_help_content(frame, popup, app, theme);
+}
+
+/// Version and connection header, then the keybinding table
+fn render_help_content(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(0)])
+        .split(area);
+
+    let info = |label: &'static str, value: String| {
+        Line::from(vec![
+            Span::styled(label, theme.label()),
+            Span::styled(value, theme.normal()),
+        ])
+    };
+    let header = Paragraph::new(vec![
+        info("Version:      ", env!("CARGO_PKG_VERSION").to_string()),
+        info("Build Date:   ", env!("WARPSCAN_BUILD_DATE").to_string()),
+        info("RPC Endpoint: ", app.config.network.rpc_url.clone()),
+    ])
+    .block(
+        Block::default()
+            .title("WarpScan")
+            .borders(Borders::ALL)
+            .border_style(theme.primary()),
+    );
+    frame.render_widget(header, chunks[0]);
+
+    let mut rows = Vec::with_capacity(KeyBindings::row_count());
+    for (section, bindings) in KeyBindings::SECTIONS {
+        rows.push(Row::new(vec![Cell::from(Span::styled(
+            *section,
+            theme.info().add_modifier(Modifier::BOLD),
+        ))]));
+        rows.extend(bindings.iter().map(|(key, action)| {
+            Row::new(vec![
+                Cell::from(Span::styled(format!("  {}", key), theme.highlighted())),
+                Cell::from(Span::styled(*action, theme.normal())),
+            ])
+        }));
+    }
+
+    let table = Table::new(rows, [Constraint::Length(18), Constraint::Min(0)])
+        .header(Row::new(["Key", "Action"]).style(theme.label().add_modifier(Modifier::BOLD)))
         .block(
             Block::default()
+                .title("Keybindings (↑/↓: scroll, ?/Esc: close)")
                 .borders(Borders::ALL)
-                .border_style(theme.secondary()),
+                .border_style(theme.border()),
         );
-    frame.render_widget(content, main_chunks[1]);
+    let mut state = TableState::default().with_offset(app.help_scroll);
+    frame.render_stateful_widget(table, chunks[1], &mut state);
+}
+
+/// Latest searches, recalled with Up/Down while editing
+fn render_recent_searches(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let items: Vec<ListItem> = if app.search_history.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No searches yet",
+            theme.muted(),
+        ))]
+    } else {
+        app.search_history
+            .iter()
+            .take(RECENT_SEARCHES_SHOWN)
+            .enumerate()
+            .map(|(index, search)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{:>2}. ", index + 1), theme.muted()),
+                    Span::styled(search.clone(), theme.normal()),
+                ]))
+            })
+            .collect()
+    };
+    let list = List::new(items).block(
+        Block::default()
+            .title("Recent Searches (↑/↓ while typing)")
+            .borders(Borders::ALL)
+            .border_style(theme.primary()),
+    );
+
+    frame.render_widget(list, area);
 }
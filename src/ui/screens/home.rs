@@ -2,15 +2,25 @@
 //!
 //! This module contains the home screen implementation with dashboard functionality.
 
-use crate::ui::{app::App, theme::Theme};
+use crate::ui::{
+    app::{App, InputFeedback},
+    theme::Theme,
+};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span},
     widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
 /// Render the home screen with TegroScan-style dashboard
 pub fn render_home(frame: &mut Frame, app: &App, theme: &Theme) {
+    if app.dashboard_load_progress < 100 {
+        render_dashboard_loading(frame, app.dashboard_load_progress, theme);
+        return;
+    }
+
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -55,10 +65,27 @@ pub fn render_home(frame: &mut Frame, app: &App, theme: &Theme) {
         ratatui::style::Color::Blue
     };
 
+    // Badge for what the input will open, once typing pauses
+    let mut search_block = Block::default();
+    if let Some(label) = app.input_feedback.as_ref().and_then(InputFeedback::label) {
+        let style = if app.input_feedback == Some(InputFeedback::Invalid) {
+            theme.error()
+        } else {
+            theme.success()
+        };
+        search_block = search_block.title(
+            Line::from(Span::styled(
+                format!(" {} ", label),
+                style.add_modifier(Modifier::REVERSED | Modifier::BOLD),
+            ))
+            .right_aligned(),
+        );
+    }
+
     let search_bar = Paragraph::new(app.get_input())
         .style(search_style)
         .block(
-            Block::default()
+            search_block
                 .borders(Borders::ALL)
                 .title(search_title)
                 .border_style(ratatui::style::Style::default().fg(search_border_color))
@@ -74,7 +101,7 @@ pub fn render_home(frame: &mut Frame, app: &App, theme: &Theme) {
     // Show cursor when in editing mode
     if app.input_mode == crate::ui::InputMode::Editing && app.current_tab == 2 {
         frame.set_cursor_position((
-            main_chunks[1].x + app.cursor_position as u16 + 1,
+            main_chunks[1].x + app.cursor_char_pos as u16 + 1,
             main_chunks[1].y + 1,
         ));
     }
@@ -92,8 +119,38 @@ pub fn render_home(frame: &mut Frame, app: &App, theme: &Theme) {
     render_latest_transactions(frame, content_chunks[1], app, theme);
 }
 
+/// Render the title and a progress bar while the dashboard data loads
+pub fn render_dashboard_loading(frame: &mut Frame, progress: u8, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Progress
+            Constraint::Min(0),
+        ])
+        .split(frame.area());
+
+    let title = Paragraph::new("WARPSCAN")
+        .style(theme.title())
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        );
+    frame.render_widget(title, chunks[0]);
+
+    crate::ui::components::render_progress(
+        frame,
+        chunks[1],
+        theme,
+        f64::from(progress) / 100.0,
+        &format!("Loading dashboard... {}%", progress),
+    );
+}
+
 /// Render network statistics section
-fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, _theme: &Theme) {
+fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, theme: &Theme) {
     let stats_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
@@ -106,11 +163,10 @@ fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
 
     let stats = &app.dashboard_data.network_stats;
 
-    // Network Price with gradient-like styling
-    let price_color = if stats.ethereum_price >= 0.0 {
-        ratatui::style::Color::Green
-    } else {
-        ratatui::style::Color::Red
+    // Network Price, colored by the direction of the 24h change
+    let price_color = match stats.price_change_24h {
+        Some(change) if change < 0.0 => ratatui::style::Color::Red,
+        _ => ratatui::style::Color::Green,
     };
     let price_block = Block::default()
         .title("💰 Network Price")
@@ -121,7 +177,11 @@ fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
                 .fg(ratatui::style::Color::Cyan)
                 .add_modifier(ratatui::style::Modifier::BOLD),
         );
-    let price_text = Paragraph::new(format!("${:.2}\n{:+.2}%", stats.ethereum_price, 4.15))
+    let price_text = match stats.price_change_24h {
+        Some(change) => format!("${:.2}\n{:+.2}%", stats.ethereum_price, change),
+        None => format!("${:.2}", stats.ethereum_price),
+    };
+    let price_text = Paragraph::new(price_text)
         .style(
             ratatui::style::Style::default()
                 .fg(ratatui::style::Color::White)
@@ -143,9 +203,9 @@ fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
                 .add_modifier(ratatui::style::Modifier::BOLD),
         );
     let market_cap_text = Paragraph::new(format!(
-        "${:.2}B\n{:+.2}%",
+        "${:.2}B\n🔥 {:.4} ETH burned",
         stats.market_cap / 1_000_000_000.0,
-        1.23
+        stats.eth_burned_since_start
     ))
     .style(
         ratatui::style::Style::default()
@@ -170,9 +230,24 @@ fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
                 .add_modifier(ratatui::style::Modifier::BOLD),
         );
 
-    // Show block number and network info
+    // Show block number, how far behind the safe and finalized heads trail,
+    // and block age
     let block_info_text = if stats.latest_block > 0 {
-        format!("#{}\n{}", stats.latest_block, stats.block_time)
+        match app.block_finality {
+            Some(finality) => {
+                let latest = compact_block_number(finality.latest);
+                format!(
+                    "Latest: {} | Safe: {}-{} | Final: {}-{}\n{}",
+                    latest,
+                    latest,
+                    finality.latest.saturating_sub(finality.safe),
+                    latest,
+                    finality.latest.saturating_sub(finality.finalized),
+                    stats.block_time
+                )
+            }
+            None => format!("#{}\n{}", stats.latest_block, stats.block_time),
+        }
     } else {
         "Loading...".to_string()
     };
@@ -184,7 +259,8 @@ fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
                 .add_modifier(ratatui::style::Modifier::BOLD),
         )
         .block(latest_block_block)
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
     frame.render_widget(latest_block_text, stats_chunks[2]);
 
     // Add network info below the stats (if space allows)
@@ -216,22 +292,38 @@ fn render_network_stats(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
                 .fg(ratatui::style::Color::Cyan)
                 .add_modifier(ratatui::style::Modifier::BOLD),
         );
+    let tps_text = match app.rolling_tps() {
+        Some(tps) => format!("{:.1} TPS", tps),
+        None => "Syncing...".to_string(),
+    };
     let tx_history_text = Paragraph::new(format!(
-        "{:.1}M\n14.5 TPS",
-        stats.transactions_count as f64 / 1_000_000.0
+        "{:.1}M\n{}",
+        stats.transactions_count as f64 / 1_000_000.0,
+        tps_text
     ))
     .style(
         ratatui::style::Style::default()
             .fg(ratatui::style::Color::White)
             .add_modifier(ratatui::style::Modifier::BOLD),
     )
-    .block(tx_history_block)
     .alignment(Alignment::Center);
-    frame.render_widget(tx_history_text, stats_chunks[3]);
+
+    let tx_history_area = tx_history_block.inner(stats_chunks[3]);
+    frame.render_widget(tx_history_block, stats_chunks[3]);
+
+    let tx_history_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(tx_history_area);
+    frame.render_widget(tx_history_text, tx_history_chunks[0]);
+
+    // Per-block transaction counts, oldest on the left
+    let tx_counts: Vec<u64> = app.block_tx_counts.iter().map(|&c| c as u64).collect();
+    crate::ui::components::render_sparkline(frame, tx_history_chunks[1], theme, &tx_counts);
 }
 
 /// Render latest blocks section
-fn render_latest_blocks(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, _theme: &Theme) {
+fn render_latest_blocks(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, theme: &Theme) {
     let blocks = &app.dashboard_data.latest_blocks;
 
     let block_items: Vec<ListItem> = blocks
@@ -263,11 +355,11 @@ fn render_latest_blocks(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
                 format!("{}d ago", seconds_ago / 86400)
             };
 
+            // Gas utilization bar is drawn next to each row
             let content = format!(
-                "#{:<8} │ {:<12} │ {:>3} txns │ {}",
+                "#{:<8} │ {:<12} │ {:<8} │",
                 block.number,
                 &block.hash[..12.min(block.hash.len())],
-                block.transaction_count,
                 time_str
             );
             ListItem::new(content).style(style)
@@ -280,31 +372,59 @@ fn render_latest_blocks(frame: &mut Frame, area: ratatui::layout::Rect, app: &Ap
         ratatui::style::Color::Blue
     };
 
-    let blocks_list = List::new(block_items)
-        .block(
-            Block::default()
-                .title("🔗 Latest Blocks")
-                .borders(Borders::ALL)
-                .border_style(ratatui::style::Style::default().fg(border_color))
-                .title_style(
-                    ratatui::style::Style::default()
-                        .fg(ratatui::style::Color::Cyan)
-                        .add_modifier(ratatui::style::Modifier::BOLD),
-                ),
-        )
-        .highlight_style(
+    let outer = Block::default()
+        .title("🔗 Latest Blocks")
+        .borders(Borders::ALL)
+        .border_style(ratatui::style::Style::default().fg(border_color))
+        .title_style(
             ratatui::style::Style::default()
-                .bg(ratatui::style::Color::Cyan)
-                .fg(ratatui::style::Color::Black)
+                .fg(ratatui::style::Color::Cyan)
                 .add_modifier(ratatui::style::Modifier::BOLD),
         );
+    let inner = outer.inner(area);
+    frame.render_widget(outer, area);
+
+    // Row text on the left, sized to the widest row, gas bars in the rest
+    let text_width = block_items
+        .iter()
+        .map(|item| item.width() as u16)
+        .max()
+        .unwrap_or(0);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(text_width + 1), Constraint::Min(0)])
+        .split(inner);
+
+    let blocks_list = List::new(block_items).highlight_style(
+        ratatui::style::Style::default()
+            .bg(ratatui::style::Color::Cyan)
+            .fg(ratatui::style::Color::Black)
+            .add_modifier(ratatui::style::Modifier::BOLD),
+    );
 
     let mut list_state = ListState::default();
     if app.current_tab == 0 {
         list_state.select(Some(app.current_list_index));
     }
 
-    frame.render_stateful_widget(blocks_list, area, &mut list_state);
+    frame.render_stateful_widget(blocks_list, columns[0], &mut list_state);
+
+    // Gas utilization bar per visible row
+    if columns[1].width > 0 {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); columns[1].height as usize])
+            .split(columns[1]);
+        for (block, bar_area) in blocks.iter().skip(list_state.offset()).zip(rows.iter()) {
+            crate::ui::components::render_gas_bar(
+                frame,
+                *bar_area,
+                block.gas_used,
+                block.gas_limit,
+                theme,
+            );
+        }
+    }
 }
 
 /// Render latest transactions section
@@ -388,3 +508,14 @@ fn render_latest_transactions(
 
     frame.render_stateful_widget(transactions_list, area, &mut list_state);
 }
+
+/// Shorten a block number for the stat cards, e.g. `20.1M`
+fn compact_block_number(number: u64) -> String {
+    if number >= 1_000_000 {
+        format!("{:.1}M", number as f64 / 1_000_000.0)
+    } else if number >= 1_000 {
+        format!("{:.1}K", number as f64 / 1_000.0)
+    } else {
+        number.to_string()
+    }
+}
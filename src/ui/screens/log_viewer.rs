@@ -0,0 +1,184 @@
+//! Log viewer screen for WarpScan
+//!
+//! Shows recent log entries captured in memory, with search and level filters.
+
+use crate::logging::log_buffer;
+use crate::ui::{app::App, theme::Theme, InputMode};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use tracing::Level;
+
+/// Levels in the order of their toggle keys `1` to `5`
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+/// Render the log viewer screen
+pub fn render_log_viewer(frame: &mut Frame, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Search
+            Constraint::Min(0),    // Entries
+            Constraint::Length(1), // Key hints or message
+        ])
+        .split(frame.area());
+
+    let title = Paragraph::new("Log Viewer")
+        .style(theme.title())
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        );
+    frame.render_widget(title, chunks[0]);
+
+    // Copy what is needed out of the buffer so the lock is not held while
+    // drawing; logging from this thread would otherwise deadlock
+    let (query, enabled, entries, total) = {
+        let buffer = log_buffer();
+        let entries: Vec<(Level, String)> = buffer
+            .filtered_entries()
+            .map(|entry| (entry.level, entry.line()))
+            .collect();
+        let enabled: Vec<bool> = LEVELS
+            .iter()
+            .map(|level| buffer.level_filter.contains(level))
+            .collect();
+        (
+            buffer.search_query.clone().unwrap_or_default(),
+            enabled,
+            entries,
+            buffer.len(),
+        )
+    };
+
+    let editing = app.input_mode == InputMode::Editing;
+    crate::ui::components::render_input_field(
+        frame,
+        chunks[1],
+        theme,
+        "Search (/ to edit, x to clear)",
+        if editing { &app.input } else { &query },
+        if editing {
+            app.cursor_byte_pos
+        } else {
+            query.len()
+        },
+        if editing {
+            app.cursor_char_pos
+        } else {
+            query.chars().count()
+        },
+        editing,
+    );
+
+    // Level toggles in the block title
+    let mut title_spans = vec![Span::raw(format!(
+        " Logs ({} of {}) ",
+        entries.len(),
+        total
+    ))];
+    for (index, (level, on)) in LEVELS.iter().zip(&enabled).enumerate() {
+        let style = if *on {
+            level_style(level, theme).add_modifier(Modifier::BOLD)
+        } else {
+            theme.muted().add_modifier(Modifier::CROSSED_OUT)
+        };
+        title_spans.push(Span::styled(format!("{}:{} ", index + 1, level), style));
+    }
+    let block = Block::default()
+        .title(Line::from(title_spans))
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    // Keep the selected entry in view
+    let visible = chunks[2].height.saturating_sub(2) as usize;
+    let selected = app.current_list_index.min(entries.len().saturating_sub(1));
+    let start = selected.saturating_sub(visible.saturating_sub(1));
+    let lines: Vec<Line> = entries
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(index, (level, line))| {
+            let is_selected = index == selected;
+            let base = if is_selected {
+                level_style(level, theme).add_modifier(Modifier::BOLD)
+            } else {
+                level_style(level, theme)
+            };
+            let mut spans = vec![Span::styled(if is_selected { "▶ " } else { "  " }, base)];
+            spans.extend(highlight_matches(line, &query, base, theme));
+            Line::from(spans)
+        })
+        .collect();
+
+    let content = if lines.is_empty() {
+        Paragraph::new("No log entries match the current filters")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+    } else {
+        Paragraph::new(lines)
+    };
+    frame.render_widget(content.block(block), chunks[2]);
+
+    let footer = match (app.error_message(), &app.success_message) {
+        (Some(error), _) => Span::styled(error, theme.error()),
+        (None, Some(success)) => Span::styled(success.clone(), theme.success()),
+        (None, None) => Span::styled(
+            "↑/↓: select  c: copy line  s: save view  1-5: toggle levels  /: search  x: clear",
+            theme.muted(),
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(footer)).alignment(Alignment::Center),
+        chunks[3],
+    );
+}
+
+/// Split `line` into spans, highlighting case-insensitive matches of `query`
+fn highlight_matches(line: &str, query: &str, base: Style, theme: &Theme) -> Vec<Span<'static>> {
+    if query.is_empty() {
+        return vec![Span::styled(line.to_string(), base)];
+    }
+
+    // ASCII lowercasing keeps byte offsets aligned with the original line
+    let haystack = line.to_ascii_lowercase();
+    let needle = query.to_ascii_lowercase();
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for (start, _) in haystack.match_indices(&needle) {
+        if start > last {
+            spans.push(Span::styled(line[last..start].to_string(), base));
+        }
+        let end = start + needle.len();
+        spans.push(Span::styled(line[start..end].to_string(), theme.selected()));
+        last = end;
+    }
+    if last < line.len() {
+        spans.push(Span::styled(line[last..].to_string(), base));
+    }
+    spans
+}
+
+/// Color of a log line by level
+fn level_style(level: &Level, theme: &Theme) -> Style {
+    match *level {
+        Level::ERROR => theme.error(),
+        Level::WARN => theme.warning(),
+        Level::INFO => theme.info(),
+        _ => theme.muted(),
+    }
+}
@@ -0,0 +1,164 @@
+//! Mempool monitor screen for WarpScan
+//!
+//! Lists pending transactions as the node announces them, with an address
+//! filter.
+
+use crate::ui::app::utils::format_address_display;
+use crate::ui::{app::App, theme::Theme, InputMode};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+/// Render the mempool monitor screen
+pub fn render_mempool_monitor(frame: &mut Frame, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Filter
+            Constraint::Min(0),    // Pending transactions
+            Constraint::Length(1), // Key hints or message
+        ])
+        .split(frame.area());
+
+    let title = Paragraph::new("Mempool Monitor")
+        .style(theme.title())
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        );
+    frame.render_widget(title, chunks[0]);
+
+    let editing = app.input_mode == InputMode::Editing;
+    let filter = &app.mempool_filter;
+    crate::ui::components::render_input_field(
+        frame,
+        chunks[1],
+        theme,
+        "Filter by from/to address (/ to edit, x to clear)",
+        if editing { &app.input } else { filter },
+        if editing {
+            app.cursor_byte_pos
+        } else {
+            filter.len()
+        },
+        if editing {
+            app.cursor_char_pos
+        } else {
+            filter.chars().count()
+        },
+        editing,
+    );
+
+    if app.mempool_ws_missing {
+        let banner = Paragraph::new(vec![
+            Line::from(Span::styled(
+                "WebSocket required",
+                theme.warning().add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(Span::styled(
+                "Pending transactions are only pushed over WebSocket. Set network.ws_rpc_url \
+                 (or a ws:// RPC URL) in the config and restart.",
+                theme.muted(),
+            )),
+        ])
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.warning()),
+        );
+        frame.render_widget(banner, chunks[2]);
+    } else {
+        render_pending_table(frame, chunks[2], app, theme);
+    }
+
+    let footer = match (app.error_message(), &app.success_message) {
+        (Some(error), _) => Span::styled(error, theme.error()),
+        (None, Some(success)) => Span::styled(success.clone(), theme.success()),
+        (None, None) => Span::styled(
+            "↑/↓: select  y: copy hash  /: filter  x: clear filter",
+            theme.muted(),
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(footer)).alignment(Alignment::Center),
+        chunks[3],
+    );
+}
+
+/// Pending transactions, newest first
+fn render_pending_table(frame: &mut Frame, area: ratatui::layout::Rect, app: &App, theme: &Theme) {
+    let transactions = app.filtered_mempool_txs();
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    let header = Row::new(["Hash", "From", "To", "Value (ETH)", "Gas (Gwei)", "Age"])
+        .style(theme.label().add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = transactions
+        .iter()
+        .map(|tx| {
+            let to = if tx.to.is_empty() {
+                Span::styled("Contract Creation", theme.info())
+            } else {
+                Span::styled(format_address_display(&tx.to, None, 0), theme.address())
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    format_address_display(&tx.hash, None, 0),
+                    theme.transaction_hash(),
+                )),
+                Cell::from(Span::styled(
+                    format_address_display(&tx.from, None, 0),
+                    theme.address(),
+                )),
+                Cell::from(to),
+                Cell::from(format!("{:.4}", tx.value)),
+                Cell::from(tx.gas_price.to_string()),
+                Cell::from(format!("{}s", now.saturating_sub(tx.timestamp))),
+            ])
+            .style(theme.normal())
+        })
+        .collect();
+
+    let title = if app.mempool_filter.is_empty() {
+        format!("Pending Transactions | Seen: {} txs", app.mempool_seen)
+    } else {
+        format!(
+            "Pending Transactions ({} of {}) | Seen: {} txs",
+            transactions.len(),
+            app.mempool_txs.len(),
+            app.mempool_seen
+        )
+    };
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(18),
+            Constraint::Length(14),
+            Constraint::Length(11),
+            Constraint::Min(6),
+        ],
+    )
+    .header(header)
+    .highlight_style(theme.selected())
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+
+    // Keeps the selected row in view as the list scrolls
+    let mut state = TableState::default()
+        .with_selected((!transactions.is_empty()).then_some(app.current_list_index));
+    frame.render_stateful_widget(table, area, &mut state);
+}
@@ -4,13 +4,19 @@ pub mod block_explorer;
 pub mod contract_interaction;
 pub mod contract_search;
 pub mod contract_verification;
+pub mod create2_calculator;
 pub mod event_monitor;
 pub mod gas_tracker;
 pub mod help;
 pub mod home;
+pub mod log_viewer;
+pub mod mempool_monitor;
 pub mod mode_selection;
 pub mod multisig_wallet;
+pub mod nft_gallery;
 pub mod settings;
+pub mod setup_wizard;
+pub mod simulation;
 pub mod token_info;
 pub mod transaction_viewer;
 pub mod wallet_manager;
@@ -21,13 +27,19 @@ pub use block_explorer::render_block_explorer;
 pub use contract_interaction::render_contract_interaction;
 pub use contract_search::render_contract_search;
 pub use contract_verification::render_contract_verification;
+pub use create2_calculator::render_create2_calculator;
 pub use event_monitor::render_event_monitor;
 pub use gas_tracker::render_gas_tracker;
-pub use help::render_help;
-pub use home::render_home;
+pub use help::{render_help, render_help_overlay};
+pub use home::{render_dashboard_loading, render_home};
+pub use log_viewer::render_log_viewer;
+pub use mempool_monitor::render_mempool_monitor;
 pub use mode_selection::render_mode_selection;
 pub use multisig_wallet::render_multisig_wallet;
+pub use nft_gallery::render_nft_gallery;
 pub use settings::render_settings;
+pub use setup_wizard::render_setup_wizard;
+pub use simulation::render_simulation;
 pub use token_info::render_token_info;
 pub use transaction_viewer::render_transaction_viewer;
 pub use wallet_manager::render_wallet_manager;
@@ -2,19 +2,26 @@
 //!
 //! This module contains the multi-signature wallet screen implementation.
 
+use crate::blockchain::SafeDeployment;
+use crate::ui::app::utils::format_address_display;
+use crate::ui::models::{MultisigState, MultisigTab};
 use crate::ui::{app::App, theme::Theme};
+use ethers::utils::to_checksum;
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Cell, Clear, List, ListItem, Paragraph, Row, Table, Tabs, Wrap},
     Frame,
 };
 
 /// Render the multi-signature wallet screen
-pub fn render_multisig_wallet(frame: &mut Frame, _app: &App, theme: &Theme) {
+pub fn render_multisig_wallet(frame: &mut Frame, app: &App, theme: &Theme) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
+            Constraint::Length(3), // Tabs
             Constraint::Min(0),    // Content area
         ])
         .split(frame.area());
@@ -30,14 +37,343 @@ pub fn render_multisig_wallet(frame: &mut Frame, _app: &App, theme: &Theme) {
         );
     frame.render_widget(title, main_chunks[0]);
 
-    // Content area
-    let content = Paragraph::new("Multi-signature Wallet screen - Coming soon")
-        .style(theme.normal())
+    let state = &app.multisig_state;
+    let pending_title = format!(
+        "{} ({})",
+        MultisigTab::Pending.title(),
+        state.proposals.len()
+    );
+    let tabs = Tabs::new(vec![
+        MultisigTab::Wallets.title().to_string(),
+        MultisigTab::Propose.title().to_string(),
+        pending_title,
+    ])
+    .block(
+        Block::default()
+            .title("Tab to switch")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    )
+    .style(theme.normal())
+    .highlight_style(theme.selected())
+    .select(
+        MultisigTab::ALL
+            .iter()
+            .position(|tab| *tab == state.tab)
+            .unwrap_or(0),
+    );
+    frame.render_widget(tabs, main_chunks[1]);
+
+    match state.tab {
+        MultisigTab::Wallets => render_wallets(frame, main_chunks[2], app, theme),
+        MultisigTab::Propose => render_propose(frame, main_chunks[2], app, theme),
+        MultisigTab::Pending => render_pending(frame, main_chunks[2], app, theme),
+    }
+
+    if let Some(ref deployment) = state.pending_deployment {
+        render_deployment_popup(frame, deployment, theme);
+    }
+}
+
+/// New wallet form above the tracked wallets
+fn render_wallets(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Owners
+            Constraint::Length(3), // Threshold
+            Constraint::Length(3), // Create button
+            Constraint::Min(0),    // Tracked wallets
+        ])
+        .split(area);
+    render_form(
+        frame,
+        &chunks[..3],
+        &MultisigState::CREATE_LABELS,
+        app,
+        theme,
+    );
+    render_button(frame, chunks[2], "[ Create Safe ]", app, theme);
+
+    let wallets = &app.multisig_state.wallets;
+    let items: Vec<ListItem> = if wallets.is_empty() {
+        vec![ListItem::new(Span::styled(
+            "No wallets yet. Enter owners and a threshold, then press Enter on Create Safe",
+            theme.muted(),
+        ))]
+    } else {
+        wallets
+            .iter()
+            .map(|wallet| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(wallet.address.clone(), theme.address()),
+                    Span::styled(
+                        format!("  {} of {} owners", wallet.threshold, wallet.owners.len()),
+                        theme.info(),
+                    ),
+                ]))
+            })
+            .collect()
+    };
+    let mut block = Block::default()
+        .title(format!("Tracked Wallets ({})", wallets.len()))
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+    if let Some(ref success) = app.success_message {
+        block = block.title_bottom(Span::styled(success.clone(), theme.success()));
+    }
+    let list = List::new(items).block(block);
+    frame.render_widget(list, chunks[3]);
+}
+
+/// Transaction proposal form
+fn render_propose(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let mut constraints = vec![Constraint::Length(3); MultisigState::PROPOSE_LABELS.len() + 1];
+    constraints.push(Constraint::Min(0));
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+    let button_row = MultisigState::PROPOSE_LABELS.len();
+    render_form(
+        frame,
+        &chunks[..=button_row],
+        &MultisigState::PROPOSE_LABELS,
+        app,
+        theme,
+    );
+    render_button(frame, chunks[button_row], "[ Propose ]", app, theme);
+
+    let hint = match app.error_message() {
+        Some(error) => Span::styled(error, theme.error()),
+        None => Span::styled(
+            "Use ↑/↓ to select a field, Enter to edit; the nonce is the wallet's next nonce",
+            theme.muted(),
+        ),
+    };
+    frame.render_widget(
+        Paragraph::new(hint).wrap(Wrap { trim: true }),
+        chunks[button_row + 1],
+    );
+}
+
+/// Proposals with their collected signatures, and the key prompt while signing
+fn render_pending(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let state = &app.multisig_state;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area);
+
+    let header = Row::new([
+        "Safe Tx Hash",
+        "Wallet",
+        "To",
+        "Value (ETH)",
+        "Nonce",
+        "Signatures",
+    ])
+    .style(theme.label());
+    let rows: Vec<Row> = state
+        .proposals
+        .iter()
+        .enumerate()
+        .map(|(index, proposal)| {
+            let threshold = state.threshold_of(&proposal.safe_address).unwrap_or(0);
+            let collected = proposal.signatures.len() as u32;
+            let signatures_style = if threshold > 0 && collected >= threshold {
+                theme.success()
+            } else {
+                theme.warning()
+            };
+            let row_style = if index == app.current_list_index {
+                theme.selected()
+            } else {
+                Style::default().fg(theme.foreground)
+            };
+            Row::new(vec![
+                Cell::from(format_address_display(&proposal.id, None, 0)),
+                Cell::from(format_address_display(&proposal.safe_address, None, 0)),
+                Cell::from(format_address_display(&proposal.to, None, 0)),
+                Cell::from(ethers::utils::format_ether(proposal.value)),
+                Cell::from(proposal.nonce.to_string()),
+                Cell::from(Span::styled(
+                    format!("{}/{}", collected, threshold),
+                    signatures_style,
+                )),
+            ])
+            .style(row_style)
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(14),
+            Constraint::Length(7),
+            Constraint::Min(10),
+        ],
+    )
+    .header(header)
+    .block(
+        Block::default()
+            .title("Awaiting Signatures (Enter: sign selected)")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(table, chunks[0]);
+
+    // The key is masked; only its length is shown
+    let editing = state.signing && app.input_mode == crate::ui::InputMode::Editing;
+    let prompt = if editing {
+        Span::styled("•".repeat(app.input.chars().count()), theme.input_active())
+    } else if let Some(error) = app.error_message() {
+        Span::styled(error, theme.error())
+    } else if let Some(ref success) = app.success_message {
+        Span::styled(success.clone(), theme.success())
+    } else {
+        Span::styled(
+            "Select a proposal and press Enter to sign it",
+            theme.muted(),
+        )
+    };
+    frame.render_widget(
+        Paragraph::new(prompt).block(
+            Block::default()
+                .title("Owner Private Key (Enter: sign, Esc: cancel)")
+                .borders(Borders::ALL)
+                .border_style(if editing {
+                    theme.primary()
+                } else {
+                    theme.border()
+                }),
+        ),
+        chunks[1],
+    );
+}
+
+/// Input rows of a form; the last area is the button row and is skipped
+fn render_form(frame: &mut Frame, areas: &[Rect], labels: &[&str], app: &App, theme: &Theme) {
+    let editing = app.input_mode == crate::ui::InputMode::Editing;
+    for (index, (label, area)) in labels.iter().zip(areas).enumerate() {
+        let focused = app.current_list_index == index;
+        let is_editing = focused && editing;
+        let value = if is_editing {
+            app.input.as_str()
+        } else {
+            app.multisig_state.field(index)
+        };
+
+        crate::ui::components::render_input_field(
+            frame,
+            *area,
+            theme,
+            label,
+            value,
+            app.cursor_byte_pos,
+            app.cursor_char_pos,
+            is_editing,
+        );
+
+        if focused && !is_editing {
+            let marker = Block::default()
+                .title(*label)
+                .borders(Borders::ALL)
+                .border_style(theme.selected());
+            frame.render_widget(marker, *area);
+        }
+    }
+}
+
+/// The form's submit button, highlighted when focused
+fn render_button(frame: &mut Frame, area: Rect, label: &str, app: &App, theme: &Theme) {
+    let focused = app.current_list_index + 1 == app.multisig_state.tab.field_count();
+    let button = Paragraph::new(label.to_string())
+        .style(if focused {
+            theme.selected()
+        } else {
+            theme.normal()
+        })
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(theme.secondary()),
+                .border_style(theme.border()),
         );
-    frame.render_widget(content, main_chunks[1]);
+    frame.render_widget(button, area);
+}
+
+/// Confirmation of the unsigned Safe deployment transaction
+fn render_deployment_popup(frame: &mut Frame, deployment: &SafeDeployment, theme: &Theme) {
+    let screen = frame.area();
+    let width = 80.min(screen.width);
+    let height = 16.min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+
+    let address = deployment
+        .predicted_address
+        .map(|address| to_checksum(&address, None))
+        .unwrap_or_else(|| "unknown".to_string());
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Safe Address: ", theme.label()),
+            Span::styled(address, theme.address()),
+        ]),
+        Line::from(vec![
+            Span::styled("Threshold:    ", theme.label()),
+            Span::styled(
+                format!(
+                    "{} of {} owners",
+                    deployment.threshold,
+                    deployment.owners.len()
+                ),
+                theme.info(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("To:           ", theme.label()),
+            Span::styled(to_checksum(&deployment.to, None), theme.address()),
+            Span::styled(" (Safe proxy factory)", theme.muted()),
+        ]),
+        Line::from(vec![
+            Span::styled("Value:        ", theme.label()),
+            Span::styled("0 ETH", theme.normal()),
+        ]),
+        Line::from(vec![
+            Span::styled("Data:         ", theme.label()),
+            Span::styled(format!("{} bytes", deployment.data.len()), theme.normal()),
+        ]),
+        Line::from(""),
+    ];
+    for owner in &deployment.owners {
+        lines.push(Line::from(vec![
+            Span::styled("  owner ", theme.muted()),
+            Span::styled(to_checksum(owner, None), theme.address()),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "y/Enter: track and copy calldata   n/Esc: cancel",
+        theme.warning(),
+    )));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(Text::from(lines))
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .title("Deploy Safe (unsigned transaction)")
+                    .borders(Borders::ALL)
+                    .border_style(theme.primary()),
+            ),
+        area,
+    );
 }
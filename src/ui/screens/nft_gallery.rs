@@ -0,0 +1,198 @@
+//! NFT Gallery screen for WarpScan
+//!
+//! This module shows the NFTs of a single collection held by the looked-up address.
+
+use crate::ui::{app::App, theme::Theme};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the NFT gallery screen
+pub fn render_nft_gallery(frame: &mut Frame, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(6), // Collection info
+            Constraint::Min(0),    // Token IDs
+        ])
+        .split(frame.area());
+
+    // Title
+    let title = Paragraph::new("NFT Gallery")
+        .style(theme.title())
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        );
+    frame.render_widget(title, chunks[0]);
+
+    let token = match app.nft_gallery_token {
+        Some(ref token) => token,
+        None => {
+            let empty = Paragraph::new("No NFT collection selected")
+                .style(theme.muted())
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(theme.border()),
+                );
+            frame.render_widget(empty, chunks[1]);
+            return;
+        }
+    };
+
+    // Collection info
+    let token_type_text = match &token.token_type {
+        crate::ui::models::TokenType::ERC721 => "ERC-721",
+        crate::ui::models::TokenType::ERC1155 => "ERC-1155",
+        crate::ui::models::TokenType::ERC20 => "ERC-20",
+        crate::ui::models::TokenType::Other(name) => name,
+    };
+    let floor_price_text = token
+        .floor_price
+        .map(|p| format!("{:.4} ETH", p))
+        .unwrap_or_else(|| "Unavailable".to_string());
+
+    let info = Text::from(vec![
+        Line::from(vec![
+            Span::styled("Collection: ", theme.label()),
+            Span::styled(
+                format!("{} ({})", token.name, token.symbol),
+                theme.primary(),
+            ),
+            Span::raw(" | "),
+            Span::styled(token_type_text, theme.info()),
+        ]),
+        Line::from(vec![
+            Span::styled("Contract: ", theme.label()),
+            Span::styled(&token.contract_address, theme.normal()),
+        ]),
+        Line::from(vec![
+            Span::styled("Owned: ", theme.label()),
+            Span::styled(format!("{}", token.balance as u64), theme.warning()),
+            Span::raw(" | "),
+            Span::styled("Floor Price: ", theme.label()),
+            Span::styled(floor_price_text, theme.success()),
+        ]),
+    ]);
+    let info_paragraph = Paragraph::new(info).block(
+        Block::default()
+            .title("Collection")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(info_paragraph, chunks[1]);
+
+    // Token IDs seen in this address's transfer history for the collection
+    let token_ids = app.nft_gallery_token_ids();
+
+    if token_ids.is_empty() {
+        let empty = Paragraph::new("No token IDs found in transfer history")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title("Tokens")
+                    .borders(Borders::ALL)
+                    .border_style(theme.border()),
+            );
+        frame.render_widget(empty, chunks[2]);
+        return;
+    }
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(chunks[2]);
+
+    let items: Vec<ListItem> = token_ids
+        .iter()
+        .enumerate()
+        .map(|(index, id)| {
+            let style = if index == app.current_list_index {
+                theme.selected()
+            } else {
+                theme.normal()
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled("◆ ", theme.primary()),
+                Span::styled(format!("{} #{}", token.symbol, id), style),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(format!("Tokens ({})", token_ids.len()))
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(list, columns[0]);
+
+    render_nft_metadata_panel(frame, columns[1], app, theme);
+}
+
+/// Render the metadata of the selected NFT card
+fn render_nft_metadata_panel(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let content = if app.is_loading("nft_metadata") {
+        Text::from(Span::styled("Loading metadata...", theme.muted()))
+    } else if let Some(ref metadata) = app.nft_metadata {
+        let mut lines = vec![Line::from(Span::styled(
+            metadata.name.clone(),
+            theme.primary().add_modifier(Modifier::BOLD),
+        ))];
+        if let Some(ref description) = metadata.description {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                description.clone(),
+                theme.normal(),
+            )));
+        }
+        if let Some(ref image_url) = metadata.image_url {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("[Image: {}]", image_url),
+                theme.muted(),
+            )));
+        }
+        if let Some(ref external_url) = metadata.external_url {
+            lines.push(Line::from(vec![
+                Span::styled("External URL: ", theme.label()),
+                Span::styled(external_url.clone(), theme.info()),
+            ]));
+        }
+        if !metadata.attributes.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Attributes", theme.label())));
+            for attribute in &metadata.attributes {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("  {}: ", attribute.trait_type), theme.muted()),
+                    Span::styled(attribute.value.clone(), theme.normal()),
+                ]));
+            }
+        }
+        Text::from(lines)
+    } else if let Some(error) = app.error_message() {
+        Text::from(Span::styled(error, theme.error()))
+    } else {
+        Text::from(Span::styled("No metadata available", theme.muted()))
+    };
+
+    let panel = Paragraph::new(content)
+        .block(
+            Block::default()
+                .title("Metadata")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .wrap(Wrap { trim: true });
+    frame.render_widget(panel, area);
+}
@@ -2,42 +2,472 @@
 //!
 //! This module contains the settings screen implementation.
 
+use crate::cache::CacheType;
+use crate::config::Network;
+use crate::ui::app::settings::format_ttl;
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{
+        Block, Borders, Cell, Clear, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+    },
     Frame,
 };
 
 /// Render the settings screen
-pub fn render_settings(frame: &mut Frame, _app: &App, theme: &Theme) {
+pub fn render_settings(frame: &mut Frame, app: &App, theme: &Theme) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
+            Constraint::Length(3), // Theme selector
             Constraint::Min(0),    // Content area
         ])
         .split(frame.area());
 
-    // Title
-    let title = Paragraph::new("Settings")
-        .style(theme.title())
+    // Title, with the active network
+    let title = Paragraph::new(format!(
+        "Settings - {} (Chain {})",
+        app.config.network.name, app.config.network.chain_id
+    ))
+    .style(theme.title())
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.primary()),
+    );
+    frame.render_widget(title, main_chunks[0]);
+
+    // Theme selector
+    let current = app.theme_manager.current_name();
+    let mut spans = vec![Span::styled("◀ ", theme.muted())];
+    for (index, name) in app.theme_manager.list_themes().iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::raw("  "));
+        }
+        let style = if name == current {
+            theme.selected()
+        } else {
+            theme.normal()
+        };
+        spans.push(Span::styled(format!(" {} ", name), style));
+    }
+    spans.push(Span::styled(" ▶", theme.muted()));
+
+    let selector = Paragraph::new(Line::from(spans))
         .alignment(Alignment::Center)
         .block(
             Block::default()
+                .title("Theme (←/→ or Enter to change)")
                 .borders(Borders::ALL)
-                .border_style(theme.primary()),
+                .border_style(if app.settings_ttl_focus {
+                    theme.border()
+                } else {
+                    theme.primary()
+                }),
         );
-    frame.render_widget(title, main_chunks[0]);
+    frame.render_widget(selector, main_chunks[1]);
+
+    if app.cache_inspector_open {
+        render_cache_inspector(frame, main_chunks[2], app, theme);
+        return;
+    }
+    if app.settings_editor.open {
+        render_settings_editor(frame, main_chunks[2], app, theme);
+        return;
+    }
 
-    // Content area
-    let content = Paragraph::new("Settings screen - Coming soon")
-        .style(theme.normal())
+    let content_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(main_chunks[2]);
+    let cache_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(36)])
+        .split(content_chunks[0]);
+    render_cache_stats(frame, cache_chunks[0], app, theme);
+    render_cache_ttls(frame, cache_chunks[1], app, theme);
+
+    let message = if app.cache_clear_confirm {
+        Span::styled(
+            "Clear all cached data? y/Enter: confirm, any other key: cancel",
+            theme.warning().add_modifier(Modifier::BOLD),
+        )
+    } else {
+        match (app.error_message(), &app.success_message) {
+            (Some(error), _) => Span::styled(error, theme.error()),
+            (None, Some(success)) => Span::styled(success.clone(), theme.success()),
+            (None, None) => Span::styled(
+                "Tab: theme/TTLs  n: switch network  e: edit config  i: cache inspector (debug mode)  Ctrl+E: clear expired  Ctrl+Del: clear all",
+                theme.muted(),
+            ),
+        }
+    };
+    let content = Paragraph::new(Line::from(message))
         .alignment(Alignment::Center)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(theme.secondary()),
         );
-    frame.render_widget(content, main_chunks[1]);
+    frame.render_widget(content, content_chunks[1]);
+
+    if app.network_picker_open {
+        render_network_picker(frame, app, theme);
+    }
+}
+
+/// Render the network switcher dialog over the settings screen
+fn render_network_picker(frame: &mut Frame, app: &App, theme: &Theme) {
+    let items: Vec<ListItem> = Network::PRESET_CHAINS
+        .iter()
+        .map(|&chain| {
+            let network = Network::preset(chain);
+            let marker = if network.chain_id == app.config.network.chain_id {
+                "● "
+            } else {
+                "  "
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, theme.success()),
+                Span::styled(network.name, theme.normal()),
+                Span::styled(format!("  chain {}", network.chain_id), theme.muted()),
+            ]))
+        })
+        .collect();
+
+    let screen = frame.area();
+    let width = screen.width.min(44);
+    let height = (items.len() as u16 + 2).min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+
+    let mut state = ListState::default().with_selected(Some(app.network_picker_index));
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("Switch Network (Enter: switch, Esc: close)")
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        )
+        .highlight_style(theme.selected());
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+/// Render the cache statistics table from `export_stats_to_json`
+fn render_cache_stats(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let stats = app.cache_manager.export_stats_to_json();
+
+    let hit_ratio = stats["hit_ratio"]
+        .as_f64()
+        .map(|ratio| format!("{:.1}%", ratio * 100.0))
+        .unwrap_or_else(|| "N/A".to_string());
+    let last_cleared = stats["last_cleared"]
+        .as_u64()
+        .map(format_time)
+        .unwrap_or_else(|| "Never".to_string());
+    let title = format!(
+        "Cache{} - {} entries, ~{} | Hit ratio {} ({} hits, {} misses) | Last cleared {}",
+        if stats["enabled"].as_bool() == Some(false) {
+            " (disabled)"
+        } else {
+            ""
+        },
+        stats["total_entries"],
+        format_bytes(stats["total_approx_memory_bytes"].as_u64().unwrap_or(0)),
+        hit_ratio,
+        stats["hits"],
+        stats["misses"],
+        last_cleared,
+    );
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    let header = Row::new(
+        ["Cache", "Entries", "Approx. Memory", "Oldest Entry"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+    let rows: Vec<Row> = stats["caches"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|cache| {
+            let entries = cache["entries"].as_u64().unwrap_or(0);
+            let oldest = match cache["oldest_entry"].as_u64() {
+                Some(timestamp) => format_time(timestamp),
+                None if entries > 0 => "Permanent".to_string(),
+                None => "-".to_string(),
+            };
+            let style = if entries > 0 {
+                theme.normal()
+            } else {
+                theme.muted()
+            };
+            Row::new(vec![
+                Cell::from(cache["name"].as_str().unwrap_or_default().to_string()),
+                Cell::from(entries.to_string()),
+                Cell::from(format_bytes(
+                    cache["approx_memory_bytes"].as_u64().unwrap_or(0),
+                )),
+                Cell::from(oldest),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(24),
+            Constraint::Length(10),
+            Constraint::Length(16),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(block);
+    frame.render_widget(table, area);
+}
+
+/// Render the TTL of each cache, editable with the arrow keys once focused
+fn render_cache_ttls(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let rows: Vec<Row> = CacheType::ALL
+        .iter()
+        .map(|&cache| {
+            Row::new(vec![
+                Cell::from(cache.label()),
+                Cell::from(format_ttl(app.cache_manager.ttl(cache))),
+            ])
+            .style(theme.normal())
+        })
+        .collect();
+
+    let mut state = TableState::default()
+        .with_selected(app.settings_ttl_focus.then_some(app.settings_ttl_index));
+    let table = Table::new(rows, [Constraint::Min(22), Constraint::Length(8)])
+        .block(
+            Block::default()
+                .title("Cache TTLs (Tab, ↑/↓, ←/→)")
+                .borders(Borders::ALL)
+                .border_style(if app.settings_ttl_focus {
+                    theme.primary()
+                } else {
+                    theme.border()
+                }),
+        )
+        .highlight_style(theme.selected());
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+/// Format a byte count with a binary unit, e.g. `12.3 KiB`
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+/// Render the config file editor: the fields, then the selected field's
+/// description or the value being edited
+fn render_settings_editor(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let editor = &app.settings_editor;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(area);
+
+    let header = Row::new(
+        ["Key", "Value", "Type"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+    let rows: Vec<Row> = editor
+        .fields
+        .iter()
+        .map(|field| {
+            let value = if field.current_value.is_empty() {
+                Span::styled("(unset)", theme.muted())
+            } else {
+                Span::styled(field.current_value.clone(), theme.normal())
+            };
+            Row::new(vec![
+                Cell::from(Span::styled(field.key.clone(), theme.info())),
+                Cell::from(value),
+                Cell::from(Span::styled(field.field_type.label(), theme.muted())),
+            ])
+        })
+        .collect();
+
+    let title = format!(
+        "Config Editor{} (↑/↓: select, Enter: edit, s: save, Esc: close)",
+        if editor.dirty { " *" } else { "" }
+    );
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(42),
+            Constraint::Min(20),
+            Constraint::Length(8),
+        ],
+    )
+    .header(header)
+    .highlight_style(theme.selected())
+    .block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(theme.primary()),
+    );
+    let mut state = TableState::default().with_selected(Some(editor.selected));
+    frame.render_stateful_widget(table, chunks[0], &mut state);
+
+    let Some(field) = editor.selected_field() else {
+        return;
+    };
+    if editor.editing {
+        crate::ui::components::render_input_field(
+            frame,
+            chunks[1],
+            theme,
+            &format!("{} (Enter: accept, Esc: cancel)", field.key),
+            &app.input,
+            app.cursor_byte_pos,
+            app.cursor_char_pos,
+            true,
+        );
+    }
+
+    // Validation errors take the bottom border while editing, the whole
+    // line otherwise
+    let message = match editor.error {
+        Some(ref error) => Span::styled(error.clone(), theme.error()),
+        None if editor.editing => return,
+        None => Span::styled(field.description.clone(), theme.muted()),
+    };
+    if editor.editing {
+        let error_area = Rect {
+            x: chunks[1].x + 2,
+            y: chunks[1].y + chunks[1].height.saturating_sub(1),
+            width: chunks[1].width.saturating_sub(4),
+            height: 1,
+        };
+        frame.render_widget(Paragraph::new(Line::from(message)), error_area);
+    } else {
+        let description = Paragraph::new(Line::from(message)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.secondary()),
+        );
+        frame.render_widget(description, chunks[1]);
+    }
+}
+
+/// Render the cache inspector: a key query and the matching entries
+fn render_cache_inspector(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(area);
+
+    crate::ui::components::render_input_field(
+        frame,
+        chunks[0],
+        theme,
+        "Cache key (e.g. block:12345, ens:0x..., or a bare address)",
+        &app.input,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
+        app.input_mode == crate::ui::InputMode::Editing,
+    );
+
+    let block = Block::default()
+        .title("Cache Inspector (i: new query, Esc: close)")
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    let entries = match app.cache_inspector_entries {
+        Some(ref entries) if !entries.is_empty() => entries,
+        Some(_) => {
+            let empty = Paragraph::new("No cache entries match this key")
+                .style(theme.muted())
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(empty, chunks[1]);
+            return;
+        }
+        None => {
+            let hint = Paragraph::new("Enter a key and press Enter to inspect the cache")
+                .style(theme.muted())
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(hint, chunks[1]);
+            return;
+        }
+    };
+
+    let header = Row::new(
+        ["Key", "Size", "Created", "TTL", "Expires", "Status"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+    let rows: Vec<Row> = entries
+        .iter()
+        .map(|entry| {
+            let status = if entry.is_expired {
+                Span::styled("Expired", theme.error())
+            } else {
+                Span::styled("Live", theme.success())
+            };
+            Row::new(vec![
+                Cell::from(entry.key.clone()),
+                Cell::from(format!("{} B", entry.data_size_bytes)),
+                Cell::from(format_time(entry.created_at)),
+                Cell::from(format!("{}s", entry.ttl_seconds)),
+                Cell::from(format_time(entry.expires_at)),
+                Cell::from(status),
+            ])
+            .style(theme.normal())
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(30),
+            Constraint::Length(10),
+            Constraint::Length(20),
+            Constraint::Length(10),
+            Constraint::Length(20),
+            Constraint::Length(8),
+        ],
+    )
+    .header(header)
+    .block(block);
+    frame.render_widget(table, chunks[1]);
+}
+
+/// Format a unix timestamp as a UTC date and time
+fn format_time(timestamp: u64) -> String {
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "Unknown".to_string())
 }
@@ -0,0 +1,151 @@
+//! First-run setup wizard for WarpScan
+//!
+//! Shown before the data source selection when no config file exists.
+
+use crate::ui::models::{
+    SetupWizard, SetupWizardStep, ETHERSCAN_SIGNUP_URL, PUBLIC_RPC_ENDPOINTS, WIZARD_NETWORKS,
+};
+use crate::ui::{app::App, theme::Theme};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the setup wizard
+pub fn render_setup_wizard(frame: &mut Frame, app: &App, theme: &Theme) {
+    let Some(ref wizard) = app.setup_wizard else {
+        return;
+    };
+
+    let area = frame.area();
+    let popup = area.inner(Margin {
+        horizontal: area.width / 6,
+        vertical: area.height / 8,
+    });
+    frame.render_widget(Clear, popup);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // Input
+            Constraint::Min(0),    // Step details
+            Constraint::Length(3), // Instructions
+        ])
+        .split(popup);
+
+    // Title
+    let title = Paragraph::new(format!(
+        "Welcome to WarpScan - Step {} of 3: {}",
+        wizard.step.number(),
+        wizard.step.title()
+    ))
+    .style(theme.title())
+    .alignment(Alignment::Center)
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.primary()),
+    );
+    frame.render_widget(title, chunks[0]);
+
+    // Input (the network step picks from a list instead)
+    if wizard.step == SetupWizardStep::Network {
+        let chosen = wizard
+            .list_index
+            .map(|i| WIZARD_NETWORKS[i].0)
+            .unwrap_or("(keep default)");
+        let selection = Paragraph::new(chosen).style(theme.normal()).block(
+            Block::default()
+                .title("Selected Network")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        );
+        frame.render_widget(selection, chunks[1]);
+    } else {
+        let label = match wizard.step {
+            SetupWizardStep::RpcUrl => "RPC URL",
+            _ => "API Key",
+        };
+        crate::ui::components::render_input_field(
+            frame,
+            chunks[1],
+            theme,
+            label,
+            &app.input,
+            app.cursor_byte_pos,
+            app.cursor_char_pos,
+            true,
+        );
+    }
+
+    render_step_details(frame, chunks[2], wizard, theme);
+
+    // Instructions
+    let instructions =
+        Paragraph::new("Enter/Tab: next step (leave blank to skip)  ↑/↓: choose  Esc: skip setup")
+            .style(theme.warning())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.border()),
+            );
+    frame.render_widget(instructions, chunks[3]);
+}
+
+/// Render the explanation and choices of the current step
+fn render_step_details(frame: &mut Frame, area: Rect, wizard: &SetupWizard, theme: &Theme) {
+    let choice = |index: usize, name: &str, detail: &str| {
+        let style = if wizard.list_index == Some(index) {
+            theme.selected()
+        } else {
+            theme.normal()
+        };
+        Line::from(vec![
+            Span::styled(format!("  {:<24}", name), style),
+            Span::styled(detail.to_string(), theme.muted()),
+        ])
+    };
+
+    let mut lines = Vec::new();
+    match wizard.step {
+        SetupWizardStep::RpcUrl => {
+            lines.push(Line::from(
+                "Type the JSON-RPC URL of your node or pick a public endpoint:",
+            ));
+            lines.push(Line::from(""));
+            for (index, (name, url)) in PUBLIC_RPC_ENDPOINTS.iter().enumerate() {
+                lines.push(choice(index, name, url));
+            }
+        }
+        SetupWizardStep::EtherscanApiKey => {
+            lines.push(Line::from(
+                "An Etherscan API key unlocks transaction history, token balances and name tags.",
+            ));
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("Get a free key at: ", theme.label()),
+                Span::styled(ETHERSCAN_SIGNUP_URL, theme.info()),
+            ]));
+        }
+        SetupWizardStep::Network => {
+            lines.push(Line::from("Which network does this RPC endpoint serve?"));
+            lines.push(Line::from(""));
+            for (index, (name, chain_id, _)) in WIZARD_NETWORKS.iter().enumerate() {
+                lines.push(choice(index, name, &format!("Chain ID {}", chain_id)));
+            }
+        }
+    }
+
+    let details = Paragraph::new(Text::from(lines))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(details, area);
+}
@@ -0,0 +1,161 @@
+//! Transaction Simulation screen for WarpScan
+//!
+//! This module contains the dry-run simulation screen implementation.
+
+use crate::ui::models::{SimulationForm, SIMULATION_FIELD_COUNT};
+use crate::ui::{app::App, theme::Theme};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the transaction simulation screen
+pub fn render_simulation(frame: &mut Frame, app: &App, theme: &Theme) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Length(3), // From
+            Constraint::Length(3), // To
+            Constraint::Length(3), // Data
+            Constraint::Length(3), // Value
+            Constraint::Length(3), // Simulate button
+            Constraint::Min(0),    // Result
+        ])
+        .split(frame.area());
+
+    // Title
+    let title = Paragraph::new("Transaction Simulation")
+        .style(theme.title())
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.primary()),
+        );
+    frame.render_widget(title, chunks[0]);
+
+    // Input fields
+    let editing = app.input_mode == crate::ui::InputMode::Editing;
+    for (index, label) in SimulationForm::FIELD_LABELS.iter().enumerate() {
+        let area = chunks[index + 1];
+        let focused = app.current_list_index == index;
+        let is_editing = focused && editing;
+        let value = if is_editing {
+            app.input.as_str()
+        } else {
+            app.simulation_form.field(index)
+        };
+
+        crate::ui::components::render_input_field(
+            frame,
+            area,
+            theme,
+            label,
+            value,
+            app.cursor_byte_pos,
+            app.cursor_char_pos,
+            is_editing,
+        );
+
+        if focused && !is_editing {
+            let marker = Block::default()
+                .title(*label)
+                .borders(Borders::ALL)
+                .border_style(theme.selected());
+            frame.render_widget(marker, area);
+        }
+    }
+
+    // Simulate button
+    let button_style = if app.current_list_index == SIMULATION_FIELD_COUNT - 1 {
+        theme.selected()
+    } else {
+        theme.normal()
+    };
+    let button = Paragraph::new("[ Simulate ]")
+        .style(button_style)
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        );
+    frame.render_widget(button, chunks[5]);
+
+    // Result
+    let result_text = if app.is_loading("simulation") {
+        Text::from("Simulating...")
+    } else if let Some(error) = app.error_message() {
+        Text::from(Span::styled(error, theme.error()))
+    } else if let Some(ref result) = app.simulation_form.result {
+        if result.success {
+            let mut lines = vec![
+                Line::from(vec![
+                    Span::styled("Status: ", theme.label()),
+                    Span::styled("Success", theme.success()),
+                ]),
+                Line::from(vec![
+                    Span::styled("Estimated Gas: ", theme.label()),
+                    Span::styled(result.gas_used.to_string(), theme.normal()),
+                ]),
+                Line::from(vec![
+                    Span::styled("Gas Cost: ", theme.label()),
+                    Span::styled(format!("{:.6} ETH", result.gas_cost_eth), theme.normal()),
+                ]),
+            ];
+            match &app.simulation_form.decoded_output {
+                // Fall back to the raw bytes when no ABI is known
+                Some(values) if !values.is_empty() => {
+                    lines.push(Line::from(Span::styled("Return Values:", theme.label())));
+                    lines.extend(values.iter().map(|(kind, value)| {
+                        Line::from(vec![
+                            Span::styled(format!("  {}: ", kind), theme.muted()),
+                            Span::styled(value.clone(), theme.normal()),
+                        ])
+                    }));
+                }
+                _ => lines.push(Line::from(vec![
+                    Span::styled("Return Data: ", theme.label()),
+                    Span::styled(result.return_data.clone(), theme.normal()),
+                ])),
+            }
+            Text::from(lines)
+        } else {
+            Text::from(vec![
+                Line::from(vec![
+                    Span::styled("Status: ", theme.label()),
+                    Span::styled("Reverted", theme.error()),
+                ]),
+                Line::from(vec![
+                    Span::styled("Reason: ", theme.label()),
+                    Span::styled(
+                        result.revert_reason.clone().unwrap_or_default(),
+                        theme.error(),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::styled("Revert Data: ", theme.label()),
+                    Span::styled(result.return_data.clone(), theme.muted()),
+                ]),
+            ])
+        }
+    } else {
+        Text::from(Span::styled(
+            "Use ↑/↓ to select a field, Enter to edit, Enter on Simulate to run",
+            theme.muted(),
+        ))
+    };
+
+    let result_paragraph = Paragraph::new(result_text)
+        .block(
+            Block::default()
+                .title("Result")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .wrap(Wrap { trim: false });
+    frame.render_widget(result_paragraph, chunks[6]);
+}
@@ -2,19 +2,25 @@
 //!
 //! This module contains the token information screen implementation.
 
+use crate::blockchain::{KnownAddresses, TokenHolder};
+use crate::ui::models::{TokenInfo, TokenInfoTab};
 use crate::ui::{app::App, theme::Theme};
+use ethers::types::U256;
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    widgets::{Block, Borders, Paragraph},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Tabs},
     Frame,
 };
 
 /// Render the token information screen
-pub fn render_token_info(frame: &mut Frame, _app: &App, theme: &Theme) {
+pub fn render_token_info(frame: &mut Frame, app: &App, theme: &Theme) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3), // Title
+            Constraint::Length(3), // Tabs
             Constraint::Min(0),    // Content area
         ])
         .split(frame.area());
@@ -30,14 +36,158 @@ pub fn render_token_info(frame: &mut Frame, _app: &App, theme: &Theme) {
         );
     frame.render_widget(title, main_chunks[0]);
 
-    // Content area
-    let content = Paragraph::new("Token Information screen - Coming soon")
-        .style(theme.normal())
-        .alignment(Alignment::Center)
+    let Some(ref token) = app.token_info_token else {
+        let empty = Paragraph::new("Select a token in the address Tokens tab and press i")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.secondary()),
+            );
+        frame.render_widget(empty, main_chunks[1].union(main_chunks[2]));
+        return;
+    };
+
+    // Tabs
+    let titles = [TokenInfoTab::Overview, TokenInfoTab::Holders];
+    let selected = titles
+        .iter()
+        .position(|tab| *tab == app.token_info_tab)
+        .unwrap_or(0);
+    let tabs = Tabs::new(titles.iter().map(|tab| tab.title()))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(theme.secondary()),
-        );
-    frame.render_widget(content, main_chunks[1]);
+                .border_style(theme.border()),
+        )
+        .style(theme.normal())
+        .highlight_style(theme.selected())
+        .select(selected);
+    frame.render_widget(tabs, main_chunks[1]);
+
+    match app.token_info_tab {
+        TokenInfoTab::Overview => render_overview(frame, main_chunks[2], token, theme),
+        TokenInfoTab::Holders => render_holders(frame, main_chunks[2], app, token, theme),
+    }
+}
+
+/// Render the Overview tab
+fn render_overview(frame: &mut Frame, area: Rect, token: &TokenInfo, theme: &Theme) {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Name: ", theme.label()),
+            Span::styled(
+                format!("{} ({})", token.name, token.symbol),
+                theme.primary(),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Contract: ", theme.label()),
+            Span::styled(&token.contract_address, theme.address()),
+        ]),
+        Line::from(vec![
+            Span::styled("Decimals: ", theme.label()),
+            Span::styled(token.decimals.to_string(), theme.normal()),
+        ]),
+        Line::from(vec![
+            Span::styled("Balance: ", theme.label()),
+            Span::styled(format!("{:.4}", token.balance), theme.warning()),
+        ]),
+    ];
+    if token.value_usd > 0.0 {
+        lines.push(Line::from(vec![
+            Span::styled("Value: ", theme.label()),
+            Span::styled(format!("${:.2}", token.value_usd), theme.success()),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "Use ←/→ to switch tabs",
+        theme.muted(),
+    )));
+
+    let overview = Paragraph::new(Text::from(lines)).block(
+        Block::default()
+            .title("Overview")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(overview, area);
+}
+
+/// Render the Token Holders tab
+fn render_holders(frame: &mut Frame, area: Rect, app: &App, token: &TokenInfo, theme: &Theme) {
+    let block = Block::default()
+        .title("Top Holders")
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+
+    let message = if app.is_loading("token_holders") {
+        Some(("Loading holders...".to_string(), theme.muted()))
+    } else if let Some(ref error) = app.token_holders_error {
+        Some((error.clone(), theme.warning()))
+    } else if app.token_holders.as_ref().is_none_or(|h| h.is_empty()) {
+        Some(("No holders found".to_string(), theme.muted()))
+    } else {
+        None
+    };
+    if let Some((text, style)) = message {
+        let paragraph = Paragraph::new(text)
+            .style(style)
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+    let holders = app.token_holders.as_deref().unwrap_or_default();
+
+    let header = Row::new(
+        ["Rank", "Address", "Quantity", "Share"]
+            .into_iter()
+            .map(|h| Cell::from(Span::styled(h, theme.label().add_modifier(Modifier::BOLD)))),
+    );
+
+    let rows: Vec<Row> = holders
+        .iter()
+        .enumerate()
+        .map(|(index, holder)| {
+            let mut address = vec![Span::styled(holder.address.clone(), theme.address())];
+            if let Some(label) = KnownAddresses::lookup(&holder.address) {
+                address.push(Span::styled(format!(" ({})", label), theme.info()));
+            }
+            Row::new(vec![
+                Cell::from(format!("#{}", index + 1)),
+                Cell::from(Line::from(address)),
+                Cell::from(format_quantity(holder, token.decimals)),
+                Cell::from(format!("{:.2}%", holder.share)),
+            ])
+            .style(Style::default().fg(theme.foreground))
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(6),
+            Constraint::Min(44),
+            Constraint::Length(24),
+            Constraint::Length(9),
+        ],
+    )
+    .header(header)
+    .block(block);
+    frame.render_widget(table, area);
+}
+
+/// Holder balance scaled by the token decimals
+fn format_quantity(holder: &TokenHolder, decimals: u8) -> String {
+    match U256::from_dec_str(&holder.quantity) {
+        Ok(quantity) => ethers::utils::format_units(quantity, decimals as u32)
+            .ok()
+            .and_then(|units| units.parse::<f64>().ok())
+            .map(|value| format!("{:.4}", value))
+            .unwrap_or_else(|| holder.quantity.clone()),
+        Err(_) => holder.quantity.clone(),
+    }
 }
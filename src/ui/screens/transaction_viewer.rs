@@ -2,14 +2,32 @@
 //!
 //! This module contains the transaction viewer screen implementation.
 
+use crate::blockchain::DecodedLog;
+use crate::blockchain::KnownAddresses;
+use crate::ui::app::utils::{format_address_display, format_eth_value, format_gwei};
+use crate::ui::models::transaction::{TransactionDetails, TransactionTransfer, TransferType};
+use crate::ui::models::TransactionTab;
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph, Wrap},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Tabs, Wrap},
     Frame,
 };
 
+/// Transfers drawn individually in the flow diagram; more are summarized
+const MAX_DIAGRAM_TRANSFERS: usize = 3;
+
+/// Inner width of an address box in the flow diagram
+const FLOW_BOX_WIDTH: usize = 22;
+
+/// Space between address boxes on the same row
+const FLOW_BOX_GAP: usize = 2;
+
+/// Tallest the expanded access list panel grows, borders included
+const MAX_ACCESS_LIST_HEIGHT: u16 = 12;
+
 /// Render the transaction viewer screen
 pub fn render_transaction_viewer(frame: &mut Frame, app: &App, theme: &Theme) {
     let chunks = Layout::default()
@@ -39,18 +57,21 @@ pub fn render_transaction_viewer(frame: &mut Frame, app: &App, theme: &Theme) {
         theme,
         "Enter Transaction Hash:",
         app.get_input(),
-        app.cursor_position,
+        app.cursor_byte_pos,
+        app.cursor_char_pos,
         app.input_mode == crate::ui::InputMode::Editing,
     );
 
     // Content area
     if app.is_loading("transaction_search") {
-        let message = "Loading transaction information...";
-        crate::ui::components::render_loading(frame, chunks[2], theme, message);
-    } else if let Some(ref error) = app.error_message {
+        let message = app
+            .loading_message("transaction_search")
+            .unwrap_or("Loading transaction information...");
+        crate::ui::components::render_loading(frame, chunks[2], theme, message, app.spinner_frame);
+    } else if let Some(error) = app.error_message() {
         let error_text = Text::from(vec![
             Line::from(Span::styled("Error: ", theme.error())),
-            Line::from(error.clone()),
+            Line::from(error),
         ]);
         let error_paragraph = Paragraph::new(error_text)
             .block(
@@ -62,7 +83,29 @@ pub fn render_transaction_viewer(frame: &mut Frame, app: &App, theme: &Theme) {
             .wrap(Wrap { trim: true });
         frame.render_widget(error_paragraph, chunks[2]);
     } else if let Some(ref tx_data) = app.transaction_data {
-        render_transaction_details(frame, chunks[2], tx_data, app, theme);
+        let content_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(chunks[2]);
+        render_transaction_tabs(
+            frame,
+            content_chunks[0],
+            tx_data,
+            app.transaction_tab,
+            theme,
+        );
+        match app.transaction_tab {
+            TransactionTab::Overview => {
+                render_transaction_details(frame, content_chunks[1], tx_data, app, theme)
+            }
+            TransactionTab::Logs => render_logs_table(
+                frame,
+                content_chunks[1],
+                &tx_data.logs,
+                app.current_list_index,
+                theme,
+            ),
+        }
     } else if !app.input.is_empty() {
         let prompt = Paragraph::new("Press Enter to search for transaction")
             .style(theme.muted())
@@ -86,6 +129,127 @@ pub fn render_transaction_viewer(frame: &mut Frame, app: &App, theme: &Theme) {
             );
         frame.render_widget(prompt, chunks[2]);
     }
+
+    // Finality updates arrive as toasts while the transaction is on screen
+    if let Some(ref message) = app.success_message {
+        let area = frame.area();
+        let width = (message.chars().count() as u16 + 4).clamp(30, area.width);
+        let toast = ratatui::layout::Rect {
+            x: area.x + area.width - width,
+            y: area.y + area.height.saturating_sub(5),
+            width,
+            height: 5.min(area.height),
+        };
+        crate::ui::components::render_success(frame, toast, theme, message);
+    }
+}
+
+/// Render the Overview / Logs tab bar
+fn render_transaction_tabs(
+    frame: &mut Frame,
+    area: Rect,
+    tx: &TransactionDetails,
+    current_tab: TransactionTab,
+    theme: &Theme,
+) {
+    let logs_title = format!("{} ({})", TransactionTab::Logs.title(), tx.logs.len());
+    let tabs = Tabs::new(vec![
+        TransactionTab::Overview.title().to_string(),
+        logs_title,
+    ])
+    .block(
+        Block::default()
+            .title("Tab to switch")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    )
+    .style(theme.normal())
+    .highlight_style(theme.selected())
+    .select(match current_tab {
+        TransactionTab::Overview => 0,
+        TransactionTab::Logs => 1,
+    });
+    frame.render_widget(tabs, area);
+}
+
+/// Render the event logs emitted by the transaction
+fn render_logs_table(
+    frame: &mut Frame,
+    area: Rect,
+    logs: &[DecodedLog],
+    selected: usize,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title(format!("Event Logs ({})", logs.len()))
+        .borders(Borders::ALL)
+        .border_style(theme.border());
+    if logs.is_empty() {
+        let empty = Paragraph::new("This transaction emitted no logs")
+            .style(theme.muted())
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(vec!["#", "Contract", "Event", "Parameters"])
+        .style(theme.label())
+        .bottom_margin(1);
+    let rows: Vec<Row> = logs
+        .iter()
+        .enumerate()
+        .map(|(index, log)| {
+            let (event, event_style) = match log.event_name {
+                Some(ref name) => (name.clone(), theme.primary()),
+                None => ("Unknown".to_string(), theme.muted()),
+            };
+            let params = if log.event_name.is_none() && log.decoded_params.is_empty() {
+                format!("data {}", log.raw_data)
+            } else {
+                log.decoded_params
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, log_param_display(value)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            Row::new(vec![
+                Cell::from(index.to_string()),
+                Cell::from(format_address_display(
+                    &log.address,
+                    KnownAddresses::lookup(&log.address),
+                    0,
+                ))
+                .style(theme.address()),
+                Cell::from(event).style(event_style),
+                Cell::from(params).style(theme.normal()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(4),
+            Constraint::Length(22),
+            Constraint::Length(22),
+            Constraint::Min(20),
+        ],
+    )
+    .header(header)
+    .block(block)
+    .highlight_style(theme.selected());
+    let mut state = TableState::default().with_selected(Some(selected.min(logs.len() - 1)));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+/// Shorten 32-byte values and addresses in the parameter column
+fn log_param_display(value: &str) -> String {
+    if value.starts_with("0x") && value.len() >= 42 {
+        format_address_display(value, KnownAddresses::lookup(value), 0)
+    } else {
+        value.to_string()
+    }
 }
 
 /// Render detailed transaction information
@@ -98,16 +262,21 @@ fn render_transaction_details(
 ) {
     use crate::ui::models::TransactionStatus;
 
+    let details_lines = additional_details_lines(tx, app, theme);
+    let details_height = details_lines.len() as u16 + 2;
+    // Room for the Flashbots bundle banner below the status
+    let status_height = if tx.mev_bundle.is_some() { 4 } else { 3 };
+
     // Split area into sections
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Status and hash
-            Constraint::Length(4), // Overview
-            Constraint::Length(4), // Gas info
-            Constraint::Length(6), // Additional details
-            Constraint::Min(8),    // Transfers section (needs more space for detailed view)
-            Constraint::Min(3),    // Input data (expandable)
+            Constraint::Length(status_height),  // Status and hash
+            Constraint::Length(4),              // Overview
+            Constraint::Length(4),              // Gas info
+            Constraint::Length(details_height), // Additional details
+            Constraint::Min(8), // Transfers section (needs more space for detailed view)
+            Constraint::Min(3), // Input data (expandable)
         ])
         .split(area);
 
@@ -118,15 +287,60 @@ fn render_transaction_details(
         TransactionStatus::Pending => ("⏳ Pending", theme.warning()),
     };
 
-    let status_line = Line::from(vec![
+    let mut status_spans = vec![
         Span::styled("Status: ", theme.label()),
         Span::styled(status_text.0, status_text.1),
         Span::raw(" | "),
         Span::styled("Hash: ", theme.label()),
         Span::styled(&tx.hash, theme.primary()),
-    ]);
+    ];
+
+    // Heuristic MEV hint (informational only)
+    if let Some(pattern) = crate::blockchain::detect_mev_pattern(tx, &tx.transfers) {
+        status_spans.push(Span::raw(" "));
+        status_spans.push(Span::styled(
+            format!(
+                " ⚡ MEV: {} (heuristic, {:.0}%) ",
+                pattern.kind.label(),
+                pattern.confidence * 100.0
+            ),
+            ratatui::style::Style::default()
+                .fg(theme.background)
+                .bg(theme.warning)
+                .add_modifier(ratatui::style::Modifier::BOLD),
+        ));
+    }
+
+    let mut status_lines = vec![Line::from(status_spans)];
+
+    if let Some(bundle) = &tx.mev_bundle {
+        status_lines.push(Line::from(vec![
+            Span::styled(
+                " 🤖 MEV Bundle detected ",
+                ratatui::style::Style::default()
+                    .fg(theme.background)
+                    .bg(theme.accent)
+                    .add_modifier(ratatui::style::Modifier::BOLD),
+            ),
+            Span::raw(" "),
+            Span::styled("Bundle: ", theme.label()),
+            Span::styled(&bundle.bundle_id, theme.primary()),
+            Span::raw(" | "),
+            Span::styled("Coinbase transfer: ", theme.label()),
+            Span::styled(
+                format!("{:.6} ETH", bundle.coinbase_transfer),
+                theme.amount(),
+            ),
+            Span::raw(" | "),
+            Span::styled("Miner reward: ", theme.label()),
+            Span::styled(
+                format!("{:.6} ETH", bundle.total_miner_reward),
+                theme.amount(),
+            ),
+        ]));
+    }
 
-    let status_block = Paragraph::new(status_line).block(
+    let status_block = Paragraph::new(status_lines).block(
         Block::default()
             .title("Transaction Status")
             .borders(Borders::ALL)
@@ -153,7 +367,7 @@ fn render_transaction_details(
         ]),
         Line::from(vec![
             Span::styled("Value: ", theme.label()),
-            Span::styled(format!("{:.6} ETH", tx.value), theme.warning()),
+            Span::styled(format_eth_value(tx.value), theme.warning()),
             Span::raw(" | "),
             Span::styled("Block: ", theme.label()),
             Span::styled(tx.block_number.to_string(), theme.normal()),
@@ -195,10 +409,10 @@ fn render_transaction_details(
         ]),
         Line::from(vec![
             Span::styled("Gas Price: ", theme.label()),
-            Span::styled(format!("{} gwei", tx.gas_price), theme.normal()),
+            Span::styled(format_gwei(tx.gas_price), theme.normal()),
             Span::raw(" | "),
             Span::styled("Transaction Fee: ", theme.label()),
-            Span::styled(format!("{:.6} ETH", tx.transaction_fee), theme.warning()),
+            Span::styled(format_eth_value(tx.transaction_fee), theme.warning()),
         ]),
     ];
 
@@ -210,12 +424,75 @@ fn render_transaction_details(
     );
     frame.render_widget(gas_block, chunks[2]);
 
-    // Additional Details section
+    let details_block = Paragraph::new(Text::from(details_lines))
+        .block(
+            Block::default()
+                .title("Additional Details")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        )
+        .wrap(Wrap { trim: true });
+    frame.render_widget(details_block, chunks[3]);
+
+    // Transfers section, with the access list below it when there is one
+    let access_list_height = match (tx.access_list.is_empty(), app.tx_access_list_expanded) {
+        (true, _) => 0,
+        (false, false) => 3,
+        (false, true) => {
+            let rows = tx
+                .access_list
+                .iter()
+                .map(|entry| entry.storage_keys.len() + 1)
+                .sum::<usize>();
+            (rows as u16 + 2).min(MAX_ACCESS_LIST_HEIGHT)
+        }
+    };
+    let transfer_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(access_list_height)])
+        .split(chunks[4]);
+
+    // Transfers, as a list or a flow diagram
+    if app.tx_diagram_mode {
+        render_transfer_flow_diagram(frame, transfer_chunks[0], tx, theme);
+    } else {
+        render_transfers_section(frame, transfer_chunks[0], tx, theme);
+    }
+    if access_list_height > 0 {
+        render_access_list_section(frame, transfer_chunks[1], tx, app, theme);
+    }
+
+    // Input Data section (expandable)
+    render_input_data_section(frame, chunks[5], tx, app, theme);
+}
+
+/// Lines of the Additional Details panel
+fn additional_details_lines<'a>(
+    tx: &'a TransactionDetails,
+    app: &App,
+    theme: &Theme,
+) -> Vec<Line<'a>> {
     let timestamp_str = chrono::DateTime::from_timestamp(tx.timestamp as i64, 0)
         .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
         .unwrap_or_else(|| "Unknown".to_string());
 
-    let method_display = tx.method.as_deref().unwrap_or("N/A");
+    // Unknown selectors fall back to their raw hex
+    let method_display = match tx.method.as_deref() {
+        Some(method) => method,
+        None => tx
+            .input_data
+            .get(..10)
+            .filter(|selector| selector.starts_with("0x"))
+            .unwrap_or("N/A"),
+    };
+
+    // Post-Merge finality beats a raw count once the block is safe
+    let confirmations = app
+        .block_finality
+        .filter(|_| tx.block_number > 0)
+        .and_then(|finality| finality.label(tx.block_number))
+        .map(str::to_string)
+        .unwrap_or_else(|| tx.confirmations.to_string());
 
     let details_lines = vec![
         Line::from(vec![
@@ -223,7 +500,7 @@ fn render_transaction_details(
             Span::styled(timestamp_str, theme.normal()),
             Span::raw(" | "),
             Span::styled("Confirmations: ", theme.label()),
-            Span::styled(tx.confirmations.to_string(), theme.success()),
+            Span::styled(confirmations, theme.success()),
         ]),
         Line::from(vec![
             Span::styled("Nonce: ", theme.label()),
@@ -243,8 +520,25 @@ fn render_transaction_details(
         ]),
     ];
 
-    // Add contract address if this is a contract creation
     let mut final_lines = details_lines;
+    final_lines.push(Line::from(vec![
+        Span::styled("Type: ", theme.label()),
+        Span::styled(tx.tx_type_label(), theme.normal()),
+    ]));
+    if let Some(max_fee) = tx.max_fee_per_gas {
+        final_lines.push(Line::from(vec![
+            Span::styled("Max Fee: ", theme.label()),
+            Span::styled(format_gwei(max_fee), theme.normal()),
+        ]));
+    }
+    if let Some(priority_fee) = tx.max_priority_fee_per_gas {
+        final_lines.push(Line::from(vec![
+            Span::styled("Max Priority Fee: ", theme.label()),
+            Span::styled(format_gwei(priority_fee), theme.normal()),
+        ]));
+    }
+
+    // Add contract address if this is a contract creation
     if let Some(ref contract_addr) = tx.contract_address {
         final_lines.push(Line::from(vec![
             Span::styled("Contract Created: ", theme.label()),
@@ -252,21 +546,61 @@ fn render_transaction_details(
         ]));
     }
 
-    let details_block = Paragraph::new(Text::from(final_lines))
-        .block(
-            Block::default()
-                .title("Additional Details")
-                .borders(Borders::ALL)
-                .border_style(theme.border()),
-        )
-        .wrap(Wrap { trim: true });
-    frame.render_widget(details_block, chunks[3]);
+    final_lines
+}
 
-    // Transfers section
-    render_transfers_section(frame, chunks[4], tx, theme);
+/// Access list of the transaction, listing every slot when expanded
+fn render_access_list_section(
+    frame: &mut Frame,
+    area: Rect,
+    tx: &TransactionDetails,
+    app: &App,
+    theme: &Theme,
+) {
+    let slot_count: usize = tx
+        .access_list
+        .iter()
+        .map(|entry| entry.storage_keys.len())
+        .sum();
+    let lines: Vec<Line> = if app.tx_access_list_expanded {
+        tx.access_list
+            .iter()
+            .flat_map(|entry| {
+                std::iter::once(Line::from(Span::styled(
+                    entry.address.clone(),
+                    theme.info(),
+                )))
+                .chain(entry.storage_keys.iter().map(|key| {
+                    Line::from(vec![
+                        Span::styled("  Slot: ", theme.label()),
+                        Span::styled(key.clone(), theme.muted()),
+                    ])
+                }))
+            })
+            .collect()
+    } else {
+        vec![Line::from(Span::styled(
+            format!(
+                "{} addresses, {} storage slots",
+                tx.access_list.len(),
+                slot_count
+            ),
+            theme.normal(),
+        ))]
+    };
 
-    // Input Data section (expandable)
-    render_input_data_section(frame, chunks[5], tx, app, theme);
+    let hint = if app.tx_access_list_expanded {
+        "a: collapse"
+    } else {
+        "a: expand"
+    };
+    let access_list = Paragraph::new(Text::from(lines)).block(
+        Block::default()
+            .title(format!("Access List ({})", hint))
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(access_list, area);
 }
 
 /// Render expandable input data section
@@ -340,6 +674,16 @@ fn render_transfers_section(
     // Build transfer lines
     let mut transfer_lines = Vec::new();
 
+    // Swap summary first, its two sides marked in the list below
+    let swap = crate::blockchain::DeFiDecoder::detect_transaction_swap(tx);
+    if let Some(ref swap) = swap {
+        transfer_lines.push(Line::from(vec![Span::styled(
+            format!("⇄ {}", swap.description()),
+            theme.success().add_modifier(ratatui::style::Modifier::BOLD),
+        )]));
+        transfer_lines.push(Line::from(vec![Span::raw("")]));
+    }
+
     // Add header
     transfer_lines.push(Line::from(vec![Span::styled(
         "All Transfers:",
@@ -381,13 +725,21 @@ fn render_transfers_section(
             };
 
             // Full address display with all details
-            transfer_lines.push(Line::from(vec![
+            let mut header_spans = vec![
                 Span::styled(format!("  {}. ", idx + 1), theme.muted()),
                 Span::styled(
                     type_text,
                     theme.primary().add_modifier(ratatui::style::Modifier::BOLD),
                 ),
-            ]));
+            ];
+            if let Some(ref swap) = swap {
+                if swap.token_in.transfers.contains(&idx) {
+                    header_spans.push(Span::styled("  [swap in]", theme.warning()));
+                } else if swap.token_out.transfers.contains(&idx) {
+                    header_spans.push(Span::styled("  [swap out]", theme.success()));
+                }
+            }
+            transfer_lines.push(Line::from(header_spans));
             transfer_lines.push(Line::from(vec![
                 Span::raw("     From: "),
                 Span::styled(&transfer.from, theme.info()),
@@ -505,7 +857,7 @@ fn render_transfers_section(
     let transfers_block = Paragraph::new(Text::from(transfer_lines))
         .block(
             Block::default()
-                .title("Transfers & Net Transfers")
+                .title("Transfers & Net Transfers (d: flow diagram)")
                 .borders(Borders::ALL)
                 .border_style(theme.border()),
         )
@@ -513,3 +865,224 @@ fn render_transfers_section(
 
     frame.render_widget(transfers_block, area);
 }
+
+/// Render the transfers as a box-and-arrow flow diagram
+///
+/// Up to three transfers are drawn individually, grouped by sender. Busier
+/// transactions such as multi-hop swaps are summarized as net flows per address.
+pub fn render_transfer_flow_diagram(
+    frame: &mut Frame,
+    area: Rect,
+    tx: &TransactionDetails,
+    theme: &Theme,
+) {
+    let lines = if tx.transfers.is_empty() {
+        vec![Line::styled("  No transfers found", theme.muted())]
+    } else if tx.transfers.len() > MAX_DIAGRAM_TRANSFERS {
+        flow_overview_lines(tx, theme)
+    } else {
+        flow_diagram_lines(tx, theme)
+    };
+
+    let diagram = Paragraph::new(Text::from(lines)).block(
+        Block::default()
+            .title("Transfer Flow (d: list view)")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(diagram, area);
+}
+
+/// One FROM box per sender with arrows down to a row of TO boxes
+fn flow_diagram_lines(tx: &TransactionDetails, theme: &Theme) -> Vec<Line<'static>> {
+    // Group transfers by sender, keeping the order they occurred in
+    let mut groups: Vec<(&str, Vec<&TransactionTransfer>)> = Vec::new();
+    for transfer in &tx.transfers {
+        match groups
+            .iter_mut()
+            .find(|(from, _)| from.eq_ignore_ascii_case(&transfer.from))
+        {
+            Some((_, transfers)) => transfers.push(transfer),
+            None => groups.push((&transfer.from, vec![transfer])),
+        }
+    }
+
+    let column_width = FLOW_BOX_WIDTH + 4 + FLOW_BOX_GAP;
+    let mut lines = Vec::new();
+    for (from, transfers) in groups {
+        if !lines.is_empty() {
+            lines.push(Line::raw(""));
+        }
+
+        lines.extend(address_box(
+            &format!("FROM {}", flow_box_label(from)),
+            theme.info(),
+            theme,
+        ));
+
+        let arrows: Vec<Span> = transfers
+            .iter()
+            .map(|_| Span::styled(fit("  │", column_width), theme.border()))
+            .collect();
+        lines.push(Line::from(arrows));
+        let labels: Vec<Span> = transfers
+            .iter()
+            .flat_map(|transfer| {
+                [
+                    Span::styled("  ↓ ", theme.border()),
+                    Span::styled(
+                        fit(&transfer_amount(transfer), column_width - 4),
+                        transfer_style(&transfer.transfer_type, theme),
+                    ),
+                ]
+            })
+            .collect();
+        lines.push(Line::from(labels));
+
+        // Recipient boxes side by side
+        let border = "─".repeat(FLOW_BOX_WIDTH + 2);
+        let gap = " ".repeat(FLOW_BOX_GAP);
+        let top = format!("┌{}┐{}", border, gap).repeat(transfers.len());
+        let bottom = format!("└{}┘{}", border, gap).repeat(transfers.len());
+        let middle: Vec<Span> = transfers
+            .iter()
+            .flat_map(|transfer| {
+                [
+                    Span::styled("│ ", theme.border()),
+                    Span::styled(
+                        fit(
+                            &format!("TO {}", flow_box_label(&transfer.to)),
+                            FLOW_BOX_WIDTH,
+                        ),
+                        theme.info(),
+                    ),
+                    Span::styled(format!(" │{}", gap), theme.border()),
+                ]
+            })
+            .collect();
+        lines.push(Line::styled(top, theme.border()));
+        lines.push(Line::from(middle));
+        lines.push(Line::styled(bottom, theme.border()));
+    }
+    lines
+}
+
+/// Net amount of each asset gained or lost per address, one box per address
+fn flow_overview_lines(tx: &TransactionDetails, theme: &Theme) -> Vec<Line<'static>> {
+    // (address, [(asset, net amount)]) in order of first appearance
+    let mut net_flows: Vec<(String, Vec<(String, f64)>)> = Vec::new();
+    let mut add_flow = |address: &str, asset: &str, amount: f64| {
+        let index = match net_flows
+            .iter()
+            .position(|(a, _)| a.eq_ignore_ascii_case(address))
+        {
+            Some(index) => index,
+            None => {
+                net_flows.push((address.to_string(), Vec::new()));
+                net_flows.len() - 1
+            }
+        };
+        let flows = &mut net_flows[index].1;
+        match flows.iter_mut().find(|(a, _)| a == asset) {
+            Some((_, total)) => *total += amount,
+            None => flows.push((asset.to_string(), amount)),
+        }
+    };
+    for transfer in &tx.transfers {
+        let asset = transfer.token_symbol.as_deref().unwrap_or("ETH");
+        add_flow(&transfer.from, asset, -transfer.value);
+        add_flow(&transfer.to, asset, transfer.value);
+    }
+
+    let mut lines = vec![
+        Line::styled(
+            format!(
+                "Overview: {} transfers between {} addresses (net flows)",
+                tx.transfers.len(),
+                net_flows.len()
+            ),
+            theme.label(),
+        ),
+        Line::raw(""),
+    ];
+    let border = "─".repeat(FLOW_BOX_WIDTH + 2);
+    for (address, flows) in &net_flows {
+        let mut middle = vec![
+            Span::styled("│ ", theme.border()),
+            Span::styled(fit(&flow_box_label(address), FLOW_BOX_WIDTH), theme.info()),
+            Span::styled(" │ ", theme.border()),
+        ];
+        let changes: Vec<&(String, f64)> = flows
+            .iter()
+            .filter(|(_, amount)| amount.abs() > f64::EPSILON)
+            .collect();
+        if changes.is_empty() {
+            middle.push(Span::styled(" no net change", theme.muted()));
+        }
+        for (asset, amount) in changes {
+            let (arrow, style) = if *amount > 0.0 {
+                ("←", theme.success())
+            } else {
+                ("→", theme.error())
+            };
+            middle.push(Span::styled(
+                format!(" {} {:+.4} {}", arrow, amount, asset),
+                style,
+            ));
+        }
+
+        lines.push(Line::styled(format!("┌{}┐", border), theme.border()));
+        lines.push(Line::from(middle));
+        lines.push(Line::styled(format!("└{}┘", border), theme.border()));
+    }
+    lines
+}
+
+/// A single address box holding one line of text
+fn address_box(text: &str, style: Style, theme: &Theme) -> Vec<Line<'static>> {
+    let border = "─".repeat(FLOW_BOX_WIDTH + 2);
+    vec![
+        Line::styled(format!("┌{}┐", border), theme.border()),
+        Line::from(vec![
+            Span::styled("│ ", theme.border()),
+            Span::styled(fit(text, FLOW_BOX_WIDTH), style),
+            Span::styled(" │", theme.border()),
+        ]),
+        Line::styled(format!("└{}┘", border), theme.border()),
+    ]
+}
+
+/// Known label or shortened form of an address
+fn flow_box_label(address: &str) -> String {
+    format_address_display(address, KnownAddresses::lookup(address), FLOW_BOX_WIDTH - 5)
+}
+
+/// Amount and asset of a transfer, e.g. `1.5000 USDC`
+fn transfer_amount(transfer: &TransactionTransfer) -> String {
+    format!(
+        "{:.4} {}",
+        transfer.value,
+        transfer.token_symbol.as_deref().unwrap_or("ETH")
+    )
+}
+
+/// Color of a transfer amount by kind
+fn transfer_style(transfer_type: &TransferType, theme: &Theme) -> Style {
+    match transfer_type {
+        TransferType::ETH => theme.success(),
+        TransferType::Token => theme.primary(),
+        TransferType::Internal => theme.warning(),
+    }
+}
+
+/// Pad `text` to exactly `width` characters, truncating with an ellipsis
+fn fit(text: &str, width: usize) -> String {
+    let count = text.chars().count();
+    if count > width {
+        let mut truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+        truncated.push('…');
+        truncated
+    } else {
+        format!("{}{}", text, " ".repeat(width - count))
+    }
+}
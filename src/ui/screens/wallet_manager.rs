@@ -1,8 +1,9 @@
+use crate::ui::models::MNEMONIC_WORD_COUNTS;
 use crate::ui::{app::App, theme::Theme};
 use ratatui::{
-    layout::{Alignment, Constraint, Direction, Layout},
-    text::Text,
-    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Tabs, Wrap},
     Frame,
 };
 
@@ -41,10 +42,14 @@ pub fn render_wallet_manager(frame: &mut Frame, app: &App, theme: &Theme) {
         .highlight_style(theme.tab_active());
     frame.render_widget(tabs, chunks[1]);
 
+    if app.current_tab == crate::ui::app::wallet::WALLET_GENERATE_TAB {
+        render_generate_tab(frame, chunks[2], app, theme);
+        return;
+    }
+
     // Content based on selected tab
     let content = match app.current_tab {
         0 => Text::from("Wallet list will be displayed here"),
-        1 => Text::from("Wallet generation interface will be displayed here"),
         2 => Text::from("Wallet import interface will be displayed here"),
         _ => Text::from("Unknown tab"),
     };
@@ -60,3 +65,113 @@ pub fn render_wallet_manager(frame: &mut Frame, app: &App, theme: &Theme) {
 
     frame.render_widget(content_paragraph, chunks[2]);
 }
+
+/// Word count selector, Generate/Save buttons, mnemonic and derived accounts
+fn render_generate_tab(frame: &mut Frame, area: Rect, app: &App, theme: &Theme) {
+    let state = &app.wallet_generate;
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5), // Controls
+            Constraint::Length(6), // Mnemonic
+            Constraint::Min(0),    // Derived addresses
+        ])
+        .split(area);
+
+    let focus = |row: usize| {
+        if app.current_list_index == row {
+            theme.selected()
+        } else {
+            theme.normal()
+        }
+    };
+    let mut word_counts = vec![Span::styled("Words: ", focus(0))];
+    for count in MNEMONIC_WORD_COUNTS {
+        let style = if count == state.word_count {
+            theme.tab_active()
+        } else {
+            theme.muted()
+        };
+        word_counts.push(Span::styled(format!(" {} ", count), style));
+    }
+    let mut buttons = vec![Span::styled("[ Generate ]", focus(1))];
+    if state.mnemonic.is_some() {
+        buttons.push(Span::raw("  "));
+        buttons.push(Span::styled("[ Save encrypted keystore ]", focus(2)));
+    }
+    let status = if state.password_prompt && app.input_mode == crate::ui::InputMode::Editing {
+        // The password is masked; only its length is shown
+        Line::from(vec![
+            Span::styled("Keystore password: ", theme.label()),
+            Span::styled("•".repeat(app.input.chars().count()), theme.input_active()),
+        ])
+    } else if let Some(error) = app.error_message() {
+        Line::from(Span::styled(error, theme.error()))
+    } else if let Some(ref path) = state.keystore_path {
+        Line::from(Span::styled(
+            format!("Keystore saved to {}", path.display()),
+            theme.success(),
+        ))
+    } else {
+        Line::from(Span::styled(
+            "↑/↓ select, Enter toggle/press",
+            theme.muted(),
+        ))
+    };
+    frame.render_widget(
+        Paragraph::new(vec![Line::from(word_counts), Line::from(buttons), status]).block(
+            Block::default()
+                .title("Generate HD Wallet")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        ),
+        chunks[0],
+    );
+
+    let mnemonic = match state.mnemonic {
+        Some(ref phrase) => vec![
+            Line::from(Span::styled(
+                "⚠ Write this phrase down and keep it offline. It is never saved to disk.",
+                theme.warning(),
+            )),
+            Line::from(Span::styled(phrase.clone(), theme.normal())),
+        ],
+        None => vec![Line::from(Span::styled(
+            "No wallet generated yet",
+            theme.muted(),
+        ))],
+    };
+    frame.render_widget(
+        Paragraph::new(mnemonic).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .title("Mnemonic")
+                .borders(Borders::ALL)
+                .border_style(theme.border()),
+        ),
+        chunks[1],
+    );
+
+    let rows = state.addresses.iter().map(|account| {
+        Row::new(vec![
+            Cell::from(account.index.to_string()),
+            Cell::from(Span::styled(account.path.clone(), theme.muted())),
+            Cell::from(Span::styled(account.address.clone(), theme.address())),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(6),
+            Constraint::Length(18),
+            Constraint::Min(42),
+        ],
+    )
+    .header(Row::new(vec!["Index", "Path", "Address"]).style(theme.label()))
+    .block(
+        Block::default()
+            .title("Derived Addresses")
+            .borders(Borders::ALL)
+            .border_style(theme.border()),
+    );
+    frame.render_widget(table, chunks[2]);
+}
@@ -74,6 +74,42 @@ impl Theme {
         }
     }
 
+    /// Create a high contrast theme: pure black and white with bright accents
+    pub fn high_contrast() -> Self {
+        Self {
+            primary: Color::White,
+            secondary: Color::LightCyan,
+            accent: Color::LightMagenta,
+            background: Color::Black,
+            foreground: Color::White,
+            success: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            info: Color::LightCyan,
+            muted: Color::Gray,
+            border: Color::White,
+            selected: Color::LightYellow,
+        }
+    }
+
+    /// Create a dark purple theme based on the Dracula palette
+    pub fn dracula() -> Self {
+        Self {
+            primary: Color::Rgb(189, 147, 249),    // Purple
+            secondary: Color::Rgb(255, 121, 198),  // Pink
+            accent: Color::Rgb(139, 233, 253),     // Cyan
+            background: Color::Rgb(40, 42, 54),    // Background
+            foreground: Color::Rgb(248, 248, 242), // Foreground
+            success: Color::Rgb(80, 250, 123),     // Green
+            warning: Color::Rgb(255, 184, 108),    // Orange
+            error: Color::Rgb(255, 85, 85),        // Red
+            info: Color::Rgb(139, 233, 253),       // Cyan
+            muted: Color::Rgb(98, 114, 164),       // Comment
+            border: Color::Rgb(68, 71, 90),        // Current line
+            selected: Color::Rgb(241, 250, 140),   // Yellow
+        }
+    }
+
     /// Create a custom theme with Ethereum-inspired colors
     pub fn ethereum() -> Self {
         Self {
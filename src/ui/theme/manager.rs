@@ -1,32 +1,64 @@
 //! Theme manager for handling theme switching
 
 use super::colors::Theme;
+use crate::config::UiConfig;
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+
+/// Name of the theme used when the configured one is unknown
+pub const DEFAULT_THEME: &str = "dark";
 
 /// Theme manager for handling theme switching
 pub struct ThemeManager {
     current_theme: Theme,
-    available_themes: Vec<(&'static str, Theme)>,
+    current_name: String,
+    themes: HashMap<String, Theme>,
+    /// Theme names in display order
+    names: Vec<String>,
 }
 
 impl Default for ThemeManager {
     fn default() -> Self {
-        Self::new()
+        Self::with_theme(DEFAULT_THEME)
     }
 }
 
 impl ThemeManager {
-    /// Create a new theme manager
-    pub fn new() -> Self {
-        let available_themes = vec![
-            ("Dark", Theme::dark()),
-            ("Light", Theme::light()),
-            ("Ethereum", Theme::ethereum()),
+    /// Create a new theme manager using the theme named in the UI config
+    pub fn new(config: &UiConfig) -> Self {
+        Self::with_theme(&config.theme)
+    }
+
+    /// Create a theme manager starting with `name`, falling back to the default theme
+    fn with_theme(name: &str) -> Self {
+        let builtin = [
+            (DEFAULT_THEME, Theme::dark()),
+            ("light", Theme::light()),
+            ("high_contrast", Theme::high_contrast()),
+            ("dracula", Theme::dracula()),
+            ("ethereum", Theme::ethereum()),
         ];
 
-        Self {
+        let names = builtin.iter().map(|(n, _)| n.to_string()).collect();
+        let themes = builtin
+            .into_iter()
+            .map(|(n, theme)| (n.to_string(), theme))
+            .collect();
+
+        let mut manager = Self {
             current_theme: Theme::default(),
-            available_themes,
-        }
+            current_name: DEFAULT_THEME.to_string(),
+            themes,
+            names,
+        };
+        // Older configs called the dark theme "default"
+        let name = if name == "default" {
+            DEFAULT_THEME
+        } else {
+            name
+        };
+        let _ = manager.set_theme(name);
+        manager
     }
 
     /// Get the current theme
@@ -34,36 +66,48 @@ impl ThemeManager {
         &self.current_theme
     }
 
+    /// Get the name of the current theme
+    pub fn current_name(&self) -> &str {
+        &self.current_name
+    }
+
     /// Set the current theme by name
-    pub fn set_theme(&mut self, name: &str) -> bool {
-        if let Some((_, theme)) = self.available_themes.iter().find(|(n, _)| *n == name) {
-            self.current_theme = theme.clone();
-            true
-        } else {
-            false
-        }
+    pub fn set_theme(&mut self, name: &str) -> Result<()> {
+        let theme = self
+            .themes
+            .get(name)
+            .ok_or_else(|| Error::ui(format!("Unknown theme: {}", name)))?;
+        self.current_theme = theme.clone();
+        self.current_name = name.to_string();
+        Ok(())
     }
 
-    /// Get available theme names
-    pub fn available_themes(&self) -> Vec<&'static str> {
-        self.available_themes
-            .iter()
-            .map(|(name, _)| *name)
-            .collect()
+    /// Get available theme names, in display order
+    pub fn list_themes(&self) -> &[String] {
+        &self.names
     }
 
-    /// Cycle to the next theme
+    /// Switch to the next theme, wrapping around to the first
     pub fn next_theme(&mut self) {
+        self.cycle_theme(true);
+    }
+
+    /// Cycle to the next (or previous) theme and return its name
+    pub fn cycle_theme(&mut self, forward: bool) -> String {
+        let len = self.names.len();
         let current_index = self
-            .available_themes
+            .names
             .iter()
-            .position(|(_, theme)| {
-                // Simple comparison by checking primary color
-                theme.primary == self.current_theme.primary
-            })
+            .position(|n| *n == self.current_name)
             .unwrap_or(0);
+        let next_index = if forward {
+            (current_index + 1) % len
+        } else {
+            (current_index + len - 1) % len
+        };
 
-        let next_index = (current_index + 1) % self.available_themes.len();
-        self.current_theme = self.available_themes[next_index].1.clone();
+        let name = self.names[next_index].clone();
+        let _ = self.set_theme(&name);
+        name
     }
 }
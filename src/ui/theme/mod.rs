@@ -6,4 +6,4 @@ pub mod styles;
 
 // Re-export commonly used types and structs
 pub use colors::Theme;
-pub use manager::ThemeManager;
+pub use manager::{ThemeManager, DEFAULT_THEME};
@@ -3,16 +3,27 @@
 //! This module provides functionality for generating and managing test wallets
 //! for contract interaction and testing purposes.
 
+use crate::config::Config;
 use crate::error::{Error, Result};
 use bip39::{Language, Mnemonic};
 use ethers::{
-    signers::{LocalWallet, Signer},
+    signers::{
+        coins_bip39::{English, Mnemonic as HdMnemonic},
+        LocalWallet, MnemonicBuilder, Signer,
+    },
     types::{Address, Signature, U256},
 };
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::str::FromStr;
 
+/// Standard Ethereum derivation path, completed with the account index
+pub const ETHEREUM_DERIVATION_PATH: &str = "m/44'/60'/0'/0/";
+
+/// Number of accounts derived from a generated HD wallet
+pub const HD_DERIVED_ACCOUNTS: u32 = 5;
+
 /// Wallet information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletInfo {
@@ -22,6 +33,15 @@ pub struct WalletInfo {
     pub name: Option<String>,
 }
 
+/// An account derived from an HD wallet mnemonic
+#[derive(Debug, Clone)]
+pub struct DerivedAddress {
+    pub index: u32,
+    /// Full derivation path, e.g. `m/44'/60'/0'/0/0`
+    pub path: String,
+    pub address: String,
+}
+
 /// Wallet balance information
 #[derive(Debug, Clone)]
 pub struct WalletBalance {
@@ -118,6 +138,59 @@ impl WalletManager {
         Ok((wallet_info, wallet))
     }
 
+    /// Generate an HD wallet mnemonic of `mnemonic_words` words (12 or 24)
+    /// and derive its first accounts on the standard Ethereum path
+    pub fn generate_hd_wallet(
+        mnemonic_words: usize,
+    ) -> Result<(HdMnemonic<English>, Vec<DerivedAddress>)> {
+        let mnemonic =
+            HdMnemonic::<English>::new_with_count(&mut rand::thread_rng(), mnemonic_words)
+                .map_err(|e| Error::wallet(format!("Failed to generate mnemonic: {}", e)))?;
+        let phrase = mnemonic.to_phrase();
+
+        let addresses = (0..HD_DERIVED_ACCOUNTS)
+            .map(|index| {
+                let wallet = Self::derive_hd_account(&phrase, index)?;
+                Ok(DerivedAddress {
+                    index,
+                    path: format!("{}{}", ETHEREUM_DERIVATION_PATH, index),
+                    address: format!("{:?}", wallet.address()),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((mnemonic, addresses))
+    }
+
+    /// Derive account `index` of a mnemonic on the standard Ethereum path
+    pub fn derive_hd_account(phrase: &str, index: u32) -> Result<LocalWallet> {
+        MnemonicBuilder::<English>::default()
+            .phrase(phrase)
+            .derivation_path(&format!("{}{}", ETHEREUM_DERIVATION_PATH, index))
+            .and_then(|builder| builder.build())
+            .map_err(|e| Error::wallet(format!("Failed to derive account {}: {}", index, e)))
+    }
+
+    /// Encrypt the wallet's key into a keystore file under `Config::data_dir()`
+    ///
+    /// Only the encrypted keystore is written; the raw key never touches disk.
+    pub fn save_encrypted_keystore(wallet: &LocalWallet, password: &str) -> Result<PathBuf> {
+        let dir = Config::data_dir()?.join("keystores");
+        std::fs::create_dir_all(&dir)?;
+
+        let name = format!("{:?}.json", wallet.address());
+        LocalWallet::encrypt_keystore(
+            &dir,
+            &mut rand::thread_rng(),
+            wallet.signer().to_bytes(),
+            password,
+            Some(&name),
+        )
+        .map_err(|e| Error::wallet(format!("Failed to encrypt keystore: {}", e)))?;
+
+        Ok(dir.join(name))
+    }
+
     /// Import wallet from private key
     pub fn import_wallet_from_private_key(
         &mut self,